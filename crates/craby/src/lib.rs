@@ -13,3 +13,6 @@ pub mod types;
 
 // craby_marco crate
 pub use craby_macro;
+
+// Used by the generated code to log through the logger of the app (eg. `android_logger`, `oslog`)
+pub use log;
@@ -1,9 +1,15 @@
 pub type Boolean = bool;
 pub type Number = f64;
+pub type Int32 = i32;
+pub type Int64 = i64;
+/// `bigint` of JavaScript (within the range of `i64`).
+pub type BigInt = i64;
 pub type String = std::string::String;
 pub type ArrayBuffer = std::vec::Vec<u8>;
 pub type Array<T> = std::vec::Vec<T>;
-pub type Promise<T> = std::result::Result<T, anyhow::Error>;
+pub type Map<T> = std::collections::HashMap<String, T>;
+/// Promise rejected with `E` (eg. the structured error type of `@throws` methods).
+pub type Promise<T, E = anyhow::Error> = std::result::Result<T, E>;
 pub type Void = ();
 
 /// JavaScript-like Promise utilities.
@@ -16,6 +22,12 @@ pub mod promise {
         Ok(val)
     }
 
+    /// Resolves a Promise rejected with the custom error type `E` (eg. the error type of `@throws` methods).
+    /// Same as `Ok(v)`.
+    pub fn resolve_with<T, E>(val: T) -> Promise<T, E> {
+        Ok(val)
+    }
+
     /// Rejects a Promise with an error message.
     /// Same as `Err(e)`.
     pub fn reject<T>(err: impl AsRef<str>) -> Promise<T> {
@@ -68,3 +80,46 @@ impl<T> Nullable<T> {
         self.val
     }
 }
+
+/// Cancellation flag of the `@cancellable` Promise methods.
+///
+/// The flag is set when JavaScript calls `cancel()` on the returned Promise.
+/// Rust implementations should poll it and stop the work as early as possible.
+///
+/// ```typescript
+/// const promise = MyModule.longTask(10);
+/// (promise as CancellablePromise<number>).cancel();
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new `CancellationToken`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the token as cancelled.
+    pub fn cancel(&self) {
+        self.cancelled
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Returns an error if the token has been cancelled.
+    ///
+    /// Useful with `?` operator in the Promise methods.
+    pub fn check(&self) -> Result<(), anyhow::Error> {
+        if self.is_cancelled() {
+            anyhow::bail!("Cancelled");
+        }
+        Ok(())
+    }
+}
@@ -1,11 +1,18 @@
-use std::{path::Path, process::Command};
+use std::{fs, path::Path, process::Command};
 
-use craby_common::constants::crate_manifest_path;
+use craby_common::constants::{build_log_path, crate_manifest_path};
 use log::{debug, error};
 
 use crate::constants::toolchain::Target;
 
-pub fn build_target(project_root: &Path, target: &Target) -> Result<(), anyhow::Error> {
+/// Builds the cargo project for the target.
+///
+/// If `build_log` is enabled, the output of the build is also written to `target/craby-logs/<target>.log`.
+pub fn build_target(
+    project_root: &Path,
+    target: &Target,
+    build_log: bool,
+) -> Result<(), anyhow::Error> {
     let manifest_path = crate_manifest_path(project_root)
         .to_string_lossy()
         .to_string();
@@ -31,6 +38,16 @@ pub fn build_target(project_root: &Path, target: &Target) -> Result<(), anyhow::
         Target::Ios(_) => Command::new("cargo").args(args).output(),
     }?;
 
+    if build_log {
+        let log_path = build_log_path(project_root, target.to_str());
+        debug!("Writing build log: {}", log_path.display());
+        fs::create_dir_all(log_path.parent().unwrap())?;
+        fs::write(
+            &log_path,
+            [res.stdout.as_slice(), res.stderr.as_slice()].concat(),
+        )?;
+    }
+
     if !res.status.success() {
         error!("{}", String::from_utf8_lossy(&res.stderr));
         anyhow::bail!("Failed to build (Target: {})", target.to_str());
@@ -26,6 +26,8 @@ pub mod toolchain {
                     Identifier::Arm64 => "aarch64-apple-ios",
                     Identifier::Arm64Simulator => "aarch64-apple-ios-sim",
                     Identifier::X86_64Simulator => "x86_64-apple-ios",
+                    Identifier::Arm64MacCatalyst => "aarch64-apple-ios-macabi",
+                    Identifier::X86_64MacCatalyst => "x86_64-apple-ios-macabi",
                     _ => unreachable!(),
                 },
             }
@@ -44,6 +46,8 @@ pub mod toolchain {
                 "aarch64-apple-ios" => Ok(Target::Ios(Identifier::Arm64)),
                 "aarch64-apple-ios-sim" => Ok(Target::Ios(Identifier::Arm64Simulator)),
                 "x86_64-apple-ios" => Ok(Target::Ios(Identifier::X86_64Simulator)),
+                "aarch64-apple-ios-macabi" => Ok(Target::Ios(Identifier::Arm64MacCatalyst)),
+                "x86_64-apple-ios-macabi" => Ok(Target::Ios(Identifier::X86_64MacCatalyst)),
                 _ => anyhow::bail!("Invalid target: {}", value),
             }
         }
@@ -67,6 +71,12 @@ pub mod toolchain {
         Target::Ios(Identifier::Arm64Simulator),
         Target::Ios(Identifier::X86_64Simulator),
     ];
+
+    /// Mac Catalyst targets for running the modules in a desktop test host (not built by default)
+    pub const MAC_CATALYST_TARGETS: [Target; 2] = [
+        Target::Ios(Identifier::Arm64MacCatalyst),
+        Target::Ios(Identifier::X86_64MacCatalyst),
+    ];
 }
 
 pub mod android {
@@ -155,6 +165,13 @@ pub mod ios {
         /// For XCFramework identifier (arm64 + x86_64 architecture for simulator)
         /// Each libraries are combined into a single library by `lipo`
         Simulator,
+        /// For Mac Catalyst (arm64)
+        Arm64MacCatalyst,
+        /// For Mac Catalyst (x86_64)
+        X86_64MacCatalyst,
+        /// For XCFramework identifier (arm64 + x86_64 architecture for Mac Catalyst)
+        /// Each libraries are combined into a single library by `lipo`
+        MacCatalyst,
     }
 
     impl Identifier {
@@ -162,8 +179,38 @@ pub mod ios {
             Ok(match self {
                 Identifier::Arm64 => "ios-arm64",
                 Identifier::Simulator => "ios-arm64_x86_64-simulator",
+                Identifier::MacCatalyst => "ios-arm64_x86_64-maccatalyst",
                 _ => anyhow::bail!("Invalid identifier"),
             })
         }
+
+        /// Returns the architecture name used by Xcode (eg. `arm64`)
+        pub fn try_into_arch(&self) -> Result<&str, anyhow::Error> {
+            Ok(match self {
+                Identifier::Arm64 | Identifier::Arm64Simulator | Identifier::Arm64MacCatalyst => {
+                    "arm64"
+                }
+                Identifier::X86_64Simulator | Identifier::X86_64MacCatalyst => "x86_64",
+                Identifier::Simulator | Identifier::MacCatalyst => {
+                    anyhow::bail!("Invalid identifier")
+                }
+            })
+        }
+
+        pub fn is_simulator(&self) -> bool {
+            matches!(
+                self,
+                Identifier::Arm64Simulator | Identifier::X86_64Simulator | Identifier::Simulator
+            )
+        }
+
+        pub fn is_mac_catalyst(&self) -> bool {
+            matches!(
+                self,
+                Identifier::Arm64MacCatalyst
+                    | Identifier::X86_64MacCatalyst
+                    | Identifier::MacCatalyst
+            )
+        }
     }
 }
@@ -1,6 +1,38 @@
+use std::{env, fs, path::Path};
+
 pub fn setup() {
+    write_features_header(&Path::new("include").join("CrabyFeatures.h"));
+
     cxx_build::bridge("src/ffi.rs")
         .std("c++20")
         .include("include")
         .compile("cxxbridge")
 }
+
+/// Writes the enabled cargo features as C++ macros (eg. `CRABY_FEATURE_MY_MODULE`)
+/// to exclude the C++ modules of the disabled module features.
+fn write_features_header(path: &Path) {
+    let mut features = env::vars()
+        .filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_").map(str::to_string))
+        .filter(|feature| feature != "DEFAULT")
+        .collect::<Vec<_>>();
+    features.sort();
+
+    let content = features_header(&features);
+
+    // Avoid touching the header (and rebuilding the C++ sources) if nothing changed
+    if fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return;
+    }
+
+    fs::write(path, content).expect("Failed to write the features header");
+}
+
+fn features_header(features: &[String]) -> String {
+    let defines = features
+        .iter()
+        .map(|feature| format!("#define CRABY_FEATURE_{feature} 1\n"))
+        .collect::<String>();
+
+    format!("#pragma once\n\n// Generated by `craby_build` from the enabled cargo features\n\n{defines}")
+}
@@ -1,4 +1,4 @@
-use std::{path::PathBuf, process::Command};
+use std::{fs, path::PathBuf, process::Command};
 
 use craby_common::{config::CompleteConfig, constants::jni_base_path};
 use log::{debug, info};
@@ -15,6 +15,7 @@ use crate::{
 
 pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(), anyhow::Error> {
     let jni_base_path = jni_base_path(&config.project_root);
+    let strip = config.android.strip.unwrap_or(true);
 
     for target in build_targets {
         debug!("Copying artifacts to JNI base path: {:?}", jni_base_path);
@@ -23,16 +24,29 @@ pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(
             let artifacts = Artifacts::get_artifacts(config, target)?;
             let abi = abi.to_str();
 
-            artifacts.path_of(ArtifactType::Lib).iter().try_for_each(
-                |lib| -> Result<(), anyhow::Error> {
-                    info!(
-                        "Optimizing library... {}",
-                        format!("({})", artifacts.identifier).dimmed()
-                    );
-                    strip_lib(lib)?;
-                    Ok(())
-                },
-            )?;
+            if strip {
+                artifacts.path_of(ArtifactType::Lib).iter().try_for_each(
+                    |lib| -> Result<(), anyhow::Error> {
+                        info!(
+                            "Optimizing library... {}",
+                            format!("({})", artifacts.identifier).dimmed()
+                        );
+                        let size_before = fs::metadata(lib)?.len();
+                        strip_lib(lib)?;
+                        let size_after = fs::metadata(lib)?.len();
+                        info!(
+                            "Stripped debug symbols {}",
+                            format!(
+                                "({} -> {})",
+                                format_size(size_before),
+                                format_size(size_after)
+                            )
+                            .dimmed()
+                        );
+                        Ok(())
+                    },
+                )?;
+            }
 
             // android/src/main/jni/src
             artifacts.copy_to(ArtifactType::Src, &jni_base_path.join("src"))?;
@@ -77,6 +91,24 @@ fn strip_lib(lib: &PathBuf) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+/// Formats the byte size in a human-readable unit (eg. `1.5 MB`)
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[unit])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
 pub mod path {
     use std::path::PathBuf;
 
@@ -118,3 +150,15 @@ pub mod path {
         Ok(ndk_bin_path()?.join("llvm-strip"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     platform::common::{replace_cxx_header, replace_cxx_iter_template},
 };
 
+use craby_codegen::utils::indent_str;
 use craby_common::{
     config::CompleteConfig,
     constants::{crate_target_dir, dest_lib_name, ios_base_path, lib_base_name},
@@ -22,32 +23,73 @@ use owo_colors::OwoColorize;
 pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(), anyhow::Error> {
     let ios_base_path = ios_base_path(&config.project_root);
 
-    let (sims, devices): (Vec<_>, Vec<_>) = build_targets.iter().partition(|target| {
-        matches!(
-            target,
-            Target::Ios(Identifier::Arm64Simulator) | Target::Ios(Identifier::X86_64Simulator)
-        )
-    });
+    let targets = build_targets
+        .iter()
+        .filter_map(|target| match target {
+            Target::Ios(identifier) => Some((target, identifier)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let of_variant = |variant: Variant| {
+        targets
+            .iter()
+            .filter(|(_, identifier)| Variant::from(*identifier) == variant)
+            .copied()
+            .collect::<Vec<_>>()
+    };
+    let (devices, sims, catalysts) = (
+        of_variant(Variant::Device),
+        of_variant(Variant::Simulator),
+        of_variant(Variant::MacCatalyst),
+    );
 
-    let sims = sims
-        .into_iter()
-        .map(|target| Artifacts::get_artifacts(config, target))
-        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+    let device_archs = archs(&devices)?;
+    let sim_archs = archs(&sims)?;
+    let catalyst_archs = archs(&catalysts)?;
 
-    let devices = devices
-        .into_iter()
-        .filter(|target| matches!(target, Target::Ios(_)))
-        .map(|target| Artifacts::get_artifacts(config, target))
-        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+    let get_artifacts = |targets: Vec<(&Target, &Identifier)>| {
+        targets
+            .into_iter()
+            .map(|(target, _)| Artifacts::get_artifacts(config, target))
+            .collect::<Result<Vec<_>, anyhow::Error>>()
+    };
+    let devices = get_artifacts(devices)?;
+    let sims = get_artifacts(sims)?;
+    let catalysts = get_artifacts(catalysts)?;
 
     let sims = if sims.len() > 1 {
-        vec![create_sim_lib(sims)?]
+        vec![create_sim_lib(sims, Identifier::Simulator)?]
     } else {
         sims
     };
-    let xcframework_path = create_xcframework(config)?;
-
-    for artifacts in [devices, sims].concat() {
+    let catalysts = if catalysts.len() > 1 {
+        vec![create_sim_lib(catalysts, Identifier::MacCatalyst)?]
+    } else {
+        catalysts
+    };
+    let xcframework_path = create_xcframework(config, &device_archs, &sim_archs, &catalyst_archs)?;
+    let libs = devices
+        .into_iter()
+        .map(|artifacts| {
+            (
+                artifacts,
+                library_identifier(&device_archs, Variant::Device),
+            )
+        })
+        .chain(sims.into_iter().map(|artifacts| {
+            (
+                artifacts,
+                library_identifier(&sim_archs, Variant::Simulator),
+            )
+        }))
+        .chain(catalysts.into_iter().map(|artifacts| {
+            (
+                artifacts,
+                library_identifier(&catalyst_archs, Variant::MacCatalyst),
+            )
+        }));
+
+    for (artifacts, library_identifier) in libs {
         artifacts.path_of(ArtifactType::Lib).iter().try_for_each(
             |lib| -> Result<(), anyhow::Error> {
                 info!(
@@ -65,15 +107,10 @@ pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(
         // ios/include
         artifacts.copy_to(ArtifactType::Header, &ios_base_path.join("include"))?;
 
-        // ios/framework/lib{lib_name}.xcframework/{identifier}
-        let is_sim = artifacts.identifier.contains("sim");
+        // ios/framework/lib{lib_name}.xcframework/{library_identifier}
         artifacts.copy_to(
             ArtifactType::Lib,
-            &xcframework_path.join(if is_sim {
-                Identifier::Simulator.try_into_str()?
-            } else {
-                Identifier::Arm64.try_into_str()?
-            }),
+            &xcframework_path.join(library_identifier),
         )?;
     }
 
@@ -94,8 +131,10 @@ pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(
 ///
 /// This function takes a vector of artifacts and creates a simulator library from them.
 /// It uses the `lipo` command to combine the libraries into a single library.
-fn create_sim_lib(sims: Vec<Artifacts>) -> Result<Artifacts, anyhow::Error> {
-    let identifier = Identifier::Simulator.try_into_str()?;
+///
+/// The Mac Catalyst libraries are combined in the same way with `Identifier::MacCatalyst`.
+fn create_sim_lib(sims: Vec<Artifacts>, combined: Identifier) -> Result<Artifacts, anyhow::Error> {
+    let identifier = combined.try_into_str()?;
     let orig = sims
         .first()
         .cloned()
@@ -139,7 +178,7 @@ fn create_sim_lib(sims: Vec<Artifacts>) -> Result<Artifacts, anyhow::Error> {
     }
 
     Ok(Artifacts {
-        identifier: Identifier::Simulator.try_into_str()?.to_string(),
+        identifier: identifier.to_string(),
         headers: orig.headers,
         srcs: orig.srcs,
         libs: vec![dest_path],
@@ -163,10 +202,73 @@ fn strip_lib(lib: &PathBuf) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-fn create_xcframework(config: &CompleteConfig) -> Result<PathBuf, anyhow::Error> {
+/// Returns the architectures of the given iOS targets (eg. `["arm64", "x86_64"]`)
+fn archs(targets: &[(&Target, &Identifier)]) -> Result<Vec<String>, anyhow::Error> {
+    let mut archs = targets
+        .iter()
+        .map(|(_, identifier)| identifier.try_into_arch().map(|arch| arch.to_string()))
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+    archs.sort();
+    archs.dedup();
+
+    Ok(archs)
+}
+
+/// Platform variant of the XCFramework libraries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Variant {
+    Device,
+    Simulator,
+    MacCatalyst,
+}
+
+impl Variant {
+    /// Returns the `SupportedPlatformVariant` of the `Info.plist` (`None` for device)
+    fn as_platform_variant(&self) -> Option<&str> {
+        match self {
+            Variant::Device => None,
+            Variant::Simulator => Some("simulator"),
+            Variant::MacCatalyst => Some("maccatalyst"),
+        }
+    }
+}
+
+impl From<&Identifier> for Variant {
+    fn from(identifier: &Identifier) -> Self {
+        if identifier.is_simulator() {
+            Variant::Simulator
+        } else if identifier.is_mac_catalyst() {
+            Variant::MacCatalyst
+        } else {
+            Variant::Device
+        }
+    }
+}
+
+/// Returns the library identifier of the XCFramework (eg. `ios-arm64`, `ios-arm64_x86_64-simulator`)
+fn library_identifier(archs: &[String], variant: Variant) -> String {
+    let archs = archs.join("_");
+
+    match variant.as_platform_variant() {
+        Some(platform_variant) => format!("ios-{}-{}", archs, platform_variant),
+        None => format!("ios-{}", archs),
+    }
+}
+
+fn create_xcframework(
+    config: &CompleteConfig,
+    device_archs: &[String],
+    sim_archs: &[String],
+    catalyst_archs: &[String],
+) -> Result<PathBuf, anyhow::Error> {
     let name = SanitizedString::from(&config.project.name);
     let lib_base_name = lib_base_name(&name);
-    let info_plist_content = info_plist(&config.project.name)?;
+    let info_plist_content = info_plist(
+        &config.project.name,
+        device_archs,
+        sim_archs,
+        catalyst_archs,
+    );
     let framework_path = ios_base_path(&config.project_root).join("framework");
     let xcframework_path = framework_path.join(format!("lib{}.xcframework", lib_base_name));
 
@@ -182,10 +284,63 @@ fn create_xcframework(config: &CompleteConfig) -> Result<PathBuf, anyhow::Error>
     Ok(xcframework_path)
 }
 
-pub fn info_plist(name: &String) -> Result<String, anyhow::Error> {
+/// Returns the `Info.plist` of the XCFramework
+///
+/// Only the libraries of the built architectures are listed (eg. no simulator entry if no simulator target is built).
+pub fn info_plist(
+    name: &String,
+    device_archs: &[String],
+    sim_archs: &[String],
+    catalyst_archs: &[String],
+) -> String {
     let lib_name = dest_lib_name(&SanitizedString::from(name));
+    let library = |archs: &[String], variant: Variant| {
+        let identifier = library_identifier(archs, variant);
+        let archs = archs
+            .iter()
+            .map(|arch| format!("<string>{}</string>", arch))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let variant = variant
+            .as_platform_variant()
+            .map(|variant| {
+                format!("\n<key>SupportedPlatformVariant</key>\n<string>{variant}</string>")
+            })
+            .unwrap_or_default();
+
+        formatdoc! {
+            r#"
+            <dict>
+                <key>BinaryPath</key>
+                <string>{lib_name}</string>
+                <key>LibraryIdentifier</key>
+                <string>{identifier}</string>
+                <key>LibraryPath</key>
+                <string>{lib_name}</string>
+                <key>SupportedArchitectures</key>
+                <array>
+            {archs}
+                </array>
+                <key>SupportedPlatform</key>
+                <string>ios</string>{variant}
+            </dict>"#,
+            archs = indent_str(&archs, 8),
+            variant = indent_str(&variant, 4),
+        }
+    };
 
-    let content = formatdoc! {
+    let libraries = [
+        (device_archs, Variant::Device),
+        (sim_archs, Variant::Simulator),
+        (catalyst_archs, Variant::MacCatalyst),
+    ]
+    .into_iter()
+    .filter(|(archs, _)| !archs.is_empty())
+    .map(|(archs, variant)| library(archs, variant))
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    formatdoc! {
         r#"
         <?xml version="1.0" encoding="UTF-8"?>
         <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
@@ -193,37 +348,7 @@ pub fn info_plist(name: &String) -> Result<String, anyhow::Error> {
         <dict>
             <key>AvailableLibraries</key>
             <array>
-                <dict>
-                    <key>BinaryPath</key>
-                    <string>{lib_name}</string>
-                    <key>LibraryIdentifier</key>
-                    <string>{lib_identifier}</string>
-                    <key>LibraryPath</key>
-                    <string>{lib_name}</string>
-                    <key>SupportedArchitectures</key>
-                    <array>
-                        <string>arm64</string>
-                    </array>
-                    <key>SupportedPlatform</key>
-                    <string>ios</string>
-                </dict>
-                <dict>
-                    <key>BinaryPath</key>
-                    <string>{lib_name}</string>
-                    <key>LibraryIdentifier</key>
-                    <string>{lib_sim_identifier}</string>
-                    <key>LibraryPath</key>
-                    <string>{lib_name}</string>
-                    <key>SupportedArchitectures</key>
-                    <array>
-                        <string>arm64</string>
-                        <string>x86_64</string>
-                    </array>
-                    <key>SupportedPlatform</key>
-                    <string>ios</string>
-                    <key>SupportedPlatformVariant</key>
-                    <string>simulator</string>
-                </dict>
+        {libraries}
             </array>
             <key>CFBundlePackageType</key>
             <string>XFWK</string>
@@ -231,10 +356,46 @@ pub fn info_plist(name: &String) -> Result<String, anyhow::Error> {
             <string>1.0</string>
         </dict>
         </plist>"#,
-        lib_name = lib_name,
-        lib_identifier = Identifier::Arm64.try_into_str()?,
-        lib_sim_identifier = Identifier::Simulator.try_into_str()?,
-    };
+        libraries = indent_str(&libraries, 8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_plist() {
+        let archs = |archs: &[&str]| archs.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+        let plist = info_plist(
+            &"my-module".to_string(),
+            &archs(&["arm64"]),
+            &archs(&["arm64"]),
+            &[],
+        );
+
+        assert!(plist.contains("<string>ios-arm64</string>"));
+        assert!(plist.contains("<string>ios-arm64-simulator</string>"));
+        assert!(!plist.contains("x86_64"));
+        assert!(!plist.contains("maccatalyst"));
 
-    Ok(content)
+        // Without simulator targets
+        let plist = info_plist(&"my-module".to_string(), &archs(&["arm64"]), &[], &[]);
+        assert!(!plist.contains("simulator"));
+    }
+
+    #[test]
+    fn test_info_plist_mac_catalyst() {
+        let archs = |archs: &[&str]| archs.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+        let plist = info_plist(
+            &"my-module".to_string(),
+            &archs(&["arm64"]),
+            &[],
+            &archs(&["arm64", "x86_64"]),
+        );
+
+        assert!(plist.contains("<string>ios-arm64_x86_64-maccatalyst</string>"));
+        assert!(plist.contains("<string>maccatalyst</string>"));
+        assert!(!plist.contains("simulator"));
+    }
 }
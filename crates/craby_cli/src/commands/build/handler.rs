@@ -1,21 +1,31 @@
 use std::path::PathBuf;
 
-use craby_build::platform::{android as android_build, ios as ios_build};
+use craby_build::{
+    constants::toolchain::Target,
+    platform::{android as android_build, ios as ios_build},
+};
 use craby_codegen::codegen;
-use craby_common::{config::load_config, env::is_initialized};
+use craby_common::{
+    config::load_config,
+    env::{is_initialized, is_macos_host},
+};
 use log::{debug, info};
 use owo_colors::OwoColorize;
 
 use crate::{
     commands::build::validate_schema,
     utils::{
-        build_targets::{get_build_targets, print_build_targets},
+        build_targets::{filter_build_targets, get_build_targets, print_build_targets},
         terminal::with_spinner,
     },
 };
 
+pub use crate::utils::build_targets::parse_build_targets;
+
 pub struct BuildOptions {
     pub project_root: PathBuf,
+    /// Build only these targets instead of all of the build targets in `craby.toml`
+    pub targets: Option<Vec<Target>>,
 }
 
 pub fn perform(opts: BuildOptions) -> anyhow::Result<()> {
@@ -25,11 +35,22 @@ pub fn perform(opts: BuildOptions) -> anyhow::Result<()> {
         anyhow::bail!("Craby project is not initialized. Please run `craby init` first.");
     }
 
-    let build_targets = get_build_targets(&config)?;
+    let build_targets = filter_build_targets(get_build_targets(&config)?, opts.targets.as_deref())?;
     if build_targets.is_empty() {
         anyhow::bail!("No build targets found. Please check your `craby.toml` file.");
     }
 
+    // `lipo` and `xcodebuild` (XCFramework) are only available on macOS
+    let has_ios_targets = build_targets
+        .iter()
+        .any(|target| matches!(target, Target::Ios(_)));
+    if has_ios_targets && !is_macos_host() {
+        anyhow::bail!(
+            "iOS targets can only be built on macOS (host: {}). Set `targets = []` in the `[ios]` section of `craby.toml` or pass the Android targets with `--target` to build the Android targets only.",
+            std::env::consts::OS
+        );
+    }
+
     debug!(
         "Collecting source files to validate schema(s)... ({})",
         config.source_dir.display()
@@ -37,6 +58,9 @@ pub fn perform(opts: BuildOptions) -> anyhow::Result<()> {
     let schemas = codegen(craby_codegen::CodegenOptions {
         project_root: &opts.project_root,
         source_dir: &config.source_dir,
+        spec_glob: config.project.spec_glob.as_deref(),
+        expose: config.project.expose.as_ref(),
+        diagnostics_out: None,
     })?;
     let total_schemas = schemas.len();
     debug!("{} module schema(s) found", total_schemas);
@@ -53,7 +77,11 @@ pub fn perform(opts: BuildOptions) -> anyhow::Result<()> {
                 build_targets.len(),
                 target.to_str().dimmed()
             ));
-            craby_build::cargo::build::build_target(&opts.project_root, target)?;
+            craby_build::cargo::build::build_target(
+                &opts.project_root,
+                target,
+                config.project.build_log.unwrap_or(false),
+            )?;
         }
         Ok(())
     })?;
@@ -62,8 +90,10 @@ pub fn perform(opts: BuildOptions) -> anyhow::Result<()> {
     info!("Creating Android artifacts...");
     android_build::crate_libs(&config, &build_targets)?;
 
-    info!("Creating iOS XCFramework...");
-    ios_build::crate_libs(&config, &build_targets)?;
+    if has_ios_targets {
+        info!("Creating iOS XCFramework...");
+        ios_build::crate_libs(&config, &build_targets)?;
+    }
 
     info!("Build completed successfully 🎉");
 
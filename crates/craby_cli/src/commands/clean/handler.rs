@@ -1,13 +1,51 @@
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
+use craby_codegen::constants::MODULE_REGISTRY_HEADER;
 use craby_common::{
     config::load_config,
-    constants::{android_path, craby_tmp_dir, ios_base_path, jni_base_path},
+    constants::{
+        android_path, craby_tmp_dir, crate_dir, cxx_bridge_include_dir, cxx_dir, ios_base_path,
+        jni_base_path,
+    },
 };
 use log::{debug, info};
 
 pub struct CleanOptions {
     pub project_root: PathBuf,
+    pub scope: CleanScope,
+}
+
+/// Category of the files to remove.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanScope {
+    /// Build output and temporary files (Default)
+    All,
+    /// Generated Rust sources (`ffi.rs`, `generated.rs`)
+    Rust,
+    /// Generated C++ sources and the Android C++ build output
+    Cxx,
+    /// Built libraries (`target`, Android JNI libraries and XCFramework)
+    Artifacts,
+}
+
+impl TryFrom<&str> for CleanScope {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "all" => Ok(CleanScope::All),
+            "rust" => Ok(CleanScope::Rust),
+            "cxx" => Ok(CleanScope::Cxx),
+            "artifacts" => Ok(CleanScope::Artifacts),
+            _ => anyhow::bail!(
+                "Invalid clean scope: {} (Expected: all, rust, cxx or artifacts)",
+                value
+            ),
+        }
+    }
 }
 
 pub fn perform(opts: CleanOptions) -> anyhow::Result<()> {
@@ -17,28 +55,151 @@ pub fn perform(opts: CleanOptions) -> anyhow::Result<()> {
 
     info!("🧹 Cleaning up files...");
 
-    let cargo_target_dir = opts.project_root.join("target");
-    let android_build_dir = android_path(&opts.project_root).join("build");
-    let android_cxx_dir = android_path(&opts.project_root).join(".cxx");
-    let android_libs_dir = jni_base_path(&opts.project_root).join("libs");
-    let ios_framework_dir = ios_base_path(&opts.project_root).join("framework");
-    let tmp_dir = craby_tmp_dir(&opts.project_root);
-
-    for dir in [
-        cargo_target_dir,
-        android_build_dir,
-        android_cxx_dir,
-        android_libs_dir,
-        ios_framework_dir,
-        tmp_dir,
-    ] {
+    let (dirs, files) = clean_targets(&opts.project_root, opts.scope)?;
+
+    for dir in dirs {
         if dir.try_exists()? {
             debug!("Removing directory: {}", dir.display());
             fs::remove_dir_all(dir)?;
         }
     }
 
+    for file in files {
+        if file.try_exists()? {
+            debug!("Removing file: {}", file.display());
+            fs::remove_file(file)?;
+        }
+    }
+
     info!("Done!");
 
     Ok(())
 }
+
+/// Returns the directories and files to remove for the scope.
+///
+/// The hand-written files (eg. `lib.rs`, `*_impl.rs`) are never included.
+fn clean_targets(
+    project_root: &Path,
+    scope: CleanScope,
+) -> anyhow::Result<(Vec<PathBuf>, Vec<PathBuf>)> {
+    let cargo_target_dir = project_root.join("target");
+    let android_build_dir = android_path(project_root).join("build");
+    let android_cxx_dir = android_path(project_root).join(".cxx");
+    let android_libs_dir = jni_base_path(project_root).join("libs");
+    let ios_framework_dir = ios_base_path(project_root).join("framework");
+    let tmp_dir = craby_tmp_dir(project_root);
+
+    let res = match scope {
+        CleanScope::All => (
+            vec![
+                cargo_target_dir,
+                android_build_dir,
+                android_cxx_dir,
+                android_libs_dir,
+                ios_framework_dir,
+                tmp_dir,
+            ],
+            vec![],
+        ),
+        CleanScope::Artifacts => (
+            vec![cargo_target_dir, android_libs_dir, ios_framework_dir],
+            vec![],
+        ),
+        CleanScope::Rust => {
+            let src_dir = crate_dir(project_root).join("src");
+            (
+                vec![],
+                vec![src_dir.join("ffi.rs"), src_dir.join("generated.rs")],
+            )
+        }
+        CleanScope::Cxx => {
+            let cxx_dir = cxx_dir(project_root);
+            let mut files = vec![
+                cxx_dir.join("bridging-generated.hpp"),
+                cxx_dir.join("CrabyUtils.hpp"),
+                cxx_dir.join(MODULE_REGISTRY_HEADER),
+                cxx_bridge_include_dir(project_root).join("CrabySignals.h"),
+            ];
+
+            // C++ modules (eg. `CxxCalculatorModule.cpp`)
+            if cxx_dir.try_exists()? {
+                for entry in fs::read_dir(&cxx_dir)? {
+                    let path = entry?.path();
+                    let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+
+                    if file_name.starts_with("Cxx")
+                        && (file_name.ends_with("Module.cpp") || file_name.ends_with("Module.hpp"))
+                    {
+                        files.push(path);
+                    }
+                }
+            }
+
+            (vec![android_build_dir, android_cxx_dir], files)
+        }
+    };
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_scope() {
+        assert_eq!(CleanScope::try_from("all").unwrap(), CleanScope::All);
+        assert_eq!(CleanScope::try_from("rust").unwrap(), CleanScope::Rust);
+        assert_eq!(CleanScope::try_from("cxx").unwrap(), CleanScope::Cxx);
+        assert_eq!(
+            CleanScope::try_from("artifacts").unwrap(),
+            CleanScope::Artifacts
+        );
+        assert!(CleanScope::try_from("ios").is_err());
+    }
+
+    #[test]
+    fn test_clean_targets() {
+        let root = Path::new("/project");
+        let (dirs, files) = clean_targets(root, CleanScope::All).unwrap();
+
+        assert_eq!(dirs.len(), 6);
+        assert!(files.is_empty());
+
+        let (dirs, files) = clean_targets(root, CleanScope::Rust).unwrap();
+
+        assert!(dirs.is_empty());
+        assert_eq!(
+            files,
+            [
+                root.join("crates/lib/src/ffi.rs"),
+                root.join("crates/lib/src/generated.rs")
+            ]
+        );
+
+        let (dirs, _) = clean_targets(root, CleanScope::Artifacts).unwrap();
+
+        assert_eq!(
+            dirs,
+            [
+                root.join("target"),
+                root.join("android/src/main/jni/libs"),
+                root.join("ios/framework")
+            ]
+        );
+
+        // Hand-written implementations are never removed
+        for scope in [
+            CleanScope::All,
+            CleanScope::Rust,
+            CleanScope::Cxx,
+            CleanScope::Artifacts,
+        ] {
+            let (_, files) = clean_targets(root, scope).unwrap();
+            assert!(files
+                .iter()
+                .all(|file| !file.to_string_lossy().ends_with("_impl.rs")));
+        }
+    }
+}
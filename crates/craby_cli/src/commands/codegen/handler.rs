@@ -1,53 +1,107 @@
 use std::{
+    fs,
     path::{Path, PathBuf},
     time::Instant,
 };
 
 use craby_codegen::{
-    codegen,
-    constants::GENERATED_COMMENT,
+    codegen, collect_spec_files,
+    constants::{DEFAULT_THREAD_POOL_SIZE, GENERATED_COMMENT},
     generators::{
         android_generator::AndroidGenerator,
+        c_shim_generator::CShimGenerator,
         cxx_generator::CxxGenerator,
+        docs_generator::DocsGenerator,
+        dts_generator::DtsGenerator,
         ios_generator::IosGenerator,
+        kotlin_generator::KotlinGenerator,
+        mock_generator::MockGenerator,
         rs_generator::RsGenerator,
-        types::{Generator, GeneratorInvoker},
+        types::{Generator, GeneratorInvoker, TemplateResult},
     },
     types::CodegenContext,
+    utils::{base_indent_size, depfile_str, reindent_str},
 };
-use craby_common::{config::load_config, constants::craby_tmp_dir, env::is_initialized};
-use log::{debug, info};
+use craby_common::{
+    config::{load_config, validate_cxx_namespace, validate_module_features, IndentConfig},
+    constants::craby_tmp_dir,
+    env::is_initialized,
+};
+use log::{debug, info, warn};
 use owo_colors::OwoColorize;
+use similar::TextDiff;
 
-use crate::utils::{file::write_file, schema::print_schema};
+use crate::utils::{
+    clang_format::{clang_format_str, is_clang_format_available},
+    file::write_file,
+    post_codegen::{run_post_codegen, GENERATED_FILES_ENV},
+    schema::print_schema,
+};
 
 #[derive(Debug)]
 pub struct CodegenOptions {
     pub project_root: PathBuf,
     pub overwrite: bool,
+    /// Generation options below are also enabled by the same options in the `[project]` section of `craby.toml`
+    pub stub_defaults: bool,
+    pub c_shim: bool,
+    pub listener_error_hook: bool,
+    pub swift_header: bool,
+    pub module_registry: bool,
+    pub lazy_registration: bool,
+    pub unimplemented_error: bool,
+    pub docs: bool,
+    pub kotlin_stubs: bool,
+    pub dts: bool,
+    pub mocks: bool,
+    /// Fail if the generated files are out of date instead of writing them
+    pub check: bool,
+    /// Print the changes of the generated files instead of writing them
+    pub dry_run: bool,
+    pub diagnostics_out: Option<PathBuf>,
+    pub depfile: Option<PathBuf>,
 }
 
-pub fn perform(opts: CodegenOptions) -> anyhow::Result<()> {
+/// Generates the files from the spec files.
+///
+/// Returns the generated files (relative to the project root) whose content has changed.
+pub fn perform(opts: CodegenOptions) -> anyhow::Result<Vec<PathBuf>> {
     if !is_initialized(&opts.project_root) {
         anyhow::bail!("Craby project is not initialized. Please run `craby init` first.");
     }
 
     let tmp_dir = craby_tmp_dir(&opts.project_root);
     let config = load_config(&opts.project_root)?;
+    validate_cxx_namespace(&config)?;
     let start_time = Instant::now();
 
     debug!("Options: {:?}", opts);
+    let spec_location = match &config.project.spec_glob {
+        Some(spec_glob) => spec_glob.to_string(),
+        None => config.source_dir.display().to_string(),
+    };
     info!(
         "Collecting source files... {}",
-        format!("({})", config.source_dir.display()).dimmed()
+        format!("({})", spec_location).dimmed()
     );
     let schemas = codegen(craby_codegen::CodegenOptions {
         project_root: &opts.project_root,
         source_dir: &config.source_dir,
+        spec_glob: config.project.spec_glob.as_deref(),
+        expose: config.project.expose.as_ref(),
+        diagnostics_out: opts.diagnostics_out.as_ref(),
     })?;
     let total_schemas = schemas.len();
     info!("{} module schema(s) found", total_schemas);
 
+    if config.project.module_features.unwrap_or(false) {
+        let module_names = schemas
+            .iter()
+            .map(|schema| schema.module_name.as_str())
+            .collect::<Vec<_>>();
+        validate_module_features(&opts.project_root, &module_names)?;
+    }
+
     // Print schema for each module
     for (i, schema) in schemas.iter().enumerate() {
         info!(
@@ -60,45 +114,132 @@ pub fn perform(opts: CodegenOptions) -> anyhow::Result<()> {
         println!();
     }
 
+    let clang_format = config.project.clang_format.unwrap_or(false)
+        && {
+            let available = is_clang_format_available();
+            if !available {
+                warn!("`clang_format` is enabled but `clang-format` is not found in PATH. Skipping formatting");
+            }
+            available
+        };
     let ctx = CodegenContext {
+        cxx_namespace: config.project.cxx_namespace(),
         project_name: config.project.name,
         root: opts.project_root.clone(),
+        source_dir: config.source_dir.clone(),
         schemas,
         android_package_name: config.android.package_name,
+        android_page_size_16kb: config.android.page_size_16kb.unwrap_or(true),
+        stub_defaults: opts.stub_defaults || config.project.stub_defaults.unwrap_or(false),
+        c_shim: opts.c_shim || config.project.c_shim.unwrap_or(false),
+        listener_error_hook: opts.listener_error_hook
+            || config.project.listener_error_hook.unwrap_or(false),
+        swift_header: opts.swift_header || config.project.swift_header.unwrap_or(false),
+        module_registry: opts.module_registry || config.project.module_registry.unwrap_or(false),
+        lazy_registration: opts.lazy_registration
+            || config.project.lazy_registration.unwrap_or(false),
+        unimplemented_error: opts.unimplemented_error
+            || config.project.unimplemented_error.unwrap_or(false),
+        docs: opts.docs || config.project.docs.unwrap_or(false),
+        kotlin_stubs: opts.kotlin_stubs || config.project.kotlin_stubs.unwrap_or(false),
+        dts: opts.dts || config.project.dts.unwrap_or(false),
+        mocks: opts.mocks || config.project.mocks.unwrap_or(false),
+        cxx_base_class: config.project.cxx_base_class,
+        instance_counter: config.project.instance_counter.unwrap_or(false),
+        module_features: config.project.module_features.unwrap_or(false),
+        thread_pool_size: config
+            .project
+            .thread_pool_size
+            .unwrap_or(DEFAULT_THREAD_POOL_SIZE),
     };
 
-    debug!("Cleaning up...");
-    AndroidGenerator::cleanup(&ctx)?;
-    IosGenerator::cleanup(&ctx)?;
-    RsGenerator::cleanup(&ctx)?;
-    CxxGenerator::cleanup(&ctx)?;
-
     let mut generate_res = vec![];
-    let generators: Vec<Box<dyn GeneratorInvoker>> = vec![
+    let mut generators: Vec<Box<dyn GeneratorInvoker>> = vec![
         Box::new(AndroidGenerator::new()),
         Box::new(IosGenerator::new()),
         Box::new(RsGenerator::new()),
         Box::new(CxxGenerator::new()),
     ];
 
+    if ctx.c_shim {
+        generators.push(Box::new(CShimGenerator::new()));
+    }
+
+    if ctx.docs {
+        generators.push(Box::new(DocsGenerator::new()));
+    }
+
+    if ctx.kotlin_stubs {
+        generators.push(Box::new(KotlinGenerator::new()));
+    }
+
+    if ctx.dts {
+        generators.push(Box::new(DtsGenerator::new()));
+    }
+
+    if ctx.mocks {
+        generators.push(Box::new(MockGenerator::new()));
+    }
+
     info!("Generating files...");
     for generator in generators {
         generate_res.extend(generator.invoke_generate(&ctx)?);
     }
 
+    if opts.check {
+        check_generated(
+            &opts.project_root,
+            &generate_res,
+            &config.project.indent,
+            clang_format,
+        )?;
+        return Ok(vec![]);
+    }
+
+    if opts.dry_run {
+        preview_generated(
+            &opts.project_root,
+            &generate_res,
+            opts.overwrite,
+            &config.project.indent,
+            clang_format,
+        )?;
+        return Ok(vec![]);
+    }
+
+    // Read the existing files before they are cleaned up to report the changed files
+    let prev_contents = generate_res
+        .iter()
+        .map(|res| fs::read_to_string(&res.path).ok())
+        .collect::<Vec<_>>();
+
+    debug!("Cleaning up...");
+    AndroidGenerator::cleanup(&ctx)?;
+    IosGenerator::cleanup(&ctx)?;
+    RsGenerator::cleanup(&ctx)?;
+    CxxGenerator::cleanup(&ctx)?;
+
     let mut generated_cnt = 0;
+    let mut generated_files = vec![];
+    let mut written_files = vec![];
     let mut preserved_files = vec![];
-    for res in generate_res {
-        let content = if res.overwrite {
-            with_generated_comment(&res.path, &res.content)
-        } else {
-            without_generated_comment(&res.content)
-        };
+    let mut changed_files = vec![];
+    for (res, prev_content) in generate_res.into_iter().zip(prev_contents) {
+        let content = formatted_content(&res, &config.project.indent, clang_format)?;
+
+        if opts.depfile.is_some() {
+            generated_files.push(relative_path(&res.path, &opts.project_root));
+        }
 
-        let should_overwrite = opts.overwrite && res.overwrite;
+        let should_overwrite = opts.overwrite && (res.overwrite || res.merged);
         if write_file(&res.path, &content, should_overwrite)? {
             generated_cnt += 1;
             debug!("File generated: {}", res.path.display());
+            written_files.push(relative_path(&res.path, &opts.project_root));
+
+            if prev_content.as_ref() != Some(&content) {
+                changed_files.push(relative_path(&res.path, &opts.project_root));
+            }
         } else {
             // Save the content to a temporary directory if it's not written
             let file_name = res.path.file_name().unwrap();
@@ -106,7 +247,7 @@ pub fn perform(opts: CodegenOptions) -> anyhow::Result<()> {
             debug!("Saving to temporary directory: {}", dest.display());
             write_file(&dest, &content, true)?;
 
-            if res.overwrite {
+            if res.overwrite || res.merged {
                 preserved_files.push(
                     res.path
                         .strip_prefix(&opts.project_root)?
@@ -117,6 +258,41 @@ pub fn perform(opts: CodegenOptions) -> anyhow::Result<()> {
         }
     }
 
+    if let Some(depfile) = &opts.depfile {
+        let spec_files = collect_spec_files(
+            &opts.project_root,
+            &config.source_dir,
+            config.project.spec_glob.as_deref(),
+        )?
+        .iter()
+        .map(|path| relative_path(path, &opts.project_root))
+        .collect::<Vec<_>>();
+
+        write_file(depfile, &depfile_str(&generated_files, &spec_files), true)?;
+        debug!("Depfile written: {}", depfile.display());
+    }
+
+    // Only run the command when explicitly configured
+    if let Some(post_codegen) = &config.project.post_codegen {
+        info!(
+            "Running post-codegen command... {}",
+            format!("({})", post_codegen.command).dimmed()
+        );
+        debug!(
+            "Passing {} generated file(s) via `{}`",
+            written_files.len(),
+            GENERATED_FILES_ENV
+        );
+
+        if let Err(e) = run_post_codegen(&post_codegen.command, &opts.project_root, &written_files)
+        {
+            if post_codegen.fail_on_error.unwrap_or(true) {
+                return Err(e);
+            }
+            warn!("{}", e);
+        }
+    }
+
     let elapsed = start_time.elapsed().as_millis();
     info!("{} files generated", generated_cnt);
 
@@ -139,22 +315,256 @@ pub fn perform(opts: CodegenOptions) -> anyhow::Result<()> {
         format!("({}ms)", elapsed).dimmed()
     );
 
+    Ok(changed_files)
+}
+
+/// Compares the generated files with the existing files, formatted the same as they are written.
+///
+/// Bails with the generated files that differ from the existing ones (including the missing files),
+/// so the changes of the config (eg. `cxx_namespace`) are also detected, not only the changes of the specs.
+fn check_generated(
+    project_root: &Path,
+    generate_res: &[TemplateResult],
+    indent: &Option<IndentConfig>,
+    clang_format: bool,
+) -> anyhow::Result<()> {
+    let mut stale_files = vec![];
+
+    for res in generate_res
+        .iter()
+        .filter(|res| res.overwrite || res.merged)
+    {
+        let content = formatted_content(res, indent, clang_format)?;
+
+        if fs::read_to_string(&res.path).ok() != Some(content) {
+            debug!("Out of date: {}", res.path.display());
+            stale_files.push(format!(
+                "  - {}",
+                relative_path(&res.path, project_root).display()
+            ));
+        }
+    }
+
+    if !stale_files.is_empty() {
+        anyhow::bail!(
+            "Generated files are out of date. Run `crabygen codegen` to update them\n{}",
+            stale_files.join("\n")
+        );
+    }
+
+    info!("Generated files are up to date");
+
+    Ok(())
+}
+
+/// Change of the generated file to preview with the dry run.
+#[derive(Debug, PartialEq)]
+enum FilePreview {
+    New,
+    /// Exists and not overwritten (eg. implementations of the modules, `--no-overwrite`)
+    Skipped,
+    Unchanged,
+    /// Unified diff against the current file
+    Changed(String),
+}
+
+/// Prints the generated files with the changes against the current files without writing them.
+fn preview_generated(
+    project_root: &Path,
+    generate_res: &[TemplateResult],
+    overwrite: bool,
+    indent: &Option<IndentConfig>,
+    clang_format: bool,
+) -> anyhow::Result<()> {
+    info!("Dry run: no files are written");
+
+    for res in generate_res {
+        let path = relative_path(&res.path, project_root);
+        let prev_content = fs::read_to_string(&res.path).ok();
+        let should_overwrite = overwrite && (res.overwrite || res.merged);
+        let preview = file_preview(
+            &path,
+            prev_content.as_deref(),
+            &formatted_content(res, indent, clang_format)?,
+            should_overwrite,
+        );
+
+        match preview {
+            FilePreview::New => println!("{} {}", path.display(), "(new file)".green()),
+            FilePreview::Skipped => println!("{} {}", path.display(), "(skipped)".dimmed()),
+            FilePreview::Unchanged => println!("{} {}", path.display(), "(unchanged)".dimmed()),
+            FilePreview::Changed(diff) => {
+                println!("{} {}", path.display(), "(changed)".yellow());
+                for line in diff.lines() {
+                    if line.starts_with("+++") || line.starts_with("---") {
+                        println!("{}", line.bold());
+                    } else if line.starts_with('+') {
+                        println!("{}", line.green());
+                    } else if line.starts_with('-') {
+                        println!("{}", line.red());
+                    } else if line.starts_with("@@") {
+                        println!("{}", line.cyan());
+                    } else {
+                        println!("{}", line);
+                    }
+                }
+            }
+        }
+    }
+
     Ok(())
 }
 
+fn file_preview(
+    path: &Path,
+    prev_content: Option<&str>,
+    content: &str,
+    overwrite: bool,
+) -> FilePreview {
+    match prev_content {
+        None => FilePreview::New,
+        Some(_) if !overwrite => FilePreview::Skipped,
+        Some(prev_content) if prev_content == content => FilePreview::Unchanged,
+        Some(prev_content) => {
+            let path = path.display();
+            let diff = TextDiff::from_lines(prev_content, content)
+                .unified_diff()
+                .header(&format!("a/{path}"), &format!("b/{path}"))
+                .to_string();
+
+            FilePreview::Changed(diff)
+        }
+    }
+}
+
+/// Returns the content of the generated file to write.
+fn file_content(res: &TemplateResult, indent: &Option<IndentConfig>) -> String {
+    let code = match (indent, base_indent_size(&res.path)) {
+        (Some(indent), Some(base_size)) => reindent_str(&res.content, base_size, indent),
+        _ => res.content.clone(),
+    };
+
+    if res.overwrite {
+        with_generated_comment(&res.path, &code)
+    } else {
+        without_generated_comment(&code)
+    }
+}
+
+/// Returns the content of the generated file to write, formatted with clang-format if enabled.
+///
+/// Only the overwritten C++ files are formatted, so the existing implementations are kept as is.
+fn formatted_content(
+    res: &TemplateResult,
+    indent: &Option<IndentConfig>,
+    clang_format: bool,
+) -> anyhow::Result<String> {
+    let content = file_content(res, indent);
+
+    if clang_format && res.overwrite && is_cxx_file(&res.path) {
+        debug!("Formatting with clang-format: {}", res.path.display());
+        return clang_format_str(&content, &res.path);
+    }
+
+    Ok(content)
+}
+
 fn with_generated_comment(path: &Path, code: &str) -> String {
     match path.extension() {
         Some(ext) => match ext.to_str().unwrap() {
             // Source files
-            "rs" | "cpp" | "hpp" | "mm" => format!("// {}\n{}\n", GENERATED_COMMENT, code),
+            "rs" | "cpp" | "hpp" | "mm" | "ts" | "kt" => {
+                format!("// {}\n{}\n", GENERATED_COMMENT, code)
+            }
             // CMakeLists.txt
             "txt" => format!("# {}\n{}\n", GENERATED_COMMENT, code),
+            // Markdown
+            "md" => format!("<!-- {} -->\n{}\n", GENERATED_COMMENT, code),
             _ => without_generated_comment(code),
         },
         None => without_generated_comment(code),
     }
 }
 
+fn is_cxx_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("cpp" | "hpp" | "h" | "mm")
+    )
+}
+
 fn without_generated_comment(code: &str) -> String {
     format!("{}\n", code)
 }
+
+fn relative_path(path: &Path, project_root: &Path) -> PathBuf {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_preview() {
+        let path = Path::new("crates/lib/src/ffi.rs");
+
+        assert_eq!(file_preview(path, None, "a\n", true), FilePreview::New);
+        assert_eq!(
+            file_preview(path, Some("a\n"), "b\n", false),
+            FilePreview::Skipped
+        );
+        assert_eq!(
+            file_preview(path, Some("a\n"), "a\n", true),
+            FilePreview::Unchanged
+        );
+        assert_eq!(
+            file_preview(path, Some("a\nb\n"), "a\nc\n", true),
+            FilePreview::Changed(
+                [
+                    "--- a/crates/lib/src/ffi.rs",
+                    "+++ b/crates/lib/src/ffi.rs",
+                    "@@ -1,2 +1,2 @@",
+                    " a",
+                    "-b",
+                    "+c",
+                    "",
+                ]
+                .join("\n")
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_generated() {
+        let root = std::env::temp_dir().join(format!("craby-check-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let generated = |name: &str, content: &str| TemplateResult {
+            path: root.join(name),
+            content: content.to_string(),
+            overwrite: true,
+            merged: false,
+        };
+        let up_to_date = generated("ffi.rs", "mod ffi;");
+        fs::write(&up_to_date.path, file_content(&up_to_date, &None)).unwrap();
+        let outdated = generated("CxxModule.hpp", "namespace craby::other {}");
+        fs::write(&outdated.path, "namespace craby::mymodule {}").unwrap();
+        let missing = generated("CxxModule.cpp", "");
+        let impl_stub = TemplateResult {
+            overwrite: false,
+            ..generated("lib.rs", "impl MyModule {}")
+        };
+
+        let ok = check_generated(&root, &[up_to_date], &None, false);
+        let err = check_generated(&root, &[outdated, missing, impl_stub], &None, false);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(ok.is_ok());
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Generated files are out of date. Run `crabygen codegen` to update them\n  - CxxModule.hpp\n  - CxxModule.cpp"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use craby_build::constants::toolchain::Target;
+use craby_codegen::constants::{DEFAULT_CXX_BASE_CLASS, DEFAULT_THREAD_POOL_SIZE};
+use craby_common::{
+    config::{load_config, CompleteConfig},
+    constants::{android::NDK_VERSION_RANGE, ios::XCODE_VERSION_RANGE},
+    utils::version::VersionRange,
+};
+use owo_colors::OwoColorize;
+use serde_json::{json, Value};
+
+use crate::utils::build_targets::get_build_targets;
+
+pub struct ConfigOptions {
+    pub project_root: PathBuf,
+    /// Print the resolved configuration as JSON
+    pub json: bool,
+}
+
+pub fn perform(opts: ConfigOptions) -> anyhow::Result<()> {
+    let config = load_config(&opts.project_root)?;
+    let resolved = resolve_config(&config)?;
+
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&resolved)?);
+    } else {
+        let mut entries = vec![];
+        flatten(&resolved, String::new(), &mut entries);
+
+        let key_width = entries.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+        for (key, value) in entries {
+            println!("{:<key_width$}  {}", key.bold(), value);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the configuration with the default values applied
+fn resolve_config(config: &CompleteConfig) -> anyhow::Result<Value> {
+    let targets = get_build_targets(config)?;
+    let (android_targets, ios_targets): (Vec<Target>, Vec<Target>) = targets
+        .into_iter()
+        .partition(|target| matches!(target, Target::Android(_)));
+    let cxx_base_class = config.project.cxx_base_class.as_ref();
+    let ndk_version = config
+        .android
+        .ndk_version
+        .clone()
+        .unwrap_or_else(|| VersionRange::new(NDK_VERSION_RANGE.0, NDK_VERSION_RANGE.1));
+    let xcode_version = config
+        .ios
+        .xcode_version
+        .clone()
+        .unwrap_or_else(|| VersionRange::new(XCODE_VERSION_RANGE.0, XCODE_VERSION_RANGE.1));
+
+    Ok(json!({
+        "project": {
+            "name": config.project.name,
+            "cxx_namespace": config.project.cxx_namespace(),
+            "root": config.project_root,
+            "source_dir": config.source_dir,
+            "spec_glob": config.project.spec_glob,
+            "indent": config.project.indent,
+            "expose": config.project.expose,
+            "cxx_base_class": {
+                "name": cxx_base_class.map_or(DEFAULT_CXX_BASE_CLASS, |base_class| base_class.name.as_str()),
+                "header": cxx_base_class.and_then(|base_class| base_class.header.as_ref()),
+            },
+            "clang_format": config.project.clang_format.unwrap_or(false),
+            "instance_counter": config.project.instance_counter.unwrap_or(false),
+            "module_features": config.project.module_features.unwrap_or(false),
+            "post_codegen": config.project.post_codegen.as_ref().map(|post_codegen| json!({
+                "command": post_codegen.command,
+                "fail_on_error": post_codegen.fail_on_error.unwrap_or(true),
+            })),
+            "build_log": config.project.build_log.unwrap_or(false),
+            "thread_pool_size": config.project.thread_pool_size.unwrap_or(DEFAULT_THREAD_POOL_SIZE),
+            "stub_defaults": config.project.stub_defaults.unwrap_or(false),
+            "c_shim": config.project.c_shim.unwrap_or(false),
+            "listener_error_hook": config.project.listener_error_hook.unwrap_or(false),
+            "swift_header": config.project.swift_header.unwrap_or(false),
+            "module_registry": config.project.module_registry.unwrap_or(false),
+            "lazy_registration": config.project.lazy_registration.unwrap_or(false),
+            "unimplemented_error": config.project.unimplemented_error.unwrap_or(false),
+            "docs": config.project.docs.unwrap_or(false),
+            "kotlin_stubs": config.project.kotlin_stubs.unwrap_or(false),
+            "dts": config.project.dts.unwrap_or(false),
+            "mocks": config.project.mocks.unwrap_or(false),
+        },
+        "android": {
+            "package_name": config.android.package_name,
+            "targets": android_targets.iter().map(|target| target.to_str()).collect::<Vec<_>>(),
+            "ndk_version": ndk_version,
+            "page_size_16kb": config.android.page_size_16kb.unwrap_or(true),
+            "strip": config.android.strip.unwrap_or(true),
+        },
+        "ios": {
+            "targets": ios_targets.iter().map(|target| target.to_str()).collect::<Vec<_>>(),
+            "xcode_version": xcode_version,
+        },
+    }))
+}
+
+/// Flattens the nested object into dot-separated keys (eg. `project.cxx_base_class.name`)
+fn flatten(value: &Value, prefix: String, entries: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(value, key, entries);
+            }
+        }
+        Value::Array(values) => {
+            let values = values
+                .iter()
+                .map(|value| match value {
+                    Value::String(s) => s.clone(),
+                    value => value.to_string(),
+                })
+                .collect::<Vec<_>>();
+            entries.push((prefix, values.join(", ")));
+        }
+        Value::Null => entries.push((prefix, format!("{}", "(default)".dimmed()))),
+        Value::String(s) => entries.push((prefix, s.clone())),
+        value => entries.push((prefix, value.to_string())),
+    }
+}
@@ -0,0 +1,3 @@
+pub use handler::*;
+
+mod handler;
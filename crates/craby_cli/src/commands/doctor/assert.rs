@@ -1,26 +1,203 @@
 use log::debug;
 use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use crate::commands::doctor::suggestion::Suggestion;
 
 const STATUS_OK: &str = "✓";
 const STATUS_ERR: &str = "✗";
+const STATUS_WARN: &str = "!";
+const STATUS_SKIP: &str = "-";
 
+#[derive(Serialize)]
+#[serde(tag = "status", content = "message", rename_all = "lowercase")]
 pub enum Status {
     Ok,
+    Warn(String),
+    Error(String),
+    Skip(String),
+}
+
+/// Result of a single check
+#[derive(Serialize)]
+pub struct Check {
+    pub section: &'static str,
+    pub name: String,
+    #[serde(flatten)]
+    pub status: Status,
+    /// Suggestion to fix the failed check
+    pub suggestion: Option<Suggestion>,
 }
 
-pub fn assert_with_status(label: &str, f: impl FnOnce() -> Result<Status, anyhow::Error>) {
-    match f() {
-        Ok(Status::Ok) => {
-            println!("{} {}", STATUS_OK.bold().green(), label);
+/// Collects the results of the checks
+///
+/// The results are printed as they are collected unless `json` is set.
+pub struct Report {
+    json: bool,
+    section: &'static str,
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    pub fn new(json: bool) -> Self {
+        Self {
+            json,
+            section: "",
+            checks: Vec::new(),
+        }
+    }
+
+    pub fn section(&mut self, section: &'static str) {
+        self.section = section;
+
+        if !self.json {
+            println!("\n{}", section.bold().dimmed());
         }
-        Err(e) => {
-            println!(
-                "{} {} - {}",
-                STATUS_ERR.bold().red(),
-                label,
-                e.to_string().red()
-            );
+    }
+
+    /// Runs the check labeled with `name` and the dimmed `detail` (eg. `NDK version (>=27)`).
+    ///
+    /// The `suggestion` is kept only if the check fails.
+    pub fn assert_with_status(
+        &mut self,
+        name: &str,
+        detail: Option<&str>,
+        suggestion: Option<Suggestion>,
+        f: impl FnOnce() -> Result<Status, anyhow::Error>,
+    ) {
+        let status = f().unwrap_or_else(|e| {
             debug!("Assertion failed: {}", e);
+            Status::Error(e.to_string())
+        });
+
+        if !self.json {
+            let label = match detail {
+                Some(detail) => format!("{} {}", name, detail.dimmed()),
+                None => name.to_string(),
+            };
+
+            match &status {
+                Status::Ok => println!("{} {}", STATUS_OK.bold().green(), label),
+                Status::Warn(message) => println!(
+                    "{} {} - {}",
+                    STATUS_WARN.bold().yellow(),
+                    label,
+                    message.yellow()
+                ),
+                Status::Error(message) => {
+                    println!("{} {} - {}", STATUS_ERR.bold().red(), label, message.red())
+                }
+                Status::Skip(message) => println!(
+                    "{} {} - {}",
+                    STATUS_SKIP.bold().dimmed(),
+                    label,
+                    message.dimmed()
+                ),
+            }
         }
+
+        let suggestion = match status {
+            Status::Error(..) => suggestion,
+            _ => None,
+        };
+
+        self.checks.push(Check {
+            section: self.section,
+            name: match detail {
+                Some(detail) => format!("{name} {detail}"),
+                None => name.to_string(),
+            },
+            status,
+            suggestion,
+        });
+    }
+
+    /// Records the check labeled with `name` and `detail` as skipped without running it.
+    pub fn skip(&mut self, name: &str, detail: Option<&str>, reason: &str) {
+        self.assert_with_status(name, detail, None, || Ok(Status::Skip(reason.to_string())));
+    }
+
+    pub fn passed(&self) -> bool {
+        !self
+            .checks
+            .iter()
+            .any(|check| matches!(check.status, Status::Error(..)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_report_json() {
+        let mut report = Report::new(true);
+        report.section("Rust");
+        report.assert_with_status(
+            "Toolchain Target",
+            Some("(aarch64-apple-ios)"),
+            Some(Suggestion::command(
+                "Install 'aarch64-apple-ios' target with rustup",
+                "rustup target install aarch64-apple-ios",
+            )),
+            || anyhow::bail!("Not installed"),
+        );
+        report.assert_with_status(
+            "NDK version",
+            None,
+            Some(Suggestion::plain_text("Unused", None)),
+            || Ok(Status::Ok),
+        );
+
+        assert!(!report.passed());
+        assert_eq!(
+            serde_json::to_value(&report.checks).unwrap(),
+            json!([
+                {
+                    "section": "Rust",
+                    "name": "Toolchain Target (aarch64-apple-ios)",
+                    "status": "error",
+                    "message": "Not installed",
+                    "suggestion": {
+                        "message": "Install 'aarch64-apple-ios' target with rustup",
+                        "type": "command",
+                        "value": "rustup target install aarch64-apple-ios",
+                    },
+                },
+                {
+                    "section": "Rust",
+                    "name": "NDK version",
+                    "status": "ok",
+                    "suggestion": null,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_report_skip() {
+        let mut report = Report::new(true);
+        report.section("iOS");
+        report.skip(
+            "Xcode version",
+            Some("(>=16.1)"),
+            "skipped (host not macOS)",
+        );
+
+        assert!(report.passed());
+        assert_eq!(
+            serde_json::to_value(&report.checks).unwrap(),
+            json!([
+                {
+                    "section": "iOS",
+                    "name": "Xcode version (>=16.1)",
+                    "status": "skip",
+                    "message": "skipped (host not macOS)",
+                    "suggestion": null,
+                },
+            ])
+        );
     }
 }
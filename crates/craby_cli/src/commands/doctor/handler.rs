@@ -2,93 +2,144 @@ use std::path::PathBuf;
 
 use craby_build::constants::toolchain::{Target, DEFAULT_ANDROID_TARGETS};
 use craby_common::{
-    constants::toolchain::TARGETS,
-    env::get_installed_targets,
+    config::load_config,
+    constants::{android::NDK_VERSION_RANGE, ios::XCODE_VERSION_RANGE, toolchain::TARGETS},
+    env::{get_installed_targets, is_initialized, is_macos_host},
     utils::{
-        android::is_gradle_configured,
-        ios::{is_podspec_configured, is_xcode_cli_tools_installed},
+        android::{get_ndk_version, is_gradle_configured},
+        ios::{get_xcode_version, is_podspec_configured, is_xcode_cli_tools_installed},
+        version::{Version, VersionRange},
     },
 };
 use indoc::formatdoc;
-use owo_colors::OwoColorize;
+use serde_json::json;
 
 use crate::commands::doctor::{
-    assert::{assert_with_status, Status},
+    assert::{Report, Status},
     suggestion::{print_suggestions, Suggestion},
 };
 
+const SKIPPED_NOT_MACOS: &str = "skipped (host not macOS)";
+
 pub struct DoctorOptions {
     pub project_root: PathBuf,
+    /// Print the results of the checks as JSON
+    pub json: bool,
 }
 
 pub fn perform(opts: DoctorOptions) -> anyhow::Result<()> {
-    println!("\n{}", "Platform".bold().dimmed());
-    let mut passed = true;
-    let mut suggestions = Vec::new();
+    let mut report = Report::new(opts.json);
+
+    report.section("Project");
+    let mut config = None;
+    report.assert_with_status(
+        "Configuration",
+        Some("(craby.toml)"),
+        Some(Suggestion::plain_text(
+            "Fix the errors of `craby.toml`",
+            None,
+        )),
+        || {
+            // The version ranges fall back to the defaults outside of a Craby project
+            if !is_initialized(&opts.project_root) {
+                return Ok(Status::Warn(
+                    "`craby.toml` not found, using the default version ranges".to_string(),
+                ));
+            }
+
+            config = Some(load_config(&opts.project_root)?);
+            Ok(Status::Ok)
+        },
+    );
 
-    assert_with_status("macOS", || {
-        if std::env::consts::OS == "macos" {
+    let (ndk_version_range, xcode_version_range) = match config {
+        Some(config) => (config.android.ndk_version, config.ios.xcode_version),
+        None => (None, None),
+    };
+    let ndk_version_range = ndk_version_range
+        .unwrap_or_else(|| VersionRange::new(NDK_VERSION_RANGE.0, NDK_VERSION_RANGE.1));
+    let xcode_version_range = xcode_version_range
+        .unwrap_or_else(|| VersionRange::new(XCODE_VERSION_RANGE.0, XCODE_VERSION_RANGE.1));
+
+    // The iOS targets can only be built on macOS, but the other commands work on any host
+    let is_macos = is_macos_host();
+
+    report.section("Platform");
+    report.assert_with_status("macOS", None, None, || {
+        if is_macos {
             Ok(Status::Ok)
         } else {
-            passed &= false;
-            anyhow::bail!("Unsupported platform: {}", std::env::consts::OS);
+            Ok(Status::Warn(format!(
+                "iOS targets can not be built on {}",
+                std::env::consts::OS
+            )))
         }
     });
 
-    println!("\n{}", "Rust".bold().dimmed());
+    report.section("Rust");
     let installed_targets = get_installed_targets()?;
     TARGETS.iter().for_each(|target| {
-        let target_label = format!("({target})");
-        assert_with_status(
-            &format!("Toolchain Target {}", target_label.dimmed()),
+        if !is_macos && target.contains("-apple-ios") {
+            report.skip(
+                "Toolchain Target",
+                Some(&format!("({target})")),
+                SKIPPED_NOT_MACOS,
+            );
+            return;
+        }
+
+        report.assert_with_status(
+            "Toolchain Target",
+            Some(&format!("({target})")),
+            Some(Suggestion::command(
+                &format!("Install '{}' target with rustup", target),
+                &format!("rustup target install {target}"),
+            )),
             || {
                 if installed_targets.contains(&target.to_string()) {
                     Ok(Status::Ok)
                 } else {
-                    passed &= false;
-                    suggestions.push(Suggestion::command(
-                        &format!("Install '{}' target with rustup", target),
-                        &format!("rustup target install {target}"),
-                    ));
                     anyhow::bail!("Not installed");
                 }
             },
         );
     });
 
-    println!("\n{}", "Android".bold().dimmed());
-    assert_with_status(
-        &format!("Environment variable: {}", "ANDROID_NDK_HOME".dimmed()),
+    report.section("Android");
+    report.assert_with_status(
+        "Environment variable:",
+        Some("ANDROID_NDK_HOME"),
+        Some(Suggestion::plain_text(
+            "Check `$ANDROID_NDK_HOME` path is set correctly",
+            Some(&formatdoc! {
+                r#"
+                If Android NDK is not installed, please install it from the following link:
+                https://developer.android.com/ndk/downloads"#,
+            }),
+        )),
         || match std::env::var("ANDROID_NDK_HOME") {
             Ok(_) => Ok(Status::Ok),
-            Err(e) => {
-                passed &= false;
-                suggestions.push(Suggestion::plain_text(
-                    &format!(
-                        "Check {} path is set correctly",
-                        "$ANDROID_NDK_HOME".yellow()
-                    ),
-                    Some(&formatdoc! {
-                        r#"
-                        If Android NDK is not installed, please install it from the following link:
-                        {link}"#,
-                        link = "https://developer.android.com/ndk/downloads".dimmed().underline()
-                    }),
-                ));
-                anyhow::bail!("Environment variable is not set: {}", e);
-            }
+            Err(e) => anyhow::bail!("Environment variable is not set: {}", e),
         },
     );
 
+    report.assert_with_status(
+        "NDK version",
+        Some(&format!("({ndk_version_range})")),
+        None,
+        || assert_version(&get_ndk_version()?, &ndk_version_range),
+    );
+
     for target in DEFAULT_ANDROID_TARGETS {
         match target {
             Target::Android(abi) => {
-                assert_with_status(
-                    &format!("Clang toolchain {}", format!("({abi})").dimmed()),
+                report.assert_with_status(
+                    "Clang toolchain",
+                    Some(&format!("({abi})")),
+                    None,
                     || {
                         for (_, value) in abi.to_env()? {
                             if !value.try_exists()? {
-                                passed &= false;
                                 anyhow::bail!("Clang toolchain not found: {abi}");
                             }
                         }
@@ -100,52 +151,97 @@ pub fn perform(opts: DoctorOptions) -> anyhow::Result<()> {
         }
     }
 
-    assert_with_status(
-        &format!("Build configuration {}", "(build.gradle)".dimmed()),
+    report.assert_with_status(
+        "Build configuration",
+        Some("(build.gradle)"),
+        Some(Suggestion::plain_text(
+            "Run `crabygen codegen` to fix this issue",
+            None,
+        )),
         || {
             if is_gradle_configured(&opts.project_root)? {
                 Ok(Status::Ok)
             } else {
-                passed &= false;
-                suggestions.push(Suggestion::plain_text(
-                    "Run `crabygen codegen` to fix this issue",
-                    None,
-                ));
                 anyhow::bail!("`android/build.gradle` is not configured correctly");
             }
         },
     );
 
-    println!("\n{}", "iOS".bold().dimmed());
-    assert_with_status("XCode Command Line Tools", || {
-        if is_xcode_cli_tools_installed()? {
+    report.section("iOS");
+    if is_macos {
+        assert_xcode(&mut report, &xcode_version_range);
+    } else {
+        report.skip("XCode Command Line Tools", None, SKIPPED_NOT_MACOS);
+        report.skip(
+            "Xcode version",
+            Some(&format!("({xcode_version_range})")),
+            SKIPPED_NOT_MACOS,
+        );
+    }
+    report.assert_with_status("Build configuration", Some("(.podspec)"), None, || {
+        if is_podspec_configured(&opts.project_root)? {
             Ok(Status::Ok)
         } else {
-            passed &= false;
-            suggestions.push(Suggestion::command(
-                "Install XCode Command Line Tools",
-                "xcode-select --install",
-            ));
-            anyhow::bail!("XCode Command Line Tools is not installed");
+            anyhow::bail!("`.podspec` is not configured correctly");
         }
     });
-    assert_with_status(
-        &format!("Build configuration {}", "(.podspec)".dimmed()),
+
+    let passed = report.passed();
+
+    if opts.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "passed": passed,
+                "checks": report.checks,
+            }))?
+        );
+    } else if !passed {
+        let mut suggestions = report
+            .checks
+            .iter()
+            .filter_map(|check| check.suggestion.as_ref())
+            .collect::<Vec<_>>();
+
+        println!();
+        print_suggestions(&mut suggestions);
+    }
+
+    if !passed {
+        anyhow::bail!("Some required configurations are not configured correctly");
+    }
+
+    Ok(())
+}
+
+fn assert_xcode(report: &mut Report, xcode_version_range: &VersionRange) {
+    report.assert_with_status(
+        "XCode Command Line Tools",
+        None,
+        Some(Suggestion::command(
+            "Install XCode Command Line Tools",
+            "xcode-select --install",
+        )),
         || {
-            if is_podspec_configured(&opts.project_root)? {
+            if is_xcode_cli_tools_installed()? {
                 Ok(Status::Ok)
             } else {
-                passed &= false;
-                anyhow::bail!("`.podspec` is not configured correctly");
+                anyhow::bail!("XCode Command Line Tools is not installed");
             }
         },
     );
+    report.assert_with_status(
+        "Xcode version",
+        Some(&format!("({xcode_version_range})")),
+        None,
+        || assert_version(&get_xcode_version()?, xcode_version_range),
+    );
+}
 
-    if !passed {
-        println!();
-        print_suggestions(&mut suggestions);
-        anyhow::bail!("Some required configurations are not configured correctly");
+fn assert_version(version: &Version, range: &VersionRange) -> Result<Status, anyhow::Error> {
+    if range.contains(version)? {
+        Ok(Status::Ok)
+    } else {
+        Ok(Status::Warn(format!("Unsupported version: {}", version)))
     }
-
-    Ok(())
 }
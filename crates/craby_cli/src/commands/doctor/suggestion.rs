@@ -4,14 +4,19 @@ use std::{
 };
 
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
+#[derive(Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
 pub enum SuggestionType {
     Command(String),
     PlainText(Option<String>),
 }
 
+#[derive(Serialize)]
 pub struct Suggestion {
     pub message: String,
+    #[serde(flatten)]
     pub suggestion_type: SuggestionType,
 }
 
@@ -54,7 +59,7 @@ impl Display for Suggestion {
     }
 }
 
-pub fn print_suggestions(suggestions: &mut [Suggestion]) {
+pub fn print_suggestions(suggestions: &mut [&Suggestion]) {
     if suggestions.is_empty() {
         return;
     }
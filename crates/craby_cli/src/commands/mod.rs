@@ -1,6 +1,8 @@
 pub mod build;
 pub mod clean;
 pub mod codegen;
+pub mod config;
 pub mod doctor;
 pub mod init;
 pub mod show;
+pub mod watch;
@@ -5,10 +5,14 @@ use craby_common::config::load_config;
 use log::info;
 use owo_colors::OwoColorize;
 
-use crate::utils::schema::print_schema;
+use crate::utils::schema::{print_ffi_surface, print_schema};
 
 pub struct ShowOptions {
     pub project_root: PathBuf,
+    /// Print the number of the generated FFI items per module
+    pub ffi_surface: bool,
+    /// Print the parsed schemas as JSON
+    pub json: bool,
 }
 
 pub fn perform(opts: ShowOptions) -> anyhow::Result<()> {
@@ -16,8 +20,16 @@ pub fn perform(opts: ShowOptions) -> anyhow::Result<()> {
     let schemas = codegen(craby_codegen::CodegenOptions {
         project_root: &opts.project_root,
         source_dir: &config.source_dir,
+        spec_glob: config.project.spec_glob.as_deref(),
+        expose: config.project.expose.as_ref(),
+        diagnostics_out: None,
     })?;
 
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&schemas)?);
+        return Ok(());
+    }
+
     let total_mods = schemas.len();
     info!("{} module(s) found\n", total_mods);
 
@@ -25,6 +37,11 @@ pub fn perform(opts: ShowOptions) -> anyhow::Result<()> {
         println!("{} ({}/{})", schema.module_name.bold(), i + 1, total_mods);
         print_schema(schema)?;
         println!();
+
+        if opts.ffi_surface {
+            print_ffi_surface(&schema.as_rs_cxx_bridge()?.surface());
+            println!();
+        }
     }
 
     Ok(())
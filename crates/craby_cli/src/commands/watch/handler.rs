@@ -0,0 +1,187 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+use craby_codegen::{codegen, collect_spec_files, types::Schema};
+use craby_common::config::{load_config, CompleteConfig};
+use log::{debug, error, info};
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
+use owo_colors::OwoColorize;
+
+use crate::commands::codegen::{self, CodegenOptions};
+
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Options of the watch command, passed to the codegen on each change (same as the `codegen` command).
+#[derive(Debug)]
+pub struct WatchOptions {
+    pub project_root: PathBuf,
+    pub overwrite: bool,
+    pub stub_defaults: bool,
+    pub c_shim: bool,
+    pub listener_error_hook: bool,
+    pub swift_header: bool,
+    pub module_registry: bool,
+    pub lazy_registration: bool,
+    pub unimplemented_error: bool,
+    pub docs: bool,
+    pub kotlin_stubs: bool,
+    pub dts: bool,
+    pub mocks: bool,
+}
+
+enum WatchEvent {
+    Changed(DebounceEventResult),
+    Exit,
+}
+
+pub fn perform(opts: WatchOptions) -> anyhow::Result<()> {
+    let config = load_config(&opts.project_root)?;
+
+    codegen::perform(codegen_options(&opts))?;
+    let mut prev_hash = Some(schema_hash(&opts.project_root, &config)?);
+
+    let (tx, rx) = mpsc::channel();
+    let exit_tx = tx.clone();
+    ctrlc::set_handler(move || {
+        let _ = exit_tx.send(WatchEvent::Exit);
+    })?;
+
+    let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, move |res| {
+        let _ = tx.send(WatchEvent::Changed(res));
+    })?;
+
+    for path in watch_paths(&opts.project_root, &config)? {
+        debug!("Watching: {}", path.display());
+        debouncer.watcher().watch(&path, RecursiveMode::Recursive)?;
+    }
+
+    info!(
+        "Watching for spec changes... {}",
+        "(press Ctrl-C to stop)".dimmed()
+    );
+
+    while let Ok(event) = rx.recv() {
+        let events = match event {
+            WatchEvent::Changed(Ok(events)) => events,
+            WatchEvent::Changed(Err(e)) => {
+                error!("Watch error: {}", e);
+                continue;
+            }
+            WatchEvent::Exit => break,
+        };
+
+        if !events.iter().any(|event| is_spec_file(&event.path)) {
+            continue;
+        }
+
+        // Keep watching even if the specs are invalid while editing
+        let hash = match schema_hash(&opts.project_root, &config) {
+            Ok(hash) => hash,
+            Err(e) => {
+                error!("Error: {}", e);
+                prev_hash = None;
+                continue;
+            }
+        };
+
+        if prev_hash.as_ref() == Some(&hash) {
+            info!("Schemas are not changed. Skipping regeneration");
+            continue;
+        }
+
+        match codegen::perform(codegen_options(&opts)) {
+            Ok(changed_files) => {
+                prev_hash = Some(hash);
+                print_changed_files(&changed_files);
+            }
+            Err(e) => error!("Error: {}", e),
+        }
+    }
+
+    info!("Stopped watching");
+
+    Ok(())
+}
+
+fn codegen_options(opts: &WatchOptions) -> CodegenOptions {
+    CodegenOptions {
+        project_root: opts.project_root.clone(),
+        overwrite: opts.overwrite,
+        stub_defaults: opts.stub_defaults,
+        c_shim: opts.c_shim,
+        listener_error_hook: opts.listener_error_hook,
+        swift_header: opts.swift_header,
+        module_registry: opts.module_registry,
+        lazy_registration: opts.lazy_registration,
+        unimplemented_error: opts.unimplemented_error,
+        docs: opts.docs,
+        kotlin_stubs: opts.kotlin_stubs,
+        dts: opts.dts,
+        mocks: opts.mocks,
+        check: false,
+        dry_run: false,
+        diagnostics_out: None,
+        depfile: None,
+    }
+}
+
+fn schema_hash(project_root: &PathBuf, config: &CompleteConfig) -> anyhow::Result<String> {
+    let schemas = codegen(craby_codegen::CodegenOptions {
+        project_root,
+        source_dir: &config.source_dir,
+        spec_glob: config.project.spec_glob.as_deref(),
+        expose: config.project.expose.as_ref(),
+        diagnostics_out: None,
+    })?;
+
+    Ok(Schema::to_hash(&schemas))
+}
+
+/// Returns the directories to watch.
+///
+/// With `spec_glob`, the parent directories of the matched spec files are watched
+/// instead of the source directory.
+fn watch_paths(project_root: &Path, config: &CompleteConfig) -> anyhow::Result<Vec<PathBuf>> {
+    if config.project.spec_glob.is_none() {
+        return Ok(vec![config.source_dir.clone()]);
+    }
+
+    let mut paths = collect_spec_files(
+        project_root,
+        &config.source_dir,
+        config.project.spec_glob.as_deref(),
+    )?
+    .into_iter()
+    .filter_map(|path| path.parent().map(Path::to_path_buf))
+    .collect::<Vec<_>>();
+    paths.sort();
+    paths.dedup();
+
+    Ok(paths)
+}
+
+fn is_spec_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == "ts" || ext == "tsx")
+}
+
+fn print_changed_files(changed_files: &[PathBuf]) {
+    let changed_file_cnt = changed_files.len();
+    if changed_file_cnt == 0 {
+        info!("No generated files changed");
+        return;
+    }
+
+    info!("{} generated file(s) changed", changed_file_cnt);
+    for (idx, file) in changed_files.iter().enumerate() {
+        let line = if idx == changed_file_cnt - 1 {
+            "└─"
+        } else {
+            "├─"
+        };
+        println!("{} {}", line, file.display().dimmed());
+    }
+}
@@ -1,4 +1,6 @@
-use craby_build::constants::toolchain::{Target, DEFAULT_ANDROID_TARGETS, DEFAULT_IOS_TARGETS};
+use craby_build::constants::toolchain::{
+    Target, DEFAULT_ANDROID_TARGETS, DEFAULT_IOS_TARGETS, MAC_CATALYST_TARGETS,
+};
 use craby_common::config::CompleteConfig;
 use owo_colors::OwoColorize;
 
@@ -10,12 +12,68 @@ pub fn get_build_targets(config: &CompleteConfig) -> Result<Vec<Target>, anyhow:
     Ok([android, ios].concat())
 }
 
+/// Returns the build targets of the given target triples (eg. `aarch64-linux-android`).
+///
+/// Fails if any of the triples is not a supported target.
+pub fn parse_build_targets(triples: &[String]) -> Result<Vec<Target>, anyhow::Error> {
+    triples
+        .iter()
+        .map(|triple| {
+            Target::try_from(triple.as_str()).map_err(|_| {
+                let supported = DEFAULT_ANDROID_TARGETS
+                    .iter()
+                    .chain(DEFAULT_IOS_TARGETS.iter())
+                    .chain(MAC_CATALYST_TARGETS.iter())
+                    .map(|target| target.to_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow::anyhow!("Unknown target: `{}` (supported: {})", triple, supported)
+            })
+        })
+        .collect()
+}
+
+/// Keeps only the requested targets of the build targets. All of the build targets are kept if `None`.
+///
+/// Fails if any of the requested targets is not one of the build targets.
+pub fn filter_build_targets(
+    build_targets: Vec<Target>,
+    requested: Option<&[Target]>,
+) -> Result<Vec<Target>, anyhow::Error> {
+    let Some(requested) = requested else {
+        return Ok(build_targets);
+    };
+
+    if let Some(target) = requested.iter().find(|requested| {
+        !build_targets
+            .iter()
+            .any(|target| target.to_str() == requested.to_str())
+    }) {
+        anyhow::bail!(
+            "Target `{}` is not one of the build targets. Please check your `craby.toml` file.",
+            target
+        );
+    }
+
+    Ok(build_targets
+        .into_iter()
+        .filter(|target| {
+            requested
+                .iter()
+                .any(|requested| requested.to_str() == target.to_str())
+        })
+        .collect())
+}
+
 pub fn print_build_targets(targets: &[Target]) {
     for (idx, target) in targets.iter().enumerate() {
         let is_last = idx == targets.len() - 1;
         let branch = if is_last { "└─" } else { "├─" };
         let platform = match target {
             Target::Android(_) => format!("{}", "(Android)".green()),
+            Target::Ios(identifier) if identifier.is_mac_catalyst() => {
+                format!("{}", "(Mac Catalyst)".blue())
+            }
             Target::Ios(_) => format!("{}", "(iOS)".blue()),
         };
         println!("{} {} {}", branch, platform, target.to_str().dimmed());
@@ -34,3 +92,65 @@ fn get_targets_with_defaults(
         None => Ok(defaults.to_vec()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_strs(targets: &[Target]) -> Vec<&str> {
+        targets.iter().map(|target| target.to_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_build_targets() {
+        let targets = parse_build_targets(&[
+            "aarch64-linux-android".to_string(),
+            "aarch64-apple-ios-sim".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            to_strs(&targets),
+            vec!["aarch64-linux-android", "aarch64-apple-ios-sim"]
+        );
+    }
+
+    #[test]
+    fn test_parse_build_targets_unknown() {
+        let err = parse_build_targets(&["arm64-linux-android".to_string()]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unknown target: `arm64-linux-android` (supported: aarch64-linux-android, armv7-linux-androideabi, x86_64-linux-android, i686-linux-android, aarch64-apple-ios, aarch64-apple-ios-sim, x86_64-apple-ios, aarch64-apple-ios-macabi, x86_64-apple-ios-macabi)"
+        );
+    }
+
+    #[test]
+    fn test_filter_build_targets() {
+        let build_targets = DEFAULT_ANDROID_TARGETS.to_vec();
+        let requested = parse_build_targets(&["aarch64-linux-android".to_string()]).unwrap();
+
+        assert_eq!(
+            to_strs(&filter_build_targets(build_targets.clone(), Some(&requested)).unwrap()),
+            vec!["aarch64-linux-android"]
+        );
+        assert_eq!(
+            filter_build_targets(build_targets.clone(), None)
+                .unwrap()
+                .len(),
+            build_targets.len()
+        );
+    }
+
+    #[test]
+    fn test_filter_build_targets_not_configured() {
+        let requested = parse_build_targets(&["aarch64-apple-ios".to_string()]).unwrap();
+        let err =
+            filter_build_targets(DEFAULT_ANDROID_TARGETS.to_vec(), Some(&requested)).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Target `aarch64-apple-ios` is not one of the build targets. Please check your `craby.toml` file."
+        );
+    }
+}
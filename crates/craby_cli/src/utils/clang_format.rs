@@ -0,0 +1,42 @@
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+};
+
+pub fn is_clang_format_available() -> bool {
+    Command::new("clang-format")
+        .arg("--version")
+        .output()
+        .is_ok()
+}
+
+/// Formats the source with the nearest `.clang-format` style of the given path.
+///
+/// The source is formatted in memory, so the files do not need to be written first.
+pub fn clang_format_str(src: &str, path: &Path) -> anyhow::Result<String> {
+    let assume_filename = format!("--assume-filename={}", path.display());
+    let mut child = Command::new("clang-format")
+        .args(["--style=file", &assume_filename])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Write the input on another thread so that a full stdout pipe does not block the input
+    let mut stdin = child.stdin.take().unwrap();
+    let src = src.to_string();
+    let writer = thread::spawn(move || stdin.write_all(src.as_bytes()));
+    let output = child.wait_with_output()?;
+    writer.join().unwrap()?;
+
+    match output.status.code() {
+        Some(0) => Ok(String::from_utf8(output.stdout)?),
+        _ => anyhow::bail!(
+            "Failed to format {} with clang-format: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
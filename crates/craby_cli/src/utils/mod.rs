@@ -1,7 +1,9 @@
 pub mod build_targets;
+pub mod clang_format;
 pub mod file;
 pub mod git;
 pub mod log;
+pub mod post_codegen;
 pub mod schema;
 pub mod template;
 pub mod terminal;
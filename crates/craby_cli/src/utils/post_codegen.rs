@@ -0,0 +1,44 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Environment variable that holds the generated files (relative to the project root, one per line)
+pub const GENERATED_FILES_ENV: &str = "CRABY_GENERATED_FILES";
+
+/// Runs the post-codegen command with the system shell in the project root.
+pub fn run_post_codegen(
+    command: &str,
+    project_root: &Path,
+    generated_files: &[PathBuf],
+) -> anyhow::Result<()> {
+    let generated_files = generated_files
+        .iter()
+        .map(|path| path.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+
+    let status = cmd
+        .current_dir(project_root)
+        .env(GENERATED_FILES_ENV, generated_files)
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to run the post-codegen command: {}", e))?;
+
+    match status.code() {
+        Some(0) => Ok(()),
+        _ => anyhow::bail!(
+            "Post-codegen command exited with code {}",
+            status.code().unwrap_or(-1)
+        ),
+    }
+}
@@ -1,4 +1,4 @@
-use craby_codegen::types::Schema;
+use craby_codegen::types::{FfiSurface, Schema};
 use owo_colors::OwoColorize;
 
 use crate::utils::terminal::CodeHighlighter;
@@ -70,3 +70,11 @@ pub fn print_schema(schema: &Schema) -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+pub fn print_ffi_surface(surface: &FfiSurface) {
+    println!("FFI surface");
+    println!("├─ Extern functions ({})", surface.extern_funcs);
+    println!("├─ Structs ({})", surface.structs);
+    println!("├─ Enums ({})", surface.enums);
+    println!("└─ Nullable helpers ({})", surface.nullables);
+}
@@ -1,13 +1,21 @@
-use std::{fs, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
-use craby_common::{constants::SPEC_FILE_PREFIX, utils::fs::collect_files};
+use craby_common::{
+    constants::SPEC_FILE_PREFIX,
+    utils::fs::{collect_files, collect_glob_files},
+};
 use log::debug;
 
 use crate::{
+    constants::MOCKS_DIR,
     parser::{
-        native_spec_parser::try_parse_schema,
+        native_spec_parser::try_parse_schema_from_path,
         types::ParseError,
-        utils::{render_report, RenderReportOptions},
+        utils::{render_report, to_diagnostic_entries, DiagnosticEntry, RenderReportOptions},
     },
     types::Schema,
 };
@@ -15,53 +23,239 @@ use crate::{
 pub struct CodegenOptions<'a> {
     pub project_root: &'a PathBuf,
     pub source_dir: &'a PathBuf,
+    /// Glob of the spec files relative to the project root (Default: `Native*.ts` files in the source directory)
+    pub spec_glob: Option<&'a str>,
+    /// Path to write the collected diagnostics as JSON
+    pub diagnostics_out: Option<&'a PathBuf>,
+    /// Method and signal names to expose per module (Default: all)
+    pub expose: Option<&'a BTreeMap<String, Vec<String>>>,
 }
 
-pub fn codegen<'a>(opts: CodegenOptions<'a>) -> Result<Vec<Schema>, anyhow::Error> {
-    let srcs = collect_files(opts.source_dir, &|path: &PathBuf| {
+/// Collects the native module specification files matching the spec glob,
+/// or the `Native*.ts` files in the source directory by default.
+pub fn collect_spec_files(
+    project_root: &Path,
+    source_dir: &PathBuf,
+    spec_glob: Option<&str>,
+) -> Result<Vec<PathBuf>, anyhow::Error> {
+    if let Some(spec_glob) = spec_glob {
+        let mut files = collect_glob_files(project_root, spec_glob)?;
+        files.retain(|path| !is_generated_mock(path));
+        files.sort();
+        return Ok(files);
+    }
+
+    collect_files(source_dir, &|path: &PathBuf| {
         path.extension().unwrap_or_default() == "ts"
             && path
                 .file_name()
                 .unwrap()
                 .to_string_lossy()
                 .starts_with(SPEC_FILE_PREFIX)
-    })?;
+            && !is_generated_mock(path)
+    })
+}
+
+/// Returns `true` if the file is in the mocks directory (`mocks`),
+/// where the mocks are named after the spec files.
+fn is_generated_mock(path: &Path) -> bool {
+    path.components()
+        .any(|component| component.as_os_str() == MOCKS_DIR)
+}
+
+pub fn codegen<'a>(opts: CodegenOptions<'a>) -> Result<Vec<Schema>, anyhow::Error> {
+    let srcs = collect_spec_files(opts.project_root, opts.source_dir, opts.spec_glob)?;
     debug!("{} source file(s) found", srcs.len());
 
     if srcs.is_empty() {
         anyhow::bail!("No native module specification files found.");
     }
 
-    let collected_schemas = srcs
-        .iter()
-        .map(|path| {
-            let src = fs::read_to_string(path)?;
-            let src = src.as_str();
-
-            match try_parse_schema(src) {
-                Ok(schemas) => Ok(schemas),
-                Err(ParseError::Oxc { diagnostics }) => {
-                    render_report(
-                        diagnostics,
-                        RenderReportOptions {
-                            project_root: opts.project_root,
-                            path,
-                            src,
-                        },
-                    );
-                    anyhow::bail!("Failed to parse schema");
+    let mut schemas = vec![];
+    let mut diagnostic_entries = vec![];
+    let mut error = None;
+
+    for path in &srcs {
+        let src = fs::read_to_string(path)?;
+        let src = src.as_str();
+
+        match try_parse_schema_from_path(src, path) {
+            Ok(collected_schemas) => {
+                schemas.extend(collected_schemas.into_iter().map(|schema| Schema {
+                    spec_file: Some(path.clone()),
+                    ..schema
+                }))
+            }
+            Err(ParseError::Oxc { diagnostics }) => {
+                let report_opts = RenderReportOptions {
+                    project_root: opts.project_root,
+                    path,
+                    src,
+                };
+
+                if opts.diagnostics_out.is_some() {
+                    diagnostic_entries.extend(to_diagnostic_entries(&diagnostics, &report_opts));
                 }
-                Err(ParseError::General(e)) => {
-                    anyhow::bail!(e);
+
+                render_report(diagnostics, report_opts);
+                error.get_or_insert(anyhow::anyhow!("Failed to parse schema"));
+            }
+            Err(ParseError::General(e)) => {
+                if opts.diagnostics_out.is_some() {
+                    diagnostic_entries.push(DiagnosticEntry {
+                        file: path
+                            .strip_prefix(opts.project_root)
+                            .unwrap_or(path)
+                            .to_string_lossy()
+                            .to_string(),
+                        line: 1,
+                        column: 1,
+                        severity: "error".to_string(),
+                        message: e.to_string(),
+                    });
                 }
+
+                error.get_or_insert(e);
             }
-        })
-        .collect::<Result<Vec<Vec<Schema>>, anyhow::Error>>()?;
+        }
+
+        // Keep collecting diagnostics of the remaining files only if they are requested
+        if error.is_some() && opts.diagnostics_out.is_none() {
+            break;
+        }
+    }
+
+    if let Some(diagnostics_out) = opts.diagnostics_out {
+        write_diagnostics(diagnostics_out, &diagnostic_entries)?;
+    }
+
+    if let Some(e) = error {
+        return Err(e);
+    }
 
-    let mut schemas = collected_schemas.into_iter().flatten().collect::<Vec<_>>();
     schemas.sort_by_key(|v| v.module_name.to_lowercase());
 
+    if let Some(expose) = opts.expose {
+        retain_exposed(&mut schemas, expose)?;
+    }
+
     debug!("Collected schemas: {:?}", schemas);
 
     Ok(schemas)
 }
+
+/// Keeps only the methods and signals listed in the allowlist of each module.
+///
+/// Modules without an allowlist are kept as is.
+fn retain_exposed(
+    schemas: &mut [Schema],
+    expose: &BTreeMap<String, Vec<String>>,
+) -> Result<(), anyhow::Error> {
+    for (module_name, names) in expose {
+        let schema = schemas
+            .iter_mut()
+            .find(|schema| &schema.module_name == module_name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown module in `expose`: {}", module_name))?;
+
+        for name in names {
+            let is_method = schema.methods.iter().any(|method| &method.name == name);
+            let is_signal = schema.signals.iter().any(|signal| &signal.name == name);
+
+            if !is_method && !is_signal {
+                anyhow::bail!(
+                    "Unknown method or signal in `expose` of {}: {}",
+                    module_name,
+                    name
+                );
+            }
+        }
+
+        schema.methods.retain(|method| names.contains(&method.name));
+        schema.signals.retain(|signal| names.contains(&signal.name));
+        debug!("Exposed members of {}: {:?}", module_name, names);
+    }
+
+    Ok(())
+}
+
+fn write_diagnostics(path: &PathBuf, entries: &[DiagnosticEntry]) -> Result<(), anyhow::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(entries)?)?;
+    debug!("Diagnostics written: {}", path.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::native_spec_parser::try_parse_schema;
+
+    use super::*;
+
+    const SRC: &str = "
+    import type { NativeModule, Signal } from 'craby-modules';
+    import { NativeModuleRegistry } from 'craby-modules';
+
+    export interface Spec extends NativeModule {
+        add(a: number, b: number): number;
+        subtract(a: number, b: number): number;
+        onProgress: Signal;
+        onDone: Signal;
+    }
+
+    export default NativeModuleRegistry.getEnforcing<Spec>('Calculator');
+    ";
+
+    #[test]
+    fn test_collect_spec_files_without_mocks() {
+        let root = std::env::temp_dir().join(format!("craby-{}", uuid::Uuid::new_v4()));
+        let source_dir = root.join("src");
+        fs::create_dir_all(source_dir.join(MOCKS_DIR)).unwrap();
+        fs::write(source_dir.join("NativeCalculator.ts"), SRC).unwrap();
+        fs::write(source_dir.join(MOCKS_DIR).join("NativeCalculator.ts"), "").unwrap();
+
+        let files = collect_spec_files(&root, &source_dir, None).unwrap();
+        let glob_files = collect_spec_files(&root, &source_dir, Some("src/**/Native*.ts")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(files, vec![source_dir.join("NativeCalculator.ts")]);
+        assert_eq!(glob_files, vec![source_dir.join("NativeCalculator.ts")]);
+    }
+
+    #[test]
+    fn test_retain_exposed() {
+        let mut schemas = try_parse_schema(SRC).unwrap();
+        let expose = BTreeMap::from([(
+            "Calculator".to_string(),
+            vec!["add".to_string(), "onDone".to_string()],
+        )]);
+
+        retain_exposed(&mut schemas, &expose).unwrap();
+
+        let methods = schemas[0]
+            .methods
+            .iter()
+            .map(|m| &m.name)
+            .collect::<Vec<_>>();
+        let signals = schemas[0]
+            .signals
+            .iter()
+            .map(|s| &s.name)
+            .collect::<Vec<_>>();
+        assert_eq!(methods, ["add"]);
+        assert_eq!(signals, ["onDone"]);
+    }
+
+    #[test]
+    fn test_retain_exposed_unknown_name() {
+        let mut schemas = try_parse_schema(SRC).unwrap();
+        let unknown_member =
+            BTreeMap::from([("Calculator".to_string(), vec!["multiply".to_string()])]);
+        let unknown_module = BTreeMap::from([("Unknown".to_string(), vec!["add".to_string()])]);
+
+        assert!(retain_exposed(&mut schemas, &unknown_member).is_err());
+        assert!(retain_exposed(&mut schemas, &unknown_module).is_err());
+    }
+}
@@ -1,19 +1,58 @@
 pub const GENERATED_COMMENT: &str = "Auto generated by Craby. DO NOT EDIT.";
 
+pub const DEFAULT_CXX_BASE_CLASS: &str = "facebook::react::TurboModule";
+
+/// Number of worker threads of the C++ modules (`project.thread_pool_size`)
+pub const DEFAULT_THREAD_POOL_SIZE: usize = 10;
+
+/// Directory of the JS mocks next to the spec files (`mocks`)
+pub const MOCKS_DIR: &str = "__mocks__";
+
+/// Shared module registry header (`module_registry`)
+pub const MODULE_REGISTRY_HEADER: &str = "CrabyModuleRegistry.hpp";
+
 pub mod specs {
     pub const NATIVE_MODULE_PKG: &str = "craby-modules";
     pub const NATIVE_MODULE_INTERFACE: &str = "NativeModule";
     pub const NATIVE_MODULE_REGISTRY: &str = "NativeModuleRegistry";
     pub const SIGNAL_TYPE: &str = "Signal";
+    pub const INT32_TYPE: &str = "Int32";
+    pub const INT64_TYPE: &str = "Int64";
     pub const REGISTRY_GET: &str = "get";
     pub const REGISTRY_GET_ENFORCING: &str = "getEnforcing";
 
     pub const RESERVED_TYPE_ARRAY_BUFFER: &str = "ArrayBuffer";
+    pub const RESERVED_TYPE_UINT8_ARRAY: &str = "Uint8Array";
+    pub const RESERVED_TYPE_INT8_ARRAY: &str = "Int8Array";
     pub const RESERVED_TYPE_PROMISE: &str = "Promise";
+    pub const RESERVED_TYPE_RECORD: &str = "Record";
+    pub const RESERVED_TYPE_READONLY_ARRAY: &str = "ReadonlyArray";
+
+    /// Typed arrays that are not supported yet (only the byte arrays are bridged as `ArrayBuffer`)
+    pub const UNSUPPORTED_TYPED_ARRAYS: &[&str] = &[
+        "Uint8ClampedArray",
+        "Int16Array",
+        "Uint16Array",
+        "Int32Array",
+        "Uint32Array",
+        "Float32Array",
+        "Float64Array",
+        "BigInt64Array",
+        "BigUint64Array",
+    ];
+
+    /// Maximum number of the tuple elements (eg. `[number, string]`)
+    pub const MAX_TUPLE_ELEMENTS: usize = 8;
 
     /// `it_` is reserved for the `shared_ptr` of the module
     pub const RESERVED_ARG_NAME_MODULE: &str = "it_";
 
+    /// `cancel_token` is reserved for the `CancellationToken` of `@cancellable` methods
+    pub const RESERVED_ARG_NAME_CANCEL_TOKEN: &str = "cancel_token";
+
+    /// `error_` is reserved for the structured error of `@throws` methods
+    pub const RESERVED_ARG_NAME_ERROR: &str = "error_";
+
     /// `emit` is reserved for signals
     pub const RESERVED_METHOD_NAME_MODULE: &str = "emit";
 }
@@ -7,9 +7,10 @@ use craby_common::{
 use indoc::formatdoc;
 
 use crate::{
+    constants::MODULE_REGISTRY_HEADER,
     generators::types::TemplateResult,
     types::{CodegenContext, CxxModuleName, CxxNamespace},
-    utils::indent_str,
+    utils::{cxx_feature_guard, indent_str},
 };
 
 use super::types::{Generator, GeneratorInvoker, Template};
@@ -32,11 +33,13 @@ impl AndroidTemplate {
     /// # Generated Code
     ///
     /// ```cpp
+    /// static std::string dataPath;
+    ///
     /// jint JNI_OnLoad(JavaVM *vm, void *reserved) {
     ///   facebook::react::registerCxxModuleToGlobalModuleMap(
     ///     craby::myproject::modules::MyTestModule::kModuleName,
     ///     [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
-    ///       return std::make_shared<craby::myproject::modules::mymodule::MyTestModule>(jsInvoker);
+    ///       return std::make_shared<craby::myproject::modules::mymodule::MyTestModule>(jsInvoker, dataPath);
     ///     });
     ///   return JNI_VERSION_1_6;
     /// }
@@ -45,15 +48,13 @@ impl AndroidTemplate {
     /// JNIEXPORT void JNICALL
     /// Java_com_mymodule_MyTestModulePackage_nativeSetDataPath(JNIEnv *env, jclass clazz, jstring jDataPath) {
     ///     const char* cDataPath = env->GetStringUTFChars(jDataPath, nullptr);
-    ///     auto dataPath = std::string(cDataPath);
+    ///     dataPath = std::string(cDataPath);
     ///     env->ReleaseStringUTFChars(jDataPath, cDataPath);
-    ///     craby::myproject::modules::MyTestModule::dataPath = dataPath;
     /// }
     /// ```
     fn jni_entry(&self, ctx: &CodegenContext) -> Result<String, anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(&ctx.project_name);
+        let cxx_ns = CxxNamespace::from(&ctx.cxx_namespace);
         let mut cxx_includes = vec![];
-        let mut cxx_prepares = Vec::with_capacity(ctx.schemas.len());
         let mut cxx_registers = Vec::with_capacity(ctx.schemas.len());
         let jni_extern_fn_name = ctx
             .android_package_name
@@ -68,23 +69,33 @@ impl AndroidTemplate {
             pascal_case(&ctx.project_name)
         );
 
-        for schema in &ctx.schemas {
-            let cxx_mod = CxxModuleName::from(&schema.module_name);
-            let cxx_include = format!("#include <{cxx_mod}.hpp>");
-            let cxx_mod_namespace = format!("{cxx_ns}::modules::{cxx_mod}");
-            let cxx_prepare = format!("{cxx_mod_namespace}::dataPath = dataPath;");
-            let cxx_register = formatdoc! {
-                r#"
-                facebook::react::registerCxxModuleToGlobalModuleMap(
-                  {cxx_mod_namespace}::kModuleName,
-                  [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {{
-                    return std::make_shared<{cxx_mod_namespace}>(jsInvoker);
-                  }});"#,
-            };
-
-            cxx_includes.push(cxx_include);
-            cxx_prepares.push(cxx_prepare);
-            cxx_registers.push(cxx_register);
+        if ctx.module_registry {
+            // Register the modules through the shared module registry
+            cxx_includes.push(format!("#include <{MODULE_REGISTRY_HEADER}>"));
+            cxx_registers.push(format!(
+                "{cxx_ns}::registry::registerModules([] {{ return dataPath; }});"
+            ));
+        } else {
+            for schema in &ctx.schemas {
+                let cxx_mod = CxxModuleName::from(&schema.module_name);
+                let cxx_include = format!("#include <{cxx_mod}.hpp>");
+                let cxx_mod_namespace = format!("{cxx_ns}::modules::{cxx_mod}");
+                let cxx_register = formatdoc! {
+                    r#"
+                    facebook::react::registerCxxModuleToGlobalModuleMap(
+                      {cxx_mod_namespace}::kModuleName,
+                      [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {{
+                        return std::make_shared<{cxx_mod_namespace}>(jsInvoker, dataPath);
+                      }});"#,
+                };
+
+                cxx_includes.push(cxx_include);
+                cxx_registers.push(if ctx.module_features {
+                    cxx_feature_guard(&cxx_register, &schema.module_name)
+                } else {
+                    cxx_register
+                });
+            }
         }
 
         let content = formatdoc! {
@@ -92,6 +103,10 @@ impl AndroidTemplate {
             {cxx_includes}
             #include <ReactCommon/CxxTurboModuleUtils.h>
             #include <jni.h>
+            #include <string>
+
+            // Data path of the app, set by the package before any module is created
+            static std::string dataPath;
 
             jint JNI_OnLoad(JavaVM *vm, void *reserved) {{
             {cxx_registers}
@@ -102,12 +117,10 @@ impl AndroidTemplate {
             JNIEXPORT void JNICALL
             {jni_fn_name}(JNIEnv *env, jclass clazz, jstring jDataPath) {{
               const char* cDataPath = env->GetStringUTFChars(jDataPath, nullptr);
-              auto dataPath = std::string(cDataPath);
+              dataPath = std::string(cDataPath);
               env->ReleaseStringUTFChars(jDataPath, cDataPath);
-            {cxx_prepares}
             }}"#,
             cxx_includes = cxx_includes.join("\n"),
-            cxx_prepares = indent_str(&cxx_prepares.join("\n"), 2),
             cxx_registers = indent_str(&cxx_registers.join("\n"), 2),
         };
 
@@ -312,6 +325,11 @@ impl AndroidTemplate {
     ///   # the following line. NDK uses GNU style stderror_r() after API 23.
     ///   -DFOLLY_HAVE_XSI_STRERROR_R=1
     /// )
+    ///
+    /// # Android 15+ requires 16KB page aligned shared libraries
+    /// target_link_options(cxx-my-app PRIVATE
+    ///   -Wl,-z,max-page-size=16384
+    /// )
     /// ```
     fn cmakelists(&self, ctx: &CodegenContext) -> String {
         let kebab_name = kebab_case(&ctx.project_name);
@@ -321,6 +339,19 @@ impl AndroidTemplate {
             .iter()
             .map(|schema| format!("../cpp/{}.cpp", CxxModuleName::from(&schema.module_name)))
             .collect::<Vec<_>>();
+        let link_options = if ctx.android_page_size_16kb {
+            formatdoc! {
+                r#"
+
+
+                # Android 15+ requires 16KB page aligned shared libraries
+                target_link_options(cxx-{kebab_name} PRIVATE
+                  -Wl,-z,max-page-size=16384
+                )"#,
+            }
+        } else {
+            String::new()
+        };
 
         formatdoc! {
             r#"
@@ -372,7 +403,7 @@ impl AndroidTemplate {
               # Once we target android-23 above, we can comment
               # the following line. NDK uses GNU style stderror_r() after API 23.
               -DFOLLY_HAVE_XSI_STRERROR_R=1
-            )"#,
+            ){link_options}"#,
             kebab_name = kebab_name,
             lib_name = lib_name,
             cxx_mod_cpp_files = indent_str(&cxx_mod_cpp_files.join("\n"), 2),
@@ -388,6 +419,20 @@ impl AndroidTemplate {
             .map(|schema| format!("\"__craby{}_JNI_prepare__\"", schema.module_name))
             .collect::<Vec<_>>();
 
+        // With lazy registration, the library (and the modules registered in `JNI_OnLoad`) is loaded
+        // when the first module is prepared, instead of when the package is created
+        let (init_block, lazy_load_stmt) = if ctx.lazy_registration {
+            (
+                String::new(),
+                format!("\n      SoLoader.loadLibrary(\"{lib_name}\")"),
+            )
+        } else {
+            (
+                format!("\n  init {{\n    SoLoader.loadLibrary(\"{lib_name}\")\n  }}\n"),
+                String::new(),
+            )
+        };
+
         formatdoc! {
             r#"
             package {package_name}
@@ -408,13 +453,9 @@ impl AndroidTemplate {
             {jni_prepare_module_names}
                 )
               }}
-
-              init {{
-                SoLoader.loadLibrary("{lib_name}")
-              }}
-
+            {init_block}
               override fun getModule(name: String, reactContext: ReactApplicationContext): NativeModule? {{
-                if (name in JNI_PREPARE_MODULE_NAME) {{
+                if (name in JNI_PREPARE_MODULE_NAME) {{{lazy_load_stmt}
                   nativeSetDataPath(reactContext.filesDir.absolutePath)
                   return {pascal_name}Package.TurboModulePlaceholder(reactContext, name)
                 }}
@@ -450,7 +491,6 @@ impl AndroidTemplate {
               }}
             }}"#,
             package_name = ctx.android_package_name,
-            lib_name = lib_name,
             pascal_name = pascal_name,
             jni_prepare_module_names = indent_str(&jni_prepare_module_names.join(",\n"), 6),
         }
@@ -470,32 +510,38 @@ impl Template for AndroidTemplate {
                 path: jni_base_path(&ctx.root).join("OnLoad.cpp"),
                 content: self.jni_entry(ctx)?,
                 overwrite: true,
+                merged: false,
             }],
             AndroidFileType::CmakeLists => vec![TemplateResult {
                 path: android_path(&ctx.root).join("CMakeLists.txt"),
                 content: self.cmakelists(ctx),
                 overwrite: true,
+                merged: false,
             }],
             AndroidFileType::ManifestXml => vec![TemplateResult {
                 path: android_src_main_path(&ctx.root).join("AndroidManifest.xml"),
                 content: self.manifest_xml(ctx),
                 overwrite: true,
+                merged: false,
             }],
             AndroidFileType::BuildGradle => vec![TemplateResult {
                 path: android_path(&ctx.root).join("build.gradle"),
                 content: self.build_gradle(ctx),
                 overwrite: true,
+                merged: false,
             }],
             AndroidFileType::GradleProps => vec![TemplateResult {
                 path: android_path(&ctx.root).join("gradle.properties"),
                 content: self.grable_props(ctx),
                 overwrite: false,
+                merged: false,
             }],
             AndroidFileType::RctPackage => vec![TemplateResult {
                 path: java_base_path(&ctx.root, &ctx.android_package_name)
                     .join(format!("{}Package.kt", pascal_case(&ctx.project_name))),
                 content: self.rct_package(ctx),
                 overwrite: true,
+                merged: false,
             }],
         };
 
@@ -569,4 +615,41 @@ mod tests {
 
         assert_snapshot!(result);
     }
+
+    #[test]
+    fn test_android_generator_page_size_16kb() {
+        let mut ctx = get_codegen_context();
+        let result = AndroidTemplate.cmakelists(&ctx);
+
+        assert!(result.contains(
+            "target_link_options(cxx-test-module PRIVATE\n  -Wl,-z,max-page-size=16384\n)"
+        ));
+
+        ctx.android_page_size_16kb = false;
+        let result = AndroidTemplate.cmakelists(&ctx);
+
+        assert!(!result.contains("max-page-size"));
+    }
+
+    #[test]
+    fn test_android_generator_module_registry() {
+        let mut ctx = get_codegen_context();
+        ctx.module_registry = true;
+        let result = AndroidTemplate.jni_entry(&ctx).unwrap();
+
+        assert!(result.contains("#include <CrabyModuleRegistry.hpp>"));
+        assert!(result
+            .contains("craby::testmodule::registry::registerModules([] { return dataPath; });"));
+        assert!(!result.contains("registerCxxModuleToGlobalModuleMap"));
+    }
+
+    #[test]
+    fn test_android_generator_lazy_registration() {
+        let mut ctx = get_codegen_context();
+        ctx.lazy_registration = true;
+        let result = AndroidTemplate.rct_package(&ctx);
+
+        assert!(!result.contains("init {"));
+        assert_snapshot!(result);
+    }
 }
@@ -0,0 +1,523 @@
+use craby_common::{
+    constants::{crate_dir, cxx_bridge_include_dir, impl_mod_name},
+    utils::string::{pascal_case, snake_case},
+};
+use indoc::formatdoc;
+
+use crate::{
+    generators::types::TemplateResult,
+    parser::types::{Method, TypeAnnotation},
+    types::{CodegenContext, Schema},
+    utils::{attr_items, indent_str, rs_feature_cfg},
+};
+
+use super::types::{Generator, GeneratorInvoker, Template};
+
+pub struct CShimTemplate;
+pub struct CShimGenerator;
+
+pub enum CShimFileType {
+    /// c_ffi.rs
+    RsShim,
+    /// CrabyFFI.h
+    Header,
+}
+
+/// Rust and C signatures of a module method exposed through the C ABI.
+struct CShimMethod {
+    /// `extern "C"` function implementation
+    rs_impl: String,
+    /// C function declaration
+    c_decl: String,
+}
+
+impl CShimTemplate {
+    /// Returns the prefix of the exported symbols. (eg. `myproject_my_module`)
+    ///
+    /// Prefixed with the C++ namespace (`cxx_namespace`) so that the symbols
+    /// of the projects sharing the same name do not collide.
+    fn symbol_prefix(&self, cxx_namespace: &str, module_name: &str) -> String {
+        format!("{}_{}", cxx_namespace, snake_case(module_name))
+    }
+
+    /// Returns `true` if the type can be passed through the C ABI.
+    ///
+    /// Only primitive and string types are supported for now.
+    fn is_supported(&self, type_annotation: &TypeAnnotation) -> bool {
+        matches!(
+            type_annotation,
+            TypeAnnotation::Boolean | TypeAnnotation::Number | TypeAnnotation::String
+        )
+    }
+
+    /// Returns the Rust/C parameter signatures and the argument expression of the parameter.
+    ///
+    /// Strings are passed as a pointer and byte length pair (not null-terminated).
+    ///
+    /// ```rust,ignore
+    /// (vec!["arg: f64"], vec!["double arg"], "arg")
+    /// (vec!["arg: *const u8", "arg_len: usize"], vec!["const uint8_t *arg", "size_t arg_len"], "&arg")
+    /// ```
+    fn param(
+        &self,
+        name: &str,
+        type_annotation: &TypeAnnotation,
+    ) -> (Vec<String>, Vec<String>, String) {
+        match type_annotation {
+            TypeAnnotation::Boolean => (
+                vec![format!("{name}: bool")],
+                vec![format!("bool {name}")],
+                name.to_string(),
+            ),
+            TypeAnnotation::Number => (
+                vec![format!("{name}: f64")],
+                vec![format!("double {name}")],
+                name.to_string(),
+            ),
+            TypeAnnotation::String => (
+                vec![format!("{name}: *const u8"), format!("{name}_len: usize")],
+                vec![
+                    format!("const uint8_t *{name}"),
+                    format!("size_t {name}_len"),
+                ],
+                format!("&{name}"),
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the `extern "C"` function and C declaration of the method.
+    /// Returns `None` if the method uses types that are not supported by the C ABI.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[no_mangle]
+    /// pub unsafe extern "C" fn myproject_my_module_greet(it_: *mut MyModule, name: *const u8, name_len: usize) -> CrabyString {
+    ///     let it_ = &mut *it_;
+    ///     let name = String::from_utf8_lossy(std::slice::from_raw_parts(name, name_len));
+    ///     let ret = it_.greet(&name);
+    ///     CrabyString::from(ret)
+    /// }
+    /// ```
+    ///
+    /// ```c
+    /// CrabyString myproject_my_module_greet(MyModule *it_, const uint8_t *name, size_t name_len);
+    /// ```
+    fn method(&self, prefix: &str, module_name: &str, method: &Method) -> Option<CShimMethod> {
+        let ret_supported =
+            matches!(method.ret_type, TypeAnnotation::Void) || self.is_supported(&method.ret_type);
+        let params_supported = method
+            .params
+            .iter()
+            .all(|param| self.is_supported(&param.type_annotation));
+
+        if !ret_supported || !params_supported {
+            return None;
+        }
+
+        let fn_name = format!("{prefix}_{}", snake_case(&method.name));
+        let mut rs_params = vec![format!("it_: *mut {module_name}")];
+        let mut c_params = vec![format!("{module_name} *it_")];
+        let mut conversions = vec![];
+        let mut args = vec![];
+
+        for param in &method.params {
+            let name = snake_case(&param.name);
+            let (rs_param, c_param, arg) = self.param(&name, &param.type_annotation);
+
+            if let TypeAnnotation::String = param.type_annotation {
+                conversions.push(format!(
+                    "let {name} = String::from_utf8_lossy(std::slice::from_raw_parts({name}, {name}_len));"
+                ));
+            }
+
+            rs_params.extend(rs_param);
+            c_params.extend(c_param);
+            args.push(arg);
+        }
+
+        let (rs_ret, c_ret, ret) = match method.ret_type {
+            TypeAnnotation::Boolean => (" -> bool", "bool", "ret"),
+            TypeAnnotation::Number => (" -> f64", "double", "ret"),
+            TypeAnnotation::String => (" -> CrabyString", "CrabyString", "CrabyString::from(ret)"),
+            _ => ("", "void", "ret"),
+        };
+
+        let stmts = [
+            vec!["let it_ = &mut *it_;".to_string()],
+            conversions,
+            vec![
+                format!(
+                    "let ret = it_.{}({});",
+                    snake_case(&method.name),
+                    args.join(", ")
+                ),
+                ret.to_string(),
+            ],
+        ]
+        .concat()
+        .join("\n");
+
+        let rs_impl = formatdoc! {
+            r#"
+            #[no_mangle]
+            pub unsafe extern "C" fn {fn_name}({rs_params}){rs_ret} {{
+            {stmts}
+            }}"#,
+            rs_params = rs_params.join(", "),
+            stmts = indent_str(&stmts, 4),
+        };
+
+        let c_decl = format!("{c_ret} {fn_name}({});", c_params.join(", "));
+
+        Some(CShimMethod { rs_impl, c_decl })
+    }
+
+    fn methods(&self, cxx_namespace: &str, schema: &Schema) -> Vec<Result<CShimMethod, String>> {
+        let prefix = self.symbol_prefix(cxx_namespace, &schema.module_name);
+        let module_name = pascal_case(&schema.module_name);
+
+        schema
+            .methods
+            .iter()
+            .map(|method| {
+                self.method(&prefix, &module_name, method)
+                    .ok_or_else(|| method.name.clone())
+            })
+            .collect()
+    }
+
+    /// Generates the `c_ffi.rs` file exposing module methods through the C ABI.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #![allow(clippy::missing_safety_doc)]
+    ///
+    /// use craby::prelude::*;
+    ///
+    /// use crate::my_module_impl::*;
+    /// use crate::generated::*;
+    ///
+    /// #[repr(C)]
+    /// pub struct CrabyString {
+    ///     pub ptr: *mut u8,
+    ///     pub len: usize,
+    /// }
+    ///
+    /// #[no_mangle]
+    /// pub unsafe extern "C" fn myproject_string_free(value: CrabyString) {
+    ///     // ...
+    /// }
+    ///
+    /// #[no_mangle]
+    /// pub unsafe extern "C" fn myproject_my_module_create(id: usize, data_path: *const u8, data_path_len: usize) -> *mut MyModule {
+    ///     // ...
+    /// }
+    ///
+    /// #[no_mangle]
+    /// pub unsafe extern "C" fn myproject_my_module_destroy(it_: *mut MyModule) {
+    ///     // ...
+    /// }
+    ///
+    /// #[no_mangle]
+    /// pub unsafe extern "C" fn myproject_my_module_multiply(it_: *mut MyModule, a: f64, b: f64) -> f64 {
+    ///     let it_ = &mut *it_;
+    ///     let ret = it_.multiply(a, b);
+    ///     ret
+    /// }
+    /// ```
+    fn rs_shim(&self, ctx: &CodegenContext) -> Result<String, anyhow::Error> {
+        let project_prefix = &ctx.cxx_namespace;
+        let mut impl_uses = vec![];
+        let mut shims = vec![];
+
+        for schema in &ctx.schemas {
+            let prefix = self.symbol_prefix(&ctx.cxx_namespace, &schema.module_name);
+            let module_name = pascal_case(&schema.module_name);
+
+            let impl_use = format!("use crate::{}::*;", impl_mod_name(&schema.module_name));
+            let mut schema_shims = vec![formatdoc! {
+                r#"
+                #[no_mangle]
+                pub unsafe extern "C" fn {prefix}_create(id: usize, data_path: *const u8, data_path_len: usize) -> *mut {module_name} {{
+                    let data_path = String::from_utf8_lossy(std::slice::from_raw_parts(data_path, data_path_len));
+                    let ctx = Context::new(id, &data_path);
+                    Box::into_raw(Box::new({module_name}::new(ctx)))
+                }}
+
+                #[no_mangle]
+                pub unsafe extern "C" fn {prefix}_destroy(it_: *mut {module_name}) {{
+                    if !it_.is_null() {{
+                        drop(Box::from_raw(it_));
+                    }}
+                }}"#,
+            }];
+
+            for method in self.methods(&ctx.cxx_namespace, schema) {
+                match method {
+                    Ok(method) => schema_shims.push(method.rs_impl),
+                    Err(name) => schema_shims.push(format!(
+                        "// `{module_name}::{name}` is not supported by the C ABI shim"
+                    )),
+                }
+            }
+
+            // Gate the shims behind the cargo feature of the module
+            if ctx.module_features {
+                let cfg = rs_feature_cfg(&[&schema.module_name]);
+                impl_uses.push(attr_items(&impl_use, &cfg));
+                shims.extend(schema_shims.iter().map(|shim| attr_items(shim, &cfg)));
+            } else {
+                impl_uses.push(impl_use);
+                shims.extend(schema_shims);
+            }
+        }
+
+        let content = formatdoc! {
+            r#"
+            #![allow(clippy::missing_safety_doc)]
+
+            use craby::prelude::*;
+
+            {impl_uses}
+            use crate::generated::*;
+
+            /// UTF-8 string owned by Rust (not null-terminated)
+            ///
+            /// Must be released with `{project_prefix}_string_free`.
+            #[repr(C)]
+            pub struct CrabyString {{
+                pub ptr: *mut u8,
+                pub len: usize,
+            }}
+
+            impl From<String> for CrabyString {{
+                fn from(value: String) -> Self {{
+                    let mut bytes = std::mem::ManuallyDrop::new(value.into_bytes().into_boxed_slice());
+                    CrabyString {{
+                        ptr: bytes.as_mut_ptr(),
+                        len: bytes.len(),
+                    }}
+                }}
+            }}
+
+            #[no_mangle]
+            pub unsafe extern "C" fn {project_prefix}_string_free(value: CrabyString) {{
+                if !value.ptr.is_null() {{
+                    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(value.ptr, value.len)));
+                }}
+            }}
+
+            {shims}"#,
+            impl_uses = impl_uses.join("\n"),
+            shims = shims.join("\n\n"),
+        };
+
+        Ok(content)
+    }
+
+    /// Generates the C header of the `extern "C"` shim.
+    ///
+    /// # Generated Code
+    ///
+    /// ```c
+    /// #pragma once
+    ///
+    /// #include <stdbool.h>
+    /// #include <stddef.h>
+    /// #include <stdint.h>
+    ///
+    /// #ifdef __cplusplus
+    /// extern "C" {
+    /// #endif
+    ///
+    /// typedef struct CrabyString {
+    ///   uint8_t *ptr;
+    ///   size_t len;
+    /// } CrabyString;
+    ///
+    /// void myproject_string_free(CrabyString value);
+    ///
+    /// typedef struct MyModule MyModule;
+    ///
+    /// MyModule *myproject_my_module_create(size_t id, const uint8_t *data_path, size_t data_path_len);
+    /// void myproject_my_module_destroy(MyModule *it_);
+    /// double myproject_my_module_multiply(MyModule *it_, double a, double b);
+    ///
+    /// #ifdef __cplusplus
+    /// }
+    /// #endif
+    /// ```
+    fn header(&self, ctx: &CodegenContext) -> Result<String, anyhow::Error> {
+        let project_prefix = &ctx.cxx_namespace;
+        let decls = ctx
+            .schemas
+            .iter()
+            .map(|schema| {
+                let prefix = self.symbol_prefix(&ctx.cxx_namespace, &schema.module_name);
+                let module_name = pascal_case(&schema.module_name);
+                let mut decls = vec![
+                    format!("typedef struct {module_name} {module_name};"),
+                    String::new(),
+                    format!("{module_name} *{prefix}_create(size_t id, const uint8_t *data_path, size_t data_path_len);"),
+                    format!("void {prefix}_destroy({module_name} *it_);"),
+                ];
+
+                for method in self.methods(&ctx.cxx_namespace, schema) {
+                    match method {
+                        Ok(method) => decls.push(method.c_decl),
+                        Err(name) => decls.push(format!(
+                            "// `{module_name}::{name}` is not supported by the C ABI shim"
+                        )),
+                    }
+                }
+
+                decls.join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let content = formatdoc! {
+            r#"
+            #pragma once
+
+            #include <stdbool.h>
+            #include <stddef.h>
+            #include <stdint.h>
+
+            #ifdef __cplusplus
+            extern "C" {{
+            #endif
+
+            // UTF-8 string owned by Rust (not null-terminated)
+            // Must be released with `{project_prefix}_string_free`
+            typedef struct CrabyString {{
+              uint8_t *ptr;
+              size_t len;
+            }} CrabyString;
+
+            void {project_prefix}_string_free(CrabyString value);
+
+            {decls}
+
+            #ifdef __cplusplus
+            }}
+            #endif"#,
+        };
+
+        Ok(content)
+    }
+}
+
+impl Template for CShimTemplate {
+    type FileType = CShimFileType;
+
+    fn render(
+        &self,
+        ctx: &CodegenContext,
+        file_type: &Self::FileType,
+    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let res = match file_type {
+            CShimFileType::RsShim => vec![TemplateResult {
+                path: crate_dir(&ctx.root).join("src").join("c_ffi.rs"),
+                content: self.rs_shim(ctx)?,
+                overwrite: true,
+                merged: false,
+            }],
+            CShimFileType::Header => vec![TemplateResult {
+                path: cxx_bridge_include_dir(&ctx.root).join("CrabyFFI.h"),
+                content: self.header(ctx)?,
+                overwrite: true,
+                merged: false,
+            }],
+        };
+
+        Ok(res)
+    }
+}
+
+impl Default for CShimGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CShimGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator<CShimTemplate> for CShimGenerator {
+    fn cleanup(_: &CodegenContext) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    fn generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let template = self.template_ref();
+        let res = [
+            template.render(ctx, &CShimFileType::RsShim)?,
+            template.render(ctx, &CShimFileType::Header)?,
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        Ok(res)
+    }
+
+    fn template_ref(&self) -> &CShimTemplate {
+        &CShimTemplate
+    }
+}
+
+impl GeneratorInvoker for CShimGenerator {
+    fn invoke_generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.generate(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::tests::get_codegen_context;
+
+    use super::*;
+
+    #[test]
+    fn test_c_shim_generator() {
+        let ctx = get_codegen_context();
+        let generator = CShimGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_c_shim_cxx_namespace() {
+        let mut ctx = get_codegen_context();
+        ctx.cxx_namespace = "custom".to_string();
+        let results = CShimGenerator::new().generate(&ctx).unwrap();
+
+        for res in &results {
+            assert!(
+                res.content.contains("custom_string_free"),
+                "{}",
+                res.path.display()
+            );
+            assert!(
+                !res.content.contains("testmodule_"),
+                "{}",
+                res.path.display()
+            );
+        }
+    }
+}
@@ -1,16 +1,16 @@
 use std::fs;
 
 use craby_common::{
-    constants::{cxx_bridge_include_dir, cxx_dir},
-    utils::string::{camel_case, flat_case, pascal_case, snake_case},
+    constants::{cxx_bridge_include_dir, cxx_dir, module_feature_macro},
+    utils::string::{camel_case, pascal_case, snake_case},
 };
-use indoc::formatdoc;
+use indoc::{formatdoc, indoc};
 
 use crate::{
-    constants::specs::RESERVED_ARG_NAME_MODULE,
+    constants::{specs::RESERVED_ARG_NAME_MODULE, DEFAULT_CXX_BASE_CLASS, MODULE_REGISTRY_HEADER},
     platform::cxx::CxxMethod,
     types::{CodegenContext, CxxModuleName, CxxNamespace, Schema},
-    utils::indent_str,
+    utils::{cxx_feature_guard, doc_comment, indent_str},
 };
 
 use super::types::{Generator, GeneratorInvoker, Template, TemplateResult};
@@ -27,6 +27,8 @@ pub enum CxxFileType {
     UtilsHpp,
     /// CrabySignals.h
     SignalsH,
+    /// CrabyModuleRegistry.hpp
+    ModuleRegistryHpp,
 }
 
 impl CxxTemplate {
@@ -38,15 +40,16 @@ impl CxxTemplate {
     /// ```
     fn cxx_methods(
         &self,
-        project_name: &str,
+        cxx_namespace: &str,
         schema: &Schema,
+        unimplemented_error: bool,
     ) -> Result<Vec<CxxMethod>, anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(project_name);
+        let cxx_ns = CxxNamespace::from(cxx_namespace);
         let mod_name = CxxModuleName::from(&schema.module_name);
         let res = schema
             .methods
             .iter()
-            .map(|spec| spec.as_cxx_method(&cxx_ns, &mod_name))
+            .map(|spec| spec.as_cxx_method(&cxx_ns, &mod_name, unimplemented_error))
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(res)
@@ -89,10 +92,12 @@ impl CxxTemplate {
     /// namespace modules {
     ///
     /// CxxMyTestModule::CxxMyTestModule(
-    ///     std::shared_ptr<react::CallInvoker> jsInvoker)
-    ///     : TurboModule(CxxMyTestModule::kModuleName, jsInvoker) {
+    ///     std::shared_ptr<react::CallInvoker> jsInvoker,
+    ///     std::string dataPath)
+    ///     : TurboModule(CxxMyTestModule::kModuleName, jsInvoker),
+    ///       dataPath_(std::move(dataPath)) {
     ///   callInvoker_ = std::move(jsInvoker);
-    ///   threadPool_ = std::make_shared<craby::utils::ThreadPool>(10);
+    ///   threadPool_ = std::make_shared<craby::utils::ThreadPool>(10); // `project.thread_pool_size`
     ///   methodMap_["multiply"] = MethodMetadata{2, &CxxMyTestModule::multiply};
     /// }
     /// jsi::Value CxxMyTestModule::multiply(jsi::Runtime &rt,
@@ -125,9 +130,8 @@ impl CxxTemplate {
     /// class JSI_EXPORT CxxMyTestModule : public facebook::react::TurboModule {
     /// public:
     ///   static constexpr const char *kModuleName = "MyTestModule";
-    ///   static std::string dataPath;
     ///
-    ///   CxxMyTestModule(std::shared_ptr<facebook::react::CallInvoker> jsInvoker);
+    ///   CxxMyTestModule(std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath);
     ///   ~CxxMyTestModule();
     ///
     ///   static facebook::jsi::Value
@@ -137,6 +141,7 @@ impl CxxTemplate {
     ///
     /// protected:
     ///   std::shared_ptr<facebook::react::CallInvoker> callInvoker_;
+    ///   std::string dataPath_;
     ///   std::shared_ptr<craby::mymodule::bridging::MyTestModule> module_;
     /// };
     ///
@@ -146,28 +151,63 @@ impl CxxTemplate {
     /// ```
     fn cxx_mod(
         &self,
+        ctx: &CodegenContext,
         schema: &Schema,
-        project_name: &str,
     ) -> Result<(String, String), anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(project_name);
+        let listener_error_hook = ctx.listener_error_hook;
+        let cxx_ns = CxxNamespace::from(&ctx.cxx_namespace);
         let cxx_mod = CxxModuleName::from(&schema.module_name);
-        let project_ns = flat_case(project_name);
-        let cxx_methods = self.cxx_methods(project_name, schema)?;
+        let project_ns = &ctx.cxx_namespace;
+        let cxx_methods = self.cxx_methods(&ctx.cxx_namespace, schema, ctx.unimplemented_error)?;
         let include_stmt = format!("#include \"{cxx_mod}.hpp\"");
 
+        // Base class of the module (eg. `facebook::react::TurboModule`)
+        let base_class = ctx
+            .cxx_base_class
+            .as_ref()
+            .map_or(DEFAULT_CXX_BASE_CLASS, |base_class| base_class.name.as_str());
+        // Injected class name of the base class for the constructor initializer (eg. `TurboModule`)
+        let base_class_name = base_class.rsplit("::").next().unwrap_or(base_class);
+        let base_class_includes = match ctx
+            .cxx_base_class
+            .as_ref()
+            .and_then(|base_class| base_class.header.as_ref())
+        {
+            Some(header) => format!("#include <ReactCommon/TurboModule.h>\n#include \"{header}\""),
+            None => "#include <ReactCommon/TurboModule.h>".to_string(),
+        };
+
         // Assign method metadata with function pointer to the TurboModule's method map
         //
         // ```cpp
         // methodMap_["multiply"] = MethodMetadata{1, &CxxMyTestModule::multiply};
         // ```
+        //
+        // Methods annotated with `@platform` are registered on that platform only
+        //
+        // The entries are emitted in a stable order to keep the generated code (and its hash) deterministic:
+        // methods, then signals (both sorted by name while collecting the schema), then the built-in methods
         let mut method_maps = cxx_methods
             .iter()
-            .map(|method| format!("methodMap_[\"{}\"] = {};", method.name, method.metadata))
+            .map(|method| {
+                let method_map =
+                    format!("methodMap_[\"{}\"] = {};", method.name, method.metadata);
+                match &method.platform {
+                    Some(platform) => format!(
+                        "#if {}\n{method_map}\n#endif",
+                        platform.as_cxx_condition()
+                    ),
+                    None => method_map,
+                }
+            })
             .collect::<Vec<_>>();
 
         let mut method_defs = cxx_methods
             .iter()
-            .map(|method| self.cxx_method_def(&method.name))
+            .map(|method| {
+                let method_def = self.cxx_method_def(&method.name);
+                format!("{}{method_def}", doc_comment(&method.docs))
+            })
             .collect::<Vec<_>>();
 
         // Functions implementations
@@ -196,14 +236,15 @@ impl CxxTemplate {
             let register_stmt = if let Some(ref signal_enum) = signal_enum_name {
                 formatdoc! {
                     r#"
-                    uintptr_t id = reinterpret_cast<uintptr_t>(this);
-                    auto& manager = {cxx_ns}::signals::SignalManager::getInstance();
+                    size_t id = reinterpret_cast<size_t>(this);
+                    auto& manager = {cxx_ns}::signals::{module_name}SignalManager::getInstance();
                     manager.registerDelegate(id,
-                      [this](const std::string& name, void* signal) {{
-                        this->emit(name, reinterpret_cast<bridging::{signal_enum}*>(signal));
+                      [this](const std::string& name, bridging::{signal_enum}* signal) {{
+                        this->emit(name, signal);
                       }}
                     );"#,
                     signal_enum = signal_enum,
+                    module_name = schema.module_name,
                 }
             } else {
                 String::new()
@@ -212,9 +253,10 @@ impl CxxTemplate {
             let unregister_stmt = formatdoc! {
                 r#"
                 // Unregister from signal manager
-                uintptr_t id = reinterpret_cast<uintptr_t>(this);
-                auto& manager = {cxx_ns}::signals::SignalManager::getInstance();
+                size_t id = reinterpret_cast<size_t>(this);
+                auto& manager = {cxx_ns}::signals::{module_name}SignalManager::getInstance();
                 manager.unregisterDelegate(id);"#,
+                module_name = schema.module_name,
             };
 
             for signal in &schema.signals {
@@ -310,19 +352,20 @@ impl CxxTemplate {
                 let mut conditions: Vec<String> = schema.signals
                     .iter()
                     .filter_map(|signal| {
-                        signal.payload_type.as_ref().map(|_| {
+                        signal.payload_type.as_ref().map(|payload_type| {
                             let function_name = format!("get_{}_payload", snake_case(&signal.name));
-                            formatdoc! {
+                            Ok(formatdoc! {
                                 r#"else if (name == "{signal_name}") {{
-                                  auto payload = craby::{project_ns}::bridging::{function_name}(*signalPtr);
-                                  data = react::bridging::toJs(rt, payload);
+                                  auto payload = signalPtr->{function_name}();
+                                  data = {to_js};
                                 }}"#,
                                 signal_name = signal.name,
                                 function_name = function_name,
-                            }
+                                to_js = payload_type.as_cxx_to_js("payload")?.expr,
+                            })
                         })
                     })
-                    .collect();
+                    .collect::<Result<Vec<_>, anyhow::Error>>()?;
                 
                 if !conditions.is_empty() {
                     // Replace first "else if" with "if"
@@ -330,7 +373,7 @@ impl CxxTemplate {
                         *first = first.replace("else if", "if");
                     }
                     let joined = conditions.join(" ");
-                    indent_str(&joined, 10)
+                    indent_str(&joined, 8)
                 } else {
                     String::new()
                 }
@@ -338,13 +381,59 @@ impl CxxTemplate {
                 String::new()
             };
 
+            // Report exceptions thrown by the listeners to the Rust module
+            // (or propagate them to the runtime by default)
+            let (hook_decl, hook_captures, call_listener) = if listener_error_hook {
+                let call_listener = |data: &str| {
+                    formatdoc! {
+                        r#"
+                        try {{
+                          listener->call(rt, {data});
+                        }} catch (const jsi::JSError &err) {{
+                          {cxx_ns}::bridging::on{module_name}ListenerError(id, name, err.getMessage());
+                        }} catch (const std::exception &err) {{
+                          {cxx_ns}::bridging::on{module_name}ListenerError(id, name, {cxx_ns}::utils::errorMessage(err));
+                        }}"#,
+                        module_name = pascal_case(&schema.module_name),
+                    }
+                };
+                (
+                    "size_t id = reinterpret_cast<size_t>(this);\n  ",
+                    ", id",
+                    (call_listener("data"), call_listener("*payloadPtr")),
+                )
+            } else {
+                (
+                    "",
+                    "",
+                    (
+                        "listener->call(rt, data);".to_string(),
+                        formatdoc! {
+                            r#"
+                            try {{
+                              listener->call(rt, *payloadPtr);
+                            }} catch (const jsi::JSError &err) {{
+                              throw err;
+                            }} catch (const std::exception &err) {{
+                              throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
+                            }}"#,
+                        },
+                    ),
+                )
+            };
+            let (call_with_signal, call_with_payload) = (
+                indent_str(&call_listener.0, 8),
+                indent_str(&call_listener.1, 6),
+            );
+            let payload_captures = if listener_error_hook { ", name, id" } else { "" };
+
             method_impls.insert(
                 0,
                 if let Some(ref signal_enum) = signal_enum_name {
                     formatdoc! {
                         r#"
                         void {cxx_mod}::emit(std::string name, bridging::{signal_enum}* signal) {{
-                          std::vector<std::shared_ptr<facebook::jsi::Function>> listeners;
+                          {hook_decl}std::vector<std::shared_ptr<facebook::jsi::Function>> listeners;
                           {{
                             std::lock_guard<std::mutex> lock(listenersMutex_);
                             auto it = listenersMap_.find(name);
@@ -367,7 +456,7 @@ impl CxxTemplate {
                               [](bridging::{signal_enum}* ptr) {{
                                 // Use Rust FFI function to drop signal memory
                                 if (ptr != nullptr) {{
-                                  craby::{project_ns}::bridging::drop_signal(ptr);
+                                  craby::{project_ns}::bridging::drop_{snake_module_name}_signal(ptr);
                                 }}
                               }}
                             );
@@ -375,46 +464,32 @@ impl CxxTemplate {
                             // Extract payload using FFI function and convert to jsi::Value
                             // We'll need to capture signalPtr in the lambda
                             for (auto& listener : listeners) {{
-                              try {{
-                                callInvoker_->invokeAsync([listener, signalPtr, name](jsi::Runtime &rt) {{
-                                  jsi::Value data = jsi::Value::undefined();
+                              {cxx_ns}::utils::invokeAsync(callInvoker_, [listener, signalPtr, name{hook_captures}](jsi::Runtime &rt) {{
+                                jsi::Value data = jsi::Value::undefined();
                         {payload_extraction}
-                                  listener->call(rt, data);
-                                }});
-                              }} catch (const std::exception& err) {{
-                                // Noop
-                              }}
+                        {call_with_signal}
+                              }});
                             }}
                             return;
                           }}
 
                           for (auto& listener : listeners) {{
-                            try {{
-                              callInvoker_->invokeAsync([listener, payloadPtr](jsi::Runtime &rt) {{
-                                try {{
-                                  listener->call(rt, *payloadPtr);
-                                }} catch (const jsi::JSError &err) {{
-                                  throw err;
-                                }} catch (const std::exception &err) {{
-                                  throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
-                                }}
-                              }});
-                            }} catch (const std::exception& err) {{
-                              // Noop
-                            }}
+                            {cxx_ns}::utils::invokeAsync(callInvoker_, [listener, payloadPtr{payload_captures}](jsi::Runtime &rt) {{
+                        {call_with_payload}
+                            }});
                           }}
                         }}"#,
                         signal_enum = signal_enum,
                         project_ns = project_ns,
                         cxx_mod = cxx_mod,
-                        cxx_ns = cxx_ns,
                         payload_extraction = payload_extraction,
+                        snake_module_name = snake_case(&schema.module_name),
                     }
                 } else {
                     formatdoc! {
                         r#"
                         void {cxx_mod}::emit(std::string name) {{
-                          std::vector<std::shared_ptr<facebook::jsi::Function>> listeners;
+                          {hook_decl}std::vector<std::shared_ptr<facebook::jsi::Function>> listeners;
                           {{
                             std::lock_guard<std::mutex> lock(listenersMutex_);
                             auto it = listenersMap_.find(name);
@@ -425,20 +500,14 @@ impl CxxTemplate {
                             }}
                           }}
 
+                          // Signals without payloads are emitted with `undefined`
+                          auto payloadPtr = std::make_shared<facebook::jsi::Value>();
+                          *payloadPtr = facebook::jsi::Value::undefined();
+
                           for (auto& listener : listeners) {{
-                            try {{
-                              callInvoker_->invokeAsync([listener, payloadPtr](jsi::Runtime &rt) {{
-                                try {{
-                                  listener->call(rt, *payloadPtr);
-                                }} catch (const jsi::JSError &err) {{
-                                  throw err;
-                                }} catch (const std::exception &err) {{
-                                  throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
-                                }}
-                              }});
-                            }} catch (const std::exception& err) {{
-                              // Noop
-                            }}
+                            {cxx_ns}::utils::invokeAsync(callInvoker_, [listener, payloadPtr{payload_captures}](jsi::Runtime &rt) {{
+                        {call_with_payload}
+                            }});
                           }}
                         }}"#,
                     }
@@ -451,32 +520,63 @@ impl CxxTemplate {
             (String::from("// No signals"), String::from("// No signals"))
         };
 
+        // Count the live instances of the module for leak detection (exposed as `__instanceCount()`)
+        let (ctor_count_stmt, dtor_count_stmt, instance_count_field) = if ctx.instance_counter {
+            method_maps.push(format!(
+                "methodMap_[\"__instanceCount\"] = MethodMetadata{{0, &{cxx_mod}::getInstanceCount}};"
+            ));
+            method_defs.push("static size_t instanceCount();".to_string());
+            method_defs.push(self.cxx_method_def("getInstanceCount"));
+            method_impls.push(formatdoc! {
+                r#"
+                size_t {cxx_mod}::instanceCount() {{
+                  return instanceCount_.load();
+                }}
+
+                jsi::Value {cxx_mod}::getInstanceCount(jsi::Runtime &rt,
+                                      react::TurboModule &turboModule,
+                                      const jsi::Value args[],
+                                      size_t count) {{
+                  return jsi::Value(static_cast<double>({cxx_mod}::instanceCount()));
+                }}"#,
+            });
+            (
+                "\n  instanceCount_.fetch_add(1);",
+                "\n  instanceCount_.fetch_sub(1);",
+                "\n  static inline std::atomic<size_t> instanceCount_{0};",
+            )
+        } else {
+            ("", "", "")
+        };
+
         let rs_module_name = pascal_case(&schema.module_name);
+        // The pool needs at least one worker to run the async methods
+        let thread_pool_size = ctx.thread_pool_size.max(1);
         let register_stmts = indent_str(&register_stmt, 2);
         let unregister_stmts = indent_str(&unregister_stmt, 2);
         let method_mapping_stmts = indent_str(&method_maps.join("\n"), 2);
         let method_impls = method_impls.join("\n\n");
         let cpp = formatdoc! {
             r#"
-            std::string {cxx_mod}::dataPath = std::string();
-
             {cxx_mod}::{cxx_mod}(
-                std::shared_ptr<react::CallInvoker> jsInvoker)
-                : TurboModule({cxx_mod}::kModuleName, jsInvoker) {{
+                std::shared_ptr<react::CallInvoker> jsInvoker,
+                std::string dataPath)
+                : {base_class_name}({cxx_mod}::kModuleName, jsInvoker),
+                  dataPath_(std::move(dataPath)) {{
             {register_stmts}
               callInvoker_ = std::move(jsInvoker);
               module_ = std::shared_ptr<{cxx_ns}::bridging::{rs_module_name}>(
                 {cxx_ns}::bridging::create{rs_module_name}(
-                  reinterpret_cast<uintptr_t>(this),
-                  rust::Str(dataPath.data(), dataPath.size())).into_raw(),
+                  reinterpret_cast<size_t>(this),
+                  rust::Str(dataPath_.data(), dataPath_.size())).into_raw(),
                 []({cxx_ns}::bridging::{rs_module_name} *ptr) {{ rust::Box<{cxx_ns}::bridging::{rs_module_name}>::from_raw(ptr); }}
               );
-              threadPool_ = std::make_shared<{cxx_ns}::utils::ThreadPool>(10);
-            {method_mapping_stmts}
+              threadPool_ = std::make_shared<{cxx_ns}::utils::ThreadPool>({thread_pool_size});
+            {method_mapping_stmts}{ctor_count_stmt}
             }}
 
             {cxx_mod}::~{cxx_mod}() {{
-              invalidate();
+              invalidate();{dtor_count_stmt}
             }}
 
             void {cxx_mod}::invalidate() {{
@@ -484,13 +584,19 @@ impl CxxTemplate {
                 return;
               }}
 
-              invalidated_.store(true);
-              listenersMap_.clear();
-            
+              // Teardown order matters since in-flight tasks may still reference `module_` and listeners
+              // 1. Stop routing signals to this module
+              // 2. Stop accepting new tasks and join the workers (waits for the queued and in-flight tasks)
+              // 3. Clear listeners once nothing can reference them anymore
+
             {unregister_stmts}
 
-              // Shutdown thread pool
               threadPool_->shutdown();
+
+              {{
+                std::lock_guard<std::mutex> lock(listenersMutex_);
+                listenersMap_.clear();
+              }}
             }}
             
             {method_impls}"#,
@@ -499,12 +605,11 @@ impl CxxTemplate {
         let method_defs = indent_str(&method_defs.join("\n\n"), 2);
         let hpp = formatdoc! {
             r#"
-            class JSI_EXPORT {cxx_mod} : public facebook::react::TurboModule {{
+            class JSI_EXPORT {cxx_mod} : public {base_class} {{
             public:
               static constexpr const char *kModuleName = "{turbo_module_name}";
-              static std::string dataPath;
 
-              {cxx_mod}(std::shared_ptr<facebook::react::CallInvoker> jsInvoker);
+              {cxx_mod}(std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath);
               ~{cxx_mod}();
 
               void invalidate();
@@ -512,6 +617,7 @@ impl CxxTemplate {
 
             protected:
               std::shared_ptr<facebook::react::CallInvoker> callInvoker_;
+              std::string dataPath_;
               std::shared_ptr<{cxx_ns}::bridging::{rs_module_name}> module_;
               std::atomic<bool> invalidated_{{false}};
               std::atomic<size_t> nextListenerId_{{0}};
@@ -520,11 +626,22 @@ impl CxxTemplate {
                 std::string,
                 std::unordered_map<size_t, std::shared_ptr<facebook::jsi::Function>>>
                 listenersMap_;
-              std::shared_ptr<{cxx_ns}::utils::ThreadPool> threadPool_;
+              std::shared_ptr<{cxx_ns}::utils::ThreadPool> threadPool_;{instance_count_field}
             }};"#,
             turbo_module_name = schema.module_name,
         };
 
+        // Exclude the module when its cargo feature is disabled (see `CrabyFeatures.h`)
+        let (feature_include, guard_begin, guard_end) = if ctx.module_features {
+            (
+                "#include \"CrabyFeatures.h\"\n",
+                format!("#if defined({})\n\n", module_feature_macro(&schema.module_name)),
+                "\n\n#endif",
+            )
+        } else {
+            ("", String::new(), "")
+        };
+
         let cpp_content = formatdoc! {
             r#"
             {include_stmt}
@@ -532,7 +649,7 @@ impl CxxTemplate {
             #include "bridging-generated.hpp"
             #include <react/bridging/Bridging.h>
 
-            using namespace facebook;
+            {guard_begin}using namespace facebook;
 
             namespace craby {{
             namespace {project_ns} {{
@@ -542,20 +659,20 @@ impl CxxTemplate {
 
             }} // namespace modules
             }} // namespace {project_ns}
-            }} // namespace craby"#,
+            }} // namespace craby{guard_end}"#,
         };
 
         let hpp_content = formatdoc! {
             r#"
             #pragma once
 
-            #include "CrabyUtils.hpp"
+            {feature_include}#include "CrabyUtils.hpp"
             #include "ffi.rs.h"
-            #include <ReactCommon/TurboModule.h>
+            {base_class_includes}
             #include <jsi/jsi.h>
             #include <memory>
             
-            namespace craby {{
+            {guard_begin}namespace craby {{
             namespace {project_ns} {{
             namespace modules {{
 
@@ -563,7 +680,7 @@ impl CxxTemplate {
 
             }} // namespace modules
             }} // namespace {project_ns}
-            }} // namespace craby"#,
+            }} // namespace craby{guard_end}"#,
         };
 
         Ok((cpp_content, hpp_content))
@@ -593,7 +710,7 @@ impl CxxTemplate {
     ///   }
     ///
     ///   static jsi::Value toJs(jsi::Runtime& rt, const rust::String& value) {
-    ///     return react::bridging::toJs(rt, std::string(value));
+    ///     return jsi::String::createFromUtf8(rt, reinterpret_cast<const uint8_t*>(value.data()), value.size());
     ///   }
     /// };
     ///
@@ -606,7 +723,14 @@ impl CxxTemplate {
         let bridging_templates = ctx
             .schemas
             .iter()
-            .flat_map(|schema| schema.as_cxx_bridging_templates(&ctx.project_name))
+            .flat_map(|schema| {
+                schema
+                    .as_cxx_bridging_templates(&ctx.cxx_namespace)
+                    .map(|templates| match templates.is_empty() || !ctx.module_features {
+                        true => templates,
+                        false => vec![cxx_feature_guard(&templates.join("\n\n"), &schema.module_name)],
+                    })
+            })
             .flatten()
             .collect::<Vec<_>>();
 
@@ -614,13 +738,80 @@ impl CxxTemplate {
             r#"
             #pragma once
 
-            #include "cxx.h"
+            {feature_include}#include "cxx.h"
             #include "ffi.rs.h"
             #include <react/bridging/Bridging.h>
+            #include <cmath>
+            #include <cstring>
+            #include <limits>
             #include <variant>
 
             using namespace facebook;
 
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace utils {{
+
+            /// Converts the JS number to the integer, throwing if it is not an integer within the range of `T`.
+            template <typename T>
+            T fromJsInt(jsi::Runtime& rt, const jsi::Value &value) {{
+              double num = value.asNumber();
+              // `max() + 1` is exactly representable (`max()` of `int64_t` is rounded up to `2^63`)
+              if (std::trunc(num) != num
+                  || num < static_cast<double>(std::numeric_limits<T>::min())
+                  || num >= static_cast<double>(std::numeric_limits<T>::max()) + 1.0) {{
+                throw jsi::JSError(rt, "Expected a " + std::to_string(sizeof(T) * 8) + "-bit integer, got " + value.toString(rt).utf8(rt));
+              }}
+              return static_cast<T>(num);
+            }}
+
+            template <typename T>
+            struct IntVecBridging {{
+              static rust::Vec<T> fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<react::CallInvoker> callInvoker) {{
+                auto arr = value.asObject(rt).asArray(rt);
+                size_t len = arr.length(rt);
+                rust::Vec<T> vec;
+                vec.reserve(len);
+
+                for (size_t i = 0; i < len; i++) {{
+                  vec.push_back(fromJsInt<T>(rt, arr.getValueAtIndex(rt, i)));
+                }}
+
+                return vec;
+              }}
+
+              static jsi::Array toJs(jsi::Runtime& rt, const rust::Vec<T>& vec) {{
+                auto arr = jsi::Array(rt, vec.size());
+
+                for (size_t i = 0; i < vec.size(); i++) {{
+                  arr.setValueAtIndex(rt, i, jsi::Value(static_cast<double>(vec[i])));
+                }}
+
+                return arr;
+              }}
+            }};
+
+            /// Converts the JS BigInt to `int64_t`, throwing if it is not a BigInt within the range of `int64_t`.
+            inline int64_t fromJsBigInt(jsi::Runtime& rt, const jsi::Value &value) {{
+              if (!value.isBigInt()) {{
+                throw jsi::JSError(rt, "Expected a BigInt, got " + value.toString(rt).utf8(rt));
+              }}
+              auto bigint = value.getBigInt(rt);
+              if (!bigint.isInt64(rt)) {{
+                throw jsi::JSError(rt, "Expected a 64-bit integer BigInt, got " + bigint.toString(rt).utf8(rt));
+              }}
+              return bigint.asInt64(rt);
+            }}
+
+            /// `int64_t` resolved as the JS BigInt by the promises (integers are resolved as the JS numbers).
+            struct BigIntValue {{
+              int64_t value;
+            }};
+
+            }} // namespace utils
+            }} // namespace {flat_name}
+            }} // namespace craby
+
             namespace {flat_name} {{
 
             class RustVecBuffer : public jsi::MutableBuffer {{
@@ -666,7 +857,7 @@ impl CxxTemplate {
               }}
 
               static jsi::Value toJs(jsi::Runtime& rt, const rust::Str& value) {{
-                return react::bridging::toJs(rt, std::string(value.data(), value.size()));
+                return jsi::String::createFromUtf8(rt, reinterpret_cast<const uint8_t*>(value.data()), value.size());
               }}
             }};
 
@@ -678,16 +869,22 @@ impl CxxTemplate {
               }}
 
               static jsi::Value toJs(jsi::Runtime& rt, const rust::String& value) {{
-                return react::bridging::toJs(rt, std::string(value.data(), value.size()));
+                // `createFromUtf8` copies the bytes into the runtime before returning,
+                // so the borrowed buffer only has to outlive this call
+                return jsi::String::createFromUtf8(rt, reinterpret_cast<const uint8_t*>(value.data()), value.size());
               }}
             }};
 
             template <>
             struct Bridging<rust::Vec<uint8_t>> {{
               static rust::Vec<uint8_t> fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<CallInvoker> callInvoker) {{
-                auto arrayBuffer = value.asObject(rt).getArrayBuffer(rt);
-                uint8_t* data = arrayBuffer.data(rt);
-                size_t size = arrayBuffer.size(rt);
+                auto obj = value.asObject(rt);
+                // Typed arrays (eg. `Uint8Array`) are the views of the underlying `ArrayBuffer`
+                bool isView = !obj.isArrayBuffer(rt);
+                auto arrayBuffer = isView ? obj.getPropertyAsObject(rt, "buffer").getArrayBuffer(rt) : obj.getArrayBuffer(rt);
+                size_t offset = isView ? static_cast<size_t>(obj.getProperty(rt, "byteOffset").asNumber()) : 0;
+                size_t size = isView ? static_cast<size_t>(obj.getProperty(rt, "byteLength").asNumber()) : arrayBuffer.size(rt);
+                uint8_t* data = arrayBuffer.data(rt) + offset;
                 rust::Vec<uint8_t> vec;
                 vec.reserve(size);
 
@@ -729,10 +926,24 @@ impl CxxTemplate {
                 return arr;
               }}
             }};
+
+            template <>
+            struct Bridging<rust::Vec<int32_t>> : craby::{flat_name}::utils::IntVecBridging<int32_t> {{}};
+
+            template <>
+            struct Bridging<rust::Vec<int64_t>> : craby::{flat_name}::utils::IntVecBridging<int64_t> {{}};
+
+            template <>
+            struct Bridging<craby::{flat_name}::utils::BigIntValue> {{
+              static jsi::Value toJs(jsi::Runtime& rt, const craby::{flat_name}::utils::BigIntValue& value) {{
+                return jsi::BigInt::fromInt64(rt, value.value);
+              }}
+            }};
             {bridging_templates}
             }} // namespace react
             }} // namespace facebook"#,
-            flat_name = flat_case(&ctx.project_name),
+            flat_name = ctx.cxx_namespace,
+            feature_include = if ctx.module_features { "#include \"CrabyFeatures.h\"\n" } else { "" },
             bridging_templates = if bridging_templates.is_empty() { "".to_string() } else { format!("\n{}\n", bridging_templates.join("\n\n")) },
         };
 
@@ -750,6 +961,7 @@ impl CxxTemplate {
     /// #include "ffi.rs.h"
     /// #include <condition_variable>
     /// #include <functional>
+    /// #include <memory>
     /// #include <mutex>
     /// #include <queue>
     /// #include <thread>
@@ -762,6 +974,7 @@ impl CxxTemplate {
     /// class ThreadPool {
     /// private:
     ///   bool stop;
+    ///   std::once_flag shutdownFlag;
     ///   std::mutex mutex;
     ///   std::condition_variable condition;
     ///   std::queue<std::function<void()>> tasks;
@@ -805,20 +1018,20 @@ impl CxxTemplate {
     ///   }
     ///
     ///   void shutdown() {
-    ///     {
-    ///       std::unique_lock<std::mutex> lock(mutex);
-    ///       stop = true;
-    ///       std::queue<std::function<void()>> empty;
-    ///       std::swap(tasks, empty);
-    ///     }
+    ///     std::call_once(shutdownFlag, [this] {
+    ///       {
+    ///         std::unique_lock<std::mutex> lock(mutex);
+    ///         stop = true;
+    ///       }
     ///
-    ///     condition.notify_all();
+    ///       condition.notify_all();
     ///
-    ///     for (std::thread &worker : workers) {
-    ///       if (worker.joinable()) {
-    ///         worker.join();
+    ///       for (std::thread &worker : workers) {
+    ///         if (worker.joinable()) {
+    ///           worker.join();
+    ///         }
     ///       }
-    ///     }
+    ///     });
     ///   }
     ///
     ///   ~ThreadPool() {
@@ -831,12 +1044,106 @@ impl CxxTemplate {
     ///   return std::string(rs_err ? rs_err->what() : err.what());
     /// }
     ///
+    /// template <class Invoker, class F>
+    /// void invokeAsync(const std::shared_ptr<Invoker> &callInvoker, F &&task) {
+    ///   // ...
+    /// }
+    ///
     /// } // namespace utils
     /// } // namespace mymodule
     /// } // namespace craby
     /// ```
-    fn cxx_utils(&self, project_name: &str) -> Result<String, anyhow::Error> {
-        let flat_name = flat_case(project_name);
+    ///
+    /// With `structured_errors`, the promise utils of the `@throws` methods are added
+    /// (`PromiseCallbacks`, `createPromise` and `settlePromise`).
+    fn cxx_utils(
+        &self,
+        cxx_namespace: &str,
+        thread_pool_size: usize,
+        unimplemented_error: bool,
+        structured_errors: bool,
+    ) -> Result<String, anyhow::Error> {
+        let flat_name = cxx_namespace;
+        let thread_pool_size = thread_pool_size.max(1);
+        // Replaces the message of the Rust `unimplemented!()` panic (`not implemented[: reason]`)
+        let unimplemented_error_message = if unimplemented_error {
+            indoc! {
+                r#"
+
+                inline std::string errorMessage(const std::exception &err, const std::string &methodName) {
+                #if defined(__APPLE__)
+                  const std::string platform = "iOS";
+                #elif defined(__ANDROID__)
+                  const std::string platform = "Android";
+                #else
+                  const std::string platform = "this platform";
+                #endif
+                  const std::string prefix = "not implemented";
+                  auto message = errorMessage(err);
+                  if (message.rfind(prefix, 0) != 0) {
+                    return message;
+                  }
+                  return "`" + methodName + "` is not implemented on " + platform + message.substr(prefix.size());
+                }
+                "#
+            }
+        } else {
+            ""
+        };
+        // `react::AsyncPromise` only rejects with the error messages,
+        // so the promises of `@throws` methods are created and settled with the JS functions
+        let (jsi_include, promise_utils) = if structured_errors {
+            (
+                "#include <jsi/jsi.h>\n",
+                indoc! {
+                    r#"
+
+                    /// Resolve and reject functions of the promise created by `createPromise`.
+                    struct PromiseCallbacks {
+                      std::shared_ptr<facebook::jsi::Function> resolve;
+                      std::shared_ptr<facebook::jsi::Function> reject;
+                    };
+
+                    /// Creates a promise that can be rejected with any JS value (eg. structured errors).
+                    inline facebook::jsi::Value createPromise(facebook::jsi::Runtime &rt,
+                                                             const std::shared_ptr<PromiseCallbacks> &callbacks) {
+                      auto executor = facebook::jsi::Function::createFromHostFunction(
+                        rt,
+                        facebook::jsi::PropNameID::forAscii(rt, "executor"),
+                        2,
+                        [callbacks](facebook::jsi::Runtime &rt,
+                                    const facebook::jsi::Value &,
+                                    const facebook::jsi::Value *args,
+                                    size_t) -> facebook::jsi::Value {
+                          callbacks->resolve = std::make_shared<facebook::jsi::Function>(args[0].asObject(rt).asFunction(rt));
+                          callbacks->reject = std::make_shared<facebook::jsi::Function>(args[1].asObject(rt).asFunction(rt));
+                          return facebook::jsi::Value::undefined();
+                        });
+                      return rt.global().getPropertyAsFunction(rt, "Promise").callAsConstructor(rt, executor);
+                    }
+
+                    /// Settles the promise created by `createPromise` with the converted value on the JS thread.
+                    template <class Invoker, class F>
+                    void settlePromise(const std::shared_ptr<Invoker> &callInvoker,
+                                       const std::shared_ptr<PromiseCallbacks> &callbacks,
+                                       bool resolved,
+                                       F &&toJs) {
+                      invokeAsync(callInvoker, [callbacks, resolved, toJs = std::forward<F>(toJs)](facebook::jsi::Runtime &rt) {
+                        auto settle = resolved ? callbacks->resolve : callbacks->reject;
+                        // The JS functions must be released on the JS thread
+                        callbacks->resolve.reset();
+                        callbacks->reject.reset();
+                        if (settle) {
+                          settle->call(rt, toJs(rt));
+                        }
+                      });
+                    }
+                    "#
+                },
+            )
+        } else {
+            ("", "")
+        };
 
         Ok(formatdoc! {
             r#"
@@ -844,10 +1151,13 @@ impl CxxTemplate {
 
             #include "cxx.h"
             #include "ffi.rs.h"
-            #include <condition_variable>
+            {jsi_include}#include <condition_variable>
             #include <functional>
+            #include <memory>
             #include <mutex>
             #include <queue>
+            #include <stdexcept>
+            #include <string>
             #include <thread>
             #include <vector>
 
@@ -858,13 +1168,14 @@ impl CxxTemplate {
             class ThreadPool {{
             private:
               bool stop;
+              std::once_flag shutdownFlag;
               std::mutex mutex;
               std::condition_variable condition;
               std::queue<std::function<void()>> tasks;
               std::vector<std::thread> workers;
 
             public:
-              ThreadPool(size_t num_threads = 10) : stop(false) {{
+              ThreadPool(size_t num_threads = {thread_pool_size}) : stop(false) {{
                 for (size_t i = 0; i < num_threads; ++i) {{
                   workers.emplace_back([this] {{
                     while (true) {{
@@ -900,21 +1211,23 @@ impl CxxTemplate {
                 condition.notify_one();
               }}
 
+              /// Stops accepting new tasks and joins the workers once the queued tasks are finished.
+              /// Concurrent callers are blocked until the queue is drained.
               void shutdown() {{
-                {{
-                  std::unique_lock<std::mutex> lock(mutex);
-                  stop = true;
-                  std::queue<std::function<void()>> empty;
-                  std::swap(tasks, empty);
-                }}
+                std::call_once(shutdownFlag, [this] {{
+                  {{
+                    std::unique_lock<std::mutex> lock(mutex);
+                    stop = true;
+                  }}
 
-                condition.notify_all();
+                  condition.notify_all();
 
-                for (std::thread &worker : workers) {{
-                  if (worker.joinable()) {{
-                    worker.join();
+                  for (std::thread &worker : workers) {{
+                    if (worker.joinable()) {{
+                      worker.join();
+                    }}
                   }}
-                }}
+                }});
               }}
 
               ~ThreadPool() {{
@@ -927,6 +1240,28 @@ impl CxxTemplate {
               return std::string(rs_err ? rs_err->what() : err.what());
             }}
 
+            /// Schedules the task on the JS thread through the `CallInvoker`.
+            /// Failures of scheduling (eg. the runtime is being torn down) are ignored.
+            template <class Invoker, class F>
+            void invokeAsync(const std::shared_ptr<Invoker> &callInvoker, F &&task) {{
+              try {{
+                callInvoker->invokeAsync(std::forward<F>(task));
+              }} catch (const std::exception &) {{
+                // Noop
+              }}
+            }}
+
+            /// Converts the JS argument, prefixing the conversion errors with the argument name and index.
+            template <class F>
+            auto fromJsArg(const char *name, size_t index, F &&convert) -> decltype(convert()) {{
+              try {{
+                return convert();
+              }} catch (const std::exception &err) {{
+                throw std::runtime_error(
+                  "argument '" + std::string(name) + "' (arg" + std::to_string(index) + "): " + errorMessage(err));
+              }}
+            }}
+            {unimplemented_error_message}{promise_utils}
             }} // namespace utils
             }} // namespace {flat_name}
             }} // namespace craby"#,
@@ -935,12 +1270,16 @@ impl CxxTemplate {
 
     /// Generates the signal manager header file for event emission.
     ///
+    /// The signal manager is templated over the signal enum, so each module has its own
+    /// manager (eg. `MyModuleSignalManager`) that routes its typed signals.
+    ///
     /// # Generated Code
     ///
     /// ```cpp
     /// #pragma once
     ///
     /// #include "rust/cxx.h"
+    /// #include <cstddef>
     /// #include <functional>
     /// #include <memory>
     /// #include <mutex>
@@ -948,247 +1287,395 @@ impl CxxTemplate {
     ///
     /// namespace craby {
     /// namespace mymodule {
+    /// namespace bridging {
+    /// struct MyModuleSignal;
+    /// } // namespace bridging
+    /// } // namespace mymodule
+    /// } // namespace craby
+    ///
+    /// namespace craby {
+    /// namespace mymodule {
     /// namespace signals {
     ///
+    /// static_assert(sizeof(size_t) == sizeof(void*), "Module id must be able to hold a pointer");
+    ///
+    /// template <typename Signal>
     /// class SignalManager {
     /// public:
+    ///   // Typed with the signal enum to avoid casting the signal through `void*`
+    ///   using Delegate = std::function<void(const std::string& signalName, Signal* signal)>;
+    ///
     ///   static SignalManager& getInstance() {
     ///     static SignalManager instance;
     ///     return instance;
     ///   }
     ///
-    ///   void emit(uintptr_t id, rust::Str name) const {
+    ///   void emit(size_t id, rust::Str name, Signal* signal) const {
     ///     std::lock_guard<std::mutex> lock(mutex_);
     ///     auto it = delegates_.find(id);
     ///     if (it != delegates_.end()) {
-    ///       it->second(std::string(name));
+    ///       it->second(std::string(name), signal);
     ///     }
     ///   }
     ///
-    ///   void registerDelegate(uintptr_t id, Delegate delegate) const {
-    ///     std::lock_guard<std::mutex> lock(mutex_);
-    ///     delegates_.insert_or_assign(id, delegate);
-    ///   }
+    ///   // ...
+    /// };
     ///
-    ///   void unregisterDelegate(uintptr_t id) const {
-    ///     std::lock_guard<std::mutex> lock(mutex_);
-    ///     delegates_.erase(id);
-    ///   }
+    /// using MyModuleSignalManager = SignalManager<craby::mymodule::bridging::MyModuleSignal>;
     ///
-    /// private:
-    ///   SignalManager() = default;
-    ///   mutable std::unordered_map<uintptr_t, Delegate> delegates_;
-    ///   mutable std::mutex mutex_;
-    /// };
+    /// inline const MyModuleSignalManager& getMyModuleSignalManager() {
+    ///   return MyModuleSignalManager::getInstance();
+    /// }
     ///
     /// } // namespace signals
     /// } // namespace mymodule
     /// } // namespace craby
     /// ```
-    fn cxx_signals(&self, project_name: &str, schemas: &[Schema]) -> Result<String, anyhow::Error> {
-      let flat_name = flat_case(project_name);
-      
-      // Find schema with first signal
-      let signal_schema = schemas.iter().find(|s| !s.signals.is_empty());
-      let signal_enum = signal_schema.map(|s| format!("{}Signal", s.module_name));
-      let cxx_mod = signal_schema.map(|s| format!("Cxx{}", pascal_case(&s.module_name)));
-      
-      Ok(formatdoc! {
-          r#"
-          #pragma once
-
-          #include "rust/cxx.h"
-          #include <functional>
-          #include <memory>
-          #include <mutex>
-          #include <unordered_map>
-
-          {forward_declarations}
-
-          namespace craby {{
-          namespace {flat_name} {{
-          namespace signals {{
-
-          {signal_delegate_typedef}
-
-          class SignalManager {{
-          public:
-            static SignalManager& getInstance() {{
-              static SignalManager instance;
-              return instance;
-            }}
-
-            {emit_impl}
+    fn cxx_signals(&self, cxx_namespace: &str, schemas: &[Schema]) -> Result<String, anyhow::Error> {
+        let flat_name = cxx_namespace;
+        let signal_schemas = schemas
+            .iter()
+            .filter(|schema| !schema.signals.is_empty())
+            .collect::<Vec<_>>();
 
-            {register_delegate_impl}
+        let forward_decls = signal_schemas
+            .iter()
+            .map(|schema| format!("struct {}Signal;", schema.module_name))
+            .collect::<Vec<_>>()
+            .join("\n");
 
-            void unregisterDelegate(uintptr_t id) const {{
-              std::lock_guard<std::mutex> lock(mutex_);
-              delegates_.erase(id);
-            }}
+        let managers = signal_schemas
+            .iter()
+            .map(|schema| {
+                let module_name = &schema.module_name;
+                formatdoc! {
+                    r#"
+                    using {module_name}SignalManager = SignalManager<craby::{flat_name}::bridging::{module_name}Signal>;
 
-          private:
-            SignalManager() = default;
-            {delegates_map}
-            mutable std::mutex mutex_;
-          }};
-
-          inline const SignalManager& getSignalManager() {{
-            return SignalManager::getInstance();
-          }}
-
-          }} // namespace signals
-          }} // namespace {flat_name}
-          }} // namespace craby"#,
-          flat_name = flat_name,
-          forward_declarations = if let (Some(ref enum_name), Some(ref mod_name)) = (&signal_enum, &cxx_mod) {
-              formatdoc! {
-                  r#"
-                  namespace craby {{
-                  namespace {flat_name} {{
-                  namespace bridging {{
-                    struct {enum_name};
-                  }}
-                  namespace modules {{
-                    class {mod_name};
-                  }}
-                  }}
-                  }}"#,
-                  enum_name = enum_name,
-                  mod_name = mod_name,
-                  flat_name = flat_name
-              }
-          } else {
-              String::new()
-          },
-          signal_delegate_typedef = if signal_enum.is_some() {
-              formatdoc! {
-                  r#"
-                  using Delegate = std::function<void(const std::string& signalName, void* signal)>;"#
-              }
-          } else {
-              String::new()
-          },
-          emit_impl = if let Some(ref enum_name) = signal_enum {
-              formatdoc! {
-                  r#"
-                  void emit(uintptr_t id, rust::Str name, craby::{flat_name}::bridging::{enum_name}* signal) const {{
-                      std::lock_guard<std::mutex> lock(mutex_);
-                      auto it = delegates_.find(id);
-                      if (it != delegates_.end()) {{
-                        it->second(std::string(name), reinterpret_cast<void*>(signal));
-                      }}
+                    inline const {module_name}SignalManager& get{module_name}SignalManager() {{
+                      return {module_name}SignalManager::getInstance();
                     }}"#,
-                  enum_name = enum_name,
-                  flat_name = flat_name
-              }
-          } else {
-              String::new()
-          },
-          register_delegate_impl = if signal_enum.is_some() {
-              formatdoc! {
-                  r#"
-                  void registerDelegate(uintptr_t id, Delegate delegate) const {{
-                      std::lock_guard<std::mutex> lock(mutex_);
-                      delegates_.insert_or_assign(id, delegate);
-                    }}"#
-              }
-          } else {
-              String::new()
-          },
-          delegates_map = if signal_enum.is_some() {
-              formatdoc! {
-                  r#"
-                  mutable std::unordered_map<uintptr_t, Delegate> delegates_;"#
-              }
-          } else {
-              String::new()
-          },
-      })
-  }
-}
-
-impl Template for CxxTemplate {
-    type FileType = CxxFileType;
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
 
-    fn render(
-        &self,
-        ctx: &CodegenContext,
-        file_type: &Self::FileType,
-    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
-        let res = match file_type {
-            CxxFileType::Mod => ctx
-                .schemas
-                .iter()
-                .map(|schema| -> Result<Vec<TemplateResult>, anyhow::Error> {
-                    let (cpp, hpp) = self.cxx_mod(schema, &ctx.project_name)?;
-                    let cxx_mod = CxxModuleName::from(&schema.module_name);
-                    let cxx_base_path = cxx_dir(&ctx.root);
-                    let files = vec![
-                        TemplateResult {
-                            path: cxx_base_path.join(format!("{cxx_mod}.cpp")),
-                            content: cpp,
-                            overwrite: true,
-                        },
-                        TemplateResult {
-                            path: cxx_base_path.join(format!("{cxx_mod}.hpp")),
-                            content: hpp,
-                            overwrite: true,
-                        },
-                    ];
-                    Ok(files)
-                })
-                .collect::<Result<Vec<_>, _>>()
-                .map(|v| v.into_iter().flatten().collect())?,
-            CxxFileType::BridgingHpp => vec![TemplateResult {
-                path: cxx_dir(&ctx.root).join("bridging-generated.hpp"),
-                content: self.cxx_bridging(ctx)?,
-                overwrite: true,
-            }],
-            CxxFileType::UtilsHpp => vec![TemplateResult {
-                path: cxx_dir(&ctx.root).join("CrabyUtils.hpp"),
-                content: self.cxx_utils(&ctx.project_name)?,
-                overwrite: true,
-            }],
-            CxxFileType::SignalsH => {
-                let has_signals = ctx.schemas.iter().any(|schema| !schema.signals.is_empty());
+        Ok(formatdoc! {
+            r#"
+            #pragma once
 
-                if has_signals {
-                    vec![TemplateResult {
-                        path: cxx_bridge_include_dir(&ctx.root).join("CrabySignals.h"),
-                        content: self.cxx_signals(&ctx.project_name, &ctx.schemas)?,
-                        overwrite: true,
-                    }]
-                } else {
-                    Vec::default()
-                }
-            }
-        };
+            #include "rust/cxx.h"
+            #include <cstddef>
+            #include <functional>
+            #include <memory>
+            #include <mutex>
+            #include <unordered_map>
 
-        Ok(res)
-    }
-}
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace bridging {{
+            {forward_decls}
+            }} // namespace bridging
+            }} // namespace {flat_name}
+            }} // namespace craby
 
-impl Default for CxxGenerator {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace signals {{
 
-impl CxxGenerator {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
+            // Module ids are `usize` in Rust (bridged as `size_t`) and created from the module pointer
+            static_assert(sizeof(size_t) == sizeof(void*), "Module id must be able to hold a pointer");
 
-impl Generator<CxxTemplate> for CxxGenerator {
-    fn cleanup(ctx: &CodegenContext) -> Result<(), anyhow::Error> {
-        let cxx_dir = cxx_dir(&ctx.root);
+            template <typename Signal>
+            class SignalManager {{
+            public:
+              // Typed with the signal enum to avoid casting the signal through `void*`
+              using Delegate = std::function<void(const std::string& signalName, Signal* signal)>;
 
-        if cxx_dir.try_exists()? {
-            fs::read_dir(cxx_dir)?.try_for_each(|entry| -> Result<(), anyhow::Error> {
-                let path = entry?.path();
-                let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+              static SignalManager& getInstance() {{
+                static SignalManager instance;
+                return instance;
+              }}
+
+              void emit(size_t id, rust::Str name, Signal* signal) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                auto it = delegates_.find(id);
+                if (it != delegates_.end()) {{
+                  it->second(std::string(name), signal);
+                }}
+              }}
+
+              void registerDelegate(size_t id, Delegate delegate) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                delegates_.insert_or_assign(id, delegate);
+              }}
+
+              void unregisterDelegate(size_t id) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                delegates_.erase(id);
+              }}
+
+            private:
+              SignalManager() = default;
+              mutable std::unordered_map<size_t, Delegate> delegates_;
+              mutable std::mutex mutex_;
+            }};
+
+            {managers}
+
+            }} // namespace signals
+            }} // namespace {flat_name}
+            }} // namespace craby"#,
+        })
+    }
+}
+
+impl CxxTemplate {
+    /// Returns the shared registry of the module factories.
+    ///
+    /// # Generated Code
+    ///
+    /// ```cpp
+    /// namespace craby {
+    /// namespace myproject {
+    /// namespace registry {
+    ///
+    /// inline const std::vector<ModuleEntry> &modules() {
+    ///   static const std::vector<ModuleEntry> entries = {
+    ///     {
+    ///       craby::myproject::modules::CxxMyTestModule::kModuleName,
+    ///       [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath) {
+    ///         return std::make_shared<craby::myproject::modules::CxxMyTestModule>(jsInvoker, dataPath);
+    ///       },
+    ///     },
+    ///   };
+    ///   return entries;
+    /// }
+    ///
+    /// // findModule, createModule, registerModules
+    ///
+    /// } // namespace registry
+    /// } // namespace myproject
+    /// } // namespace craby
+    /// ```
+    fn cxx_module_registry(&self, ctx: &CodegenContext) -> String {
+        let cxx_ns = CxxNamespace::from(&ctx.cxx_namespace);
+        let mut cxx_includes = vec![];
+        let mut entries = vec![];
+
+        for schema in &ctx.schemas {
+            let cxx_mod = CxxModuleName::from(&schema.module_name);
+            let cxx_mod_namespace = format!("{cxx_ns}::modules::{cxx_mod}");
+
+            cxx_includes.push(format!("#include \"{cxx_mod}.hpp\""));
+            let entry = formatdoc! {
+                r#"
+                {{
+                  {cxx_mod_namespace}::kModuleName,
+                  [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath) {{
+                    return std::make_shared<{cxx_mod_namespace}>(jsInvoker, dataPath);
+                  }},
+                }},"#,
+            };
+            entries.push(if ctx.module_features {
+                cxx_feature_guard(&entry, &schema.module_name)
+            } else {
+                entry
+            });
+        }
+
+        let cxx_includes = cxx_includes.join("\n");
+        let entries = indent_str(&entries.join("\n"), 4);
+        let project_ns = &ctx.cxx_namespace;
+
+        formatdoc! {
+            r#"
+            #pragma once
+
+            {cxx_includes}
+            #include <ReactCommon/CallInvoker.h>
+            #include <ReactCommon/CxxTurboModuleUtils.h>
+            #include <ReactCommon/TurboModule.h>
+            #include <functional>
+            #include <memory>
+            #include <string>
+            #include <utility>
+            #include <vector>
+
+            namespace craby {{
+            namespace {project_ns} {{
+            namespace registry {{
+
+            using ModuleFactory = std::function<std::shared_ptr<facebook::react::TurboModule>(
+                std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath)>;
+
+            struct ModuleEntry {{
+              const char *name;
+              ModuleFactory factory;
+            }};
+
+            /// Returns every module of the project.
+            inline const std::vector<ModuleEntry> &modules() {{
+              static const std::vector<ModuleEntry> entries = {{
+            {entries}
+              }};
+              return entries;
+            }}
+
+            /// Returns the factory of the module, or `nullptr` if the module is not found.
+            inline const ModuleFactory *findModule(const std::string &name) {{
+              for (const auto &entry : modules()) {{
+                if (name == entry.name) {{
+                  return &entry.factory;
+                }}
+              }}
+              return nullptr;
+            }}
+
+            /// Creates an instance of the module, or returns `nullptr` if the module is not found.
+            inline std::shared_ptr<facebook::react::TurboModule>
+            createModule(const std::string &name,
+                         std::shared_ptr<facebook::react::CallInvoker> jsInvoker,
+                         std::string dataPath) {{
+              auto factory = findModule(name);
+              return factory ? (*factory)(std::move(jsInvoker), std::move(dataPath)) : nullptr;
+            }}
+
+            /// Registers the modules to the global module map.
+            ///
+            /// `getDataPath` is called when a module is created.
+            /// Modules are skipped if `filter` returns `false` for the module name.
+            inline void registerModules(std::function<std::string()> getDataPath,
+                                        std::function<bool(const std::string &)> filter = nullptr) {{
+              for (const auto &entry : modules()) {{
+                if (filter && !filter(entry.name)) {{
+                  continue;
+                }}
+
+                auto factory = entry.factory;
+                facebook::react::registerCxxModuleToGlobalModuleMap(
+                    entry.name,
+                    [factory, getDataPath](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {{
+                      return factory(jsInvoker, getDataPath());
+                    }});
+              }}
+            }}
+
+            }} // namespace registry
+            }} // namespace {project_ns}
+            }} // namespace craby"#,
+        }
+    }
+}
+
+impl Template for CxxTemplate {
+    type FileType = CxxFileType;
+
+    fn render(
+        &self,
+        ctx: &CodegenContext,
+        file_type: &Self::FileType,
+    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let res = match file_type {
+            CxxFileType::Mod => ctx
+                .schemas
+                .iter()
+                .map(|schema| -> Result<Vec<TemplateResult>, anyhow::Error> {
+                    let (cpp, hpp) = self.cxx_mod(ctx, schema)?;
+                    let cxx_mod = CxxModuleName::from(&schema.module_name);
+                    let cxx_base_path = cxx_dir(&ctx.root);
+                    let files = vec![
+                        TemplateResult {
+                            path: cxx_base_path.join(format!("{cxx_mod}.cpp")),
+                            content: cpp,
+                            overwrite: true,
+                            merged: false,
+                        },
+                        TemplateResult {
+                            path: cxx_base_path.join(format!("{cxx_mod}.hpp")),
+                            content: hpp,
+                            overwrite: true,
+                            merged: false,
+                        },
+                    ];
+                    Ok(files)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|v| v.into_iter().flatten().collect())?,
+            CxxFileType::BridgingHpp => vec![TemplateResult {
+                path: cxx_dir(&ctx.root).join("bridging-generated.hpp"),
+                content: self.cxx_bridging(ctx)?,
+                overwrite: true,
+                merged: false,
+            }],
+            CxxFileType::UtilsHpp => vec![TemplateResult {
+                path: cxx_dir(&ctx.root).join("CrabyUtils.hpp"),
+                content: self.cxx_utils(
+                    &ctx.cxx_namespace,
+                    ctx.thread_pool_size,
+                    ctx.unimplemented_error,
+                    ctx.schemas.iter().any(|schema| {
+                        schema
+                            .methods
+                            .iter()
+                            .any(|method| method.error_type.is_some())
+                    }),
+                )?,
+                overwrite: true,
+                merged: false,
+            }],
+            CxxFileType::SignalsH => {
+                let has_signals = ctx.schemas.iter().any(|schema| !schema.signals.is_empty());
+
+                if has_signals {
+                    vec![TemplateResult {
+                        path: cxx_bridge_include_dir(&ctx.root).join("CrabySignals.h"),
+                        content: self.cxx_signals(&ctx.cxx_namespace, &ctx.schemas)?,
+                        overwrite: true,
+                        merged: false,
+                    }]
+                } else {
+                    Vec::default()
+                }
+            }
+            CxxFileType::ModuleRegistryHpp => {
+                if ctx.module_registry {
+                    vec![TemplateResult {
+                        path: cxx_dir(&ctx.root).join(MODULE_REGISTRY_HEADER),
+                        content: self.cxx_module_registry(ctx),
+                        overwrite: true,
+                        merged: false,
+                    }]
+                } else {
+                    Vec::default()
+                }
+            }
+        };
+
+        Ok(res)
+    }
+}
+
+impl Default for CxxGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CxxGenerator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Generator<CxxTemplate> for CxxGenerator {
+    fn cleanup(ctx: &CodegenContext) -> Result<(), anyhow::Error> {
+        let cxx_dir = cxx_dir(&ctx.root);
+
+        if cxx_dir.try_exists()? {
+            fs::read_dir(cxx_dir)?.try_for_each(|entry| -> Result<(), anyhow::Error> {
+                let path = entry?.path();
+                let file_name = path.file_name().unwrap().to_string_lossy().to_string();
 
                 if file_name.starts_with("Cxx")
                     && (file_name.ends_with("Module.cpp") || file_name.ends_with("Module.hpp"))
@@ -1210,6 +1697,7 @@ impl Generator<CxxTemplate> for CxxGenerator {
             template.render(ctx, &CxxFileType::BridgingHpp)?,
             template.render(ctx, &CxxFileType::UtilsHpp)?,
             template.render(ctx, &CxxFileType::SignalsH)?,
+            template.render(ctx, &CxxFileType::ModuleRegistryHpp)?,
         ]
         .into_iter()
         .flatten()
@@ -1231,9 +1719,18 @@ impl GeneratorInvoker for CxxGenerator {
 
 #[cfg(test)]
 mod tests {
+    use craby_common::config::CxxBaseClassConfig;
     use insta::assert_snapshot;
 
-    use crate::tests::get_codegen_context;
+    use crate::{
+        constants::DEFAULT_THREAD_POOL_SIZE,
+        generators::{
+            android_generator::AndroidGenerator, ios_generator::IosGenerator,
+            rs_generator::RsGenerator,
+        },
+        parser::native_spec_parser::try_parse_schema,
+        tests::get_codegen_context,
+    };
 
     use super::*;
 
@@ -1250,4 +1747,1076 @@ mod tests {
 
         assert_snapshot!(result);
     }
+
+    #[test]
+    fn test_cxx_void_promise() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                voidPromiseMethod(arg: number): Promise<void>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = &methods[0].impl_func;
+
+        assert!(result.contains("react::AsyncPromise<std::monostate> promise(rt, callInvoker);"));
+        assert!(result.contains("promise.resolve(std::monostate{});"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_enum_array() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum MyEnum {
+                Foo = 'foo',
+                Bar = 'bar',
+            }
+
+            export interface Spec extends NativeModule {
+                getEnums(): MyEnum[];
+                getEnumsAsync(): Promise<MyEnum[]>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = methods
+            .iter()
+            .map(|method| method.impl_func.clone())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert!(result.contains(
+            "react::AsyncPromise<rust::Vec<craby::testmodule::bridging::MyEnum>> promise(rt, callInvoker);"
+        ));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_negative_enum() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum Status {
+                Unknown = -1,
+                Ready = 0,
+            }
+
+            export interface Spec extends NativeModule {
+                getStatus(): Status;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+
+        assert!(result.contains("if (raw == -1) {"));
+        assert!(result.contains("return react::bridging::toJs(rt, -1);"));
+    }
+
+    #[test]
+    fn test_cxx_enum_fallback() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum Theme {
+                Light = 'light',
+                Dark = 'dark',
+                /** @fallback */
+                Unknown = 'unknown',
+            }
+
+            export interface Spec extends NativeModule {
+                setTheme(theme: Theme): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+
+        assert!(result.contains("else {\n      return craby::testmodule::bridging::Theme::Unknown;"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_recursive_type() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface ListNode {
+                value: number;
+                next: ListNode | null;
+            }
+
+            export interface Spec extends NativeModule {
+                getList(): ListNode;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+
+        assert!(result.contains("val.push_back(react::bridging::fromJs<craby::testmodule::bridging::ListNode>(rt, value, callInvoker));"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_map() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                x: number;
+                y: number;
+            }
+
+            export interface Palette {
+                colors: Record<string, string>;
+            }
+
+            export interface Spec extends NativeModule {
+                getScores(): Record<string, number>;
+                setPoints(points: Record<string, Point>): void;
+                getPalette(): Promise<Palette>;
+                getScoresAsync(): Promise<Record<string, number>>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+        let struct_pos = |name: &str| result.find(&format!("struct Bridging<{name}>")).unwrap();
+
+        // The key/value pair templates are declared before the structs using them
+        assert!(
+            struct_pos("rust::Vec<craby::testmodule::bridging::StringMapEntry>")
+                < struct_pos("craby::testmodule::bridging::Palette")
+        );
+        assert!(
+            struct_pos("craby::testmodule::bridging::Point")
+                < struct_pos("rust::Vec<craby::testmodule::bridging::PointMapEntry>")
+        );
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_nested_array() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                x: number;
+                y: number;
+            }
+
+            export interface Path {
+                segments: Point[][];
+            }
+
+            export interface Spec extends NativeModule {
+                getGrid(): number[][];
+                setCubes(cubes: number[][][]): void;
+                getPath(): Promise<Path>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+        let struct_pos = |name: &str| result.find(&format!("struct Bridging<{name}>")).unwrap();
+
+        // The wrapper templates are declared before the structs using them
+        assert!(
+            struct_pos("craby::testmodule::bridging::Point")
+                < struct_pos("craby::testmodule::bridging::NestedPointArray")
+        );
+        assert!(
+            struct_pos("craby::testmodule::bridging::NestedPointArray")
+                < struct_pos("craby::testmodule::bridging::Path")
+        );
+        assert!(
+            struct_pos("craby::testmodule::bridging::NestedNumberArray")
+                < struct_pos("craby::testmodule::bridging::NestedNumberArrayArray")
+        );
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_tuple() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                x: number;
+                y: number;
+            }
+
+            export interface Segment {
+                ends: [Point, Point];
+            }
+
+            export interface Spec extends NativeModule {
+                getSize(): [number, string];
+                getSegment(): Segment;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+        let struct_pos = |name: &str| result.find(&format!("struct Bridging<{name}>")).unwrap();
+
+        // The tuple templates are declared before the structs using them
+        assert!(
+            struct_pos("craby::testmodule::bridging::Point")
+                < struct_pos("craby::testmodule::bridging::PointPointTuple2")
+        );
+        assert!(
+            struct_pos("craby::testmodule::bridging::PointPointTuple2")
+                < struct_pos("craby::testmodule::bridging::Segment")
+        );
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_int_types() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Int32, Int64 } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                getTimestamp(handle: Int32): Int64;
+                getTimestampAsync(handle: Int32 | null): Promise<Int64>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let templates = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+
+        assert!(methods[0]
+            .impl_func
+            .contains("craby::testmodule::utils::fromJsInt<int32_t>(rt, args[0])"));
+        assert!(methods[0]
+            .impl_func
+            .contains("return jsi::Value(static_cast<double>(ret));"));
+        assert!(methods[1]
+            .impl_func
+            .contains("react::AsyncPromise<double> promise(rt, callInvoker);"));
+        assert!(templates.contains("auto val = craby::testmodule::utils::fromJsInt<int32_t>(rt, value);"));
+        assert_snapshot!([methods[1].impl_func.clone(), templates].join("\n\n"));
+    }
+
+    #[test]
+    fn test_cxx_bigint_type() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                getRowId(key: bigint): bigint;
+                getRowIdAsync(key: bigint | null): Promise<bigint>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let templates = schemas[0]
+            .as_cxx_bridging_templates("testmodule")
+            .unwrap()
+            .join("\n\n");
+
+        assert!(methods[0]
+            .impl_func
+            .contains("craby::testmodule::utils::fromJsBigInt(rt, args[0])"));
+        assert!(methods[0]
+            .impl_func
+            .contains("return jsi::Value(jsi::BigInt::fromInt64(rt, ret));"));
+        assert!(methods[1]
+            .impl_func
+            .contains("react::AsyncPromise<craby::testmodule::utils::BigIntValue> promise(rt, callInvoker);"));
+        assert!(methods[1]
+            .impl_func
+            .contains("promise.resolve(craby::testmodule::utils::BigIntValue{ret});"));
+        assert!(templates.contains("auto val = craby::testmodule::utils::fromJsBigInt(rt, value);"));
+        assert_snapshot!([methods[1].impl_func.clone(), templates].join("\n\n"));
+    }
+
+    #[test]
+    fn test_cxx_cancellable_promise() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                /** @cancellable */
+                longTask(n: number): Promise<number>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = &methods[0].impl_func;
+
+        assert!(result.contains("[it_, promise, arg0, cancelToken]"));
+        assert!(result.contains("longTask(*it_, arg0, **cancelToken)"));
+        assert!(result.contains("(*cancelToken)->cancel();"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_throws_promise() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            type FetchError = {
+                code: number;
+                reason: string;
+            };
+
+            export interface Spec extends NativeModule {
+                /** @throws FetchError */
+                fetch(url: string): Promise<string>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = &methods[0].impl_func;
+
+        assert!(result.contains("fetch(*it_, arg0, error_);"));
+        assert!(result.contains("react::bridging::toJs(rt, error_.val)"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_detached_promise() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                /** @detached */
+                track(count: number): Promise<void>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = &methods[0].impl_func;
+
+        assert!(!result.contains("AsyncPromise"));
+        assert!(result.contains("[it_, callInvoker, arg0]"));
+        assert!(result.contains("return jsi::Value::undefined();"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_optional_params() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                log(message: string, level?: number): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = &methods[0].impl_func;
+
+        assert!(result.contains("if (count < 1 || count > 2) {"));
+        assert!(result.contains("(1 < count ? args[1] : undefinedArg)"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_default_params() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                multiply(a: number, b: number = 2, label: string = 'say \"hi\"'): number;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let methods = CxxTemplate.cxx_methods("testmodule", &schemas[0], false).unwrap();
+        let result = &methods[0].impl_func;
+
+        assert!(result.contains("if (count < 1 || count > 3) {"));
+        assert!(result.contains("const jsi::Value arg1$default = jsi::Value(2.0);"));
+        assert!(
+            result.contains("(1 < count && !args[1].isUndefined() ? args[1] : arg1$default)")
+        );
+        assert!(result.contains(r#"jsi::String::createFromUtf8(rt, "say \"hi\"")"#));
+        assert!(!result.contains("undefinedArg"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_listener_error_hook() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface ProgressEvent {
+                progress: number;
+            }
+
+            export interface Spec extends NativeModule {
+                onProgress: Signal<ProgressEvent>;
+                onDone: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let mut ctx = get_codegen_context();
+        ctx.listener_error_hook = true;
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &schemas[0]).unwrap();
+        let start = cpp.find("void CxxCrabyTestModule::emit").unwrap();
+        let end = start + cpp[start..].find("\n}\n").unwrap() + 2;
+        let result = &cpp[start..end];
+
+        assert!(!result.contains("throw err;"));
+        assert!(result.contains("[listener, signalPtr, name, id]"));
+        assert!(result.contains("[listener, payloadPtr, name, id]"));
+        assert!(result.contains(
+            "craby::testmodule::bridging::onCrabyTestListenerError(id, name, err.getMessage());"
+        ));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_signal_payload_dispatch() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface DataEvent {
+                data: string;
+            }
+
+            export interface Spec extends NativeModule {
+                onData: Signal<DataEvent>;
+                onClose: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let ctx = get_codegen_context();
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &schemas[0]).unwrap();
+        let start = cpp.find("void CxxCrabyTestModule::emit").unwrap();
+        let end = start + cpp[start..].find("\n}\n").unwrap() + 2;
+        let result = &cpp[start..end];
+
+        // Payload getters are dispatched by the signal names declared in the spec
+        assert!(result.contains("if (name == \"onData\") {"));
+        assert!(result.contains(
+            "auto payload = signalPtr->get_on_data_payload();"
+        ));
+        assert!(!result.contains("onClose"));
+        assert!(!result.contains("onProgress"));
+        assert!(!result.contains("onError"));
+    }
+
+    #[test]
+    fn test_cxx_signal_without_payload() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                onSignal: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let ctx = get_codegen_context();
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &schemas[0]).unwrap();
+        let start = cpp.find("void CxxCrabyTestModule::emit").unwrap();
+        let end = start + cpp[start..].find("\n}\n").unwrap() + 2;
+        let result = &cpp[start..end];
+
+        // `payloadPtr` is declared before the listeners capture it
+        let decl = result.find("auto payloadPtr = ").unwrap();
+        let capture = result.find("[listener, payloadPtr](jsi::Runtime &rt)").unwrap();
+        assert!(decl < capture);
+        assert!(!result.contains("get_on_signal_payload"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cxx_mod_base_class() {
+        let mut ctx = get_codegen_context();
+        let (default_cpp, default_hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        ctx.cxx_base_class = Some(CxxBaseClassConfig {
+            name: "myapp::BaseTurboModule".to_string(),
+            header: Some("BaseTurboModule.h".to_string()),
+        });
+        let (cpp, hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        assert!(default_hpp.contains("public facebook::react::TurboModule {"));
+        assert!(
+            default_cpp.contains(": TurboModule(CxxCrabyTestModule::kModuleName, jsInvoker),")
+        );
+        assert!(
+            hpp.contains("class JSI_EXPORT CxxCrabyTestModule : public myapp::BaseTurboModule {")
+        );
+        assert!(hpp.contains(
+            "#include <ReactCommon/TurboModule.h>\n#include \"BaseTurboModule.h\"\n"
+        ));
+        assert!(cpp.contains(": BaseTurboModule(CxxCrabyTestModule::kModuleName, jsInvoker),"));
+    }
+
+    #[test]
+    fn test_cxx_mod_jsdoc() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                /** Opens the URL in the browser. */
+                openUrl(url: string): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let (_, hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        assert!(hpp.contains(
+            "  /// Opens the URL in the browser.\n  static facebook::jsi::Value\n  openUrl("
+        ));
+    }
+
+    #[test]
+    fn test_cxx_mod_platform() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                /** @platform ios */
+                openAppStore(): void;
+                /** @platform android */
+                openPlayStore(): void;
+                openUrl(url: string): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        // Implementations are kept on every platform, only the registrations are guarded
+        assert!(cpp.contains("jsi::Value CxxCrabyTestModule::openAppStore("));
+        assert!(cpp.contains("jsi::Value CxxCrabyTestModule::openPlayStore("));
+        let method_maps = cpp
+            .lines()
+            .filter(|line| {
+                let line = line.trim_start();
+                line.starts_with("methodMap_") || line.starts_with("#if") || line.starts_with("#endif")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_snapshot!(method_maps);
+    }
+
+    #[test]
+    fn test_cxx_method_map_order() {
+        let method_maps = |src: &str| {
+            let mut ctx = get_codegen_context();
+            ctx.schemas = try_parse_schema(src).unwrap();
+            let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+            cpp.lines()
+                .map(str::trim_start)
+                .filter(|line| line.starts_with("methodMap_"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        let maps = method_maps(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                onUpdate: Signal;
+                subtract(a: number, b: number): number;
+                onChange: Signal;
+                Add(a: number, b: number): number;
+                add(a: number, b: number): number;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        );
+        let reordered_maps = method_maps(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                add(a: number, b: number): number;
+                onChange: Signal;
+                Add(a: number, b: number): number;
+                subtract(a: number, b: number): number;
+                onUpdate: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        );
+
+        // Methods first, then signals, each sorted by name regardless of the declaration order
+        assert_eq!(maps, reordered_maps);
+        assert_eq!(
+            maps.iter()
+                .map(|line| line.split('"').nth(1).unwrap())
+                .collect::<Vec<_>>(),
+            vec!["Add", "add", "subtract", "onChange", "onUpdate"]
+        );
+    }
+
+    #[test]
+    fn test_cxx_module_registry() {
+        let mut ctx = get_codegen_context();
+        let default_results = CxxTemplate
+            .render(&ctx, &CxxFileType::ModuleRegistryHpp)
+            .unwrap();
+
+        ctx.module_registry = true;
+        let results = CxxTemplate
+            .render(&ctx, &CxxFileType::ModuleRegistryHpp)
+            .unwrap();
+
+        assert!(default_results.is_empty());
+        assert_snapshot!(results[0].content);
+    }
+
+    #[test]
+    fn test_cxx_mod_data_path() {
+        let ctx = get_codegen_context();
+        let (cpp, hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        // Stored per instance, not shared across instances of the module class
+        assert!(!hpp.contains("static std::string dataPath"));
+        assert!(hpp.contains("std::string dataPath_;"));
+        assert!(hpp.contains("std::string dataPath);"));
+        assert!(cpp.contains("dataPath_(std::move(dataPath))"));
+        assert!(cpp.contains("rust::Str(dataPath_.data(), dataPath_.size())"));
+    }
+
+    #[test]
+    fn test_cxx_module_id_type() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                onReady: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+        let signals_h = CxxTemplate.cxx_signals(&ctx.cxx_namespace, &ctx.schemas).unwrap();
+
+        // `usize` is bridged as `size_t`, which is not the same type as `uintptr_t` on every ABI
+        assert!(!cpp.contains("uintptr_t"));
+        assert!(!signals_h.contains("uintptr_t"));
+        assert!(cpp.contains("size_t id = reinterpret_cast<size_t>(this);"));
+        assert!(signals_h.contains("void emit(size_t id, rust::Str name"));
+        assert!(signals_h.contains("void registerDelegate(size_t id, Delegate delegate) const {"));
+        assert!(signals_h.contains("void unregisterDelegate(size_t id) const {"));
+        assert!(signals_h.contains("static_assert(sizeof(size_t) == sizeof(void*)"));
+
+        // Signals are passed to the delegates with the signal enum type (no `void*` casts)
+        assert!(signals_h.contains(
+            "using Delegate = std::function<void(const std::string& signalName, Signal* signal)>;"
+        ));
+        assert!(signals_h.contains(
+            "using CrabyTestSignalManager = SignalManager<craby::testmodule::bridging::CrabyTestSignal>;"
+        ));
+        assert!(!signals_h.contains("reinterpret_cast<void*>"));
+        assert!(cpp.contains("[this](const std::string& name, bridging::CrabyTestSignal* signal) {"));
+        assert!(!cpp.contains("reinterpret_cast<bridging::CrabyTestSignal*>"));
+    }
+
+    #[test]
+    fn test_cxx_instance_counter() {
+        let mut ctx = get_codegen_context();
+        let (cpp, hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        assert!(!cpp.contains("instanceCount"));
+        assert!(!hpp.contains("instanceCount"));
+
+        ctx.instance_counter = true;
+        let (cpp, hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+
+        assert!(hpp.contains("static inline std::atomic<size_t> instanceCount_{0};"));
+        assert!(hpp.contains("static size_t instanceCount();"));
+        assert!(cpp.contains("instanceCount_.fetch_add(1);"));
+        assert!(cpp.contains("invalidate();\n  instanceCount_.fetch_sub(1);"));
+        assert!(cpp.contains(
+            "methodMap_[\"__instanceCount\"] = MethodMetadata{0, &CxxCrabyTestModule::getInstanceCount};"
+        ));
+    }
+
+    #[test]
+    fn test_cxx_module_features() {
+        let mut ctx = get_codegen_context();
+        ctx.module_features = true;
+        let (cpp, hpp) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+        let bridging = CxxTemplate.cxx_bridging(&ctx).unwrap();
+        let registry = CxxTemplate.cxx_module_registry(&ctx);
+
+        assert!(hpp.contains("#include \"CrabyFeatures.h\"\n#include \"CrabyUtils.hpp\""));
+        assert!(hpp.contains("#if defined(CRABY_FEATURE_CRABY_TEST)\n\nnamespace craby {"));
+        assert!(hpp.trim_end().ends_with("} // namespace craby\n\n#endif"));
+        assert!(cpp.contains("#if defined(CRABY_FEATURE_CRABY_TEST)\n\nusing namespace facebook;"));
+        assert!(cpp.trim_end().ends_with("} // namespace craby\n\n#endif"));
+        assert!(bridging.contains("#include \"CrabyFeatures.h\""));
+        assert!(bridging.contains("#if defined(CRABY_FEATURE_CRABY_TEST)\ntemplate <>"));
+        assert!(registry.contains("#if defined(CRABY_FEATURE_CRABY_TEST)"));
+    }
+
+    #[test]
+    fn test_cxx_unimplemented_error() {
+        let mut ctx = get_codegen_context();
+        ctx.unimplemented_error = true;
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                multiply(a: number, b: number): number;
+                multiplyAsync(a: number, b: number): Promise<number>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+        let utils_hpp = CxxTemplate
+            .cxx_utils(&ctx.cxx_namespace, ctx.thread_pool_size, true, false)
+            .unwrap();
+
+        assert!(cpp.contains(
+            "throw jsi::JSError(rt, craby::testmodule::utils::errorMessage(err, \"multiply\"));"
+        ));
+        assert!(cpp.contains(
+            "promise.reject(craby::testmodule::utils::errorMessage(err, \"multiplyAsync\"));"
+        ));
+        assert!(utils_hpp.contains(
+            "inline std::string errorMessage(const std::exception &err, const std::string &methodName) {"
+        ));
+        assert!(!CxxTemplate
+            .cxx_utils(&ctx.cxx_namespace, ctx.thread_pool_size, false, false)
+            .unwrap()
+            .contains("methodName"));
+    }
+
+    #[test]
+    fn test_cxx_thread_pool_size() {
+        let mut ctx = get_codegen_context();
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+        let utils_hpp = CxxTemplate
+            .cxx_utils(&ctx.cxx_namespace, ctx.thread_pool_size, false, false)
+            .unwrap();
+
+        assert!(cpp.contains(
+            "threadPool_ = std::make_shared<craby::testmodule::utils::ThreadPool>(10);"
+        ));
+        assert!(utils_hpp.contains("ThreadPool(size_t num_threads = 10) : stop(false) {"));
+
+        ctx.thread_pool_size = 4;
+        let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+        let utils_hpp = CxxTemplate
+            .cxx_utils(&ctx.cxx_namespace, ctx.thread_pool_size, false, false)
+            .unwrap();
+
+        assert!(cpp.contains(
+            "threadPool_ = std::make_shared<craby::testmodule::utils::ThreadPool>(4);"
+        ));
+        assert!(utils_hpp.contains("ThreadPool(size_t num_threads = 4) : stop(false) {"));
+
+        // `0` and `1` produce a single-threaded pool
+        for size in [0, 1] {
+            ctx.thread_pool_size = size;
+            let (cpp, _) = CxxTemplate.cxx_mod(&ctx, &ctx.schemas[0]).unwrap();
+            let utils_hpp = CxxTemplate
+                .cxx_utils(&ctx.cxx_namespace, ctx.thread_pool_size, false, false)
+                .unwrap();
+
+            assert!(cpp.contains(
+                "threadPool_ = std::make_shared<craby::testmodule::utils::ThreadPool>(1);"
+            ));
+            assert!(utils_hpp.contains("ThreadPool(size_t num_threads = 1) : stop(false) {"));
+        }
+    }
+
+    #[test]
+    fn test_cxx_namespace() {
+        let mut ctx = get_codegen_context();
+        ctx.cxx_namespace = "custom".to_string();
+        let results = [
+            CxxGenerator::new().generate(&ctx).unwrap(),
+            RsGenerator::new().generate(&ctx).unwrap(),
+            AndroidGenerator::new().generate(&ctx).unwrap(),
+            IosGenerator::new().generate(&ctx).unwrap(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        for res in &results {
+            assert!(
+                !res.content.contains("craby::testmodule")
+                    && !res.content.contains("namespace testmodule"),
+                "{}",
+                res.path.display()
+            );
+        }
+
+        let content = |name: &str| {
+            results
+                .iter()
+                .find(|res| res.path.ends_with(name))
+                .map(|res| res.content.as_str())
+                .unwrap()
+        };
+
+        assert!(content("CxxCrabyTestModule.cpp").contains("namespace custom {"));
+        assert!(content("bridging-generated.hpp").contains("craby::custom::utils::IntVecBridging"));
+        assert!(content("CrabyUtils.hpp").contains("} // namespace custom"));
+        assert!(content("ffi.rs").contains("#[cxx::bridge(namespace = \"craby::custom::bridging\")]"));
+        assert!(content("OnLoad.cpp").contains("craby::custom::modules::CxxCrabyTestModule"));
+        assert!(content("TestModuleModuleProvider.mm").contains("craby::custom::modules::CxxCrabyTestModule"));
+    }
+
+    #[test]
+    fn test_cxx_bridging_string() {
+        let ctx = get_codegen_context();
+        let results = CxxTemplate.render(&ctx, &CxxFileType::BridgingHpp).unwrap();
+        let content = &results[0].content;
+        let start = content.find("struct Bridging<rust::String>").unwrap();
+        let end = start + content[start..].find("};").unwrap() + 2;
+
+        assert_snapshot!(&content[start..end]);
+    }
+
+    /// Enqueues tasks from multiple threads while the pool is being shut down.
+    ///
+    /// Requires a C++ compiler (`c++`), skipped if unavailable.
+    #[test]
+    fn test_thread_pool_shutdown_stress() {
+        if std::process::Command::new("c++")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+
+        let dir = std::env::temp_dir().join(format!("craby-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let utils_hpp = CxxTemplate
+            .cxx_utils("testmodule", DEFAULT_THREAD_POOL_SIZE, false, false)
+            .unwrap();
+        let cxx_h = formatdoc! {
+            r#"
+            #pragma once
+            #include <exception>
+
+            namespace rust {{
+            class Error final : public std::exception {{
+            public:
+              const char *what() const noexcept override {{ return "rust::Error"; }}
+            }};
+            }} // namespace rust"#,
+        };
+        let main_cpp = formatdoc! {
+            r#"
+            #include "CrabyUtils.hpp"
+            #include <atomic>
+            #include <chrono>
+            #include <memory>
+
+            using craby::testmodule::utils::ThreadPool;
+
+            int main() {{
+              for (int round = 0; round < 50; ++round) {{
+                auto pool = std::make_shared<ThreadPool>(4);
+                auto counter = std::make_shared<std::atomic<int>>(0);
+                std::vector<std::thread> producers;
+
+                for (int i = 0; i < 4; ++i) {{
+                  producers.emplace_back([pool, counter] {{
+                    for (int j = 0; j < 1000; ++j) {{
+                      pool->enqueue([counter] {{ counter->fetch_add(1); }});
+                    }}
+                  }});
+                }}
+
+                std::thread concurrent([pool] {{ pool->shutdown(); }});
+                pool->shutdown();
+                concurrent.join();
+
+                for (auto &producer : producers) {{
+                  producer.join();
+                }}
+
+                // No task may run after shutdown is returned
+                auto executed = counter->load();
+                std::this_thread::sleep_for(std::chrono::milliseconds(1));
+                if (counter->load() != executed) {{
+                  return 1;
+                }}
+              }}
+
+              // Tasks queued before the destruction are not dropped
+              auto counter = std::make_shared<std::atomic<int>>(0);
+              {{
+                ThreadPool pool(1);
+                pool.enqueue([] {{ std::this_thread::sleep_for(std::chrono::milliseconds(10)); }});
+                for (int i = 0; i < 100; ++i) {{
+                  pool.enqueue([counter] {{ counter->fetch_add(1); }});
+                }}
+              }}
+              if (counter->load() != 100) {{
+                return 2;
+              }}
+              return 0;
+            }}"#,
+        };
+
+        fs::write(dir.join("CrabyUtils.hpp"), utils_hpp).unwrap();
+        fs::write(dir.join("cxx.h"), cxx_h).unwrap();
+        fs::write(dir.join("ffi.rs.h"), "#pragma once\n").unwrap();
+        fs::write(dir.join("main.cpp"), main_cpp).unwrap();
+
+        let bin = dir.join("main");
+        let compile = std::process::Command::new("c++")
+            .args(["-std=c++20", "-pthread", "-o"])
+            .arg(&bin)
+            .arg(dir.join("main.cpp"))
+            .output()
+            .unwrap();
+        assert!(
+            compile.status.success(),
+            "{}",
+            String::from_utf8_lossy(&compile.stderr)
+        );
+
+        let status = std::process::Command::new(&bin).status().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(status.success());
+    }
 }
@@ -0,0 +1,207 @@
+use indoc::formatdoc;
+use log::warn;
+
+use crate::{
+    generators::types::TemplateResult,
+    parser::types::Platform,
+    types::{CodegenContext, Schema},
+    utils::{is_generated_file, ts_import_path},
+};
+
+use super::types::{Generator, GeneratorInvoker, Template};
+
+pub struct DocsTemplate;
+pub struct DocsGenerator;
+
+pub enum DocsFileType {
+    /// docs/{ModuleName}.md
+    ModuleDoc,
+}
+
+impl DocsTemplate {
+    /// Generates the usage snippet of the module with the method signatures reconstructed from the schema.
+    ///
+    /// # Generated Code
+    ///
+    /// ````md
+    /// # Calculator
+    ///
+    /// ## Usage
+    ///
+    /// ```ts
+    /// import Calculator from '../src/NativeCalculator';
+    ///
+    /// Calculator.multiply(a, b);
+    /// ```
+    ///
+    /// ## Methods
+    ///
+    /// ```ts
+    /// multiply(a: number, b: number): number;
+    /// openSettings(): void; // iOS only
+    /// ```
+    /// ````
+    fn module_doc(&self, schema: &Schema, spec_import: &str) -> String {
+        let module_name = &schema.module_name;
+        let methods = schema
+            .methods
+            .iter()
+            .map(|method| {
+                let sig = method.as_ts_sig();
+                match method.platform {
+                    Some(Platform::Ios) => format!("{sig} // iOS only"),
+                    Some(Platform::Android) => format!("{sig} // Android only"),
+                    None => sig,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let example = match schema.methods.first() {
+            Some(method) => {
+                let args = method
+                    .params
+                    .iter()
+                    .map(|param| param.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("\n\n{module_name}.{}({args});", method.name)
+            }
+            None => String::new(),
+        };
+
+        let methods = if methods.is_empty() {
+            "This module has no methods.".to_string()
+        } else {
+            format!("```ts\n{}\n```", methods.join("\n"))
+        };
+
+        formatdoc! {
+            r#"
+            # {module_name}
+
+            ## Usage
+
+            ```ts
+            import {module_name} from '{spec_import}';{example}
+            ```
+
+            ## Methods
+
+            {methods}"#,
+        }
+    }
+}
+
+impl Template for DocsTemplate {
+    type FileType = DocsFileType;
+
+    fn render(
+        &self,
+        ctx: &CodegenContext,
+        file_type: &Self::FileType,
+    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let res = match file_type {
+            DocsFileType::ModuleDoc => ctx
+                .schemas
+                .iter()
+                .map(|schema| {
+                    let docs_dir = ctx.root.join("docs");
+                    let path = docs_dir.join(format!("{}.md", schema.module_name));
+                    let spec_import = ts_import_path(&docs_dir, &schema.spec_path(&ctx.source_dir));
+                    // Hand-written docs at the same path are kept as is
+                    let overwrite = is_generated_file(&path)?;
+
+                    if !overwrite {
+                        warn!(
+                            "Skipping the docs of {}: {} is not generated by Craby",
+                            schema.module_name,
+                            path.display()
+                        );
+                    }
+
+                    Ok(TemplateResult {
+                        path,
+                        content: self.module_doc(schema, &spec_import),
+                        overwrite,
+                        merged: false,
+                    })
+                })
+                .collect::<Result<Vec<_>, anyhow::Error>>()?,
+        };
+
+        Ok(res)
+    }
+}
+
+impl Default for DocsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocsGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator<DocsTemplate> for DocsGenerator {
+    fn cleanup(_: &CodegenContext) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    fn generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.template_ref().render(ctx, &DocsFileType::ModuleDoc)
+    }
+
+    fn template_ref(&self) -> &DocsTemplate {
+        &DocsTemplate
+    }
+}
+
+impl GeneratorInvoker for DocsGenerator {
+    fn invoke_generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.generate(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::tests::get_codegen_context;
+
+    use super::*;
+
+    #[test]
+    fn test_docs_generator() {
+        let ctx = get_codegen_context();
+        let generator = DocsGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_docs_preserves_hand_written_docs() {
+        let root = std::env::temp_dir().join(format!("craby-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs").join("CrabyTest.md"), "# My docs\n").unwrap();
+
+        let mut ctx = get_codegen_context();
+        ctx.source_dir = root.join("src");
+        ctx.root = root.clone();
+        let results = DocsGenerator::new().generate(&ctx).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(!results[0].overwrite);
+        assert!(results[0]
+            .content
+            .contains("import CrabyTest from '../src/NativeCrabyTest';"));
+    }
+}
@@ -0,0 +1,362 @@
+use indoc::formatdoc;
+
+use crate::{
+    generators::types::TemplateResult,
+    types::{CodegenContext, Schema},
+    utils::ts_import_path,
+};
+
+use super::types::{Generator, GeneratorInvoker, Template};
+
+pub struct DtsTemplate;
+pub struct DtsGenerator;
+
+pub enum DtsFileType {
+    /// {source_dir}/generated.ts
+    Declarations,
+    /// {source_dir}/generated.enums.ts
+    EnumGuards,
+}
+
+impl DtsTemplate {
+    /// Generates the declarations of the module that re-export the spec and assert the module name.
+    ///
+    /// The spec is imported from the spec file the module is declared in,
+    /// so renaming the module (or its methods) without updating the other side fails the typecheck.
+    /// The module name is a real const (not an ambient declaration), so importing it gives the registered name at runtime,
+    /// and the consumers importing it fail to typecheck once the module is renamed.
+    ///
+    /// # Generated Code
+    ///
+    /// ```ts
+    /// import type Calculator from './NativeCalculator';
+    ///
+    /// /** Module name registered with `NativeModuleRegistry.getEnforcing('Calculator')` */
+    /// export const CalculatorModuleName = 'Calculator' as const;
+    /// /** Spec of the `Calculator` module */
+    /// export type CalculatorSpec = typeof Calculator;
+    /// /** Method names of the `Calculator` module */
+    /// export type CalculatorMethodName = Assert<'add' | 'multiply', keyof CalculatorSpec>;
+    /// ```
+    fn module_decls(&self, schema: &Schema, spec_import: &str) -> (String, String) {
+        let module_name = &schema.module_name;
+        let method_names = match schema.methods.is_empty() {
+            true => "never".to_string(),
+            false => schema
+                .methods
+                .iter()
+                .map(|method| format!("'{}'", method.name))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        };
+
+        let import_stmt = format!("import type {module_name} from '{spec_import}';");
+        let decls = formatdoc! {
+            r#"
+            /** Module name registered with `NativeModuleRegistry.getEnforcing('{module_name}')` */
+            export const {module_name}ModuleName = '{module_name}' as const;
+            /** Spec of the `{module_name}` module */
+            export type {module_name}Spec = typeof {module_name};
+            /** Method names of the `{module_name}` module */
+            export type {module_name}MethodName = Assert<{method_names}, keyof {module_name}Spec>;"#,
+        };
+
+        (import_stmt, decls)
+    }
+
+    /// Generates the declarations of the modules.
+    ///
+    /// # Generated Code
+    ///
+    /// ```ts
+    /// import type Calculator from './NativeCalculator';
+    ///
+    /// /** Fails to typecheck if `T` is not assignable to `U` */
+    /// type Assert<T extends U, U> = T;
+    ///
+    /// /** Module name registered with `NativeModuleRegistry.getEnforcing('Calculator')` */
+    /// export const CalculatorModuleName = 'Calculator' as const;
+    /// // ...
+    /// ```
+    fn declarations(&self, ctx: &CodegenContext) -> String {
+        let (import_stmts, decls): (Vec<_>, Vec<_>) = ctx
+            .schemas
+            .iter()
+            .map(|schema| {
+                let spec_import =
+                    ts_import_path(&ctx.source_dir, &schema.spec_path(&ctx.source_dir));
+                self.module_decls(schema, &spec_import)
+            })
+            .unzip();
+
+        formatdoc! {
+            r#"
+            {import_stmts}
+
+            /** Fails to typecheck if `T` is not assignable to `U` */
+            type Assert<T extends U, U> = T;
+
+            {decls}"#,
+            import_stmts = import_stmts.join("\n"),
+            decls = decls.join("\n\n"),
+        }
+    }
+
+    /// Generates the values and the type guards of the enums to validate the values before passing them to the module.
+    ///
+    /// The values are emitted as literals, so the string literal unions (eg. `type Mode = 'read' | 'write'`)
+    /// and the enums that are not exported are also covered. The guards narrow the value to the enum type
+    /// only if it is declared with `export enum`, since the other enums cannot be imported.
+    /// Returns `None` if the modules have no enums.
+    ///
+    /// # Generated Code
+    ///
+    /// ```ts
+    /// import type { MyEnum } from './NativeCalculator';
+    ///
+    /// /** Values of the `MyEnum` enum */
+    /// export const MyEnumValues = ['foo', 'bar'] as const;
+    ///
+    /// /** Returns `true` if the value is a member of the `MyEnum` enum */
+    /// export function isMyEnum(value: unknown): value is MyEnum {
+    ///   return (MyEnumValues as readonly unknown[]).includes(value);
+    /// }
+    ///
+    /// /** Values of the `Mode` enum */
+    /// export const ModeValues = ['read', 'write'] as const;
+    ///
+    /// /** Returns `true` if the value is a member of the `Mode` enum */
+    /// export function isMode(value: unknown): value is (typeof ModeValues)[number] {
+    ///   return (ModeValues as readonly unknown[]).includes(value);
+    /// }
+    /// ```
+    fn enum_guards(&self, ctx: &CodegenContext) -> Option<String> {
+        let mut enum_names = vec![];
+        // (import path, enum names)
+        let mut imports: Vec<(String, Vec<String>)> = vec![];
+        let mut guards = vec![];
+
+        for schema in &ctx.schemas {
+            for enum_schema in schema
+                .enums
+                .iter()
+                .filter_map(|enum_type| enum_type.as_enum())
+            {
+                // Enums shared by the modules are declared once
+                if enum_names.contains(&enum_schema.name) {
+                    continue;
+                }
+
+                let name = &enum_schema.name;
+                let values = enum_schema
+                    .members
+                    .iter()
+                    .map(|member| member.value.as_ts_literal())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let guard_type = if enum_schema.exported {
+                    let decl_file = enum_schema
+                        .decl_file
+                        .clone()
+                        .unwrap_or_else(|| schema.spec_path(&ctx.source_dir));
+                    let import_path = ts_import_path(&ctx.source_dir, &decl_file);
+
+                    match imports.iter_mut().find(|(path, _)| *path == import_path) {
+                        Some((_, names)) => names.push(name.clone()),
+                        None => imports.push((import_path, vec![name.clone()])),
+                    }
+
+                    name.clone()
+                } else {
+                    format!("(typeof {name}Values)[number]")
+                };
+
+                guards.push(formatdoc! {
+                    r#"
+                    /** Values of the `{name}` enum */
+                    export const {name}Values = [{values}] as const;
+
+                    /** Returns `true` if the value is a member of the `{name}` enum */
+                    export function is{name}(value: unknown): value is {guard_type} {{
+                      return ({name}Values as readonly unknown[]).includes(value);
+                    }}"#,
+                });
+                enum_names.push(name.clone());
+            }
+        }
+
+        if guards.is_empty() {
+            return None;
+        }
+
+        let import_stmts = match imports.is_empty() {
+            true => String::new(),
+            false => {
+                let import_stmts = imports
+                    .iter()
+                    .map(|(path, names)| {
+                        format!("import type {{ {} }} from '{path}';", names.join(", "))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("{import_stmts}\n\n")
+            }
+        };
+
+        Some(format!("{import_stmts}{}\n", guards.join("\n\n")))
+    }
+}
+
+impl Template for DtsTemplate {
+    type FileType = DtsFileType;
+
+    fn render(
+        &self,
+        ctx: &CodegenContext,
+        file_type: &Self::FileType,
+    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let res = match file_type {
+            DtsFileType::Declarations => vec![TemplateResult {
+                path: ctx.source_dir.join("generated.ts"),
+                content: self.declarations(ctx),
+                overwrite: true,
+                merged: false,
+            }],
+            DtsFileType::EnumGuards => self
+                .enum_guards(ctx)
+                .map(|content| TemplateResult {
+                    path: ctx.source_dir.join("generated.enums.ts"),
+                    content,
+                    overwrite: true,
+                    merged: false,
+                })
+                .into_iter()
+                .collect(),
+        };
+
+        Ok(res)
+    }
+}
+
+impl Default for DtsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DtsGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator<DtsTemplate> for DtsGenerator {
+    fn cleanup(_: &CodegenContext) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    fn generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let template = self.template_ref();
+        let res = [
+            template.render(ctx, &DtsFileType::Declarations)?,
+            template.render(ctx, &DtsFileType::EnumGuards)?,
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        Ok(res)
+    }
+
+    fn template_ref(&self) -> &DtsTemplate {
+        &DtsTemplate
+    }
+}
+
+impl GeneratorInvoker for DtsGenerator {
+    fn invoke_generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.generate(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use std::path::PathBuf;
+
+    use crate::{parser::native_spec_parser::try_parse_schema, tests::get_codegen_context};
+
+    use super::*;
+
+    #[test]
+    fn test_dts_generator() {
+        let ctx = get_codegen_context();
+        let generator = DtsGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_dts_generator_without_enums() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas
+            .iter_mut()
+            .for_each(|schema| schema.enums.clear());
+        let results = DtsGenerator::new().generate(&ctx).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].path.ends_with("generated.ts"));
+    }
+
+    #[test]
+    fn test_dts_generator_enum_guards() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            type Mode = 'read' | 'write';
+
+            enum Level {
+                Low = 0,
+                High = 1,
+            }
+
+            export enum Status {
+                Idle = 'idle',
+                Busy = 'busy',
+            }
+
+            export interface Spec extends NativeModule {
+                open(mode: Mode, level: Level): Status;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        ctx.schemas[0].spec_file = Some(PathBuf::from("./src/specs/NativeTest.ts"));
+        let results = DtsGenerator::new().generate(&ctx).unwrap();
+        let result = &results[1].content;
+
+        assert!(results[0]
+            .content
+            .starts_with("import type CrabyTest from './specs/NativeTest';"));
+        assert!(result.starts_with("import type { Status } from './specs/NativeTest';\n\n"));
+        assert!(result.contains("export const ModeValues = ['read', 'write'] as const;"));
+        assert!(result.contains("value is (typeof ModeValues)[number]"));
+        assert!(result.contains("export const LevelValues = [0, 1] as const;"));
+        assert!(result.contains("value is (typeof LevelValues)[number]"));
+        assert!(result.contains("export const StatusValues = ['idle', 'busy'] as const;"));
+        assert!(result.contains("export function isStatus(value: unknown): value is Status {"));
+    }
+}
@@ -4,9 +4,10 @@ use craby_common::constants::ios_base_path;
 use indoc::formatdoc;
 
 use crate::{
+    constants::MODULE_REGISTRY_HEADER,
     generators::types::TemplateResult,
     types::{CodegenContext, CxxModuleName, CxxNamespace, ObjCProviderName},
-    utils::indent_str,
+    utils::{cxx_feature_guard, indent_str},
 };
 
 use super::types::{Generator, GeneratorInvoker, Template};
@@ -16,6 +17,7 @@ pub struct IosGenerator;
 
 pub enum IosFileType {
     ModuleProvider,
+    ModuleProviderHeader,
 }
 
 impl IosTemplate {
@@ -37,12 +39,10 @@ impl IosTemplate {
     ///   const char *cDataPath = [[self getDataPath] UTF8String];
     ///   std::string dataPath(cDataPath);
     ///
-    ///   craby::myproject::modules::CxxMyTestModule::dataPath = dataPath;
-    ///
     ///   facebook::react::registerCxxModuleToGlobalModuleMap(
     ///       craby::myproject::modules::CxxMyTestModule::kModuleName,
-    ///       [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
-    ///         return std::make_shared<craby::myproject::modules::CxxMyTestModule>(jsInvoker);
+    ///       [dataPath](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
+    ///         return std::make_shared<craby::myproject::modules::CxxMyTestModule>(jsInvoker, dataPath);
     ///       });
     /// }
     ///
@@ -71,54 +71,130 @@ impl IosTemplate {
     ///
     /// @end
     /// ```
+    ///
+    /// With `lazy_registration`, the data path is resolved by `+lazyDataPath` on the first module creation.
     fn module_provider(&self, ctx: &CodegenContext) -> Result<String, anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(&ctx.project_name);
+        let cxx_ns = CxxNamespace::from(&ctx.cxx_namespace);
         let mut cxx_includes = vec![];
-        let mut cxx_prepares = Vec::with_capacity(ctx.schemas.len());
         let mut cxx_registers = Vec::with_capacity(ctx.schemas.len());
         let objc_provider = ObjCProviderName::from(&ctx.project_name);
 
-        ctx.schemas.iter().for_each(|schema| {
-            let cxx_mod = CxxModuleName::from(&schema.module_name);
-            let cxx_include = format!("#import \"{cxx_mod}.hpp\"");
-            let cxx_mod_namespace = format!("{cxx_ns}::modules::{cxx_mod}");
-            let cxx_prepare = format!("{cxx_mod_namespace}::dataPath = dataPath;");
-            let cxx_register = formatdoc! {
+        // With lazy registration, the data path is resolved on the first module creation
+        let (data_path_capture, data_path) = if ctx.lazy_registration {
+            ("[]", format!("[{objc_provider} lazyDataPath]"))
+        } else {
+            ("[dataPath]", "dataPath".to_string())
+        };
+
+        if ctx.module_registry {
+            // Register the modules through the shared module registry
+            cxx_includes.push(format!("#import \"{MODULE_REGISTRY_HEADER}\""));
+            cxx_registers.push(format!(
+                "{cxx_ns}::registry::registerModules({data_path_capture} {{ return {data_path}; }});"
+            ));
+        } else {
+            ctx.schemas.iter().for_each(|schema| {
+                let cxx_mod = CxxModuleName::from(&schema.module_name);
+                let cxx_include = format!("#import \"{cxx_mod}.hpp\"");
+                let cxx_mod_namespace = format!("{cxx_ns}::modules::{cxx_mod}");
+                let cxx_register = formatdoc! {
+                    r#"
+                    facebook::react::registerCxxModuleToGlobalModuleMap(
+                        {cxx_mod_namespace}::kModuleName,
+                        {data_path_capture}(std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {{
+                          return std::make_shared<{cxx_mod_namespace}>(jsInvoker, {data_path});
+                        }});"#,
+                };
+
+                cxx_includes.push(cxx_include);
+                cxx_registers.push(if ctx.module_features {
+                    cxx_feature_guard(&cxx_register, &schema.module_name)
+                } else {
+                    cxx_register
+                });
+            });
+        }
+
+        let cxx_includes = cxx_includes.join("\n");
+        let data_path_decl = if ctx.lazy_registration {
+            String::new()
+        } else {
+            "const char *cDataPath = [[self getDataPath] UTF8String];\nstd::string dataPath(cDataPath);\n\n"
+                .to_string()
+        };
+        let (cxx_includes, interface, register_impl) = if ctx.swift_header {
+            // The interface is declared in the Swift-friendly header
+            let cxx_registers =
+                indent_str(&format!("{data_path_decl}{}", cxx_registers.join("\n")), 4);
+            let register_impl = formatdoc! {
                 r#"
-                facebook::react::registerCxxModuleToGlobalModuleMap(
-                    {cxx_mod_namespace}::kModuleName,
-                    [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {{
-                      return std::make_shared<{cxx_mod_namespace}>(jsInvoker);
-                    }});"#,
+                + (void)load {{
+                  [self registerModules];
+                }}
+
+                + (void)registerModules {{
+                  static dispatch_once_t onceToken;
+                  dispatch_once(&onceToken, ^{{
+                {cxx_registers}
+                  }});
+                }}"#,
             };
 
-            cxx_includes.push(cxx_include);
-            cxx_prepares.push(cxx_prepare);
-            cxx_registers.push(cxx_register);
-        });
+            (
+                format!("#import \"{objc_provider}.h\"\n{cxx_includes}"),
+                String::new(),
+                register_impl,
+            )
+        } else {
+            let cxx_registers =
+                indent_str(&format!("{data_path_decl}{}", cxx_registers.join("\n")), 2);
+            let interface = formatdoc! {
+                r#"
+                @interface {objc_provider} : NSObject
+                @end
+
+                "#,
+            };
+            let register_impl = formatdoc! {
+                r#"
+                + (void)load {{
+                {cxx_registers}
+                }}"#,
+            };
+
+            (cxx_includes, interface, register_impl)
+        };
+
+        // ```objc
+        // + (std::string)lazyDataPath {
+        //   static std::string dataPath([[self getDataPath] UTF8String]);
+        //   return dataPath;
+        // }
+        // ```
+        let register_impl = if ctx.lazy_registration {
+            formatdoc! {
+                r#"
+                {register_impl}
+
+                + (std::string)lazyDataPath {{
+                  // Resolved once on the first module creation
+                  static std::string dataPath([[self getDataPath] UTF8String]);
+                  return dataPath;
+                }}"#,
+            }
+        } else {
+            register_impl
+        };
 
-        let cxx_includes = cxx_includes.join("\n");
-        let cxx_prepares = indent_str(&cxx_prepares.join("\n"), 2);
-        let cxx_registers = indent_str(&cxx_registers.join("\n"), 2);
         let content = formatdoc! {
             r#"
             {cxx_includes}
             #import <ReactCommon/CxxTurboModuleUtils.h>
             #include <string>
 
-            @interface {objc_provider} : NSObject
-            @end
-
-            @implementation {objc_provider}
-
-            + (void)load {{
-              const char *cDataPath = [[self getDataPath] UTF8String];
-              std::string dataPath(cDataPath);
-
-            {cxx_prepares}
+            {interface}@implementation {objc_provider}
 
-            {cxx_registers}
-            }}
+            {register_impl}
 
             + (NSString *)getDataPath {{
               NSString *appGroupID = [[NSBundle mainBundle] objectForInfoDictionaryKey:@"AppGroupID"];
@@ -148,6 +224,46 @@ impl IosTemplate {
 
         Ok(content)
     }
+
+    /// Generates the Swift-friendly header of the iOS module provider.
+    ///
+    /// # Generated Code
+    ///
+    /// ```objc
+    /// #import <Foundation/Foundation.h>
+    ///
+    /// NS_ASSUME_NONNULL_BEGIN
+    ///
+    /// @interface CrabyMyAppModuleProvider : NSObject
+    ///
+    /// /// Registers the modules to the global module map.
+    /// /// Called automatically when the provider is loaded, and does nothing on subsequent calls.
+    /// + (void)registerModules NS_SWIFT_NAME(registerModules());
+    ///
+    /// @end
+    ///
+    /// NS_ASSUME_NONNULL_END
+    /// ```
+    fn module_provider_header(&self, ctx: &CodegenContext) -> String {
+        let objc_provider = ObjCProviderName::from(&ctx.project_name);
+
+        formatdoc! {
+            r#"
+            #import <Foundation/Foundation.h>
+
+            NS_ASSUME_NONNULL_BEGIN
+
+            @interface {objc_provider} : NSObject
+
+            /// Registers the modules to the global module map.
+            /// Called automatically when the provider is loaded, and does nothing on subsequent calls.
+            + (void)registerModules NS_SWIFT_NAME(registerModules());
+
+            @end
+
+            NS_ASSUME_NONNULL_END"#,
+        }
+    }
 }
 
 impl Template for IosTemplate {
@@ -166,6 +282,16 @@ impl Template for IosTemplate {
                         .join(format!("{}.mm", ObjCProviderName::from(&ctx.project_name))),
                     content: self.module_provider(ctx)?,
                     overwrite: true,
+                    merged: false,
+                }]
+            }
+            IosFileType::ModuleProviderHeader => {
+                vec![TemplateResult {
+                    path: base_path
+                        .join(format!("{}.h", ObjCProviderName::from(&ctx.project_name))),
+                    content: self.module_provider_header(ctx),
+                    overwrite: true,
+                    merged: false,
                 }]
             }
         };
@@ -208,7 +334,11 @@ impl Generator<IosTemplate> for IosGenerator {
 
     fn generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
         let template = self.template_ref();
-        let files = template.render(ctx, &IosFileType::ModuleProvider)?;
+        let mut files = template.render(ctx, &IosFileType::ModuleProvider)?;
+
+        if ctx.swift_header {
+            files.extend(template.render(ctx, &IosFileType::ModuleProviderHeader)?);
+        }
 
         Ok(files)
     }
@@ -245,4 +375,48 @@ mod tests {
 
         assert_snapshot!(result);
     }
+
+    #[test]
+    fn test_ios_generator_swift_header() {
+        let mut ctx = get_codegen_context();
+        ctx.swift_header = true;
+        let generator = IosGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_ios_generator_module_registry() {
+        let mut ctx = get_codegen_context();
+        ctx.module_registry = true;
+        let result = IosTemplate.module_provider(&ctx).unwrap();
+
+        assert!(result.contains("#import \"CrabyModuleRegistry.hpp\""));
+        assert!(result.contains(
+            "craby::testmodule::registry::registerModules([dataPath] { return dataPath; });"
+        ));
+        assert!(!result.contains("registerCxxModuleToGlobalModuleMap"));
+    }
+
+    #[test]
+    fn test_ios_generator_lazy_registration() {
+        let mut ctx = get_codegen_context();
+        ctx.lazy_registration = true;
+        let result = IosTemplate.module_provider(&ctx).unwrap();
+
+        assert!(!result.contains("std::string dataPath(cDataPath);"));
+        assert_snapshot!(result);
+
+        ctx.module_registry = true;
+        let result = IosTemplate.module_provider(&ctx).unwrap();
+
+        assert!(result
+            .contains("registerModules([] { return [TestModuleModuleProvider lazyDataPath]; });"));
+    }
 }
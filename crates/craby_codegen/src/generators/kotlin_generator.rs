@@ -0,0 +1,242 @@
+use craby_common::constants::java_base_path;
+use indoc::formatdoc;
+use log::warn;
+
+use crate::{
+    generators::types::TemplateResult,
+    parser::types::{Method, Platform, TypeAnnotation},
+    types::{CodegenContext, Schema},
+    utils::{indent_str, is_generated_file},
+};
+
+use super::types::{Generator, GeneratorInvoker, Template};
+
+pub struct KotlinTemplate;
+pub struct KotlinGenerator;
+
+pub enum KotlinFileType {
+    /// {ModuleName}Module.kt
+    ModuleStub,
+}
+
+impl KotlinTemplate {
+    /// Returns the Kotlin type of the type annotation.
+    ///
+    /// Only primitive and string types are supported for now.
+    fn as_kt_type(&self, type_annotation: &TypeAnnotation) -> Option<&'static str> {
+        match type_annotation {
+            TypeAnnotation::Boolean => Some("Boolean"),
+            TypeAnnotation::Number => Some("Double"),
+            TypeAnnotation::String => Some("String"),
+            TypeAnnotation::Int32 => Some("Int"),
+            TypeAnnotation::Int64 => Some("Long"),
+            _ => None,
+        }
+    }
+
+    /// Returns the delegate declaration and the stub of the method.
+    /// Returns `None` if the method uses types that are not supported or is iOS only.
+    ///
+    /// ```kotlin
+    /// fun multiply(a: Double, b: Double): Double
+    /// fun multiply(reactContext: ReactContext, a: Double, b: Double): Double = module(reactContext).multiply(a, b)
+    /// ```
+    fn method(&self, method: &Method) -> Option<(String, String)> {
+        if method.platform == Some(Platform::Ios) {
+            return None;
+        }
+
+        let ret = match method.ret_type {
+            TypeAnnotation::Void => String::new(),
+            ref ret_type => format!(": {}", self.as_kt_type(ret_type)?),
+        };
+        let params = method
+            .params
+            .iter()
+            .map(|param| {
+                self.as_kt_type(&param.type_annotation)
+                    .map(|kt_type| format!("{}: {}", param.name, kt_type))
+            })
+            .collect::<Option<Vec<_>>>()?
+            .join(", ");
+        let args = method
+            .params
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let stub_params = if params.is_empty() {
+            "reactContext: ReactContext".to_string()
+        } else {
+            format!("reactContext: ReactContext, {params}")
+        };
+
+        Some((
+            format!("fun {}({params}){ret}", method.name),
+            format!(
+                "fun {name}({stub_params}){ret} = module(reactContext).{name}({args})",
+                name = method.name
+            ),
+        ))
+    }
+
+    /// Generates the Kotlin object of the module with the typed method stubs.
+    ///
+    /// The module is resolved through the TurboModule lookup of the `ReactContext` on every call,
+    /// so the stubs fail with a descriptive error instead of an uninitialized property
+    /// when no Kotlin module implementing the `Delegate` is registered under the module name.
+    ///
+    /// # Generated Code
+    ///
+    /// ```kotlin
+    /// package com.mymodule
+    ///
+    /// import com.facebook.react.bridge.ReactContext
+    /// import com.facebook.react.turbomodule.core.interfaces.TurboModule
+    ///
+    /// object CalculatorModule {
+    ///   const val NAME = "Calculator"
+    ///
+    ///   interface Delegate : TurboModule {
+    ///     fun multiply(a: Double, b: Double): Double
+    ///   }
+    ///
+    ///   fun module(reactContext: ReactContext): Delegate =
+    ///     reactContext.getNativeModule(NAME) as? Delegate
+    ///       ?: throw IllegalStateException("TurboModule '$NAME' is not registered as a Kotlin module implementing the Delegate")
+    ///
+    ///   fun multiply(reactContext: ReactContext, a: Double, b: Double): Double = module(reactContext).multiply(a, b)
+    /// }
+    /// ```
+    fn module_stub(&self, ctx: &CodegenContext, schema: &Schema) -> String {
+        let (decls, stubs): (Vec<_>, Vec<_>) = schema
+            .methods
+            .iter()
+            .filter_map(|method| self.method(method))
+            .unzip();
+
+        let stubs = if stubs.is_empty() {
+            String::new()
+        } else {
+            format!("\n\n{}", indent_str(&stubs.join("\n"), 2))
+        };
+
+        formatdoc! {
+            r#"
+            package {package_name}
+
+            import com.facebook.react.bridge.ReactContext
+            import com.facebook.react.turbomodule.core.interfaces.TurboModule
+
+            object {module_name}Module {{
+              const val NAME = "{module_name}"
+
+              interface Delegate : TurboModule {{
+            {decls}
+              }}
+
+              fun module(reactContext: ReactContext): Delegate =
+                reactContext.getNativeModule(NAME) as? Delegate
+                  ?: throw IllegalStateException("TurboModule '$NAME' is not registered as a Kotlin module implementing the Delegate"){stubs}
+            }}"#,
+            package_name = ctx.android_package_name,
+            module_name = schema.module_name,
+            decls = indent_str(&decls.join("\n"), 4),
+        }
+    }
+}
+
+impl Template for KotlinTemplate {
+    type FileType = KotlinFileType;
+
+    fn render(
+        &self,
+        ctx: &CodegenContext,
+        file_type: &Self::FileType,
+    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let res = match file_type {
+            KotlinFileType::ModuleStub => ctx
+                .schemas
+                .iter()
+                .map(|schema| {
+                    let path = java_base_path(&ctx.root, &ctx.android_package_name)
+                        .join(format!("{}Module.kt", schema.module_name));
+                    // Hand-written Kotlin modules at the same path are kept as is
+                    let overwrite = is_generated_file(&path)?;
+
+                    if !overwrite {
+                        warn!(
+                            "Skipping the Kotlin stubs of {}: {} is not generated by Craby",
+                            schema.module_name,
+                            path.display()
+                        );
+                    }
+
+                    Ok(TemplateResult {
+                        path,
+                        content: self.module_stub(ctx, schema),
+                        overwrite,
+                        merged: false,
+                    })
+                })
+                .collect::<Result<Vec<_>, anyhow::Error>>()?,
+        };
+
+        Ok(res)
+    }
+}
+
+impl Default for KotlinGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KotlinGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator<KotlinTemplate> for KotlinGenerator {
+    fn cleanup(_: &CodegenContext) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    fn generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.template_ref().render(ctx, &KotlinFileType::ModuleStub)
+    }
+
+    fn template_ref(&self) -> &KotlinTemplate {
+        &KotlinTemplate
+    }
+}
+
+impl GeneratorInvoker for KotlinGenerator {
+    fn invoke_generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.generate(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::tests::get_codegen_context;
+
+    use super::*;
+
+    #[test]
+    fn test_kotlin_generator() {
+        let ctx = get_codegen_context();
+        let generator = KotlinGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+}
@@ -0,0 +1,348 @@
+use std::path::PathBuf;
+
+use craby_common::constants::SPEC_FILE_PREFIX;
+use indoc::{formatdoc, indoc};
+use log::warn;
+
+use crate::{
+    constants::MOCKS_DIR,
+    generators::types::TemplateResult,
+    parser::types::{Method, TypeAnnotation},
+    types::{CodegenContext, Schema},
+    utils::{indent_str, is_generated_file, ts_import_path},
+};
+
+use super::types::{Generator, GeneratorInvoker, Template};
+
+pub struct MockTemplate;
+pub struct MockGenerator;
+
+pub enum MockFileType {
+    /// {spec_dir}/__mocks__/{spec_file_name}.ts
+    Mock,
+}
+
+impl MockTemplate {
+    /// Returns the path of the mock in the `__mocks__` directory next to the spec file.
+    ///
+    /// The mocks of the modules declared in the same spec file are named after the modules
+    /// (eg. `NativeCalculator.ts`) since they cannot share the file name of the spec.
+    fn mock_path(&self, ctx: &CodegenContext, schema: &Schema) -> PathBuf {
+        let spec_path = schema.spec_path(&ctx.source_dir);
+        let mocks_dir = spec_path.parent().unwrap().join(MOCKS_DIR);
+        let shared_spec = ctx
+            .schemas
+            .iter()
+            .filter(|other| other.spec_path(&ctx.source_dir) == spec_path)
+            .count()
+            > 1;
+
+        match shared_spec {
+            true => mocks_dir.join(format!("{SPEC_FILE_PREFIX}{}.ts", schema.module_name)),
+            false => mocks_dir.join(spec_path.file_name().unwrap()),
+        }
+    }
+
+    /// Generates the stub of the method that returns the default value of the return type.
+    ///
+    /// # Generated Code
+    ///
+    /// ```ts
+    /// multiply() {
+    ///   return 0;
+    /// },
+    /// longTask() {
+    ///   return Object.assign(Promise.resolve(0), { cancel() {} });
+    /// },
+    /// ```
+    fn method_stub(&self, method: &Method) -> Result<String, anyhow::Error> {
+        let ret = match &method.ret_type {
+            TypeAnnotation::Void => return Ok(format!("{}() {{}},", method.name)),
+            // `@cancellable` methods may be typed as `CancellablePromise`
+            TypeAnnotation::Promise(..) if method.cancellable => format!(
+                "Object.assign({}, {{ cancel() {{}} }})",
+                method.ret_type.as_ts_default_val()?
+            ),
+            ret_type => ret_type.as_ts_default_val()?,
+        };
+
+        Ok(formatdoc! {
+            r#"
+            {name}() {{
+              return {ret};
+            }},"#,
+            name = method.name,
+        })
+    }
+
+    /// Generates the mock of the module that implements the spec with the stubs.
+    ///
+    /// The mock is placed in the `__mocks__` directory next to the spec file with the same file name,
+    /// so `jest.mock('./NativeCalculator')` picks it up without the native module.
+    ///
+    /// # Generated Code
+    ///
+    /// ```ts
+    /// import type NativeCalculator from '../NativeCalculator';
+    ///
+    /// type Spec = typeof NativeCalculator;
+    /// // ...
+    ///
+    /// /** Signal stubs of the mock (eg. `signals.onResult.emit(data)` notifies the listeners) */
+    /// export const signals = {
+    ///   onResult: createSignal<SignalData<Spec['onResult']>>(),
+    /// };
+    ///
+    /// /** Mock of the `Calculator` module with the stubs returning the default values */
+    /// const Calculator: Spec = {
+    ///   multiply() {
+    ///     return 0;
+    ///   },
+    ///   onResult: signals.onResult,
+    /// };
+    ///
+    /// export default Calculator;
+    /// ```
+    fn mock(&self, schema: &Schema, spec_import: &str) -> Result<String, anyhow::Error> {
+        let module_name = &schema.module_name;
+        let mut members = schema
+            .methods
+            .iter()
+            .map(|method| self.method_stub(method))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+        let signal_stubs = if schema.signals.is_empty() {
+            String::new()
+        } else {
+            let signals = schema
+                .signals
+                .iter()
+                .map(|signal| {
+                    members.push(format!("{name}: signals.{name},", name = signal.name));
+                    format!(
+                        "{name}: createSignal<SignalData<Spec['{name}']>>(),",
+                        name = signal.name
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let example = &schema.signals[0].name;
+
+            formatdoc! {
+                r#"
+                {signal_utils}
+                /** Signal stubs of the mock (eg. `signals.{example}.emit(data)` notifies the listeners) */
+                export const signals = {{
+                {signals}
+                }};
+
+                "#,
+                signal_utils = SIGNAL_UTILS,
+                signals = indent_str(&signals, 2),
+            }
+        };
+
+        let members = if members.is_empty() {
+            String::new()
+        } else {
+            format!("\n{}\n", indent_str(&members.join("\n"), 2))
+        };
+
+        Ok(formatdoc! {
+            r#"
+            import type {SPEC_FILE_PREFIX}{module_name} from '{spec_import}';
+
+            type Spec = typeof {SPEC_FILE_PREFIX}{module_name};
+
+            {signal_stubs}/** Mock of the `{module_name}` module with the stubs returning the default values */
+            const {module_name}: Spec = {{{members}}};
+
+            export default {module_name};"#,
+        })
+    }
+}
+
+/// Listener registration stub of the signals.
+const SIGNAL_UTILS: &str = indoc! {
+    r#"
+    /** Payload type of the signal */
+    type SignalData<S> = S extends (handler: (data: infer T) => void) => () => void ? T : never;
+
+    /** Registers the listeners of the signal and notifies them on `emit` */
+    function createSignal<T>() {
+      const listeners = new Set<(data: T) => void>();
+      const register = (handler: (data: T) => void) => {
+        listeners.add(handler);
+        return () => {
+          listeners.delete(handler);
+        };
+      };
+
+      return Object.assign(register, {
+        emit: (data: T) => listeners.forEach((listener) => listener(data)),
+      });
+    }
+    "#
+};
+
+impl Template for MockTemplate {
+    type FileType = MockFileType;
+
+    fn render(
+        &self,
+        ctx: &CodegenContext,
+        file_type: &Self::FileType,
+    ) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        let res = match file_type {
+            MockFileType::Mock => ctx
+                .schemas
+                .iter()
+                .map(|schema| {
+                    let path = self.mock_path(ctx, schema);
+                    let spec_import =
+                        ts_import_path(path.parent().unwrap(), &schema.spec_path(&ctx.source_dir));
+                    let overwrite = is_generated_file(&path)?;
+
+                    if !overwrite {
+                        warn!(
+                            "Skipping the mock of {}: {} is not generated by Craby",
+                            schema.module_name,
+                            path.display()
+                        );
+                    }
+
+                    Ok(TemplateResult {
+                        path,
+                        content: self.mock(schema, &spec_import)?,
+                        overwrite,
+                        merged: false,
+                    })
+                })
+                .collect::<Result<Vec<_>, anyhow::Error>>()?,
+        };
+
+        Ok(res)
+    }
+}
+
+impl Default for MockGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator<MockTemplate> for MockGenerator {
+    fn cleanup(_: &CodegenContext) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+
+    fn generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.template_ref().render(ctx, &MockFileType::Mock)
+    }
+
+    fn template_ref(&self) -> &MockTemplate {
+        &MockTemplate
+    }
+}
+
+impl GeneratorInvoker for MockGenerator {
+    fn invoke_generate(&self, ctx: &CodegenContext) -> Result<Vec<TemplateResult>, anyhow::Error> {
+        self.generate(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::{parser::native_spec_parser::try_parse_schema, tests::get_codegen_context};
+
+    use super::*;
+
+    #[test]
+    fn test_mock_generator() {
+        let ctx = get_codegen_context();
+        let generator = MockGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_mock_without_signals() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            enum Mode {
+                Read = 'read',
+                Write = 'write',
+            }
+
+            export interface Spec extends NativeModule {
+                track(event: string): void;
+                mode(): Mode;
+                /** @cancellable */
+                longTask(n: number): Promise<number>;
+                pair(): [number, string];
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let result = MockTemplate
+            .mock(&schemas[0], "../NativeCrabyTest")
+            .unwrap();
+
+        assert!(!result.contains("createSignal"));
+        assert!(result.contains("track() {},"));
+        assert!(result.contains("return Object.assign(Promise.resolve(0), { cancel() {} });"));
+        assert!(result.contains("return [0, ''];"));
+        assert!(result.contains("return 'read' as never;"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_mock_next_to_spec_file() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas[0].spec_file = Some(PathBuf::from("./src/specs/NativeTest.ts"));
+        let results = MockGenerator::new().generate(&ctx).unwrap();
+
+        assert_eq!(
+            results[0].path,
+            PathBuf::from("./src/specs/__mocks__/NativeTest.ts")
+        );
+        assert!(results[0]
+            .content
+            .starts_with("import type NativeCrabyTest from '../NativeTest';"));
+        assert!(results[0].overwrite);
+    }
+
+    #[test]
+    fn test_mock_preserves_hand_written_mock() {
+        let dir = std::env::temp_dir().join(format!("craby-{}", uuid::Uuid::new_v4()));
+        let mocks_dir = dir.join(MOCKS_DIR);
+        std::fs::create_dir_all(&mocks_dir).unwrap();
+        std::fs::write(mocks_dir.join("NativeCrabyTest.ts"), "export default {};\n").unwrap();
+
+        let mut ctx = get_codegen_context();
+        ctx.schemas[0].spec_file = Some(dir.join("NativeCrabyTest.ts"));
+        let results = MockGenerator::new().generate(&ctx).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!results[0].overwrite);
+    }
+}
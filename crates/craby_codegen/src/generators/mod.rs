@@ -1,6 +1,11 @@
 pub mod android_generator;
+pub mod c_shim_generator;
 pub mod cxx_generator;
+pub mod docs_generator;
+pub mod dts_generator;
 pub mod ios_generator;
+pub mod kotlin_generator;
+pub mod mock_generator;
 pub mod rs_generator;
 
 pub mod types;
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, fs};
 
 use craby_common::{
     constants::{HASH_COMMENT_PREFIX, crate_dir, impl_mod_name},
@@ -9,9 +9,10 @@ use indoc::formatdoc;
 use crate::{
     common::IntoCode,
     generators::types::TemplateResult,
+    parser::types::{Signal, TypeAnnotation},
     platform::rust::RsCxxBridge,
     types::{CodegenContext, CxxNamespace, Schema},
-    utils::indent_str,
+    utils::{attr_items, doc_comment, indent_str, rs_feature_cfg},
 };
 
 use super::types::{Generator, GeneratorInvoker, Template};
@@ -30,11 +31,48 @@ pub enum RsFileType {
     ModImpl,
 }
 
+/// Returns the constant name of the signal name (eg. `onProgress` -> `ON_PROGRESS`)
+fn signal_name_const(signal_name: &str) -> String {
+    snake_case(signal_name).to_uppercase()
+}
+
+/// Returns the Rust type of the signal payload (eg. `ProgressEvent` of `Signal<ProgressEvent>`)
+fn signal_payload_type(signal: &Signal) -> Result<Option<String>, anyhow::Error> {
+    signal
+        .payload_type
+        .as_ref()
+        .map(|payload_type| {
+            payload_type
+                .as_rs_type()
+                .map(|rs_type| rs_type.into_code())
+                .map_err(|e| anyhow::anyhow!("Unsupported payload type of the signal `{}`: {}", signal.name, e))
+        })
+        .transpose()
+}
+
+/// Gates the items behind the cargo feature of the module (eg. `#[cfg(feature = "my_module")]`)
+fn feature_gate(code: String, module_name: &str, module_features: bool) -> String {
+    if module_features {
+        attr_items(&code, &rs_feature_cfg(&[module_name]))
+    } else {
+        code
+    }
+}
+
 impl RsTemplate {
-    fn impl_mods(&self, schemas: &[Schema]) -> Vec<String> {
+    /// Returns the statements that refer to the impl modules (eg. `pub(crate) mod my_module_impl;`)
+    fn impl_mod_stmts(
+        &self,
+        schemas: &[Schema],
+        module_features: bool,
+        stmt: impl Fn(&str) -> String,
+    ) -> Vec<String> {
         schemas
             .iter()
-            .map(|schema| impl_mod_name(&schema.module_name))
+            .map(|schema| {
+                let impl_mod = impl_mod_name(&schema.module_name);
+                feature_gate(stmt(&impl_mod), &schema.module_name, module_features)
+            })
             .collect::<Vec<String>>()
     }
 
@@ -80,15 +118,18 @@ impl RsTemplate {
         cxx_ns: &CxxNamespace,
         rs_cxx_bridges: &[RsCxxBridge],
         has_signals: bool,
+        listener_error_hook: bool,
+        module_features: bool,
         schemas: &[Schema],
-    ) -> String {
-        let (impl_types, cxx_externs, struct_defs, enum_defs) = rs_cxx_bridges.iter().fold(
+    ) -> Result<String, anyhow::Error> {
+        let (impl_types, cxx_externs, struct_defs, enum_defs) = rs_cxx_bridges.iter().zip(schemas).fold(
             (vec![], vec![], vec![], vec![]),
-            |(mut impl_types, mut externs, mut structs, mut enums), bridge| {
-                impl_types.push(bridge.impl_type.clone());
-                externs.extend(bridge.func_extern_sigs.clone());
-                structs.extend(bridge.struct_defs.clone());
-                enums.extend(bridge.enum_defs.clone());
+            |(mut impl_types, mut externs, mut structs, mut enums), (bridge, schema)| {
+                let gate = |code: &String| feature_gate(code.clone(), &schema.module_name, module_features);
+                impl_types.push(gate(&bridge.impl_type));
+                externs.extend(bridge.func_extern_sigs.iter().map(gate));
+                structs.extend(bridge.struct_defs.iter().map(gate));
+                enums.extend(bridge.enum_defs.iter().map(gate));
                 (impl_types, externs, structs, enums)
             },
         );
@@ -103,9 +144,9 @@ impl RsTemplate {
 
         // Add signal enum and payload extraction functions
         let signal_ffi_functions = if has_signals {
-            schemas.iter().flat_map(|schema| {
+            schemas.iter().map(|schema| {
                 if schema.signals.is_empty() {
-                    return vec![];
+                    return Ok(vec![]);
                 }
                 
                 let signal_enum_name = format!("{}Signal", schema.module_name);
@@ -113,13 +154,10 @@ impl RsTemplate {
                 
                 // Generate payload extraction function for each signal
                 for signal in &schema.signals {
-                    if let Some(payload_type) = &signal.payload_type {
-                        let payload_type_name = payload_type.as_rs_type()
-                            .map(|t| t.into_code())
-                            .unwrap_or_else(|_| "String".to_string());
+                    if let Some(payload_type_name) = signal_payload_type(signal)? {
                         let function_name = format!("get_{}_payload", snake_case(&signal.name));
                         functions.push(format!(
-                            "fn {}(s: &{}) -> {};",
+                            "fn {}(self: &{}) -> {};",
                             function_name, signal_enum_name, payload_type_name
                         ));
                     }
@@ -127,12 +165,27 @@ impl RsTemplate {
                 
                 // Add drop_signal function for memory management
                 functions.push(format!(
-                    "unsafe fn drop_signal(signal: *mut {});",
+                    "unsafe fn drop_{}_signal(signal: *mut {});",
+                    snake_case(&schema.module_name),
                     signal_enum_name
                 ));
+
+                // Add listener error hook
+                if listener_error_hook {
+                    functions.push(formatdoc! {
+                        r#"
+                        #[cxx_name = "on{module_name}ListenerError"]
+                        fn {snake_module_name}_on_listener_error(id: usize, signal_name: &str, message: &str);"#,
+                        module_name = pascal_case(&schema.module_name),
+                        snake_module_name = snake_case(&schema.module_name),
+                    });
+                }
                 
-                functions
-            }).collect::<Vec<_>>()
+                Ok(functions
+                    .into_iter()
+                    .map(|function| feature_gate(function, &schema.module_name, module_features))
+                    .collect())
+            }).collect::<Result<Vec<Vec<_>>, anyhow::Error>>()?.into_iter().flatten().collect::<Vec<_>>()
         } else {
             vec![]
         };
@@ -149,41 +202,57 @@ impl RsTemplate {
             String::new()
         };
 
-        let cxx_signal_manager = if has_signals {
-            // Get signal enum type for each schema
-            let signal_enum_types: Vec<String> = schemas.iter()
-                .filter(|s| !s.signals.is_empty())
-                .map(|s| format!("{}Signal", s.module_name))
-                .collect();
-            
-            let signal_type = signal_enum_types.first().unwrap().clone();
-            
+        // Shared cancellation flag of the `@cancellable` Promise methods
+        let cancellation_ffi = if schemas.iter().any(Schema::has_cancellable_methods) {
             formatdoc! {
                 r#"
-                #[namespace = "{cxx_ns}::signals"]
-                unsafe extern "C++" {{
-                    include!("CrabySignals.h");
+                extern "Rust" {{
+                    type CancellationToken;
 
-                    type SignalManager;
+                    #[cxx_name = "createCancellationToken"]
+                    fn create_cancellation_token() -> Box<CancellationToken>;
 
-                    unsafe fn emit(self: &SignalManager, id: usize, name: &str, signal: *mut {signal_type});
-                    
-                    #[rust_name = "get_signal_manager"]
-                    fn getSignalManager() -> &'static SignalManager;
+                    fn cancel(self: &CancellationToken);
                 }}"#,
-                signal_type = signal_type,
             }
         } else {
             String::new()
         };
 
+        // Signal manager of each module (typed with its signal enum)
+        let cxx_signal_managers = schemas
+            .iter()
+            .filter(|schema| !schema.signals.is_empty())
+            .map(|schema| {
+                let signal_manager = formatdoc! {
+                    r#"
+                    #[namespace = "{cxx_ns}::signals"]
+                    unsafe extern "C++" {{
+                        include!("CrabySignals.h");
+
+                        type {module_name}SignalManager;
+
+                        unsafe fn emit(self: &{module_name}SignalManager, id: usize, name: &str, signal: *mut {module_name}Signal);
+
+                        #[rust_name = "get_{snake_module_name}_signal_manager"]
+                        fn get{module_name}SignalManager() -> &'static {module_name}SignalManager;
+                    }}"#,
+                    module_name = schema.module_name,
+                    snake_module_name = snake_case(&schema.module_name),
+                };
+                feature_gate(signal_manager, &schema.module_name, module_features)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
         let code = indent_str(
             &[
                 struct_defs.join("\n\n"),
                 enum_defs.join("\n\n"),
                 cxx_extern,
                 signal_ffi,
-                cxx_signal_manager,
+                cancellation_ffi,
+                cxx_signal_managers,
             ]
             .iter()
             .filter(|s| !s.is_empty())
@@ -193,13 +262,13 @@ impl RsTemplate {
             4,
         );
 
-        formatdoc! {
+        Ok(formatdoc! {
             r#"
             #[cxx::bridge(namespace = "{cxx_ns}::bridging")]
             pub mod bridging {{
             {code}
             }}"#,
-        }
+        })
     }
 
     /// Generates Rust FFI function implementations.
@@ -233,14 +302,14 @@ impl RsTemplate {
     ///     fn multiply(&mut self, a: f64, b: f64) -> f64;
     /// }
     /// ```
-    fn rs_spec(&self, schema: &Schema) -> Result<String, anyhow::Error> {
+    fn rs_spec(&self, schema: &Schema, listener_error_hook: bool) -> Result<String, anyhow::Error> {
         let trait_name = pascal_case(&format!("{}Spec", schema.module_name));
         let mut methods = schema
             .methods
             .iter()
             .map(|spec| -> Result<String, anyhow::Error> {
                 let sig = spec.try_into_impl_sig()?;
-                Ok(format!("{sig};"))
+                Ok(format!("{}{sig};", doc_comment(&spec.docs)))
             })
             .collect::<Result<Vec<_>, _>>()?;
 
@@ -253,23 +322,19 @@ impl RsTemplate {
                     let member_name = pascal_case(&signal.name);
                     
                     // Create enum variant based on payload type
-                    let enum_member = if let Some(payload_type) = &signal.payload_type {
-                        // Convert payload_type to Rust type
-                        match payload_type.as_rs_type() {
-                            Ok(rs_type) => format!("{member_name}({}),", rs_type.into_code()),
-                            Err(_) => format!("{member_name},"), // Create without payload if conversion fails
-                        }
-                    } else {
-                        format!("{member_name},")
+                    let enum_member = match signal_payload_type(signal)? {
+                        Some(payload_type_name) => format!("{member_name}({payload_type_name}),"),
+                        None => format!("{member_name},"),
                     };
                     
+                    let name_const = signal_name_const(&signal.name);
                     let enum_pattern_match = formatdoc! {
                         r#"{signal_enum_name}::{member_name} => {{
+                            // SAFETY: The signal manager accepts a null pointer for the signals without payload
                             unsafe {{
-                                manager.emit(self.id(), "{raw}", std::ptr::null_mut());
+                                manager.emit(self.id(), {signal_enum_name}::{name_const}, std::ptr::null_mut());
                             }}
                         }}"#,
-                        raw = signal.name,
                     };
                     
                     // if there is a data payload
@@ -278,19 +343,23 @@ impl RsTemplate {
                             r#"{signal_enum_name}::{member_name}(data) => {{
                                 let signal = Box::new({signal_enum_name}::{member_name}(data));
                                 let signal_ptr = Box::into_raw(signal);
+                                // SAFETY: `signal_ptr` is a valid pointer created by `Box::into_raw`.
+                                // The ownership is transferred to C++, which releases it with `drop_{snake_module_name}_signal`
                                 unsafe {{
-                                    manager.emit(self.id(), "{raw}", signal_ptr);
+                                    manager.emit(self.id(), {signal_enum_name}::{name_const}, signal_ptr);
                                 }}
                             }}"#,
                             signal_enum_name = signal_enum_name,
-                            raw = signal.name,
+                            snake_module_name = snake_case(&schema.module_name),
                         }
                     } else {
                         enum_pattern_match.clone()
                     };
 
-                    (enum_member, enum_pattern_match, enum_pattern_match_with_data)
+                    Ok((enum_member, enum_pattern_match, enum_pattern_match_with_data))
                 })
+                .collect::<Result<Vec<_>, anyhow::Error>>()?
+                .into_iter()
                 .fold(
                     (Vec::new(), Vec::new(), Vec::new()),
                     |(mut members, mut patterns, mut patterns_with_data), (member, pattern, pattern_with_data)| {
@@ -301,11 +370,33 @@ impl RsTemplate {
                     },
                 );
 
+            // Signal names on the JavaScript side
+            //
+            // ```rust,ignore
+            // pub const ON_PROGRESS: &str = "onProgress";
+            // ```
+            let name_consts = schema
+                .signals
+                .iter()
+                .map(|signal| {
+                    format!(
+                        "pub const {}: &str = {:?};",
+                        signal_name_const(&signal.name),
+                        signal.name
+                    )
+                })
+                .collect::<Vec<_>>();
+
             let signal_members_exprs = indent_str(&signal_members.join("\n"), 4);
+            let name_consts = indent_str(&name_consts.join("\n"), 4);
             let signal_enum = formatdoc! {
                 r#"
                 pub enum {signal_enum_name} {{
                 {signal_members_exprs}
+                }}
+
+                impl {signal_enum_name} {{
+                {name_consts}
                 }}"#,
             };
 
@@ -323,15 +414,27 @@ impl RsTemplate {
             let emit_impl = formatdoc! {
                 r#"
                 fn emit(&self, signal_name: {signal_enum_name}) {{
-                    let manager = crate::ffi::bridging::get_signal_manager();
+                    let manager = crate::ffi::bridging::get_{snake_module_name}_signal_manager();
                     match signal_name {{
                 {pattern_match_stmts}
                     }}
                 }}"#,
+                snake_module_name = snake_case(&schema.module_name),
             };
 
             methods.insert(0, emit_impl);
 
+            if listener_error_hook {
+                methods.insert(
+                    1,
+                    formatdoc! {
+                        r#"
+                        /// Called when a JavaScript listener of the signal throws an exception.
+                        fn on_listener_error(_id: usize, _signal_name: &str, _message: &str) {{}}"#,
+                    },
+                );
+            }
+
             Some(signal_enum)
         } else {
             None
@@ -358,6 +461,9 @@ impl RsTemplate {
 
     /// Generates default implementation structure for module.
     ///
+    /// When `stub_defaults` is enabled, each method returns the default value of its
+    /// return type (with a warning) instead of `unimplemented!()`.
+    ///
     /// # Generated Code
     ///
     /// ```rust,ignore
@@ -384,19 +490,38 @@ impl RsTemplate {
     ///     }
     /// }
     /// ```
-    fn rs_impl(&self, schema: &Schema) -> Result<String, anyhow::Error> {
+    fn rs_impl(&self, schema: &Schema, stub_defaults: bool) -> Result<String, anyhow::Error> {
         let struct_name = pascal_case(&schema.module_name);
         let trait_name = pascal_case(&format!("{}Spec", schema.module_name));
         let methods = schema
             .methods
             .iter()
             .map(|spec| -> Result<String, anyhow::Error> {
-                let func_sig = spec.try_into_impl_sig()?;
-                let code = formatdoc! {
-                  r#"
-                  {func_sig} {{
-                      unimplemented!();
-                  }}"#,
+                let func_sig = format!("{}{}", doc_comment(&spec.docs), spec.try_into_impl_sig()?);
+                let code = if stub_defaults {
+                    let default_val = match (&spec.ret_type, &spec.error_type) {
+                        // `@throws` methods are rejected with the custom error type
+                        (TypeAnnotation::Promise(resolved_type), Some(_)) => format!(
+                            "promise::resolve_with({})",
+                            resolved_type.as_rs_impl_default_val()?
+                        ),
+                        _ => spec.ret_type.as_rs_impl_default_val()?,
+                    };
+                    formatdoc! {
+                      r#"
+                      {func_sig} {{
+                          craby::log::warn!("[craby] `{struct_name}::{fn_name}` is not implemented yet, returning default value");
+                          {default_val}
+                      }}"#,
+                      fn_name = snake_case(&spec.name),
+                    }
+                } else {
+                    formatdoc! {
+                      r#"
+                      {func_sig} {{
+                          unimplemented!();
+                      }}"#,
+                    }
                 };
 
                 Ok(code)
@@ -432,29 +557,101 @@ impl RsTemplate {
     ///
     /// pub(crate) mod my_module_impl;
     /// ```
-    fn lib_rs(&self, schemas: &[Schema]) -> Result<String, anyhow::Error> {
-        let impl_mods = self
-            .impl_mods(schemas)
-            .iter()
-            .map(|impl_mod| format!("pub(crate) mod {impl_mod};"))
-            .collect::<Vec<String>>();
+    ///
+    /// `pub(crate) mod c_ffi;` is also declared when the `extern "C"` shim is enabled.
+    fn lib_rs(
+        &self,
+        schemas: &[Schema],
+        c_shim: bool,
+        module_features: bool,
+    ) -> Result<String, anyhow::Error> {
+        let impl_mods = self.impl_mod_stmts(schemas, module_features, |impl_mod| {
+            format!("pub(crate) mod {impl_mod};")
+        });
 
         let impl_mod_defs = impl_mods.join("\n");
         let content = formatdoc! {
             r#"
             #[rustfmt::skip]
             pub(crate) mod ffi;
-            pub(crate) mod generated;
+            pub(crate) mod generated;{c_ffi_def}
 
             {impl_mod_defs}"#,
+            c_ffi_def = if c_shim { "\npub(crate) mod c_ffi;" } else { "" },
         };
 
         Ok(content)
     }
 
+    /// Appends the module declarations missing from the existing `lib.rs`.
+    ///
+    /// Returns `None` if every module is already declared.
+    fn merge_lib_rs(
+        &self,
+        existing: &str,
+        schemas: &[Schema],
+        c_shim: bool,
+        module_features: bool,
+    ) -> Option<String> {
+        let declared = existing
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                let line = match line.strip_prefix("pub") {
+                    // `pub mod`, `pub(crate) mod`
+                    Some(rest) => match rest.trim_start().strip_prefix('(') {
+                        Some(rest) => rest.split_once(')')?.1,
+                        None => rest,
+                    },
+                    None => line,
+                };
+
+                line.trim_start()
+                    .strip_prefix("mod ")?
+                    .trim()
+                    .strip_suffix(';')
+                    .map(|name| name.trim().to_string())
+            })
+            .collect::<Vec<String>>();
+
+        let mut mods = vec![("ffi".to_string(), None), ("generated".to_string(), None)];
+        if c_shim {
+            mods.push(("c_ffi".to_string(), None));
+        }
+        mods.extend(
+            schemas
+                .iter()
+                .map(|schema| (impl_mod_name(&schema.module_name), Some(&schema.module_name))),
+        );
+
+        let missing_mod_defs = mods
+            .iter()
+            .filter(|(name, _)| !declared.contains(name))
+            .map(|(name, module_name)| match (name.as_str(), module_name) {
+                ("ffi", _) => format!("#[rustfmt::skip]\npub(crate) mod {name};"),
+                (_, Some(module_name)) => {
+                    feature_gate(format!("pub(crate) mod {name};"), module_name, module_features)
+                }
+                _ => format!("pub(crate) mod {name};"),
+            })
+            .collect::<Vec<String>>();
+
+        if missing_mod_defs.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "{}\n\n{}",
+            existing.trim_end(),
+            missing_mod_defs.join("\n")
+        ))
+    }
+
     /// Generate the `ffi.rs` file for the given code generation results.
     ///
     /// ```rust,ignore
+    /// #![allow(clippy::all)]
+    ///
     /// use craby::prelude::*;
     ///
     /// use crate::my_module_impl::*;
@@ -475,68 +672,121 @@ impl RsTemplate {
     /// }
     /// ```
     fn ffi_rs(&self, ctx: &CodegenContext) -> Result<String, anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(&ctx.project_name);
-        let impl_mods = self
-            .impl_mods(&ctx.schemas)
-            .iter()
-            .map(|impl_mod| format!("use crate::{impl_mod}::*;"))
-            .collect::<Vec<String>>();
+        let cxx_ns = CxxNamespace::from(&ctx.cxx_namespace);
+        let impl_mods = self.impl_mod_stmts(&ctx.schemas, ctx.module_features, |impl_mod| {
+            format!("use crate::{impl_mod}::*;")
+        });
 
         let has_signals = ctx.schemas.iter().any(|schema| !schema.signals.is_empty());
         let rs_cxx_bridges = self.rs_cxx_bridges(&ctx.schemas)?;
-        let cxx_impls = self.rs_cxx_impl(&rs_cxx_bridges);
-        let cxx_externs = self.rs_cxx_extern(&cxx_ns, &rs_cxx_bridges, has_signals, &ctx.schemas);
+        let mut cxx_impls = self
+            .rs_cxx_impl(&rs_cxx_bridges)
+            .into_iter()
+            .zip(&ctx.schemas)
+            .map(|(cxx_impl, schema)| feature_gate(cxx_impl, &schema.module_name, ctx.module_features))
+            .collect::<Vec<_>>();
+        let cxx_externs = self.rs_cxx_extern(
+            &cxx_ns,
+            &rs_cxx_bridges,
+            has_signals,
+            ctx.listener_error_hook,
+            ctx.module_features,
+            &ctx.schemas,
+        )?;
         
         // Generate signal payload extraction function implementation
         let signal_payload_impls = if has_signals {
-            ctx.schemas.iter().flat_map(|schema| {
+            ctx.schemas.iter().map(|schema| {
                 if schema.signals.is_empty() {
-                    return vec![];
+                    return Ok(vec![]);
                 }
                 
                 let signal_enum_name = format!("{}Signal", schema.module_name);
-                let mut impls: Vec<String> = schema.signals.iter().filter_map(|signal| {
-                    signal.payload_type.as_ref().map(|payload_type| {
-                        let payload_type_name = payload_type.as_rs_type()
-                            .map(|t| t.into_code())
-                            .unwrap_or_else(|_| "String".to_string());
+                let payload_getters = schema.signals.iter().filter_map(|signal| {
+                    signal_payload_type(signal).transpose().map(|payload_type_name| {
+                        let payload_type_name = payload_type_name?;
                         let function_name = format!("get_{}_payload", snake_case(&signal.name));
                         let signal_variant = pascal_case(&signal.name);
                         
-                        formatdoc! {
+                        Ok(formatdoc! {
                             r#"
-                            fn {function_name}(s: &{signal_enum_name}) -> {payload_type_name} {{
-                                match s {{
+                            fn {function_name}(&self) -> {payload_type_name} {{
+                                match self {{
                                     {signal_enum_name}::{signal_variant}(payload) => (*payload).clone(),
                                     _ => panic!("Invalid signal type for {function_name}"),
                                 }}
                             }}"#,
-                        }
+                        })
                     })
-                }).collect();
-                
+                }).collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+                let mut impls = vec![];
+
+                // Payload getters are the methods of the signal enum to avoid conflicts between the modules
+                if !payload_getters.is_empty() {
+                    impls.push(formatdoc! {
+                        r#"
+                        impl {signal_enum_name} {{
+                        {payload_getters}
+                        }}"#,
+                        payload_getters = indent_str(&payload_getters.join("\n\n"), 4),
+                    });
+                }
+
                 // Add drop_signal implementation
                 impls.push(formatdoc! {
                     r#"
-                    unsafe fn drop_signal(signal: *mut {signal_enum_name}) {{
+                    /// # Safety
+                    ///
+                    /// `signal` must be null or a pointer passed to C++ by `emit` that is not dropped yet.
+                    unsafe fn drop_{snake_module_name}_signal(signal: *mut {signal_enum_name}) {{
                         if !signal.is_null() {{
-                            drop(Box::from_raw(signal));
+                            // SAFETY: Non-null signals are created by `Box::into_raw` and dropped only once by C++
+                            drop(unsafe {{ Box::from_raw(signal) }});
                         }}
                     }}"#,
                     signal_enum_name = signal_enum_name,
+                    snake_module_name = snake_case(&schema.module_name),
                 });
+
+                // Forward the listener errors to the module
+                if ctx.listener_error_hook {
+                    impls.push(formatdoc! {
+                        r#"
+                        fn {snake_module_name}_on_listener_error(id: usize, signal_name: &str, message: &str) {{
+                            {module_name}::on_listener_error(id, signal_name, message);
+                        }}"#,
+                        module_name = pascal_case(&schema.module_name),
+                        snake_module_name = snake_case(&schema.module_name),
+                    });
+                }
                 
-                impls
-            }).collect::<Vec<_>>()
+                Ok(impls
+                    .into_iter()
+                    .map(|signal_impl| feature_gate(signal_impl, &schema.module_name, ctx.module_features))
+                    .collect())
+            }).collect::<Result<Vec<Vec<_>>, anyhow::Error>>()?.into_iter().flatten().collect::<Vec<_>>()
         } else {
             vec![]
         };
         
+        if ctx.schemas.iter().any(Schema::has_cancellable_methods) {
+            cxx_impls.push(formatdoc! {
+                r#"
+                fn create_cancellation_token() -> Box<CancellationToken> {{
+                    Box::new(CancellationToken::new())
+                }}"#,
+            });
+        }
+
         let impl_mods = impl_mods.join("\n");
         let cxx_impls = cxx_impls.join("\n\n");
         let signal_impls = signal_payload_impls.join("\n\n");
+        // Generated code should not fail the strict clippy checks of the consumer
         let content = formatdoc! {
             r#"
+            #![allow(clippy::all)]
+
             #[rustfmt::skip]
             use craby::prelude::*;
 
@@ -558,6 +808,8 @@ impl RsTemplate {
     /// Generate the `generated.rs` file for the given code generation results.
     ///
     /// ```rust,ignore
+    /// #![allow(clippy::all)]
+    ///
     /// use craby::prelude::*;
     ///
     /// use crate::ffi::bridging::*;
@@ -566,24 +818,51 @@ impl RsTemplate {
     ///     fn multiply(&mut self, a: f64, b: f64) -> f64;
     /// }
     /// ```
-    pub fn generated_rs(&self, schemas: &[Schema]) -> Result<String, anyhow::Error> {
+    pub fn generated_rs(
+        &self,
+        schemas: &[Schema],
+        listener_error_hook: bool,
+        module_features: bool,
+    ) -> Result<String, anyhow::Error> {
         let mut spec_codes = Vec::with_capacity(schemas.len());
-        let mut type_aliases = BTreeMap::new();
+        // Type implementations and the modules that use them
+        let mut type_aliases: BTreeMap<u64, (String, Vec<&str>)> = BTreeMap::new();
 
         for schema in schemas {
             // Collect the type implementations
-            schema.try_collect_type_impls(&mut type_aliases)?;
-            spec_codes.push(self.rs_spec(schema)?);
+            let mut schema_type_aliases = BTreeMap::new();
+            schema.try_collect_type_impls(&mut schema_type_aliases)?;
+            for (id, type_impl) in schema_type_aliases {
+                type_aliases
+                    .entry(id)
+                    .or_insert_with(|| (type_impl, vec![]))
+                    .1
+                    .push(&schema.module_name);
+            }
+
+            let spec_code = self.rs_spec(schema, listener_error_hook)?;
+            spec_codes.push(feature_gate(spec_code, &schema.module_name, module_features));
         }
 
         let hash = Schema::to_hash(schemas);
         let hash_comment = format!("{HASH_COMMENT_PREFIX} {hash}");
-        let type_impls = type_aliases.into_values().collect::<Vec<_>>();
+        let type_impls = type_aliases
+            .into_values()
+            .map(|(type_impl, module_names)| {
+                if module_features {
+                    attr_items(&type_impl, &rs_feature_cfg(&module_names))
+                } else {
+                    type_impl
+                }
+            })
+            .collect::<Vec<_>>();
 
         let content = [
             vec![formatdoc! {
                 r#"
                 {hash_comment}
+                #![allow(clippy::all)]
+
                 #[rustfmt::skip]
                 use craby::prelude::*;
 
@@ -609,31 +888,64 @@ impl Template for RsTemplate {
     ) -> Result<Vec<TemplateResult>, anyhow::Error> {
         let base_path = crate_dir(&ctx.root).join("src");
         let res = match file_type {
-            RsFileType::CrateEntry => vec![TemplateResult {
-                path: base_path.join("lib.rs"),
-                content: self.lib_rs(&ctx.schemas)?,
-                overwrite: false,
-            }],
+            RsFileType::CrateEntry => {
+                let path = base_path.join("lib.rs");
+
+                // Declare the newly added modules in the existing `lib.rs`
+                let merged_content = if path.try_exists()? {
+                    let existing = fs::read_to_string(&path)?;
+                    self.merge_lib_rs(
+                        &existing,
+                        &ctx.schemas,
+                        ctx.c_shim,
+                        ctx.module_features,
+                    )
+                } else {
+                    None
+                };
+
+                match merged_content {
+                    Some(content) => vec![TemplateResult {
+                        path,
+                        content,
+                        overwrite: false,
+                        merged: true,
+                    }],
+                    None => vec![TemplateResult {
+                        path,
+                        content: self.lib_rs(&ctx.schemas, ctx.c_shim, ctx.module_features)?,
+                        overwrite: false,
+                        merged: false,
+                    }],
+                }
+            }
             RsFileType::FFIEntry => vec![TemplateResult {
                 path: base_path.join("ffi.rs"),
                 content: self.ffi_rs(ctx)?,
                 overwrite: true,
+                merged: false,
             }],
             RsFileType::Generated => vec![TemplateResult {
                 path: base_path.join("generated.rs"),
-                content: self.generated_rs(&ctx.schemas)?,
+                content: self.generated_rs(
+                    &ctx.schemas,
+                    ctx.listener_error_hook,
+                    ctx.module_features,
+                )?,
                 overwrite: true,
+                merged: false,
             }],
             RsFileType::ModImpl => ctx
                 .schemas
                 .iter()
                 .map(|schema| -> Result<TemplateResult, anyhow::Error> {
-                    let impl_code = self.rs_impl(schema)?;
+                    let impl_code = self.rs_impl(schema, ctx.stub_defaults)?;
 
                     Ok(TemplateResult {
                         path: base_path.join(format!("{}.rs", impl_mod_name(&schema.module_name))),
                         content: impl_code,
                         overwrite: false,
+                        merged: false,
                     })
                 })
                 .collect::<Result<Vec<_>, _>>()?,
@@ -690,7 +1002,10 @@ impl GeneratorInvoker for RsGenerator {
 mod tests {
     use insta::assert_snapshot;
 
-    use crate::tests::get_codegen_context;
+    use crate::{
+        parser::native_spec_parser::try_parse_schema, types::FfiSurface,
+        tests::get_codegen_context,
+    };
 
     use super::*;
 
@@ -707,4 +1022,977 @@ mod tests {
 
         assert_snapshot!(result);
     }
+
+    #[test]
+    fn test_rs_generator_clippy_allow() {
+        let ctx = get_codegen_context();
+
+        for file_type in [RsFileType::FFIEntry, RsFileType::Generated] {
+            let results = RsTemplate.render(&ctx, &file_type).unwrap();
+            assert!(results[0].content.contains("#![allow(clippy::all)]"));
+        }
+    }
+
+    #[test]
+    fn test_rs_generator_stub_defaults() {
+        let mut ctx = get_codegen_context();
+        ctx.stub_defaults = true;
+        let results = RsTemplate.render(&ctx, &RsFileType::ModImpl).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_generator_void_promise() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                voidPromiseMethod(arg: number): Promise<void>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+
+        // `Result<(), anyhow::Error>` of the implementation maps to cxx `Result<()>` (`void` in C++)
+        assert!(bridge.func_extern_sigs[1].contains("-> Result<()>;"));
+        assert!(bridge.func_impls[1].contains("-> Result<(), anyhow::Error> {"));
+        assert_snapshot!([
+            bridge.func_extern_sigs.join("\n"),
+            bridge.func_impls.join("\n"),
+        ]
+        .join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_jsdoc() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                /** Horizontal position in pixels. */
+                x: number;
+                y: number;
+            }
+
+            export interface Spec extends NativeModule {
+                /**
+                 * Multiplies two numbers.
+                 *
+                 * Returns the product.
+                 * @readonly
+                 */
+                multiply(a: number, b: number): number;
+                moveTo(point: Point): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+        let impl_code = RsTemplate.rs_impl(&schemas[0], false).unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+
+        assert!(impl_code.contains(
+            "    /// Multiplies two numbers.\n    ///\n    /// Returns the product.\n    fn multiply("
+        ));
+        assert!(bridge
+            .struct_defs
+            .iter()
+            .any(|def| def.contains("    /// Horizontal position in pixels.\n    x: f64,")));
+        assert_snapshot!(spec);
+    }
+
+    #[test]
+    fn test_rs_generator_enum_array() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum MyEnum {
+                Foo = 'foo',
+                Bar = 'bar',
+            }
+
+            export interface Spec extends NativeModule {
+                getEnums(): MyEnum[];
+                getEnumsAsync(): Promise<MyEnum[]>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+
+        assert!(bridge.func_extern_sigs[1].contains("-> Result<Vec<MyEnum>>;"));
+        assert!(bridge.func_extern_sigs[2].contains("-> Result<Vec<MyEnum>>;"));
+        assert_snapshot!([
+            bridge.enum_defs.join("\n"),
+            bridge.func_extern_sigs.join("\n"),
+            bridge.func_impls.join("\n"),
+        ]
+        .join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_recursive_type() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface ListNode {
+                value: number;
+                next: ListNode | null;
+            }
+
+            export interface Spec extends NativeModule {
+                getList(): ListNode;
+                getTail(head: ListNode | null): ListNode | null;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+        let mut type_impls = BTreeMap::new();
+        schemas[0].try_collect_type_impls(&mut type_impls).unwrap();
+
+        assert!(spec.contains("fn get_tail(&mut self, head: Nullable<Box<ListNode>>) -> Nullable<Box<ListNode>>;"));
+        assert_snapshot!([
+            bridge.struct_defs.join("\n"),
+            type_impls.into_values().collect::<Vec<_>>().join("\n\n"),
+            bridge.func_impls.join("\n"),
+        ]
+        .join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_map() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                x: number;
+                y: number;
+            }
+
+            export interface Palette {
+                colors: Record<string, string>;
+            }
+
+            export interface Spec extends NativeModule {
+                getScores(): Record<string, number>;
+                setPoints(points: Record<string, Point>): void;
+                getPalette(): Promise<Palette>;
+                getScoresAsync(): Promise<Record<string, number>>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+        let mut struct_defs = bridge.struct_defs.clone();
+        struct_defs.sort();
+
+        assert!(spec.contains("fn get_scores(&mut self) -> Map<Number>;"));
+        assert!(spec.contains("fn set_points(&mut self, points: Map<Point>) -> Void;"));
+        assert_snapshot!([
+            struct_defs.join("\n"),
+            bridge.func_extern_sigs.join("\n"),
+            bridge.func_impls.join("\n"),
+        ]
+        .join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_int_types() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Int32, Int64 } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                getTimestamp(handle: Int32): Int64;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+
+        assert!(spec.contains("fn get_timestamp(&mut self, handle: Int32) -> Int64;"));
+        assert!(bridge.func_extern_sigs.contains(
+            &"#[cxx_name = \"getTimestamp\"]\nfn craby_test_get_timestamp(it_: &mut CrabyTest, handle: i32) -> Result<i64>;".to_string()
+        ));
+    }
+
+    #[test]
+    fn test_rs_generator_bigint_type() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                getRowId(key: bigint | null): bigint;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+
+        assert!(spec.contains("fn get_row_id(&mut self, key: Nullable<BigInt>) -> BigInt;"));
+        assert!(bridge.func_extern_sigs.contains(
+            &"#[cxx_name = \"getRowId\"]\nfn craby_test_get_row_id(it_: &mut CrabyTest, key: NullableBigInt) -> Result<i64>;".to_string()
+        ));
+        assert!(bridge.struct_defs.iter().any(|def| def.contains("struct NullableBigInt {")));
+    }
+
+    #[test]
+    fn test_rs_generator_nested_array() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                x: number;
+                y: number;
+            }
+
+            export interface Path {
+                segments: Point[][];
+            }
+
+            export interface Spec extends NativeModule {
+                getGrid(): number[][];
+                setCubes(cubes: number[][][]): void;
+                getPath(): Promise<Path>;
+                getGridAsync(): Promise<number[][]>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+        let mut struct_defs = bridge.struct_defs.clone();
+        struct_defs.sort();
+
+        assert!(spec.contains("fn get_grid(&mut self) -> Array<Array<Number>>;"));
+        assert!(spec.contains("fn set_cubes(&mut self, cubes: Array<Array<Array<Number>>>) -> Void;"));
+        assert_snapshot!([
+            struct_defs.join("\n"),
+            bridge.func_extern_sigs.join("\n"),
+            bridge.func_impls.join("\n"),
+        ]
+        .join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_tuple() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                x: number;
+                y: number;
+            }
+
+            export interface Segment {
+                ends: [Point, Point];
+            }
+
+            export interface Spec extends NativeModule {
+                getSize(): [number, number];
+                setLabels(labels: [number, string][]): void;
+                findSegment(id: string): Promise<Segment>;
+                getRange(): [number, number] | null;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+        let mut struct_defs = bridge.struct_defs.clone();
+        struct_defs.sort();
+
+        assert!(spec.contains("fn get_size(&mut self) -> (Number, Number);"));
+        assert!(spec.contains("fn set_labels(&mut self, labels: Array<(Number, String)>) -> Void;"));
+        assert!(spec.contains("fn get_range(&mut self) -> Nullable<(Number, Number)>;"));
+        assert_snapshot!([
+            struct_defs.join("\n"),
+            bridge.func_extern_sigs.join("\n"),
+            bridge.func_impls.join("\n"),
+        ]
+        .join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_listener_error_hook() {
+        let mut ctx = get_codegen_context();
+        ctx.listener_error_hook = true;
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface ProgressEvent {
+                progress: number;
+            }
+
+            export interface Spec extends NativeModule {
+                onProgress: Signal<ProgressEvent>;
+                onDone: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+
+        let generated = RsTemplate.generated_rs(&ctx.schemas, true, false).unwrap();
+        assert!(generated
+            .contains("fn on_listener_error(_id: usize, _signal_name: &str, _message: &str) {}"));
+
+        let results = RsTemplate.render(&ctx, &RsFileType::FFIEntry).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_ffi_surface() {
+        let ctx = get_codegen_context();
+        let surface = ctx.schemas[0].as_rs_cxx_bridge().unwrap().surface();
+
+        assert_eq!(
+            surface,
+            FfiSurface {
+                extern_funcs: 13,
+                structs: 2,
+                enums: 2,
+                nullables: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_rs_generator_enum_as_str() {
+        let schemas = try_parse_schema(
+            r#"
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum Status {
+                Active = 'active',
+                Quoted = 'say "hi"',
+            }
+
+            export enum Level {
+                Low = 0,
+                High = 1,
+            }
+
+            export interface Spec extends NativeModule {
+                myMethod(status: Status, level: Level): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            "#,
+        )
+        .unwrap();
+        let mut type_impls = BTreeMap::new();
+        schemas[0].try_collect_type_impls(&mut type_impls).unwrap();
+        let result = type_impls.into_values().collect::<Vec<_>>().join("\n\n");
+
+        assert!(!result.contains("impl Level {"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_generator_byte_length() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                setKey(/** @bytes 32 */ key: ArrayBuffer, iv: ArrayBuffer): void;
+                encrypt(/** @bytes 16 */ iv: ArrayBuffer): Promise<ArrayBuffer>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let results = RsTemplate.render(&ctx, &RsFileType::FFIEntry).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_generator_cancellable() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                /** @cancellable */
+                longTask(n: number): Promise<number>;
+                shortTask(n: number): Promise<number>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+
+        let sigs = ctx.schemas[0]
+            .methods
+            .iter()
+            .map(|method| method.try_into_impl_sig().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sigs,
+            [
+                "fn long_task(&mut self, n: Number, cancel_token: CancellationToken) -> Promise<Number>",
+                "fn short_task(&mut self, n: Number) -> Promise<Number>",
+            ]
+        );
+
+        let results = RsTemplate.render(&ctx, &RsFileType::FFIEntry).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_generator_throws() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            type FetchError = {
+                code: number;
+                reason: string;
+            };
+
+            export interface Spec extends NativeModule {
+                /** @throws FetchError */
+                fetch(url: string): Promise<string>;
+                /** @throws FetchError */
+                ping(): Promise<void>;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+
+        let sigs = ctx.schemas[0]
+            .methods
+            .iter()
+            .map(|method| method.try_into_impl_sig().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sigs,
+            [
+                "fn fetch(&mut self, url: &str) -> Promise<String, FetchError>",
+                "fn ping(&mut self) -> Promise<Void, FetchError>",
+            ]
+        );
+
+        let results = RsTemplate.render(&ctx, &RsFileType::FFIEntry).unwrap();
+        let result = results
+            .iter()
+            .map(|res| format!("{}\n{}", res.path.display(), res.content))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        assert!(result.contains("error_: &mut NullableFetchError"));
+        assert_snapshot!(result);
+
+        // Default stubs resolve with the custom error type of the return type
+        ctx.stub_defaults = true;
+        let results = RsTemplate.render(&ctx, &RsFileType::ModImpl).unwrap();
+
+        assert!(results[0].content.contains("promise::resolve_with(String::default())"));
+        assert!(results[0].content.contains("promise::resolve_with(())"));
+    }
+
+    #[test]
+    fn test_rs_generator_merge_lib_rs() {
+        let root = std::env::temp_dir().join(format!("craby-{}", uuid::Uuid::new_v4()));
+        let mut ctx = get_codegen_context();
+        ctx.root = root.clone();
+
+        // Existing project with the first module only
+        let lib_rs = RsTemplate.render(&ctx, &RsFileType::CrateEntry).unwrap();
+        fs::create_dir_all(lib_rs[0].path.parent().unwrap()).unwrap();
+        fs::write(&lib_rs[0].path, format!("{}\n", lib_rs[0].content)).unwrap();
+
+        // Add a second module
+        ctx.schemas.extend(
+            try_parse_schema(
+                "
+                import type { NativeModule } from 'craby-modules';
+                import { NativeModuleRegistry } from 'craby-modules';
+
+                export interface Spec extends NativeModule {
+                    add(a: number, b: number): number;
+                }
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('Calculator');
+                ",
+            )
+            .unwrap(),
+        );
+        let merged = RsTemplate.render(&ctx, &RsFileType::CrateEntry).unwrap();
+        fs::write(&merged[0].path, format!("{}\n", merged[0].content)).unwrap();
+
+        // Already declared modules are left as they are
+        let unchanged = RsTemplate.render(&ctx, &RsFileType::CrateEntry).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(!lib_rs[0].merged);
+        assert!(merged[0].merged);
+        assert!(!unchanged[0].merged);
+        assert_snapshot!(merged[0].content);
+    }
+
+    #[test]
+    fn test_rs_generator_module_features() {
+        let mut ctx = get_codegen_context();
+        ctx.module_features = true;
+        ctx.schemas.extend(
+            try_parse_schema(
+                "
+                import type { NativeModule } from 'craby-modules';
+                import { NativeModuleRegistry } from 'craby-modules';
+
+                export interface Spec extends NativeModule {
+                    add(a: number, b: number): number;
+                }
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('Calculator');
+                ",
+            )
+            .unwrap(),
+        );
+        let result = [
+            RsFileType::CrateEntry,
+            RsFileType::FFIEntry,
+            RsFileType::Generated,
+        ]
+        .iter()
+        .flat_map(|file_type| RsTemplate.render(&ctx, file_type).unwrap())
+        .map(|res| format!("{}\n{}", res.path.display(), res.content))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_generator_enum_try_from() {
+        let schemas = try_parse_schema(
+            r#"
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum Status {
+                Active = 'active',
+                Quoted = 'say "hi"',
+            }
+
+            export enum Level {
+                Low = -1,
+                High = 1,
+            }
+
+            export interface Spec extends NativeModule {
+                myMethod(status: Status, level: Level): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            "#,
+        )
+        .unwrap();
+        let mut type_impls = BTreeMap::new();
+        schemas[0].try_collect_type_impls(&mut type_impls).unwrap();
+        let result = type_impls.into_values().collect::<Vec<_>>().join("\n\n");
+
+        assert!(result.contains("impl TryFrom<&str> for Status {"));
+        assert!(result.contains("impl TryFrom<i64> for Level {"));
+        assert_snapshot!(result);
+    }
+
+    #[test]
+    fn test_rs_generator_enum_repr() {
+        let schemas = try_parse_schema(
+            r#"
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum Level {
+                Low = -1,
+                High,
+            }
+
+            export enum Timestamp {
+                Epoch,
+                Far = 4294967296,
+            }
+
+            export interface Spec extends NativeModule {
+                myMethod(level: Level, timestamp: Timestamp): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            "#,
+        )
+        .unwrap();
+        let enum_defs = schemas[0].as_rs_cxx_bridge().unwrap().enum_defs;
+
+        assert_snapshot!(enum_defs.join("\n\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_signal_name_consts() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface ProgressEvent {
+                progress: number;
+            }
+
+            export interface Spec extends NativeModule {
+                onProgress: Signal<ProgressEvent>;
+                onDone: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+
+        assert!(spec.contains("pub const ON_DONE: &str = \"onDone\";"));
+        assert!(spec.contains("pub const ON_PROGRESS: &str = \"onProgress\";"));
+        assert!(spec.contains("manager.emit(self.id(), CrabyTestSignal::ON_PROGRESS, signal_ptr);"));
+        assert_snapshot!(spec);
+    }
+
+    #[test]
+    fn test_rs_generator_multiple_signal_modules() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = ["Camera", "Player"]
+            .iter()
+            .flat_map(|module_name| {
+                try_parse_schema(&format!(
+                    "
+                    import type {{ NativeModule, Signal }} from 'craby-modules';
+                    import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                    export interface {module_name}Event {{
+                        value: number;
+                    }}
+
+                    export interface Spec extends NativeModule {{
+                        onChange: Signal<{module_name}Event>;
+                    }}
+
+                    export default NativeModuleRegistry.getEnforcing<Spec>('{module_name}');
+                    "
+                ))
+                .unwrap()
+            })
+            .collect();
+        let ffi = RsTemplate.render(&ctx, &RsFileType::FFIEntry).unwrap();
+        let ffi = &ffi[0].content;
+
+        // Each module owns its signal type, payload getters, drop function and manager
+        assert!(ffi.contains("unsafe fn drop_camera_signal(signal: *mut CameraSignal);"));
+        assert!(ffi.contains("unsafe fn drop_player_signal(signal: *mut PlayerSignal);"));
+        assert!(ffi.contains("fn get_on_change_payload(self: &CameraSignal) -> CameraEvent;"));
+        assert!(ffi.contains("fn get_on_change_payload(self: &PlayerSignal) -> PlayerEvent;"));
+        assert!(ffi.contains("type CameraSignalManager;"));
+        assert!(ffi.contains("type PlayerSignalManager;"));
+        assert!(!ffi.contains("fn drop_signal("));
+        assert_snapshot!(ffi);
+    }
+
+    #[test]
+    fn test_rs_generator_signal_object_payload() {
+        let mut ctx = get_codegen_context();
+        ctx.schemas = try_parse_schema(
+            "
+            import type { NativeModule, Signal } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Transfer {
+                id: string;
+            }
+
+            export type ProgressEvent = {
+                transfer: Transfer;
+                progress: number;
+            };
+
+            export interface Spec extends NativeModule {
+                onProgress: Signal<ProgressEvent>;
+                onDone: Signal;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let ffi = RsTemplate.render(&ctx, &RsFileType::FFIEntry).unwrap();
+        let ffi = &ffi[0].content;
+        let spec = RsTemplate.rs_spec(&ctx.schemas[0], false).unwrap();
+
+        assert!(ffi.contains("fn get_on_progress_payload(self: &CrabyTestSignal) -> ProgressEvent;"));
+        assert!(ffi.contains("fn get_on_progress_payload(&self) -> ProgressEvent {"));
+        assert!(ffi.contains("struct ProgressEvent {"));
+        assert!(ffi.contains("struct Transfer {"));
+        assert!(spec.contains("OnProgress(ProgressEvent),"));
+        assert!(spec.contains("OnDone,"));
+    }
+
+    #[test]
+    fn test_rs_generator_readonly() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                /** @readonly */
+                getVersion(): string;
+                setVersion(version: string): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let spec = RsTemplate.rs_spec(&schemas[0], false).unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+
+        assert!(spec.contains("fn get_version(&self) -> String;"));
+        assert!(spec.contains("fn set_version(&mut self, version: &str) -> Void;"));
+        assert!(bridge
+            .func_extern_sigs
+            .iter()
+            .any(|sig| sig.contains("fn craby_test_get_version(it_: &CrabyTest) -> Result<String>;")));
+        assert!(bridge
+            .func_extern_sigs
+            .iter()
+            .any(|sig| sig.contains("fn craby_test_set_version(it_: &mut CrabyTest, version: &str) -> Result<()>;")));
+    }
+
+    #[test]
+    fn test_rs_generator_hashable() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule, Int32 } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            /** @hashable */
+            export interface Owner {
+                id: string;
+            }
+
+            /**
+             * Key of the cached results
+             * @hashable
+             */
+            export type CacheKey = {
+                owner: Owner;
+                page: Int32;
+                tags: string[];
+                grid: Int32[][];
+                raw: ArrayBuffer;
+            };
+
+            export interface Result {
+                score: number;
+            }
+
+            export interface Spec extends NativeModule {
+                lookup(key: CacheKey): Result;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+            ",
+        )
+        .unwrap();
+        let bridge = schemas[0].as_rs_cxx_bridge().unwrap();
+        let mut struct_defs = bridge.struct_defs.clone();
+        struct_defs.sort();
+
+        assert_snapshot!(struct_defs.join("\n"));
+    }
+
+    #[test]
+    fn test_rs_generator_struct_order() {
+        let types = [
+            "export interface Point { x: number; y: number | null; }",
+            "export interface Segment { start: Point; end: Point; label: string | null; }",
+            "export type Bounds = { min: [number, number]; max: [number, number]; };",
+        ];
+        let spec = |types: &[&str]| {
+            formatdoc! {
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                {types}
+
+                export interface Spec extends NativeModule {{
+                    draw(segment: Segment, bounds: Bounds | null): Point;
+                    getGrid(): number[][];
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+                ",
+                types = types.join("\n"),
+            }
+        };
+        let ffi_rs = |src: &str| {
+            let mut ctx = get_codegen_context();
+            ctx.schemas = try_parse_schema(src).unwrap();
+            RsTemplate.ffi_rs(&ctx).unwrap()
+        };
+
+        let src = spec(&types);
+        let bridge = try_parse_schema(&src).unwrap()[0].as_rs_cxx_bridge().unwrap();
+        let names = bridge
+            .struct_defs
+            .iter()
+            .map(|def| def.lines().find_map(|line| line.strip_prefix("struct ")).unwrap())
+            .collect::<Vec<_>>();
+        let mut sorted_names = names.clone();
+        sorted_names.sort();
+
+        assert_eq!(names, sorted_names);
+
+        // Regenerating or reordering the type declarations yields the same `ffi.rs`
+        let ffi = ffi_rs(&src);
+        let mut reversed_types = types;
+        reversed_types.reverse();
+
+        assert_eq!(ffi, ffi_rs(&src));
+        assert_eq!(ffi, ffi_rs(&spec(&reversed_types)));
+    }
+
+    #[test]
+    fn test_rs_generator_invalid_hashable() {
+        let cases = [
+            (
+                "score: number;",
+                "`@hashable` type `CacheKey` cannot have the number field `score` (`f64` is not `Eq` and `Hash`). Use `Int32` or `Int64` instead",
+            ),
+            (
+                "scores: number[];",
+                "`@hashable` type `CacheKey` cannot have the number field `scores` (`f64` is not `Eq` and `Hash`). Use `Int32` or `Int64` instead",
+            ),
+            (
+                "grid: number[][];",
+                "`@hashable` type `CacheKey` cannot have the number field `grid` (`f64` is not `Eq` and `Hash`). Use `Int32` or `Int64` instead",
+            ),
+            (
+                "owner: Owner;",
+                "Object type `Owner` of the field `owner` must be `@hashable` to be used in `@hashable` type `CacheKey`",
+            ),
+            (
+                "label: string | null;",
+                "`@hashable` type `CacheKey` can only have boolean, string, integer, `ArrayBuffer`, array or `@hashable` object fields (found `label`)",
+            ),
+        ];
+
+        for (field, expected) in cases {
+            let schemas = try_parse_schema(&format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Owner {{
+                    id: string;
+                }}
+
+                /** @hashable */
+                export interface CacheKey {{
+                    {field}
+                }}
+
+                export interface Spec extends NativeModule {{
+                    lookup(key: CacheKey): void;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+                "
+            ))
+            .unwrap();
+            let err = schemas[0].as_rs_cxx_bridge().unwrap_err();
+
+            assert_eq!(err.to_string(), expected);
+        }
+    }
 }
@@ -30,4 +30,6 @@ pub struct TemplateResult {
     pub content: String,
     pub path: PathBuf,
     pub overwrite: bool,
+    /// The content is merged into the existing file, so it should be written even if `overwrite` is `false`
+    pub merged: bool,
 }
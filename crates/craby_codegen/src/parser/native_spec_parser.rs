@@ -1,8 +1,14 @@
-use log::debug;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use craby_common::utils::string::{pascal_case, snake_case};
+use log::{debug, trace, warn};
 use oxc::{
     allocator::Allocator,
-    ast::ast::*,
-    ast_visit::Visit,
+    ast::{ast::*, Comment},
+    ast_visit::{walk, Visit},
     diagnostics::OxcDiagnostic,
     parser::Parser,
     semantic::{Scoping, SemanticBuilder, SymbolId},
@@ -10,59 +16,140 @@ use oxc::{
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
+    common::IntoCode,
     constants::specs::*,
-    parser::{types::*, utils::error},
+    parser::{
+        types::*,
+        utils::{error, jsdoc_description, jsdoc_tags, warning_str},
+    },
     types::Schema,
 };
 
+/// `Number.MAX_SAFE_INTEGER`
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+/// `2^63`, the numbers below this are converted to `i64` without saturation
+const I64_RANGE: f64 = 9223372036854775808.0;
+
+/// Names imported from a local file (module specifier, [(local id, imported name)])
+type LocalImport = (String, Vec<(SymbolId, String)>);
+
+/// Names re-exported from a local file (module specifier, [(exported name, local name)]),
+/// or `None` for `export * from '...'`
+type LocalReexport = (String, Option<Vec<(String, String)>>);
+
+/// Declarations resolved from a local file and the names re-exported from the other files
+type LocalExports = (Vec<(SymbolId, TypeAnnotation)>, Vec<LocalImport>);
+
 const INVALID_SPEC: &str = "Invalid specification";
 const INVALID_TYPE_REFERENCE: &str = "Invalid type reference";
 const INVALID_COMPUTED_SIG: &str = "Computed signature is not supported";
 const INVALID_OPTIONAL_SIG: &str = "Optional signature is not supported";
 const INVALID_OPTIONAL_PROP: &str = "Optional property is not supported";
-const INVALID_OPTIONAL_PARAM: &str = "Optional parameter is not supported";
+const INVALID_OPTIONAL_PARAM: &str =
+    "Optional parameter is only allowed at the end of the parameter list";
+const INVALID_DEFAULT_PARAM: &str =
+    "Default value must be a number, string or boolean literal matching the parameter type (eg. `b: number = 2`)";
 const INVALID_NO_SPEC_GENERIC: &str = "NativeModule specification generic argument is required";
 const INVALID_FUNC_PARAM: &str = "Function parameter is not supported";
 const INVALID_TYPE_LITERAL: &str =
     "Type literal is not supported. Use defined type reference instead";
 const INVALID_UNION_TYPE: &str = "Union types only allow nullable type (eg. `T | null`)";
+const INVALID_RECORD_KEY: &str = "Record key type must be `string` (eg. `Record<string, T>`)";
+const INVALID_RECORD_VALUE: &str =
+    "Record value type must not be a nullable, record or promise type (eg. `Record<string, number>`)";
+const INVALID_EMPTY_TUPLE: &str = "Empty tuple type is not supported";
+const INVALID_TUPLE_ELEMENT: &str =
+    "Tuple element type must not be an optional, rest, void, promise, nullable, record, nested array or tuple type (eg. `[number, string]`)";
+const INVALID_BIGINT_ARRAY: &str =
+    "Array of `bigint` is not supported. Use `Int64[]` instead (eg. `ids: Int64[]`)";
 const INVALID_MIXED_ENUM_MEMBER: &str =
     "Enum member type must be single type (eg. only `number` or `string`)";
 const INVALID_REGISTRY_METHOD: &str = "Invalid NativeModuleRegistry method";
 const INVALID_RESERVED_ARG_NAME_ID: &str = "Reserved argument name `it_` is not allowed";
 const INVALID_RESERVED_METHOD_NAME_ID: &str = "Reserved method name `emit` is not allowed";
+const INVALID_BYTES_ANNOTATION: &str =
+    "`@bytes` annotation requires a positive integer on `ArrayBuffer` parameter (eg. `@bytes 32`)";
+const INVALID_CANCELLABLE_ANNOTATION: &str =
+    "`@cancellable` annotation is only allowed on methods returning `Promise`";
+const INVALID_DETACHED_ANNOTATION: &str =
+    "`@detached` annotation is only allowed on methods returning `Promise<void>` without `@cancellable`";
+const INVALID_RESERVED_ARG_NAME_CANCEL_TOKEN: &str =
+    "Reserved argument name `cancel_token` is not allowed in `@cancellable` methods";
+const INVALID_THROWS_ANNOTATION: &str =
+    "`@throws` annotation requires an object type on methods returning `Promise` without `@cancellable` or `@detached` (eg. `@throws MyError`)";
+const INVALID_PLATFORM_ANNOTATION: &str =
+    "`@platform` annotation requires `ios` or `android` (eg. `@platform ios`)";
+const INVALID_FALLBACK_ANNOTATION: &str =
+    "`@fallback` annotation is only allowed on a single member of string enum";
+const INVALID_ENUM_NUMBER_RANGE: &str = "Enum member value must be a 64-bit integer";
+const UNSAFE_ENUM_NUMBER: &str =
+    "Enum member value is not a safe integer (between `-(2^53 - 1)` and `2^53 - 1`) and may lose precision in JavaScript. Add `@lossy` to the member or the enum to suppress this warning";
+const INVALID_DUPLICATE_ENUM_NUMBER: &str = "Enum member value must be unique";
+const INVALID_SPEC_EXTENDS: &str =
+    "Spec interface can only extend `NativeModule` (declare the members in the spec interface)";
+const INVALID_HASHABLE_ANNOTATION: &str = "`@hashable` annotation is only allowed on object types";
+const INVALID_CONST_ENUM_MEMBER: &str =
+    "`as const` enum member must be a string literal with identifier key (eg. `{ Foo: 'foo' }`)";
 
 pub struct NativeModuleAnalyzer<'a> {
     pub diagnostics: Vec<OxcDiagnostic>,
+    /// Diagnostics that do not fail the parsing (eg. enum values that may lose precision)
+    pub warnings: Vec<OxcDiagnostic>,
     scoping: &'a Scoping,
+    source_text: &'a str,
+    comments: &'a [Comment],
     /// Symbol ID of `NativeModule` identifier's reference
     mod_type_sym_id: Option<SymbolId>,
     /// Symbol ID of `Signal` identifier's reference
     mod_signal_sym_id: Option<SymbolId>,
+    /// Symbol ID of `Int32` identifier's reference
+    mod_int32_sym_id: Option<SymbolId>,
+    /// Symbol ID of `Int64` identifier's reference
+    mod_int64_sym_id: Option<SymbolId>,
     /// Symbol ID of `NativeModuleRegistry` identifier's reference
     mod_reg_sym_id: Option<SymbolId>,
     /// Symbol ID of `react-native` namespace's reference
     mod_ns_sym_id: Option<SymbolId>,
+    /// Symbol IDs of the variables aliasing `NativeModuleRegistry` (eg. `const Registry = NativeModuleRegistry`)
+    mod_reg_alias_sym_ids: FxHashSet<SymbolId>,
     /// NativeModules collected from the source code
     mods: FxHashMap<SymbolId, String>,
     /// Declarations collected from the source code
     decls: FxHashMap<SymbolId, TypeAnnotation>,
     /// NativeModule specs collected from the source code
     specs: FxHashMap<SymbolId, Spec>,
+    /// `as const` object literals collected from the source code
+    const_objs: FxHashMap<SymbolId, Result<Vec<EnumMember>, OxcDiagnostic>>,
+    /// Type aliases referencing `as const` object literals (alias id, alias name, object id, span)
+    const_enum_aliases: Vec<(SymbolId, String, SymbolId, Span)>,
+    /// Types imported from the local files with `import type` or type-only specifiers
+    local_imports: Vec<LocalImport>,
+    /// Names re-exported from the local files
+    reexports: Vec<LocalReexport>,
 }
 
 impl<'a> NativeModuleAnalyzer<'a> {
-    fn new(scoping: &'a Scoping) -> Self {
+    fn new(scoping: &'a Scoping, source_text: &'a str, comments: &'a [Comment]) -> Self {
         Self {
             scoping,
+            source_text,
+            comments,
             diagnostics: vec![],
+            warnings: vec![],
             mod_type_sym_id: None,
             mod_signal_sym_id: None,
+            mod_int32_sym_id: None,
+            mod_int64_sym_id: None,
             mod_reg_sym_id: None,
             mod_ns_sym_id: None,
+            mod_reg_alias_sym_ids: FxHashSet::default(),
             specs: FxHashMap::default(),
             mods: FxHashMap::default(),
             decls: FxHashMap::default(),
+            const_objs: FxHashMap::default(),
+            const_enum_aliases: vec![],
+            local_imports: vec![],
+            reexports: vec![],
         }
     }
 
@@ -77,14 +164,138 @@ impl<'a> NativeModuleAnalyzer<'a> {
         };
 
         if let Some(mod_name) = self.as_mod_name(it) {
+            trace!(
+                "Module registration collected: {} (spec: {:?})",
+                mod_name,
+                spec_id
+            );
             drop(self.mods.insert(spec_id, mod_name))
         };
     }
 
+    fn collect_reg_alias(&mut self, it: &VariableDeclarator<'a>) {
+        let (ident, init) = match (it.id.get_binding_identifier(), &it.init) {
+            (Some(ident), Some(init)) => (ident, init.without_parentheses()),
+            _ => return,
+        };
+
+        let is_reg = match init {
+            // `const Registry = NativeModuleRegistry;`
+            Expression::Identifier(ident) => self.is_reg_ref(ident),
+            // `const Registry = Craby.NativeModuleRegistry;`
+            Expression::StaticMemberExpression(member) => {
+                member.property.name == NATIVE_MODULE_REGISTRY
+                    && match &member.object {
+                        Expression::Identifier(ident) => {
+                            let sym_id =
+                                self.scoping.get_reference(ident.reference_id()).symbol_id();
+                            self.mod_ns_sym_id
+                                .zip(sym_id)
+                                .is_some_and(|(id, s)| id == s)
+                        }
+                        _ => false,
+                    }
+            }
+            _ => false,
+        };
+
+        if is_reg {
+            trace!(
+                "NativeModuleRegistry alias collected: {} ({:?})",
+                ident.name,
+                ident.symbol_id()
+            );
+            self.mod_reg_alias_sym_ids.insert(ident.symbol_id());
+        }
+    }
+
+    fn collect_local_import(&mut self, it: &ImportDeclaration<'a>) {
+        let specifiers = match &it.specifiers {
+            Some(specifiers) => specifiers,
+            None => return,
+        };
+
+        let mut imported = vec![];
+
+        for specifier in specifiers {
+            match specifier {
+                // Value imports (eg. constants, helper functions) are not followed
+                ImportDeclarationSpecifier::ImportSpecifier(spec)
+                    if !it.import_kind.is_type() && !spec.import_kind.is_type() =>
+                {
+                    trace!(
+                        "Local value import skipped: {} from {}",
+                        spec.local.name,
+                        it.source.value
+                    );
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+                    let imported_name = match &spec.imported {
+                        ModuleExportName::IdentifierName(ident) => ident.name,
+                        ModuleExportName::IdentifierReference(ident) => ident.name,
+                        ModuleExportName::StringLiteral(lit) => lit.value,
+                    };
+
+                    trace!(
+                        "Local import collected: {} as {} from {}",
+                        imported_name,
+                        spec.local.name,
+                        it.source.value
+                    );
+                    imported.push((spec.local.symbol_id(), imported_name.to_string()));
+                }
+                _ => trace!("Local import specifier skipped: {}", it.source.value),
+            }
+        }
+
+        if !imported.is_empty() {
+            self.local_imports
+                .push((it.source.value.to_string(), imported));
+        }
+    }
+
+    /// Collects the names re-exported from the local files (eg. `export type { Foo } from './types'`),
+    /// so the types imported through the file are followed to the declaring file.
+    fn collect_reexport(&mut self, source: &str, specifiers: Option<&[ExportSpecifier<'a>]>) {
+        let specifiers = specifiers.map(|specifiers| {
+            specifiers
+                .iter()
+                .map(|spec| {
+                    (
+                        spec.exported.name().to_string(),
+                        spec.local.name().to_string(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+
+        trace!(
+            "Local re-export collected: {:?} from {}",
+            specifiers,
+            source
+        );
+        self.reexports.push((source.to_string(), specifiers));
+    }
+
+    /// Returns `true` if the identifier references `NativeModuleRegistry` or its alias.
+    fn is_reg_ref(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.scoping
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|sym_id| {
+                self.mod_reg_sym_id == Some(sym_id) || self.mod_reg_alias_sym_ids.contains(&sym_id)
+            })
+    }
+
     fn collect_spec(&mut self, it: &TSInterfaceDeclaration<'a>) {
         let mut methods = vec![];
         let mut signals = vec![];
 
+        // Members of the other base interfaces are not collected
+        if it.extends.len() > 1 {
+            self.collect_error(INVALID_SPEC_EXTENDS, it.span);
+        }
+
         for sig in &it.body.body {
             match sig {
                 TSSignature::TSMethodSignature(method_sig) => {
@@ -104,6 +315,13 @@ impl<'a> NativeModuleAnalyzer<'a> {
         }
 
         let name = it.id.name.to_string();
+        trace!(
+            "Spec collected: {} ({:?}, methods: {}, signals: {})",
+            name,
+            it.id.symbol_id(),
+            methods.len(),
+            signals.len()
+        );
         self.specs.insert(
             it.id.symbol_id(),
             Spec {
@@ -144,9 +362,14 @@ impl<'a> NativeModuleAnalyzer<'a> {
             }
         }
 
+        trace!("Interface type collected: {} ({:?})", name, id);
         self.decls.insert(
             id,
-            TypeAnnotation::Object(ObjectTypeAnnotation { name, props }),
+            TypeAnnotation::Object(ObjectTypeAnnotation {
+                name,
+                props,
+                hashable: self.is_hashable(it.span.start),
+            }),
         );
     }
 
@@ -163,6 +386,11 @@ impl<'a> NativeModuleAnalyzer<'a> {
 
         let id = it.id.symbol_id();
         let name = it.id.name.to_string();
+        let hashable = self.is_hashable(it.span.start);
+
+        if hashable && !matches!(it.type_annotation, TSType::TSTypeLiteral(..)) {
+            return self.collect_error(INVALID_HASHABLE_ANNOTATION, it.span);
+        }
 
         match &it.type_annotation {
             TSType::TSTypeLiteral(type_lit) => {
@@ -183,31 +411,356 @@ impl<'a> NativeModuleAnalyzer<'a> {
 
                 match props {
                     Ok(props) => {
+                        trace!("Type alias collected: {} ({:?})", name, id);
                         self.decls.insert(
                             id,
-                            TypeAnnotation::Object(ObjectTypeAnnotation { name, props }),
+                            TypeAnnotation::Object(ObjectTypeAnnotation {
+                                name,
+                                props,
+                                hashable,
+                            }),
                         );
                     }
                     Err(e) => self.diagnostics.push(e),
                 }
             }
-            TSType::TSUnionType(union_type) => match self.try_into_nullable(union_type) {
-                Ok(type_annotation) => drop(self.decls.insert(id, type_annotation)),
-                Err(e) => self.diagnostics.push(error(&e.to_string(), it.span)),
+            TSType::TSUnionType(union_type) => {
+                match self.try_into_literal_enum(&name, union_type) {
+                    Some(Ok(type_annotation)) => {
+                        trace!("String literal union collected: {} ({:?})", name, id);
+                        drop(self.decls.insert(id, type_annotation))
+                    }
+                    Some(Err(e)) => self.diagnostics.push(e),
+                    None => match self.try_into_nullable(union_type) {
+                        Ok(type_annotation) => {
+                            trace!("Nullable type alias collected: {} ({:?})", name, id);
+                            drop(self.decls.insert(id, type_annotation))
+                        }
+                        Err(e) => self.diagnostics.push(error(&e.to_string(), it.span)),
+                    },
+                }
+            }
+            // Single string literal is treated as its base type (eg. `type Mode = 'read'`)
+            TSType::TSLiteralType(lit_type)
+                if matches!(lit_type.literal, TSLiteral::StringLiteral(..)) =>
+            {
+                trace!("String literal type alias collected: {} ({:?})", name, id);
+                self.decls.insert(id, TypeAnnotation::String);
+            }
+            TSType::TSIndexedAccessType(indexed_type) => match self.as_const_obj_id(indexed_type) {
+                Some(obj_id) => {
+                    trace!("`as const` enum alias collected: {} ({:?})", name, id);
+                    self.const_enum_aliases.push((id, name, obj_id, it.span))
+                }
+                None => self.collect_error(INVALID_SPEC, it.span),
             },
             _ => self.collect_error(INVALID_SPEC, it.span),
         }
     }
 
+    /// Converts the union of string literals into a string enum type.
+    /// Members are named after the PascalCase of the values (eg. `'read-only'` -> `ReadOnly`).
+    ///
+    /// ```ts
+    /// type Mode = 'read' | 'write' | 'append';
+    /// ```
+    ///
+    /// Returns `None` if any of the union members is not a string literal (eg. `T | null`).
+    fn try_into_literal_enum(
+        &self,
+        name: &str,
+        union_type: &TSUnionType<'a>,
+    ) -> Option<Result<TypeAnnotation, OxcDiagnostic>> {
+        let str_lits = union_type
+            .types
+            .iter()
+            .map(|ts_type| match ts_type {
+                TSType::TSLiteralType(lit_type) => match &lit_type.literal {
+                    TSLiteral::StringLiteral(str_lit) => Some(str_lit),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        // Single string literal is treated as its base type
+        if str_lits.len() == 1 {
+            return Some(Ok(TypeAnnotation::String));
+        }
+
+        let mut members: Vec<EnumMember> = Vec::with_capacity(str_lits.len());
+        for str_lit in str_lits {
+            let value = str_lit.value.to_string();
+            let member_name = pascal_case(&value);
+            let is_ident = member_name
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && member_name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+            if !is_ident {
+                return Some(Err(error(
+                    &format!(
+                        "String literal `'{value}'` cannot be converted to an enum member name"
+                    ),
+                    str_lit.span,
+                )));
+            }
+
+            if members.iter().any(|member| member.name == member_name) {
+                return Some(Err(error(
+                    &format!("Duplicate enum member `{member_name}` in the string literal union"),
+                    str_lit.span,
+                )));
+            }
+
+            members.push(EnumMember {
+                name: member_name,
+                value: EnumMemberValue::String(value),
+            });
+        }
+
+        Some(Ok(TypeAnnotation::Enum(EnumTypeAnnotation {
+            name: name.to_string(),
+            members,
+            fallback: None,
+            exported: false,
+            decl_file: None,
+        })))
+    }
+
+    /// Collect `as const` object literal which can be used as a string enum.
+    ///
+    /// ```ts
+    /// const Colors = { Red: 'red', Green: 'green' } as const;
+    /// ```
+    ///
+    /// Invalid members are reported only when the object is referenced as an enum type.
+    fn collect_const_obj(&mut self, it: &VariableDeclarator<'a>) {
+        if !it.kind.is_const() {
+            return;
+        }
+
+        let (ident, as_expr) = match (it.id.get_binding_identifier(), &it.init) {
+            (Some(ident), Some(Expression::TSAsExpression(as_expr))) => (ident, as_expr),
+            _ => return,
+        };
+
+        let obj_expr = match &as_expr.expression {
+            Expression::ObjectExpression(obj_expr)
+                if as_expr.type_annotation.is_const_type_reference() =>
+            {
+                obj_expr
+            }
+            _ => return,
+        };
+
+        let members = obj_expr
+            .properties
+            .iter()
+            .map(|prop| match prop {
+                ObjectPropertyKind::ObjectProperty(prop) if !prop.method && !prop.shorthand => {
+                    match (&prop.key, &prop.value) {
+                        (
+                            PropertyKey::StaticIdentifier(key),
+                            Expression::StringLiteral(str_lit),
+                        ) => Ok(EnumMember {
+                            name: key.name.to_string(),
+                            value: EnumMemberValue::String(str_lit.value.into_string()),
+                        }),
+                        _ => Err(error(INVALID_CONST_ENUM_MEMBER, prop.span)),
+                    }
+                }
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    Err(error(INVALID_CONST_ENUM_MEMBER, prop.span))
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    Err(error(INVALID_CONST_ENUM_MEMBER, spread.span))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|members| {
+                trace!(
+                    "`as const` object collected: {} ({:?}, members: {})",
+                    ident.name,
+                    ident.symbol_id(),
+                    members.len()
+                );
+                if members.is_empty() {
+                    Err(error(
+                        "`as const` enum must have at least one member",
+                        obj_expr.span,
+                    ))
+                } else {
+                    Ok(members)
+                }
+            });
+
+        self.const_objs.insert(ident.symbol_id(), members);
+    }
+
+    /// Returns the symbol ID of the `as const` object from the indexed access type.
+    ///
+    /// ```ts
+    /// type Color = (typeof Colors)[keyof typeof Colors];
+    /// ```
+    fn as_const_obj_id(&self, it: &TSIndexedAccessType<'a>) -> Option<SymbolId> {
+        let as_type_query_sym_id = |ts_type: &TSType<'a>| -> Option<SymbolId> {
+            let ts_type = match ts_type {
+                TSType::TSParenthesizedType(paren_type) => &paren_type.type_annotation,
+                ts_type => ts_type,
+            };
+
+            match ts_type {
+                TSType::TSTypeQuery(type_query) => match &type_query.expr_name {
+                    TSTypeQueryExprName::IdentifierReference(ident) => {
+                        self.scoping.get_reference(ident.reference_id()).symbol_id()
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        };
+
+        let obj_id = as_type_query_sym_id(&it.object_type)?;
+        let key_obj_id = match &it.index_type {
+            TSType::TSTypeOperatorType(type_op)
+                if type_op.operator == TSTypeOperatorOperator::Keyof =>
+            {
+                as_type_query_sym_id(&type_op.type_annotation)?
+            }
+            _ => return None,
+        };
+
+        (obj_id == key_obj_id).then_some(obj_id)
+    }
+
+    /// Resolve type aliases referencing `as const` objects into string enum types.
+    fn collect_const_enum_types(&mut self) {
+        for (id, name, obj_id, span) in std::mem::take(&mut self.const_enum_aliases) {
+            match self.const_objs.get(&obj_id) {
+                Some(Ok(members)) => {
+                    self.decls.insert(
+                        id,
+                        TypeAnnotation::Enum(EnumTypeAnnotation {
+                            name,
+                            members: members.clone(),
+                            fallback: None,
+                            exported: false,
+                            decl_file: None,
+                        }),
+                    );
+                }
+                Some(Err(e)) => self.diagnostics.push(e.clone()),
+                None => self.collect_error("`as const` object literal not found", span),
+            }
+        }
+    }
+
+    /// Returns the numeric literal and its signed value (eg. `1`, `-1`).
+    fn signed_numeric_literal<'b>(
+        expr: &'b Expression<'a>,
+    ) -> Option<(&'b NumericLiteral<'a>, f64)> {
+        match expr {
+            Expression::NumericLiteral(num_lit) => Some((num_lit, num_lit.value)),
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation =>
+            {
+                match &unary.argument {
+                    Expression::NumericLiteral(num_lit) => Some((num_lit, -num_lit.value)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts the numeric enum initializer (eg. `1`, `-1`) into an integer.
+    fn try_into_enum_number(expr: &Expression<'a>) -> Result<i64, &'static str> {
+        let (_, value) = NativeModuleAnalyzer::signed_numeric_literal(expr).ok_or(INVALID_SPEC)?;
+
+        // Checked before the cast, which saturates the out of range values
+        if !value.is_finite() || value.abs() >= I64_RANGE {
+            return Err(INVALID_ENUM_NUMBER_RANGE);
+        }
+
+        // The value is checked instead of the raw literal to catch the exponents (eg. `1e-3`)
+        if value.fract() != 0.0 {
+            return Err("Float number is not supported in enum");
+        }
+
+        Ok(value as i64)
+    }
+
+    /// Converts the parameter initializer (eg. `b: number = 2`) into the literal default value.
+    ///
+    /// Integer parameters (`Int32`, `Int64`) only accept integers within their range.
+    fn try_into_default_value(
+        expr: &Expression<'a>,
+        type_annotation: &TypeAnnotation,
+    ) -> Result<DefaultValue, &'static str> {
+        match (type_annotation, expr) {
+            (TypeAnnotation::Boolean, Expression::BooleanLiteral(bool_lit)) => {
+                Ok(DefaultValue::Boolean(bool_lit.value))
+            }
+            (TypeAnnotation::String, Expression::StringLiteral(str_lit)) => {
+                Ok(DefaultValue::String(str_lit.value.to_string()))
+            }
+            (TypeAnnotation::Number | TypeAnnotation::Int32 | TypeAnnotation::Int64, _) => {
+                let (_, value) = NativeModuleAnalyzer::signed_numeric_literal(expr)
+                    .ok_or(INVALID_DEFAULT_PARAM)?;
+                let in_range = match type_annotation {
+                    TypeAnnotation::Int32 => {
+                        value.fract() == 0.0 && (i32::MIN as f64..=i32::MAX as f64).contains(&value)
+                    }
+                    TypeAnnotation::Int64 => {
+                        value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER
+                    }
+                    _ => value.is_finite(),
+                };
+
+                if !in_range {
+                    return Err(INVALID_DEFAULT_PARAM);
+                }
+
+                Ok(DefaultValue::Number(format!("{value:?}")))
+            }
+            _ => Err(INVALID_DEFAULT_PARAM),
+        }
+    }
+
     fn collect_enum_type(&mut self, it: &TSEnumDeclaration<'a>) {
         let mut members = vec![];
-        let mut prev_num_raw_val = 0;
+        // Same as TypeScript, implicit members continue from the previous member (starting from `0`)
+        let mut next_num_raw_val = 0;
         let mut member_type = None;
+        let mut fallback = None;
+        // Values that may lose precision in JavaScript are allowed with `@lossy`
+        let lossy_enum = self.has_decl_tag(it.span.start, "lossy");
+
+        for member in it.body.members.iter() {
+            let lossy = lossy_enum
+                || self
+                    .jsdoc_tags(member.span.start)
+                    .iter()
+                    .any(|(tag, _)| tag == "lossy");
+
+            if self
+                .jsdoc_tags(member.span.start)
+                .iter()
+                .any(|(tag, _)| tag == "fallback")
+            {
+                if fallback.is_some() {
+                    return self.collect_error(INVALID_FALLBACK_ANNOTATION, member.span);
+                }
+                fallback = Some(member.id.static_name().to_string());
+            }
 
-        for (idx, member) in it.body.members.iter().enumerate() {
             match &member.initializer {
                 Some(expr) => match expr {
-                    Expression::NumericLiteral(num_lit) => {
+                    Expression::NumericLiteral(..) | Expression::UnaryExpression(..) => {
                         if let Some(type_annotation) = &member_type {
                             if !matches!(type_annotation, TypeAnnotation::Number) {
                                 return self.collect_error(INVALID_MIXED_ENUM_MEMBER, it.span);
@@ -216,17 +769,26 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             member_type = Some(TypeAnnotation::Number);
                         }
 
-                        let raw = num_lit.value as usize;
-                        prev_num_raw_val = raw;
-                        let is_float = num_lit.raw_str().contains(".");
+                        match NativeModuleAnalyzer::try_into_enum_number(expr) {
+                            Ok(raw) => {
+                                if !lossy && raw.unsigned_abs() as f64 > MAX_SAFE_INTEGER {
+                                    self.collect_warning(UNSAFE_ENUM_NUMBER, member.span);
+                                }
 
-                        if is_float {
-                            self.collect_error("Float number is not supported in enum", it.span);
-                        } else {
-                            members.push(EnumMember {
-                                name: member.id.static_name().to_string(),
-                                value: EnumMemberValue::Number(raw),
-                            });
+                                next_num_raw_val = raw + 1;
+
+                                // Keep collecting the rest of the members (and the enum itself)
+                                if Self::has_enum_number(&members, raw) {
+                                    self.collect_error(INVALID_DUPLICATE_ENUM_NUMBER, member.span);
+                                    continue;
+                                }
+
+                                members.push(EnumMember {
+                                    name: member.id.static_name().to_string(),
+                                    value: EnumMemberValue::Number(raw),
+                                });
+                            }
+                            Err(message) => self.collect_error(message, member.span),
                         }
                     }
                     Expression::StringLiteral(str_lit) => {
@@ -254,23 +816,56 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         member_type = Some(TypeAnnotation::Number);
                     }
 
+                    // Implicit values may exceed the range of the last explicit value
+                    let raw = next_num_raw_val;
+                    if !lossy && raw.unsigned_abs() as f64 > MAX_SAFE_INTEGER {
+                        self.collect_warning(UNSAFE_ENUM_NUMBER, member.span);
+                    }
+
+                    next_num_raw_val = raw + 1;
+
+                    if Self::has_enum_number(&members, raw) {
+                        self.collect_error(INVALID_DUPLICATE_ENUM_NUMBER, member.span);
+                        continue;
+                    }
+
                     members.push(EnumMember {
                         name: member.id.static_name().to_string(),
-                        value: EnumMemberValue::Number(prev_num_raw_val + idx),
+                        value: EnumMemberValue::Number(raw),
                     });
                 }
             };
         }
 
+        if fallback.is_some() && !matches!(member_type, Some(TypeAnnotation::String)) {
+            return self.collect_error(INVALID_FALLBACK_ANNOTATION, it.span);
+        }
+
+        trace!(
+            "Enum type collected: {} ({:?}, members: {})",
+            it.id.name,
+            it.id.symbol_id(),
+            members.len()
+        );
         self.decls.insert(
             it.id.symbol_id(),
             TypeAnnotation::Enum(EnumTypeAnnotation {
                 name: it.id.name.to_string(),
                 members,
+                fallback,
+                exported: self.is_exported(it.span.start),
+                decl_file: None,
             }),
         );
     }
 
+    /// Returns `true` if any of the collected members has the numeric value.
+    fn has_enum_number(members: &[EnumMember], raw: i64) -> bool {
+        members
+            .iter()
+            .any(|member| member.value == EnumMemberValue::Number(raw))
+    }
+
     fn as_spec_id(&mut self, it: &CallExpression<'a>) -> Option<SymbolId> {
         let spec_generic = match &it.type_arguments {
             Some(type_arguments) => match type_arguments.params.first() {
@@ -368,6 +963,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                 Ok(Prop {
                     name: prop_name,
                     type_annotation,
+                    docs: self.jsdoc_description(prop_sig.span.start),
                 })
             }
             _ => Err(error(INVALID_SPEC, prop_sig.span)),
@@ -392,21 +988,34 @@ impl<'a> NativeModuleAnalyzer<'a> {
             return Err(error(INVALID_RESERVED_METHOD_NAME_ID, sig.span));
         }
 
-        let params = sig
-            .params
-            .items
+        let items = &sig.params.items;
+        let params = items
             .iter()
-            .map(|param| {
+            .enumerate()
+            .map(|(idx, param)| {
                 if !param.decorators.is_empty() {
                     return Err(error(INVALID_SPEC, param.span));
                 }
 
-                if param.pattern.optional {
+                // `arg: T = value` is parsed as an assignment pattern wrapping the typed pattern
+                let (pattern, initializer) = match &param.pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign) => {
+                        (&assign.left, Some(&assign.right))
+                    }
+                    _ => (&param.pattern, None),
+                };
+                let is_omittable = |p: &FormalParameter<'a>| {
+                    p.pattern.optional
+                        || matches!(p.pattern.kind, BindingPatternKind::AssignmentPattern(..))
+                };
+
+                // Optional parameters must not be followed by required parameters
+                let optional = pattern.optional;
+                if is_omittable(param) && !items[idx + 1..].iter().all(is_omittable) {
                     return Err(error(INVALID_OPTIONAL_PARAM, param.span));
                 }
 
-                let param_name = param
-                    .pattern
+                let param_name = pattern
                     .kind
                     .get_identifier_name()
                     .ok_or_else(|| error(INVALID_SPEC, param.span))?;
@@ -415,19 +1024,41 @@ impl<'a> NativeModuleAnalyzer<'a> {
                     return Err(error(INVALID_RESERVED_ARG_NAME_ID, param.span));
                 }
 
-                let param_type_annotation = param
-                    .pattern
+                let param_type_annotation = pattern
                     .type_annotation
                     .as_ref()
                     .ok_or_else(|| error(INVALID_SPEC, param.span))?;
 
-                match self.try_into_type_annotation(&param_type_annotation.type_annotation) {
-                    Ok(type_annotation) => Ok(Param {
-                        name: param_name.to_string(),
-                        type_annotation,
-                    }),
-                    Err(e) => Err(error(&e.to_string(), param.span)),
-                }
+                let type_annotation =
+                    match self.try_into_type_annotation(&param_type_annotation.type_annotation) {
+                        // Omitted arguments are treated as `null` (`arg?: T` -> `arg: T | null`)
+                        Ok(type_annotation) if optional && !type_annotation.is_nullable() => {
+                            match NativeModuleAnalyzer::try_into_nullable_base(type_annotation) {
+                                Ok(base) => TypeAnnotation::Nullable(Box::new(base)),
+                                Err(e) => return Err(error(&e.to_string(), param.span)),
+                            }
+                        }
+                        Ok(type_annotation) => type_annotation,
+                        Err(e) => return Err(error(&e.to_string(), param.span)),
+                    };
+
+                let byte_length = self.try_into_byte_length(param, &type_annotation)?;
+                let default_value = match initializer {
+                    Some(_) if optional => return Err(error(INVALID_DEFAULT_PARAM, param.span)),
+                    Some(expr) => Some(
+                        NativeModuleAnalyzer::try_into_default_value(expr, &type_annotation)
+                            .map_err(|e| error(e, param.span))?,
+                    ),
+                    None => None,
+                };
+
+                Ok(Param {
+                    name: param_name.to_string(),
+                    type_annotation,
+                    byte_length,
+                    optional,
+                    default_value,
+                })
             })
             .collect::<Result<Vec<Param>, OxcDiagnostic>>()?;
 
@@ -436,14 +1067,28 @@ impl<'a> NativeModuleAnalyzer<'a> {
             .as_ref()
             .ok_or_else(|| error(INVALID_SPEC, sig.span))?;
 
-        match self.try_into_type_annotation(&ret_type.type_annotation) {
-            Ok(type_annotation) => Ok(Method {
-                name: method_name,
-                params,
-                ret_type: type_annotation,
-            }),
-            Err(e) => Err(error(&e.to_string(), sig.span)),
-        }
+        let ret_type = match self.try_into_type_annotation(&ret_type.type_annotation) {
+            Ok(type_annotation) => type_annotation,
+            Err(e) => return Err(error(&e.to_string(), sig.span)),
+        };
+
+        let cancellable = self.try_into_cancellable(sig, &params, &ret_type)?;
+        let detached = self.try_into_detached(sig, &ret_type, cancellable)?;
+        let error_type = self.try_into_error_type(sig, &ret_type, cancellable, detached)?;
+        let platform = self.try_into_platform(sig)?;
+        let readonly = self.is_readonly(sig);
+
+        Ok(Method {
+            name: method_name,
+            params,
+            ret_type,
+            cancellable,
+            detached,
+            platform,
+            readonly,
+            error_type,
+            docs: self.jsdoc_description(sig.span.start),
+        })
     }
 
     fn try_into_signal(&mut self, sig: &TSPropertySignature<'a>) -> Result<Signal, OxcDiagnostic> {
@@ -465,15 +1110,15 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         .symbol_id();
 
                     if sym_id == self.mod_signal_sym_id {
-                        let payload_type = if let Some(type_args) = &type_ref.type_arguments {
-                            if let Some(first_arg) = type_args.params.first() {
-                                self.try_into_type_annotation(first_arg).ok()
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        };
+                        let payload_type = type_ref
+                            .type_arguments
+                            .as_ref()
+                            .and_then(|type_args| type_args.params.first())
+                            .map(|first_arg| {
+                                self.try_into_type_annotation(first_arg)
+                                    .map_err(|e| error(&e.to_string(), sig.span))
+                            })
+                            .transpose()?;
                         Ok(Signal {
                             name: event_name,
                             payload_type,
@@ -488,6 +1133,237 @@ impl<'a> NativeModuleAnalyzer<'a> {
         }
     }
 
+    /// Returns the expected byte length from the `@bytes N` annotation of `ArrayBuffer` parameter.
+    ///
+    /// ```ts
+    /// setKey(/** @bytes 32 */ key: ArrayBuffer): void;
+    /// ```
+    fn try_into_byte_length(
+        &self,
+        param: &FormalParameter<'a>,
+        type_annotation: &TypeAnnotation,
+    ) -> Result<Option<usize>, OxcDiagnostic> {
+        let value = match self
+            .jsdoc_tags(param.span.start)
+            .into_iter()
+            .find(|(tag, _)| tag == "bytes")
+        {
+            Some((_, value)) => value,
+            None => return Ok(None),
+        };
+
+        match (type_annotation, value.parse::<usize>()) {
+            (TypeAnnotation::ArrayBuffer, Ok(len)) if len > 0 => Ok(Some(len)),
+            _ => Err(error(INVALID_BYTES_ANNOTATION, param.span)),
+        }
+    }
+
+    /// Returns `true` if the Promise method is annotated with `@cancellable`.
+    ///
+    /// ```ts
+    /// /** @cancellable */
+    /// longTask(n: number): Promise<number>;
+    /// ```
+    fn try_into_cancellable(
+        &self,
+        sig: &TSMethodSignature<'a>,
+        params: &[Param],
+        ret_type: &TypeAnnotation,
+    ) -> Result<bool, OxcDiagnostic> {
+        let cancellable = self
+            .jsdoc_tags(sig.span.start)
+            .iter()
+            .any(|(tag, _)| tag == "cancellable");
+
+        if !cancellable {
+            return Ok(false);
+        }
+
+        if !matches!(ret_type, TypeAnnotation::Promise(..)) {
+            return Err(error(INVALID_CANCELLABLE_ANNOTATION, sig.span));
+        }
+
+        if params
+            .iter()
+            .any(|param| snake_case(&param.name) == RESERVED_ARG_NAME_CANCEL_TOKEN)
+        {
+            return Err(error(INVALID_RESERVED_ARG_NAME_CANCEL_TOKEN, sig.span));
+        }
+
+        Ok(true)
+    }
+
+    /// Returns `true` if the `Promise<void>` method is annotated with `@detached`.
+    ///
+    /// ```ts
+    /// /** @detached */
+    /// track(event: string): Promise<void>;
+    /// ```
+    fn try_into_detached(
+        &self,
+        sig: &TSMethodSignature<'a>,
+        ret_type: &TypeAnnotation,
+        cancellable: bool,
+    ) -> Result<bool, OxcDiagnostic> {
+        let detached = self
+            .jsdoc_tags(sig.span.start)
+            .iter()
+            .any(|(tag, _)| tag == "detached");
+
+        if !detached {
+            return Ok(false);
+        }
+
+        match ret_type {
+            TypeAnnotation::Promise(resolve_type)
+                if matches!(**resolve_type, TypeAnnotation::Void) && !cancellable =>
+            {
+                Ok(true)
+            }
+            _ => Err(error(INVALID_DETACHED_ANNOTATION, sig.span)),
+        }
+    }
+
+    /// Returns the structured error type from the `@throws` annotation of the Promise method.
+    ///
+    /// ```ts
+    /// /** @throws FetchError */
+    /// fetch(url: string): Promise<string>;
+    /// ```
+    ///
+    /// The type is declared by name only (the annotation is not a type reference), so it is returned as an empty
+    /// object type named after the annotation and resolved to the declared object type in `try_into_schema`.
+    fn try_into_error_type(
+        &self,
+        sig: &TSMethodSignature<'a>,
+        ret_type: &TypeAnnotation,
+        cancellable: bool,
+        detached: bool,
+    ) -> Result<Option<TypeAnnotation>, OxcDiagnostic> {
+        let value = match self
+            .jsdoc_tags(sig.span.start)
+            .into_iter()
+            .find(|(tag, _)| tag == "throws")
+        {
+            Some((_, value)) => value,
+            None => return Ok(None),
+        };
+
+        // `@throws MyError` or `@throws {MyError} description`
+        let name = value
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches('{')
+            .trim_end_matches('}');
+        let is_valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if !is_valid_name
+            || !matches!(ret_type, TypeAnnotation::Promise(..))
+            || cancellable
+            || detached
+        {
+            return Err(error(INVALID_THROWS_ANNOTATION, sig.span));
+        }
+
+        Ok(Some(TypeAnnotation::Object(ObjectTypeAnnotation {
+            name: name.to_string(),
+            props: vec![],
+            hashable: false,
+        })))
+    }
+
+    /// Returns the platform from the `@platform` annotation of the method.
+    ///
+    /// ```ts
+    /// /** @platform ios */
+    /// openSettings(): void;
+    /// ```
+    fn try_into_platform(
+        &self,
+        sig: &TSMethodSignature<'a>,
+    ) -> Result<Option<Platform>, OxcDiagnostic> {
+        let value = match self
+            .jsdoc_tags(sig.span.start)
+            .into_iter()
+            .find(|(tag, _)| tag == "platform")
+        {
+            Some((_, value)) => value,
+            None => return Ok(None),
+        };
+
+        match value.as_str() {
+            "ios" => Ok(Some(Platform::Ios)),
+            "android" => Ok(Some(Platform::Android)),
+            _ => Err(error(INVALID_PLATFORM_ANNOTATION, sig.span)),
+        }
+    }
+
+    /// Returns `true` if the method is annotated with `@readonly`.
+    ///
+    /// ```ts
+    /// /** @readonly */
+    /// getVersion(): string;
+    /// ```
+    fn is_readonly(&self, sig: &TSMethodSignature<'a>) -> bool {
+        self.jsdoc_tags(sig.span.start)
+            .iter()
+            .any(|(tag, _)| tag == "readonly")
+    }
+
+    /// Returns `true` if the type declaration is annotated with `@hashable`.
+    ///
+    /// ```ts
+    /// /** @hashable */
+    /// export interface CacheKey {
+    ///   id: string;
+    /// }
+    /// ```
+    fn is_hashable(&self, start: u32) -> bool {
+        self.has_decl_tag(start, "hashable")
+    }
+
+    /// Returns `true` if the declaration is annotated with the given JSDoc tag.
+    fn has_decl_tag(&self, start: u32, tag_name: &str) -> bool {
+        // Comments of the exported declarations are attached to the `export` keyword
+        let start = self.source_text[..start as usize]
+            .trim_end()
+            .strip_suffix("export")
+            .map_or(start, |prefix| prefix.len() as u32);
+
+        self.jsdoc_tags(start)
+            .iter()
+            .any(|(tag, _)| tag == tag_name)
+    }
+
+    /// Returns `true` if the declaration at the given position is exported (eg. `export enum Foo {}`).
+    fn is_exported(&self, start: u32) -> bool {
+        self.source_text[..start as usize]
+            .trim_end()
+            .ends_with("export")
+    }
+
+    /// Returns the tags of JSDoc comments attached to the given position.
+    fn jsdoc_tags(&self, start: u32) -> Vec<(String, String)> {
+        self.comments
+            .iter()
+            .filter(|comment| comment.attached_to == start && comment.is_jsdoc())
+            .flat_map(|comment| jsdoc_tags(comment.content_span().source_text(self.source_text)))
+            .collect()
+    }
+
+    /// Returns the description lines of JSDoc comments attached to the given position.
+    fn jsdoc_description(&self, start: u32) -> Vec<String> {
+        self.comments
+            .iter()
+            .filter(|comment| comment.attached_to == start && comment.is_jsdoc())
+            .flat_map(|comment| {
+                jsdoc_description(comment.content_span().source_text(self.source_text))
+            })
+            .collect()
+    }
+
     fn try_into_prop_name(&self, key: &PropertyKey) -> Result<String, anyhow::Error> {
         match key {
             PropertyKey::StaticIdentifier(ident) => Ok(ident.name.to_string()),
@@ -503,14 +1379,33 @@ impl<'a> NativeModuleAnalyzer<'a> {
             TSType::TSVoidKeyword(..) => Ok(TypeAnnotation::Void),
             TSType::TSBooleanKeyword(..) => Ok(TypeAnnotation::Boolean),
             TSType::TSNumberKeyword(..) => Ok(TypeAnnotation::Number),
+            TSType::TSBigIntKeyword(..) => Ok(TypeAnnotation::BigInt),
             TSType::TSStringKeyword(..) => Ok(TypeAnnotation::String),
-            TSType::TSArrayType(arr_type) => {
-                let type_annotation = self.try_into_type_annotation(&arr_type.element_type)?;
-                Ok(TypeAnnotation::Array(Box::new(type_annotation)))
+            TSType::TSArrayType(arr_type) => self.try_into_array(&arr_type.element_type),
+            // `readonly T[]` and `readonly [A, B]` are bridged as the mutable types
+            TSType::TSTypeOperatorType(type_op)
+                if type_op.operator == TSTypeOperatorOperator::Readonly =>
+            {
+                self.try_into_type_annotation(&type_op.type_annotation)
             }
             TSType::TSTypeReference(type_ref) => match &type_ref.type_name {
                 TSTypeName::IdentifierReference(ident_ref) => match ident_ref.name.as_str() {
-                    RESERVED_TYPE_ARRAY_BUFFER => Ok(TypeAnnotation::ArrayBuffer),
+                    // Byte arrays are bridged as `ArrayBuffer` (`Vec<u8>` in Rust)
+                    RESERVED_TYPE_ARRAY_BUFFER
+                    | RESERVED_TYPE_UINT8_ARRAY
+                    | RESERVED_TYPE_INT8_ARRAY => Ok(TypeAnnotation::ArrayBuffer),
+                    name if UNSUPPORTED_TYPED_ARRAYS.contains(&name) => {
+                        anyhow::bail!(
+                            "`{name}` is not supported yet. Use `ArrayBuffer`, `Uint8Array` or `Int8Array` instead"
+                        )
+                    }
+                    // `ReadonlyArray<T>` is bridged as `T[]`
+                    RESERVED_TYPE_READONLY_ARRAY => match &type_ref.type_arguments {
+                        Some(type_args) if type_args.params.len() == 1 => {
+                            self.try_into_array(&type_args.params[0])
+                        }
+                        _ => anyhow::bail!("Invalid readonly array type"),
+                    },
                     RESERVED_TYPE_PROMISE => match &type_ref.type_arguments {
                         Some(type_args) if type_args.params.len() == 1 => {
                             let resolved_type = type_args.params.first().unwrap();
@@ -519,26 +1414,72 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         }
                         _ => anyhow::bail!("Invalid promise type"),
                     },
-                    _ => Ok(TypeAnnotation::Ref(RefTypeAnnotation {
-                        ref_id: ident_ref.reference_id(),
-                        name: ident_ref.name.to_string(),
-                    })),
+                    RESERVED_TYPE_RECORD => match &type_ref.type_arguments {
+                        Some(type_args) if type_args.params.len() == 2 => {
+                            if !matches!(type_args.params[0], TSType::TSStringKeyword(..)) {
+                                anyhow::bail!(INVALID_RECORD_KEY);
+                            }
+
+                            let value_type = self.try_into_type_annotation(&type_args.params[1])?;
+                            if let TypeAnnotation::Void
+                            | TypeAnnotation::Nullable(..)
+                            | TypeAnnotation::Map(..)
+                            | TypeAnnotation::Promise(..) = value_type
+                            {
+                                anyhow::bail!(INVALID_RECORD_VALUE);
+                            }
+
+                            Ok(TypeAnnotation::Map(Box::new(value_type)))
+                        }
+                        _ => anyhow::bail!("Invalid record type"),
+                    },
+                    _ => {
+                        // Integer types imported from `craby-modules` (eg. `Int32`)
+                        let sym_id = self
+                            .scoping
+                            .get_reference(ident_ref.reference_id())
+                            .symbol_id();
+
+                        match sym_id {
+                            Some(_) if sym_id == self.mod_int32_sym_id => Ok(TypeAnnotation::Int32),
+                            Some(_) if sym_id == self.mod_int64_sym_id => Ok(TypeAnnotation::Int64),
+                            _ => Ok(TypeAnnotation::Ref(RefTypeAnnotation {
+                                ref_id: ident_ref.reference_id(),
+                                name: ident_ref.name.to_string(),
+                            })),
+                        }
+                    }
                 },
                 _ => anyhow::bail!(INVALID_TYPE_REFERENCE),
             },
             TSType::TSUnionType(union_type) => self.try_into_nullable(union_type),
+            TSType::TSTupleType(tuple_type) => self.try_into_tuple(tuple_type),
             TSType::TSTypeLiteral { .. } => anyhow::bail!(INVALID_TYPE_LITERAL),
             TSType::TSFunctionType { .. } => anyhow::bail!(INVALID_FUNC_PARAM),
             _ => anyhow::bail!(INVALID_SPEC),
         }
     }
 
-    fn try_into_nullable(
+    fn try_into_array(
         &mut self,
-        union_type: &TSUnionType<'a>,
+        element_type: &TSType<'a>,
     ) -> Result<TypeAnnotation, anyhow::Error> {
-        if union_type.types.len() != 2 {
-            anyhow::bail!(INVALID_UNION_TYPE);
+        let type_annotation = self.try_into_type_annotation(element_type)?;
+        match type_annotation {
+            TypeAnnotation::Map(..) => anyhow::bail!("Array of record type is not supported"),
+            // `rust::Vec<int64_t>` is bridged as the array of numbers (`Int64[]`)
+            TypeAnnotation::BigInt => anyhow::bail!(INVALID_BIGINT_ARRAY),
+            _ => {}
+        }
+        Ok(TypeAnnotation::Array(Box::new(type_annotation)))
+    }
+
+    fn try_into_nullable(
+        &mut self,
+        union_type: &TSUnionType<'a>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        if union_type.types.len() != 2 {
+            anyhow::bail!(INVALID_UNION_TYPE);
         }
 
         let base = match (&union_type.types[0], &union_type.types[1]) {
@@ -547,14 +1488,84 @@ impl<'a> NativeModuleAnalyzer<'a> {
             _ => anyhow::bail!(INVALID_UNION_TYPE),
         };
 
-        let base = match self.try_into_type_annotation(base)? {
-            TypeAnnotation::Promise(..) => anyhow::bail!("Promise type cannot be nullable"),
-            base => base,
-        };
+        let base =
+            NativeModuleAnalyzer::try_into_nullable_base(self.try_into_type_annotation(base)?)?;
 
         Ok(TypeAnnotation::Nullable(Box::new(base)))
     }
 
+    /// Validates the base type of the nullable type (`T | null` or `arg?: T`).
+    fn try_into_nullable_base(base: TypeAnnotation) -> Result<TypeAnnotation, anyhow::Error> {
+        match base {
+            TypeAnnotation::Promise(..) => anyhow::bail!("Promise type cannot be nullable"),
+            TypeAnnotation::Void => anyhow::bail!("Void type cannot be nullable"),
+            TypeAnnotation::Map(..) => anyhow::bail!("Record type cannot be nullable"),
+            base => Ok(base),
+        }
+    }
+
+    fn try_into_tuple(
+        &mut self,
+        tuple_type: &TSTupleType<'a>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        if tuple_type.element_types.is_empty() {
+            anyhow::bail!(INVALID_EMPTY_TUPLE);
+        }
+
+        if tuple_type.element_types.len() > MAX_TUPLE_ELEMENTS {
+            anyhow::bail!(
+                "Tuple type can have at most {} elements (found {})",
+                MAX_TUPLE_ELEMENTS,
+                tuple_type.element_types.len()
+            );
+        }
+
+        let element_types = tuple_type
+            .element_types
+            .iter()
+            .map(|element| {
+                // Labels of the named elements are ignored (eg. `[width: number, height: number]`)
+                let element_type = match element.as_ts_type() {
+                    Some(TSType::TSNamedTupleMember(member)) if !member.optional => {
+                        member.element_type.as_ts_type()
+                    }
+                    Some(TSType::TSNamedTupleMember(..)) => None,
+                    element_type => element_type,
+                };
+
+                match element_type {
+                    Some(element_type) => match self.try_into_type_annotation(element_type)? {
+                        TypeAnnotation::Void
+                        | TypeAnnotation::Promise(..)
+                        | TypeAnnotation::Nullable(..)
+                        | TypeAnnotation::Map(..)
+                        | TypeAnnotation::Tuple(..) => anyhow::bail!(INVALID_TUPLE_ELEMENT),
+                        TypeAnnotation::Array(element_type)
+                            if !matches!(
+                                *element_type,
+                                TypeAnnotation::Boolean
+                                    | TypeAnnotation::Number
+                                    | TypeAnnotation::String
+                                    | TypeAnnotation::Int32
+                                    | TypeAnnotation::Int64
+                                    | TypeAnnotation::Enum(..)
+                                    | TypeAnnotation::Object(..)
+                                    | TypeAnnotation::Ref(..)
+                            ) =>
+                        {
+                            anyhow::bail!(INVALID_TUPLE_ELEMENT)
+                        }
+                        element_type => Ok(element_type),
+                    },
+                    // Optional (`number?`) and rest (`...number[]`) elements
+                    None => anyhow::bail!(INVALID_TUPLE_ELEMENT),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TypeAnnotation::Tuple(element_types))
+    }
+
     /// Check the specification interface extends `NativeModule` interface of 'craby-modules' package.
     fn is_spec(&self, it: &TSInterfaceDeclaration<'a>) -> bool {
         it.extends.iter().any(|ex| {
@@ -589,11 +1600,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
         if let Expression::StaticMemberExpression(member) = &it.callee {
             match &member.object {
                 Expression::Identifier(ident) => {
-                    let sym_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
-                    let is_reg = self
-                        .mod_reg_sym_id
-                        .zip(sym_id)
-                        .is_some_and(|(id, s)| id == s);
+                    let is_reg = self.is_reg_ref(ident);
                     let is_get = member.property.name == REGISTRY_GET
                         || member.property.name == REGISTRY_GET_ENFORCING;
 
@@ -636,81 +1643,280 @@ impl<'a> NativeModuleAnalyzer<'a> {
             .push(OxcDiagnostic::error(message.to_string()).with_label(span));
     }
 
+    fn collect_warning(&mut self, message: &str, span: Span) {
+        self.warnings
+            .push(OxcDiagnostic::warn(message.to_string()).with_label(span));
+    }
+
     fn collect_types(
         type_annotation: &TypeAnnotation,
-        _scoping: &Scoping,
-        _decls: &FxHashMap<SymbolId, TypeAnnotation>,
+        scoping: &Scoping,
+        decls: &FxHashMap<SymbolId, TypeAnnotation>,
         types: &mut FxHashSet<TypeAnnotation>,
         enums: &mut FxHashSet<TypeAnnotation>,
-    ) {
+    ) -> Result<(), anyhow::Error> {
         match type_annotation {
             obj_type @ TypeAnnotation::Object(obj) => {
-                types.insert(obj_type.clone());
+                if !types.insert(obj_type.clone()) {
+                    return Ok(());
+                }
+
                 for prop in &obj.props {
                     NativeModuleAnalyzer::collect_types(
                         &prop.type_annotation,
-                        _scoping,
-                        _decls,
+                        scoping,
+                        decls,
                         types,
                         enums,
-                    );
+                    )?;
                 }
             }
             enum_type @ TypeAnnotation::Enum(..) => {
                 enums.insert(enum_type.clone());
             }
             TypeAnnotation::Nullable(base_type) => {
-                NativeModuleAnalyzer::collect_types(base_type, _scoping, _decls, types, enums);
+                NativeModuleAnalyzer::collect_types(base_type, scoping, decls, types, enums)?;
+            }
+            TypeAnnotation::Array(element_type) | TypeAnnotation::Map(element_type) => {
+                NativeModuleAnalyzer::collect_types(element_type, scoping, decls, types, enums)?;
             }
             TypeAnnotation::Promise(resolved_type) => {
-                NativeModuleAnalyzer::collect_types(resolved_type, _scoping, _decls, types, enums);
+                NativeModuleAnalyzer::collect_types(resolved_type, scoping, decls, types, enums)?;
+            }
+            TypeAnnotation::Tuple(element_types) => {
+                for element_type in element_types {
+                    NativeModuleAnalyzer::collect_types(
+                        element_type,
+                        scoping,
+                        decls,
+                        types,
+                        enums,
+                    )?;
+                }
+            }
+            // Recursive types are kept as references (eg. `next: Node | null`)
+            TypeAnnotation::Ref(RefTypeAnnotation { ref_id, .. }) => {
+                let resolved = scoping
+                    .get_reference(*ref_id)
+                    .symbol_id()
+                    .and_then(|sym_id| decls.get(&sym_id));
+
+                if let Some(resolved) = resolved {
+                    let mut resolved = resolved.clone();
+                    NativeModuleAnalyzer::resolve_refs(&mut resolved, scoping, decls)?;
+                    NativeModuleAnalyzer::collect_types(&resolved, scoping, decls, types, enums)?;
+                }
             }
             _ => {}
         }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the declaration references itself (directly or through other declarations).
+    fn is_recursive_decl(
+        sym_id: SymbolId,
+        scoping: &Scoping,
+        decls: &FxHashMap<SymbolId, TypeAnnotation>,
+    ) -> bool {
+        fn has_ref(
+            type_annotation: &TypeAnnotation,
+            target: SymbolId,
+            scoping: &Scoping,
+            decls: &FxHashMap<SymbolId, TypeAnnotation>,
+            visited: &mut FxHashSet<SymbolId>,
+        ) -> bool {
+            match type_annotation {
+                TypeAnnotation::Ref(RefTypeAnnotation { ref_id, .. }) => {
+                    match scoping.get_reference(*ref_id).symbol_id() {
+                        Some(sym_id) if sym_id == target => true,
+                        Some(sym_id) => {
+                            visited.insert(sym_id)
+                                && decls.get(&sym_id).is_some_and(|resolved| {
+                                    has_ref(resolved, target, scoping, decls, visited)
+                                })
+                        }
+                        None => false,
+                    }
+                }
+                TypeAnnotation::Object(obj) => obj
+                    .props
+                    .iter()
+                    .any(|prop| has_ref(&prop.type_annotation, target, scoping, decls, visited)),
+                TypeAnnotation::Tuple(element_types) => element_types
+                    .iter()
+                    .any(|t| has_ref(t, target, scoping, decls, visited)),
+                TypeAnnotation::Nullable(t)
+                | TypeAnnotation::Array(t)
+                | TypeAnnotation::Map(t)
+                | TypeAnnotation::Promise(t) => has_ref(t, target, scoping, decls, visited),
+                _ => false,
+            }
+        }
+
+        decls
+            .get(&sym_id)
+            .is_some_and(|decl| has_ref(decl, sym_id, scoping, decls, &mut FxHashSet::default()))
     }
 
     fn resolve_refs(
         type_annotation: &mut TypeAnnotation,
         scoping: &Scoping,
         decls: &FxHashMap<SymbolId, TypeAnnotation>,
-    ) {
+    ) -> Result<(), anyhow::Error> {
+        NativeModuleAnalyzer::resolve_refs_with_path(
+            type_annotation,
+            scoping,
+            decls,
+            &mut vec![],
+            None,
+        )
+    }
+
+    /// Resolves the type references recursively.
+    ///
+    /// `path` holds the declarations being resolved (`None` for the indirections between them, such as nullable and array types)
+    /// to detect the recursive types, and `field` is the name of the object field being resolved.
+    fn resolve_refs_with_path(
+        type_annotation: &mut TypeAnnotation,
+        scoping: &Scoping,
+        decls: &FxHashMap<SymbolId, TypeAnnotation>,
+        path: &mut Vec<Option<(SymbolId, String)>>,
+        field: Option<&str>,
+    ) -> Result<(), anyhow::Error> {
         match type_annotation {
-            TypeAnnotation::Ref(RefTypeAnnotation { ref_id, .. }) => {
+            TypeAnnotation::Ref(RefTypeAnnotation { ref_id, name }) => {
                 match scoping.get_reference(*ref_id).symbol_id() {
                     Some(sym_id) => {
+                        if let Some(pos) = path
+                            .iter()
+                            .position(|decl| matches!(decl, Some((id, _)) if *id == sym_id))
+                        {
+                            let cycle = path[pos..]
+                                .iter()
+                                .flatten()
+                                .map(|(_, name)| format!("`{name}`"))
+                                .chain(std::iter::once(format!("`{name}`")))
+                                .collect::<Vec<_>>()
+                                .join(" -> ");
+
+                            let field = field.unwrap_or("field");
+                            if path[pos..].contains(&None) {
+                                anyhow::bail!(
+                                    "Recursive type is only supported through nullable references ({cycle}). Reference the type directly as nullable (eg. `{field}: {name} | null`)"
+                                );
+                            }
+
+                            anyhow::bail!(
+                                "Recursive type has infinite size ({cycle}). Make the recursive field nullable (eg. `{field}: {name} | null`)"
+                            );
+                        }
+
                         match decls.get(&sym_id) {
                             Some(resolved) => {
                                 let mut resolved = resolved.clone();
-                                NativeModuleAnalyzer::resolve_refs(&mut resolved, scoping, decls);
+                                path.push(Some((sym_id, name.clone())));
+                                NativeModuleAnalyzer::resolve_refs_with_path(
+                                    &mut resolved,
+                                    scoping,
+                                    decls,
+                                    path,
+                                    None,
+                                )?;
+                                path.pop();
                                 *type_annotation = resolved;
                             }
-                            _ => unreachable!(
-                                "Symbol not found (ref: {:?}, sym: {:?})",
-                                ref_id, sym_id
+                            // eg. types imported from the packages, ambient (`declare`) types
+                            None => anyhow::bail!(
+                                "Type `{name}` cannot be resolved. Declare the type in the spec file or import it from a local file with `import type`"
                             ),
                         };
                     }
-                    _ => unreachable!("Unknown type reference (ref: {:?})", ref_id),
+                    None => anyhow::bail!("Type `{name}` is not defined"),
                 }
             }
             TypeAnnotation::Object(obj) => {
                 for prop in &mut obj.props {
-                    NativeModuleAnalyzer::resolve_refs(&mut prop.type_annotation, scoping, decls);
+                    NativeModuleAnalyzer::resolve_refs_with_path(
+                        &mut prop.type_annotation,
+                        scoping,
+                        decls,
+                        path,
+                        Some(&prop.name),
+                    )?;
                 }
             }
             TypeAnnotation::Nullable(base_type) => {
-                NativeModuleAnalyzer::resolve_refs(base_type, scoping, decls);
+                // Keep the recursive type as a reference to box it (eg. `next: Node | null`)
+                if let TypeAnnotation::Ref(RefTypeAnnotation { ref_id, .. }) = &**base_type {
+                    if let Some(sym_id) = scoping.get_reference(*ref_id).symbol_id() {
+                        if NativeModuleAnalyzer::is_recursive_decl(sym_id, scoping, decls) {
+                            return Ok(());
+                        }
+                    }
+                }
+
+                path.push(None);
+                NativeModuleAnalyzer::resolve_refs_with_path(
+                    base_type, scoping, decls, path, field,
+                )?;
+                path.pop();
+            }
+            TypeAnnotation::Array(element_type) | TypeAnnotation::Map(element_type) => {
+                path.push(None);
+                NativeModuleAnalyzer::resolve_refs_with_path(
+                    element_type,
+                    scoping,
+                    decls,
+                    path,
+                    field,
+                )?;
+                path.pop();
             }
             TypeAnnotation::Promise(t) => {
-                NativeModuleAnalyzer::resolve_refs(&mut *t, scoping, decls);
+                NativeModuleAnalyzer::resolve_refs_with_path(&mut *t, scoping, decls, path, field)?;
+            }
+            TypeAnnotation::Tuple(element_types) => {
+                for element_type in element_types {
+                    NativeModuleAnalyzer::resolve_refs_with_path(
+                        element_type,
+                        scoping,
+                        decls,
+                        path,
+                        field,
+                    )?;
+
+                    // Type aliases are resolved here (eg. `type MaybeNumber = number | null`)
+                    let invalid = match element_type {
+                        TypeAnnotation::Nullable(..) | TypeAnnotation::Map(..) => true,
+                        TypeAnnotation::Array(t) => matches!(
+                            **t,
+                            TypeAnnotation::Nullable(..)
+                                | TypeAnnotation::Map(..)
+                                | TypeAnnotation::Array(..)
+                        ),
+                        _ => false,
+                    };
+
+                    if invalid {
+                        anyhow::bail!(INVALID_TUPLE_ELEMENT);
+                    }
+                }
             }
             _ => {}
         }
+
+        Ok(())
     }
 
     fn try_assert_reserved_type(&self, name: &Atom<'a>) -> Result<(), anyhow::Error> {
         match name.as_str() {
-            RESERVED_TYPE_ARRAY_BUFFER | RESERVED_TYPE_PROMISE => {
+            RESERVED_TYPE_ARRAY_BUFFER
+            | RESERVED_TYPE_UINT8_ARRAY
+            | RESERVED_TYPE_INT8_ARRAY
+            | RESERVED_TYPE_PROMISE
+            | RESERVED_TYPE_READONLY_ARRAY
+            | RESERVED_TYPE_RECORD => {
                 anyhow::bail!("Cannot use reserved type: {}", name.as_str())
             }
             _ => {}
@@ -723,6 +1929,153 @@ impl<'a> NativeModuleAnalyzer<'a> {
         Ok(())
     }
 
+    /// Parses the local files imported from the source and merges the imported declarations.
+    ///
+    /// `import_stack` holds the files being parsed to detect the circular imports.
+    fn resolve_local_imports(
+        &mut self,
+        path: &Path,
+        import_stack: &mut Vec<PathBuf>,
+    ) -> Result<(), anyhow::Error> {
+        for (source, imported) in std::mem::take(&mut self.local_imports) {
+            let exports = resolve_imports(path, &source, &imported, import_stack)?;
+
+            debug!("Imported decls from {}: {:?}", source, exports);
+            self.decls.extend(exports);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the declared object type of the `@throws` annotation.
+    fn try_into_declared_error_type(
+        name: &str,
+        scoping: &Scoping,
+        decls: &FxHashMap<SymbolId, TypeAnnotation>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        let mut error_type = scoping
+            .get_root_binding(name)
+            .and_then(|sym_id| decls.get(&sym_id))
+            .ok_or(anyhow::anyhow!(
+                "Type `{name}` of the `@throws` annotation is not declared"
+            ))?
+            .clone();
+
+        NativeModuleAnalyzer::resolve_refs(&mut error_type, scoping, decls)?;
+
+        if !matches!(error_type, TypeAnnotation::Object(..)) {
+            anyhow::bail!("Type `{name}` of the `@throws` annotation must be an object type");
+        }
+
+        Ok(error_type)
+    }
+
+    /// Resolves the declarations imported by the other file.
+    ///
+    /// Returns the resolved declarations keyed by the symbol IDs of the importing file,
+    /// and the names re-exported from the other files to follow.
+    /// The names that are not declared are skipped, so only the references to them fail to resolve.
+    fn try_into_exports(
+        self,
+        imported: &[(SymbolId, String)],
+        path: &Path,
+    ) -> Result<LocalExports, anyhow::Error> {
+        fn has_ref(type_annotation: &TypeAnnotation) -> bool {
+            match type_annotation {
+                TypeAnnotation::Ref(..) => true,
+                TypeAnnotation::Object(obj) => {
+                    obj.props.iter().any(|prop| has_ref(&prop.type_annotation))
+                }
+                TypeAnnotation::Tuple(element_types) => element_types.iter().any(has_ref),
+                TypeAnnotation::Nullable(t)
+                | TypeAnnotation::Array(t)
+                | TypeAnnotation::Map(t)
+                | TypeAnnotation::Promise(t) => has_ref(t),
+                _ => false,
+            }
+        }
+
+        // Enums declared in this file are imported from this file by the generated code
+        fn set_decl_file(type_annotation: &mut TypeAnnotation, path: &Path) {
+            match type_annotation {
+                TypeAnnotation::Enum(enum_type) => {
+                    enum_type
+                        .decl_file
+                        .get_or_insert_with(|| path.to_path_buf());
+                }
+                TypeAnnotation::Object(obj) => obj
+                    .props
+                    .iter_mut()
+                    .for_each(|prop| set_decl_file(&mut prop.type_annotation, path)),
+                TypeAnnotation::Tuple(element_types) => element_types
+                    .iter_mut()
+                    .for_each(|element_type| set_decl_file(element_type, path)),
+                TypeAnnotation::Nullable(t)
+                | TypeAnnotation::Array(t)
+                | TypeAnnotation::Map(t)
+                | TypeAnnotation::Promise(t) => set_decl_file(t, path),
+                _ => {}
+            }
+        }
+
+        let mut exports = vec![];
+        let mut forwarded: Vec<LocalImport> = vec![];
+        let mut forward = |source: &str, local_id: SymbolId, name: &str| match forwarded
+            .iter_mut()
+            .find(|(s, _)| s == source)
+        {
+            Some((_, names)) => names.push((local_id, name.to_string())),
+            None => forwarded.push((source.to_string(), vec![(local_id, name.to_string())])),
+        };
+
+        for (local_id, name) in imported {
+            let decl = self
+                .scoping
+                .get_root_binding(name)
+                .and_then(|sym_id| self.decls.get(&sym_id));
+
+            let mut resolved = match decl {
+                Some(decl) => decl.clone(),
+                None => {
+                    let mut found = false;
+                    for (source, specifiers) in &self.reexports {
+                        match specifiers {
+                            Some(specifiers) => {
+                                if let Some((_, local)) =
+                                    specifiers.iter().find(|(exported, _)| exported == name)
+                                {
+                                    forward(source, *local_id, local);
+                                    found = true;
+                                }
+                            }
+                            // `export * from '...'` may provide any name
+                            None => forward(source, *local_id, name),
+                        }
+                    }
+
+                    if !found {
+                        debug!("Type `{name}` is not declared in `{}`", path.display());
+                    }
+                    continue;
+                }
+            };
+
+            NativeModuleAnalyzer::resolve_refs(&mut resolved, self.scoping, &self.decls)?;
+
+            // Recursive types are kept as references to the symbols of the other file
+            if has_ref(&resolved) {
+                anyhow::bail!(
+                    "Recursive type `{name}` cannot be imported. Declare the type in the spec file"
+                );
+            }
+            set_decl_file(&mut resolved, path);
+
+            exports.push((*local_id, resolved));
+        }
+
+        Ok((exports, forwarded))
+    }
+
     fn try_into_schema(self) -> Result<Vec<Schema>, anyhow::Error> {
         let mut schemas = Vec::with_capacity(self.specs.len());
 
@@ -732,7 +2085,11 @@ impl<'a> NativeModuleAnalyzer<'a> {
             let module_name = self
                 .mods
                 .get(&id)
-                .ok_or(anyhow::anyhow!("NativeModule name not found"))?;
+                .ok_or(anyhow::anyhow!(
+                    "NativeModule name not found for `{}`. Register the module in the same file (eg. `NativeModuleRegistry.getEnforcing<{}>('MyModule')`)",
+                    spec.name,
+                    spec.name
+                ))?;
 
             let mut methods = spec
                 .methods
@@ -743,7 +2100,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             &mut param.type_annotation,
                             self.scoping,
                             &self.decls,
-                        );
+                        )?;
 
                         NativeModuleAnalyzer::collect_types(
                             &param.type_annotation,
@@ -751,7 +2108,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             &self.decls,
                             &mut types,
                             &mut enums,
-                        );
+                        )?;
                     }
 
                     // Resolve type annotation of return value
@@ -759,7 +2116,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         &mut method.ret_type,
                         self.scoping,
                         &self.decls,
-                    );
+                    )?;
 
                     NativeModuleAnalyzer::collect_types(
                         &method.ret_type,
@@ -767,18 +2124,41 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         &self.decls,
                         &mut types,
                         &mut enums,
-                    );
+                    )?;
+
+                    // Resolve the structured error type of `@throws` annotation by name
+                    if let Some(TypeAnnotation::Object(obj)) = &method.error_type {
+                        let error_type = NativeModuleAnalyzer::try_into_declared_error_type(
+                            &obj.name,
+                            self.scoping,
+                            &self.decls,
+                        )?;
+
+                        NativeModuleAnalyzer::collect_types(
+                            &error_type,
+                            self.scoping,
+                            &self.decls,
+                            &mut types,
+                            &mut enums,
+                        )?;
+
+                        method.error_type = Some(error_type);
+                    }
 
-                    method
+                    Ok(method)
                 })
-                .collect::<Vec<Method>>();
+                .collect::<Result<Vec<Method>, anyhow::Error>>()?;
 
             let mut signals = spec
                 .signals
                 .into_iter()
                 .map(|mut signal| {
                     if let Some(ref mut payload_type) = signal.payload_type {
-                        NativeModuleAnalyzer::resolve_refs(payload_type, self.scoping, &self.decls);
+                        NativeModuleAnalyzer::resolve_refs(
+                            payload_type,
+                            self.scoping,
+                            &self.decls,
+                        )?;
 
                         NativeModuleAnalyzer::collect_types(
                             payload_type,
@@ -786,11 +2166,19 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             &self.decls,
                             &mut types,
                             &mut enums,
-                        );
+                        )?;
                     }
-                    signal
+                    Ok(signal)
                 })
-                .collect::<Vec<Signal>>();
+                .collect::<Result<Vec<Signal>, anyhow::Error>>()?;
+
+            trace!(
+                "Schema collected: {} (spec: {}, types: {}, enums: {})",
+                module_name,
+                spec.name,
+                types.len(),
+                enums.len()
+            );
 
             let mut aliases = types.into_iter().collect::<Vec<_>>();
             let mut enums = enums.into_iter().collect::<Vec<_>>();
@@ -798,16 +2186,21 @@ impl<'a> NativeModuleAnalyzer<'a> {
             // Sort collected metadata to ensure deterministic output (for hash)
             aliases.sort_by_key(|v| v.as_object().unwrap().name.to_lowercase());
             enums.sort_by_key(|v| v.as_enum().unwrap().name.to_lowercase());
-            methods.sort_by_key(|v| v.name.to_lowercase());
-            signals.sort_by_key(|v| v.name.to_lowercase());
+            // Names that only differ in case are ordered by their original names
+            methods.sort_by_key(|v| (v.name.to_lowercase(), v.name.clone()));
+            signals.sort_by_key(|v| (v.name.to_lowercase(), v.name.clone()));
 
-            schemas.push(Schema {
+            let schema = Schema {
                 module_name: module_name.to_owned(),
                 aliases,
                 enums,
                 methods,
                 signals,
-            });
+                spec_file: None,
+            };
+
+            assert_helper_type_names(&schema)?;
+            schemas.push(schema);
         }
 
         Ok(schemas)
@@ -816,7 +2209,14 @@ impl<'a> NativeModuleAnalyzer<'a> {
 
 impl<'a> Visit<'a> for NativeModuleAnalyzer<'a> {
     fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
+        if is_local_import(it.source.value.as_str()) {
+            // Collect types imported from the local files (eg. `import type { Foo } from './types'`)
+            self.collect_local_import(it);
+            return;
+        }
+
         if it.source.value.as_str() != NATIVE_MODULE_PKG {
+            trace!("Import skipped: {}", it.source.value);
             return;
         }
 
@@ -841,23 +2241,63 @@ impl<'a> Visit<'a> for NativeModuleAnalyzer<'a> {
                         NATIVE_MODULE_INTERFACE => self.mod_type_sym_id = Some(symbol_id),
                         NATIVE_MODULE_REGISTRY => self.mod_reg_sym_id = Some(symbol_id),
                         SIGNAL_TYPE => self.mod_signal_sym_id = Some(symbol_id),
-                        _ => {}
+                        INT32_TYPE => self.mod_int32_sym_id = Some(symbol_id),
+                        INT64_TYPE => self.mod_int64_sym_id = Some(symbol_id),
+                        _ => return trace!("Import specifier skipped: {}", imported_name),
                     };
+
+                    trace!(
+                        "Import specifier collected: {} as {} ({:?})",
+                        imported_name,
+                        spec.local.name,
+                        symbol_id
+                    );
                 }
             }
             ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => {
+                trace!(
+                    "Namespace import collected: {} ({:?})",
+                    spec.local.name,
+                    spec.local.symbol_id()
+                );
                 self.mod_ns_sym_id = Some(spec.local.symbol_id());
             }
             _ => {}
         }
     }
 
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        if let Some(source) = &it.source {
+            if is_local_import(source.value.as_str()) {
+                // Collect names re-exported from the local files (eg. `export type { Foo } from './types'`)
+                self.collect_reexport(source.value.as_str(), Some(&it.specifiers));
+            }
+        }
+
+        walk::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_export_all_declaration(&mut self, it: &ExportAllDeclaration<'a>) {
+        // `export * as ns from '...'` only exports the namespace
+        if it.exported.is_none() && is_local_import(it.source.value.as_str()) {
+            self.collect_reexport(it.source.value.as_str(), None);
+        }
+    }
+
     fn visit_ts_interface_declaration(&mut self, it: &TSInterfaceDeclaration<'a>) {
         if it.declare {
+            trace!("Ambient interface skipped: {}", it.id.name);
             return;
         }
 
-        if self.is_spec(it) {
+        let is_spec = self.is_spec(it);
+        trace!(
+            "Interface visited: {} (classified as {})",
+            it.id.name,
+            if is_spec { "spec" } else { "type" }
+        );
+
+        if is_spec {
             // Collect module spec
             self.collect_spec(it);
         } else {
@@ -868,29 +2308,178 @@ impl<'a> Visit<'a> for NativeModuleAnalyzer<'a> {
 
     fn visit_ts_type_alias_declaration(&mut self, it: &TSTypeAliasDeclaration<'a>) {
         if it.declare {
+            trace!("Ambient type alias skipped: {}", it.id.name);
             return;
         }
 
+        trace!("Type alias visited: {}", it.id.name);
+
         // Collect user defined type (type alias)
         self.collect_alias_type(it);
     }
 
     fn visit_ts_enum_declaration(&mut self, it: &TSEnumDeclaration<'a>) {
         if it.declare {
+            trace!("Ambient enum skipped: {}", it.id.name);
             return;
         }
 
+        trace!("Enum visited: {}", it.id.name);
+
         // Collect user defined enum type
         self.collect_enum_type(it);
     }
 
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        // Collect `as const` object literal (enum-like object)
+        self.collect_const_obj(it);
+        // Collect alias of `NativeModuleRegistry`
+        self.collect_reg_alias(it);
+        walk::walk_variable_declarator(self, it);
+    }
+
     fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
         // Collect module name from `NativeModuleRegistry.get()` or `NativeModuleRegistry.getEnforcing()`
         self.collect_mod(it);
     }
 }
 
-pub fn try_parse_schema(src: &str) -> Result<Vec<Schema>, ParseError> {
+/// Returns `true` if the module specifier points to a local file (eg. `./types`, `../types`).
+/// Ensures that the names of the generated helper structs (eg. `NullableFoo`, `FooMapEntry`)
+/// do not clash with the declared types or with the helper structs of the other types.
+fn assert_helper_type_names(schema: &Schema) -> Result<(), anyhow::Error> {
+    let declared = schema
+        .aliases
+        .iter()
+        .filter_map(|type_annotation| type_annotation.as_object().map(|obj| &obj.name))
+        .chain(
+            schema
+                .enums
+                .iter()
+                .filter_map(|type_annotation| type_annotation.as_enum().map(|e| &e.name)),
+        )
+        .collect::<FxHashSet<_>>();
+    let mut generated = FxHashMap::<String, TypeAnnotation>::default();
+
+    for (name, type_annotation) in schema.helper_types()? {
+        if declared.contains(&name) {
+            anyhow::bail!(
+                "Type `{name}` conflicts with the generated type of `{}`. Rename the type",
+                type_annotation.as_ts_type().into_code()
+            );
+        }
+
+        match generated.get(&name) {
+            Some(other) if *other != type_annotation => anyhow::bail!(
+                "Types `{}` and `{}` generate the same type `{name}`. Rename the types used in them",
+                other.as_ts_type().into_code(),
+                type_annotation.as_ts_type().into_code()
+            ),
+            Some(_) => {}
+            None => {
+                generated.insert(name, type_annotation);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_local_import(source: &str) -> bool {
+    source.starts_with("./") || source.starts_with("../")
+}
+
+/// Resolves the path of the local file imported from the directory.
+fn resolve_import_path(dir: &Path, source: &str) -> Option<PathBuf> {
+    let base = dir.join(source);
+    let candidates = [
+        base.clone(),
+        base.with_extension("ts"),
+        base.with_extension("tsx"),
+        base.join("index.ts"),
+    ];
+
+    candidates
+        .into_iter()
+        .filter(|p| matches!(p.extension(), Some(ext) if ext == "ts" || ext == "tsx"))
+        .find(|p| p.is_file())
+        .and_then(|p| p.canonicalize().ok())
+}
+
+/// Resolves the names imported from the local file, following the re-exports to the declaring files.
+///
+/// Imports of the files that cannot be found (eg. JavaScript files) are skipped,
+/// so only the references to the imported types fail to resolve.
+fn resolve_imports(
+    path: &Path,
+    source: &str,
+    imported: &[(SymbolId, String)],
+    import_stack: &mut Vec<PathBuf>,
+) -> Result<Vec<(SymbolId, TypeAnnotation)>, anyhow::Error> {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let import_path = match resolve_import_path(dir, source) {
+        Some(import_path) => import_path,
+        None => {
+            debug!(
+                "Cannot find module `{source}` imported from `{}`",
+                path.display()
+            );
+            return Ok(vec![]);
+        }
+    };
+
+    if let Some(pos) = import_stack.iter().position(|p| *p == import_path) {
+        let cycle = import_stack[pos..]
+            .iter()
+            .chain(std::iter::once(&import_path))
+            .map(|p| format!("`{}`", p.file_name().unwrap_or_default().to_string_lossy()))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+
+        anyhow::bail!("Circular import detected ({cycle})");
+    }
+
+    let src = fs::read_to_string(&import_path)?;
+
+    import_stack.push(import_path.clone());
+    let (mut exports, forwarded) = analyze(&src, Some(&import_path), import_stack, |analyzer| {
+        Ok(analyzer.try_into_exports(imported, &import_path)?)
+    })
+    .map_err(|e| match e {
+        ParseError::General(e) => e,
+        ParseError::Oxc { diagnostics } => anyhow::anyhow!(
+            "Failed to parse `{}`: {}",
+            import_path.display(),
+            diagnostics
+                .iter()
+                .map(|d| d.message.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    })?;
+
+    for (source, imported) in forwarded {
+        exports.extend(resolve_imports(
+            &import_path,
+            &source,
+            &imported,
+            import_stack,
+        )?);
+    }
+    import_stack.pop();
+
+    Ok(exports)
+}
+
+/// Parses the source and passes the analyzer to `f` once the declarations are collected.
+///
+/// The local imports are followed only if the path of the source is given.
+fn analyze<T>(
+    src: &str,
+    path: Option<&Path>,
+    import_stack: &mut Vec<PathBuf>,
+    f: impl FnOnce(NativeModuleAnalyzer<'_>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
     let allocator = Allocator::default();
     let source_type = SourceType::tsx();
     let ret = Parser::new(&allocator, src, source_type).parse();
@@ -911,28 +2500,82 @@ pub fn try_parse_schema(src: &str) -> Result<Vec<Schema>, ParseError> {
     }
 
     let scoping = ret.semantic.into_scoping();
-    let mut analyzer = NativeModuleAnalyzer::new(&scoping);
+    let mut analyzer = NativeModuleAnalyzer::new(&scoping, src, &program.comments);
 
     analyzer.visit_program(&program);
+    analyzer.collect_const_enum_types();
+
+    trace!(
+        "Collected symbols (NativeModule: {:?}, NativeModuleRegistry: {:?}, Signal: {:?}, namespace: {:?}, registry aliases: {:?})",
+        analyzer.mod_type_sym_id,
+        analyzer.mod_reg_sym_id,
+        analyzer.mod_signal_sym_id,
+        analyzer.mod_ns_sym_id,
+        analyzer.mod_reg_alias_sym_ids
+    );
+    trace!(
+        "Collected specs: {:?}, registered modules: {:?}",
+        analyzer
+            .specs
+            .values()
+            .map(|spec| &spec.name)
+            .collect::<Vec<_>>(),
+        analyzer.mods
+    );
 
     if !analyzer.diagnostics.is_empty() {
-        return Err(ParseError::Oxc {
-            diagnostics: analyzer.diagnostics,
-        });
+        let mut diagnostics = analyzer.diagnostics;
+        diagnostics.append(&mut analyzer.warnings);
+
+        return Err(ParseError::Oxc { diagnostics });
+    }
+
+    for warning in &analyzer.warnings {
+        warn!("{}", warning_str(warning, src, path));
+    }
+
+    if let Some(path) = path {
+        analyzer.resolve_local_imports(path, import_stack)?;
     }
 
     debug!("Collected decls: {:?}", analyzer.decls);
 
-    let schemas = analyzer.try_into_schema()?;
+    f(analyzer)
+}
+
+pub fn try_parse_schema(src: &str) -> Result<Vec<Schema>, ParseError> {
+    analyze(src, None, &mut vec![], |analyzer| {
+        Ok(analyzer.try_into_schema()?)
+    })
+}
+
+/// Parses the schema of the spec file, following the types imported from the local files
+/// (eg. `import type { Foo } from './types'`).
+pub fn try_parse_schema_from_path(src: &str, path: &Path) -> Result<Vec<Schema>, ParseError> {
+    let path = path.canonicalize().map_err(anyhow::Error::from)?;
 
-    Ok(schemas)
+    analyze(src, Some(&path), &mut vec![path.clone()], |analyzer| {
+        Ok(analyzer.try_into_schema()?)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use insta::{assert_debug_snapshot, assert_snapshot};
 
-    use crate::{parser::native_spec_parser::try_parse_schema, types::Schema};
+    use crate::{
+        parser::{
+            native_spec_parser::{
+                analyze, try_parse_schema, try_parse_schema_from_path, INVALID_BIGINT_ARRAY,
+                INVALID_DEFAULT_PARAM, INVALID_DETACHED_ANNOTATION, INVALID_HASHABLE_ANNOTATION,
+                INVALID_OPTIONAL_PARAM, INVALID_RECORD_KEY, INVALID_RECORD_VALUE,
+                INVALID_THROWS_ANNOTATION, INVALID_TUPLE_ELEMENT,
+            },
+            types::{DefaultValue, EnumMemberValue, ParseError, Platform, TypeAnnotation},
+            utils::warning_str,
+        },
+        types::Schema,
+    };
 
     #[test]
     fn test_common_spec() {
@@ -1005,6 +2648,56 @@ mod tests {
         assert_debug_snapshot!(schemas);
     }
 
+    #[test]
+    fn test_spec_interface_without_export() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Options {
+            verbose: boolean;
+        }
+
+        interface Spec extends NativeModule {
+            myMethod(options: Options): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].module_name, "MyModule");
+        assert_eq!(schemas[0].methods[0].name, "myMethod");
+        assert_eq!(schemas[0].aliases.len(), 1);
+    }
+
+    #[test]
+    fn test_spec_extends_other_interface() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Base {
+            id: string;
+        }
+
+        export interface Spec extends NativeModule, Base {
+            myMethod(): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected a spec extends error");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .to_string()
+            .starts_with("Spec interface can only extend `NativeModule`"));
+    }
+
     #[test]
     fn test_spec_import_without_type() {
         let src = "
@@ -1076,6 +2769,30 @@ mod tests {
         assert_debug_snapshot!(schemas);
     }
 
+    #[test]
+    fn test_invalid_signal_payload() {
+        let src = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            onFoo: Signal<number | string>;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+
+        // Invalid payload types are reported instead of making the signal payload-less
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected an invalid signal payload error");
+        };
+
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Union types only allow nullable type (eg. `T | null`)"
+        );
+    }
+
     #[test]
     fn test_ref_type() {
         let src = "
@@ -1244,7 +2961,435 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_enum_1() {
+    fn test_invalid_nullable_void() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: void | null): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected a nullable void error");
+        };
+
+        assert_eq!(diagnostics[0].to_string(), "Void type cannot be nullable");
+    }
+
+    #[test]
+    fn test_record() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Point {
+            x: number;
+            y: number;
+        }
+
+        export interface Spec extends NativeModule {
+            getPoints(): Record<string, Point>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(matches!(
+            &schemas[0].methods[0].ret_type,
+            TypeAnnotation::Map(value_type) if matches!(&**value_type, TypeAnnotation::Object(obj) if obj.name == "Point")
+        ));
+        assert_eq!(schemas[0].aliases.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_record() {
+        let messages = [
+            "getScores(): Record<number, number>;",
+            "getScores(): Record<string, number | null>;",
+            "getScores(): Record<string, number>[];",
+            "getScores(): Record<string, number> | null;",
+        ]
+        .iter()
+        .map(|method| {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a record error: {method}");
+            };
+            diagnostics[0].to_string()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [
+                INVALID_RECORD_KEY,
+                INVALID_RECORD_VALUE,
+                "Array of record type is not supported",
+                "Record type cannot be nullable",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_int_types() {
+        let src: &'static str = "
+        import type { NativeModule, Int32, Int64 } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            getHandle(id: Int32): Int64;
+            getHandles(): Int32[];
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+        let method = &schemas[0].methods[0];
+
+        assert_eq!(method.params[0].type_annotation, TypeAnnotation::Int32);
+        assert_eq!(method.ret_type, TypeAnnotation::Int64);
+        assert_eq!(
+            schemas[0].methods[1].ret_type,
+            TypeAnnotation::Array(Box::new(TypeAnnotation::Int32))
+        );
+    }
+
+    #[test]
+    fn test_int_types_without_import() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            getHandle(id: Int32): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        assert!(try_parse_schema(src).is_err());
+    }
+
+    #[test]
+    fn test_bigint_type() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            getRow(id: bigint): bigint | null;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+        let method = &schemas[0].methods[0];
+
+        assert_eq!(method.params[0].type_annotation, TypeAnnotation::BigInt);
+        assert_eq!(
+            method.ret_type,
+            TypeAnnotation::Nullable(Box::new(TypeAnnotation::BigInt))
+        );
+    }
+
+    #[test]
+    fn test_invalid_bigint_array() {
+        for ret_type in ["bigint[]", "readonly bigint[]", "ReadonlyArray<bigint>"] {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    getRows(): {ret_type};
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a bigint array error: {ret_type}");
+            };
+
+            assert_eq!(
+                diagnostics[0].to_string(),
+                INVALID_BIGINT_ARRAY,
+                "{ret_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_byte_arrays() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            fromArrayBuffer(data: ArrayBuffer): ArrayBuffer;
+            fromUint8Array(data: Uint8Array): ArrayBuffer;
+            fromInt8Array(data: Int8Array): ArrayBuffer;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_eq!(schemas[0].methods.len(), 3);
+        for method in &schemas[0].methods {
+            assert!(matches!(
+                method.params[0].type_annotation,
+                TypeAnnotation::ArrayBuffer
+            ));
+        }
+    }
+
+    #[test]
+    fn test_invalid_typed_array() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(data: Float32Array): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected a typed array error");
+        };
+
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "`Float32Array` is not supported yet. Use `ArrayBuffer`, `Uint8Array` or `Int8Array` instead"
+        );
+    }
+
+    #[test]
+    fn test_tuple_types() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Point {
+            x: number;
+            y: number;
+        }
+
+        export interface Spec extends NativeModule {
+            getSize(): [number, number];
+            getLabeled(): [width: number, label: string];
+            setPoints(points: [Point, boolean][]): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+        let methods = &schemas[0].methods;
+
+        assert_eq!(
+            methods[0].ret_type,
+            TypeAnnotation::Tuple(vec![TypeAnnotation::Number, TypeAnnotation::String])
+        );
+        assert_eq!(
+            methods[1].ret_type,
+            TypeAnnotation::Tuple(vec![TypeAnnotation::Number, TypeAnnotation::Number])
+        );
+        assert!(matches!(
+            &methods[2].params[0].type_annotation,
+            TypeAnnotation::Array(element_type)
+                if matches!(&**element_type, TypeAnnotation::Tuple(types) if types[0].as_object().is_some())
+        ));
+        assert_eq!(schemas[0].aliases.len(), 1);
+    }
+
+    #[test]
+    fn test_hashable_annotation() {
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            /** @hashable */
+            export interface Key {
+                id: string;
+            }
+
+            /** @hashable */
+            type AliasKey = {
+                id: string;
+            };
+
+            interface Value {
+                id: string;
+            }
+
+            export interface Spec extends NativeModule {
+                get(key: Key, aliasKey: AliasKey): Value;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+            ",
+        )
+        .unwrap();
+        let params = &schemas[0].methods[0].params;
+
+        assert!(params[0].type_annotation.as_object().unwrap().hashable);
+        assert!(params[1].type_annotation.as_object().unwrap().hashable);
+        assert!(!schemas[0].methods[0].ret_type.as_object().unwrap().hashable);
+
+        let src = "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            /** @hashable */
+            type Mode = 'read' | 'write';
+
+            export interface Spec extends NativeModule {
+                setMode(mode: Mode): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+            ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected a hashable error");
+        };
+
+        assert_eq!(diagnostics[0].to_string(), INVALID_HASHABLE_ANNOTATION);
+    }
+
+    #[test]
+    fn test_readonly_types() {
+        let spec = |modifier: &str, array_type: &str| {
+            format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Point {{
+                    {modifier}x: number;
+                    {modifier}tags: {array_type};
+                }}
+
+                export interface Spec extends NativeModule {{
+                    getPoint(): Point;
+                    setTags(tags: {array_type}): void;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            )
+        };
+        let mutable = try_parse_schema(&spec("", "string[]")).unwrap();
+
+        for (modifier, array_type) in [
+            ("readonly ", "string[]"),
+            ("", "readonly string[]"),
+            ("", "ReadonlyArray<string>"),
+            ("readonly ", "ReadonlyArray<string>"),
+        ] {
+            let readonly = try_parse_schema(&spec(modifier, array_type)).unwrap();
+
+            assert_eq!(
+                format!("{:?}", readonly),
+                format!("{:?}", mutable),
+                "{modifier}{array_type}"
+            );
+        }
+
+        let schemas = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Spec extends NativeModule {
+                getSize(): readonly [number, number];
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(
+            schemas[0].methods[0].ret_type,
+            TypeAnnotation::Tuple(vec![TypeAnnotation::Number, TypeAnnotation::Number])
+        );
+
+        // `readonly` on the other types is rejected instead of being ignored (same as TypeScript)
+        for invalid_type in ["readonly string", "readonly ReadonlyArray<string>"] {
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&spec("", invalid_type))
+            else {
+                panic!("expected a readonly type error: {invalid_type}");
+            };
+
+            assert_eq!(
+                diagnostics[0].to_string(),
+                "'readonly' type modifier is only permitted on array and tuple literal types.",
+                "{invalid_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_tuple_types() {
+        let cases = [
+            ("[]", "Empty tuple type is not supported"),
+            (
+                "[number, number, number, number, number, number, number, number, number]",
+                "Tuple type can have at most 8 elements (found 9)",
+            ),
+            ("[number, string?]", INVALID_TUPLE_ELEMENT),
+            ("[number, ...string[]]", INVALID_TUPLE_ELEMENT),
+            ("[number, string | null]", INVALID_TUPLE_ELEMENT),
+            ("[number, [number, number]]", INVALID_TUPLE_ELEMENT),
+            ("[number, MaybeNumber]", INVALID_TUPLE_ELEMENT),
+        ];
+
+        for (tuple_type, message) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export type MaybeNumber = number | null;
+
+                export interface Spec extends NativeModule {{
+                    myMethod(): {tuple_type};
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            // Elements resolved from the type aliases are validated after parsing
+            let error = match try_parse_schema(&src) {
+                Err(ParseError::Oxc { diagnostics }) => diagnostics[0].to_string(),
+                Err(ParseError::General(e)) => e.to_string(),
+                Ok(_) => panic!("expected a tuple error: {tuple_type}"),
+            };
+
+            assert_eq!(error, message, "{tuple_type}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_enum_1() {
         let src: &'static str = "
         import type { NativeModule, Signal } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
@@ -1255,114 +3400,1271 @@ mod tests {
         }
 
         export interface Spec extends NativeModule {
-            myMethod(arg: MyEnum): void;
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_enum_2() {
+        let src: &'static str = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            Foo = 1,
+            Bar = 3.14
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_helper_type_names() {
+        let spec = |types: &str, methods: &str| {
+            format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                {types}
+
+                export interface Spec extends NativeModule {{
+                    {methods}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            )
+        };
+        let error = |src: &str| match try_parse_schema(src) {
+            Err(ParseError::General(e)) => e.to_string(),
+            result => panic!("expected a helper type name error: {result:?}"),
+        };
+
+        // Tuples of the concatenated element names are distinct by the element count
+        let src = spec(
+            "interface NumberString { value: number; }",
+            "foo(arg: [NumberString]): [number, string];",
+        );
+        assert!(try_parse_schema(&src).is_ok());
+
+        let src = spec(
+            "interface Point { x: number; } interface PointMapEntry { y: number; }",
+            "foo(arg: Record<string, Point>): PointMapEntry;",
+        );
+        assert_eq!(
+            error(&src),
+            "Type `PointMapEntry` conflicts with the generated type of `Record<string, Point>`. Rename the type"
+        );
+
+        let src = spec(
+            "interface Point { x: number; } interface PointArray { y: number; }",
+            "foo(arg: [PointArray]): [Point[]];",
+        );
+        assert_eq!(
+            error(&src),
+            "Types `[PointArray]` and `[Point[]]` generate the same type `PointArrayTuple1`. Rename the types used in them"
+        );
+    }
+
+    #[test]
+    fn test_invalid_enum_exponent() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            A = 1e-3,
+            B = 1e3,
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected a float enum error");
+        };
+
+        // `1e3` is an integer
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Float number is not supported in enum"
+        );
+    }
+
+    #[test]
+    fn test_negative_enum() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            A = -1,
+            B = 0,
+            C = 1,
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+        let members = &result[0].enums[0].as_enum().unwrap().members;
+
+        assert_eq!(members[0].value, EnumMemberValue::Number(-1));
+        assert_eq!(members[1].value, EnumMemberValue::Number(0));
+        assert_eq!(members[2].value, EnumMemberValue::Number(1));
+    }
+
+    #[test]
+    fn test_implicit_enum() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            A,
+            B = 5,
+            C,
+            D,
+            E = -3,
+            F,
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+        let members = &result[0].enums[0].as_enum().unwrap().members;
+
+        assert_eq!(members[0].value, EnumMemberValue::Number(0));
+        assert_eq!(members[1].value, EnumMemberValue::Number(5));
+        assert_eq!(members[2].value, EnumMemberValue::Number(6));
+        assert_eq!(members[3].value, EnumMemberValue::Number(7));
+        assert_eq!(members[4].value, EnumMemberValue::Number(-3));
+        assert_eq!(members[5].value, EnumMemberValue::Number(-2));
+    }
+
+    #[test]
+    fn test_invalid_duplicate_enum_number() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            A = 1,
+            B = 0,
+            C,
+            D = 0,
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected a duplicate enum value error");
+        };
+
+        // The rest of the members are still collected after a duplicate
+        assert_eq!(diagnostics.len(), 2);
+        for diagnostic in &diagnostics {
+            assert_eq!(diagnostic.to_string(), "Enum member value must be unique");
+        }
+    }
+
+    #[test]
+    fn test_unsafe_enum_number() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            A = -9007199254740992,
+            B = 9007199254740991,
+            C,
+            /** @lossy */
+            D = 9007199254740996,
+        }
+
+        /** @lossy */
+        export enum LossyEnum {
+            A = 9007199254740992,
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum, other: LossyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let warnings = analyze(src, None, &mut vec![], |analyzer| {
+            Ok(analyzer
+                .warnings
+                .iter()
+                .map(|warning| warning_str(warning, src, None))
+                .collect::<Vec<_>>())
+        })
+        .unwrap();
+
+        // Values beyond the safe integer range are allowed with the warnings
+        assert!(try_parse_schema(src).is_ok());
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("6:13: Enum member value is not a safe integer"));
+        assert!(warnings[1].starts_with("8:13: Enum member value is not a safe integer"));
+    }
+
+    #[test]
+    fn test_invalid_enum_range() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        enum MyEnum {
+            A = 9223372036854775808,
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: MyEnum): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected an enum range error");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Enum member value must be a 64-bit integer"
+        );
+    }
+
+    #[test]
+    fn test_const_enum() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        const Colors = {
+            Red: 'red',
+            Green: 'green',
+        } as const;
+
+        const Config = { timeout: 100 } as const;
+
+        export type Color = (typeof Colors)[keyof typeof Colors];
+        export type Direction = typeof Direction[keyof typeof Direction];
+        export const Direction = { Up: 'up', Down: 'down' } as const;
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: Color): Direction;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_invalid_const_enum() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        const Colors = {
+            Red: 'red',
+            Green: 1,
+        } as const;
+
+        type Color = (typeof Colors)[keyof typeof Colors];
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: Color): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_string_literal_union() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export type Mode = 'read' | 'write' | 'read-write';
+        export type Encoding = 'utf8';
+
+        export interface Spec extends NativeModule {
+            open(path: string, mode: Mode, encoding: Encoding): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+
+        assert_eq!(
+            result[0].methods[0].params[2].type_annotation,
+            TypeAnnotation::String
+        );
+        assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_invalid_string_literal_union() {
+        let cases = [
+            (
+                "'read' | number",
+                "Union types only allow nullable type (eg. `T | null`)",
+            ),
+            (
+                "'read' | 'write' | null",
+                "Union types only allow nullable type (eg. `T | null`)",
+            ),
+            (
+                "'read' | '1x'",
+                "String literal `'1x'` cannot be converted to an enum member name",
+            ),
+            (
+                "'read-only' | 'read_only'",
+                "Duplicate enum member `ReadOnly` in the string literal union",
+            ),
+        ];
+
+        for (union_type, message) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export type Mode = {union_type};
+
+                export interface Spec extends NativeModule {{
+                    open(mode: Mode): void;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a union error: {union_type}");
+            };
+
+            assert_eq!(diagnostics[0].to_string(), message, "{union_type}");
+        }
+    }
+
+    #[test]
+    fn test_jsdoc_description() {
+        let result = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export interface Point {
+                /** Horizontal position */
+                x: number;
+                // Not a JSDoc comment
+                y: number;
+            }
+
+            export interface Spec extends NativeModule {
+                /**
+                 * Moves to the point.
+                 * @readonly
+                 */
+                moveTo(point: Point): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(result[0].methods[0].docs, vec!["Moves to the point."]);
+        let point = result[0].aliases[0].as_object().unwrap();
+        assert_eq!(point.props[0].docs, vec!["Horizontal position"]);
+        assert!(point.props[1].docs.is_empty());
+    }
+
+    #[test]
+    fn test_enum_fallback() {
+        let result = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+
+            export enum Theme {
+                Light = 'light',
+                /** @fallback */
+                Unknown = 'unknown',
+            }
+
+            export interface Spec extends NativeModule {
+                setTheme(theme: Theme): void;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+            ",
+        )
+        .unwrap();
+
+        let TypeAnnotation::Enum(enum_spec) = &result[0].methods[0].params[0].type_annotation
+        else {
+            panic!("expected an enum type");
+        };
+        assert_eq!(enum_spec.fallback.as_deref(), Some("Unknown"));
+    }
+
+    #[test]
+    fn test_invalid_enum_fallback() {
+        let cases = [
+            "/** @fallback */ A = 'a', /** @fallback */ B = 'b'",
+            "A = 1, /** @fallback */ B = 2",
+        ];
+
+        for members in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export enum Theme {{ {members} }}
+
+                export interface Spec extends NativeModule {{
+                    setTheme(theme: Theme): void;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a fallback error: {members}");
+            };
+
+            assert_eq!(
+                diagnostics[0].to_string(),
+                "`@fallback` annotation is only allowed on a single member of string enum",
+                "{members}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bytes_annotation() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(/** @bytes 32 */ key: ArrayBuffer, data: ArrayBuffer): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_invalid_bytes_annotation() {
+        let src_1: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(/** @bytes 32 */ key: number): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let src_2: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(/** @bytes 0 */ key: ArrayBuffer): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        assert!(try_parse_schema(src_1).is_err());
+        assert!(try_parse_schema(src_2).is_err());
+    }
+
+    #[test]
+    fn test_optional_1() {
+        let src: &'static str = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(arg?: number): void;
+            myMethod2(arg?: number, arg2: string): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(src) else {
+            panic!("expected an optional parameter error");
+        };
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Optional parameter is only allowed at the end of the parameter list"
+        );
+    }
+
+    #[test]
+    fn test_optional_trailing_params() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: string, arg2?: number, arg3?: boolean | null): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+        let params = &result[0].methods[0].params;
+
+        assert!(!params[0].optional);
+        assert_eq!(params[0].type_annotation, TypeAnnotation::String);
+        assert!(params[1].optional);
+        assert_eq!(
+            params[1].type_annotation,
+            TypeAnnotation::Nullable(Box::new(TypeAnnotation::Number))
+        );
+        assert!(params[2].optional);
+        assert_eq!(
+            params[2].type_annotation,
+            TypeAnnotation::Nullable(Box::new(TypeAnnotation::Boolean))
+        );
+    }
+
+    #[test]
+    fn test_default_params() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(a: number, b: number = 2, c: string = 'hi', d: boolean = true, e: number = -0.5): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+        let params = &result[0].methods[0].params;
+
+        assert!(params[0].is_required());
+        assert_eq!(params[1].type_annotation, TypeAnnotation::Number);
+        assert!(!params[1].optional);
+        assert_eq!(
+            params
+                .iter()
+                .map(|param| param.default_value.clone())
+                .collect::<Vec<_>>(),
+            [
+                None,
+                Some(DefaultValue::Number("2.0".into())),
+                Some(DefaultValue::String("hi".into())),
+                Some(DefaultValue::Boolean(true)),
+                Some(DefaultValue::Number("-0.5".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_default_params() {
+        let messages = [
+            "myMethod(a: number = 2, b: number): void;",
+            "myMethod(a: number = 'two'): void;",
+            "myMethod(a: string = `hi`): void;",
+            "myMethod(a: number = 1 + 1): void;",
+            "myMethod(a: number[] = []): void;",
+            "myMethod(a: Int32 = 1.5): void;",
+            "myMethod(a?: number = 2): void;",
+        ]
+        .iter()
+        .map(|method| {
+            let src = format!(
+                "
+                import type {{ Int32, NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a default value error: {method}");
+            };
+            diagnostics[0].to_string()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [
+                INVALID_OPTIONAL_PARAM,
+                INVALID_DEFAULT_PARAM,
+                INVALID_DEFAULT_PARAM,
+                INVALID_DEFAULT_PARAM,
+                INVALID_DEFAULT_PARAM,
+                INVALID_DEFAULT_PARAM,
+                INVALID_DEFAULT_PARAM,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_optional_2() {
+        let src: &'static str = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Foo {
+            bar?: number
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: Foo): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_optional_3() {
+        let src: &'static str = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Foo = {
+            bar?: number
+        }
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: Foo): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reserved_type() {
+        let src: &'static str = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Promise = number;
+
+        export interface Spec extends NativeModule {
+            myMethod(arg: Promise): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_enum_array() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export enum MyEnum {
+            Foo = 'foo',
+            Bar = 'bar',
+        }
+
+        export interface Spec extends NativeModule {
+            getEnums(): MyEnum[];
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+
+        // Enums referenced only through arrays must be collected as well
+        assert_eq!(result[0].enums.len(), 1);
+        assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_recursive_nullable_type() {
+        let src_1: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface ListNode {
+            value: number;
+            next: ListNode | null;
+        }
+
+        export interface Spec extends NativeModule {
+            getList(): ListNode;
+            getTail(head: ListNode | null): ListNode | null;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let src_2: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Foo {
+            bar: Bar | null;
+        }
+
+        export interface Bar {
+            foo: Foo;
+        }
+
+        export interface Spec extends NativeModule {
+            getFoo(): Foo;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let result_1 = try_parse_schema(src_1).unwrap();
+        let result_2 = try_parse_schema(src_2).unwrap();
+
+        // Types referenced only through the recursive references must be collected as well
+        assert_eq!(result_1[0].aliases.len(), 1);
+        assert_eq!(result_2[0].aliases.len(), 2);
+        assert_debug_snapshot!(result_1);
+    }
+
+    #[test]
+    fn test_recursive_type_hash() {
+        let src_1: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface ListNode {
+            value: number;
+            next: ListNode | null;
+        }
+
+        export interface Spec extends NativeModule {
+            getList(): ListNode;
+            getTail(head: ListNode | null): ListNode | null;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        // Same spec, but the extra references shift the reference ids of `ListNode`
+        let src_2: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        const a = 1;
+        const b = a + a;
+
+        export interface ListNode {
+            value: number;
+            next: ListNode | null;
+        }
+
+        export interface Spec extends NativeModule {
+            getList(): ListNode;
+            getTail(head: ListNode | null): ListNode | null;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let result_1 = try_parse_schema(src_1).unwrap();
+        let result_2 = try_parse_schema(src_2).unwrap();
+
+        let ref_id = |schemas: &[Schema]| {
+            let TypeAnnotation::Object(obj) = &schemas[0].aliases[0] else {
+                unreachable!()
+            };
+            let TypeAnnotation::Nullable(inner) = &obj.props[1].type_annotation else {
+                unreachable!()
+            };
+            let TypeAnnotation::Ref(r) = inner.as_ref() else {
+                unreachable!()
+            };
+            r.ref_id
+        };
+        assert_ne!(ref_id(&result_1), ref_id(&result_2));
+
+        assert_eq!(
+            result_1[0].aliases[0].to_id(),
+            result_2[0].aliases[0].to_id()
+        );
+        assert_eq!(
+            result_1[0].methods[0].ret_type.to_id(),
+            result_2[0].methods[0].ret_type.to_id()
+        );
+        assert_eq!(Schema::to_hash(&result_1), Schema::to_hash(&result_2));
+    }
+
+    #[test]
+    fn test_platform_annotation() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            /** @platform ios */
+            openAppStore(): void;
+            /** @platform android */
+            openPlayStore(): void;
+            openUrl(url: string): void;
         }
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
+        let result = try_parse_schema(src).unwrap();
 
-        assert!(result.is_err());
+        assert_eq!(result[0].methods[0].platform, Some(Platform::Ios));
+        assert_eq!(result[0].methods[1].platform, Some(Platform::Android));
+        assert_eq!(result[0].methods[2].platform, None);
     }
 
     #[test]
-    fn test_invalid_enum_2() {
+    fn test_invalid_platform_annotation() {
         let src: &'static str = "
-        import type { NativeModule, Signal } from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        enum MyEnum {
-            Foo = 1,
-            Bar = 3.14
+        export interface Spec extends NativeModule {
+            /** @platform web */
+            openUrl(url: string): void;
         }
 
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        assert!(try_parse_schema(src).is_err());
+    }
+
+    #[test]
+    fn test_cancellable_annotation() {
+        let src: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
         export interface Spec extends NativeModule {
-            myMethod(arg: MyEnum): void;
+            /**
+             * Long running task
+             * @cancellable
+             */
+            longTask(n: number): Promise<number>;
+            shortTask(n: number): Promise<number>;
         }
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
+        let result = try_parse_schema(src).unwrap();
 
-        assert!(result.is_err());
+        assert!(result[0].methods[0].cancellable);
+        assert!(!result[0].methods[1].cancellable);
     }
 
     #[test]
-    fn test_optional_1() {
+    fn test_detached_annotation() {
         let src: &'static str = "
-        import type { NativeModule, Signal } from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
         export interface Spec extends NativeModule {
-            myMethod(arg?: number): void;
+            /** @detached */
+            track(event: string): Promise<void>;
+            flush(): Promise<void>;
         }
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
+        let result = try_parse_schema(src).unwrap();
 
-        assert!(result.is_err());
+        assert!(!result[0].methods[0].detached);
+        assert!(result[0].methods[1].detached);
     }
 
     #[test]
-    fn test_optional_2() {
+    fn test_invalid_detached_annotation() {
+        let messages = [
+            "/** @detached */ track(event: string): void;",
+            "/** @detached */ track(event: string): Promise<number>;",
+            "/** @cancellable */ /** @detached */ track(event: string): Promise<void>;",
+        ]
+        .iter()
+        .map(|method| {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a detached error: {method}");
+            };
+            diagnostics[0].to_string()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(messages, [INVALID_DETACHED_ANNOTATION; 3]);
+    }
+
+    #[test]
+    fn test_throws_annotation() {
         let src: &'static str = "
-        import type { NativeModule, Signal } from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        interface Foo {
-            bar?: number
+        type FetchError = {
+            code: number;
+            reason: string;
+        };
+
+        export interface Spec extends NativeModule {
+            /**
+             * Fetches the content
+             * @throws {FetchError} when the request fails
+             */
+            fetch(url: string): Promise<string>;
+            ping(): Promise<void>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src).unwrap();
+
+        let Some(TypeAnnotation::Object(error_type)) = &result[0].methods[0].error_type else {
+            panic!("expected an object error type");
+        };
+        assert_eq!(error_type.name, "FetchError");
+        assert_eq!(error_type.props.len(), 2);
+        assert!(result[0].methods[1].error_type.is_none());
+        assert!(result[0].aliases.iter().any(|alias| matches!(
+            alias,
+            TypeAnnotation::Object(obj) if obj.name == "FetchError"
+        )));
+    }
+
+    #[test]
+    fn test_invalid_throws_annotation() {
+        let messages = [
+            "/** @throws FetchError */ fetch(url: string): string;",
+            "/** @throws */ fetch(url: string): Promise<string>;",
+            "/** @cancellable */ /** @throws FetchError */ fetch(url: string): Promise<string>;",
+            "/** @detached */ /** @throws FetchError */ fetch(url: string): Promise<void>;",
+        ]
+        .iter()
+        .map(|method| {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                type FetchError = {{ code: number }};
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::Oxc { diagnostics }) = try_parse_schema(&src) else {
+                panic!("expected a throws error: {method}");
+            };
+            diagnostics[0].to_string()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(messages, [INVALID_THROWS_ANNOTATION; 4]);
+    }
+
+    #[test]
+    fn test_invalid_throws_type() {
+        let messages = [
+            "/** @throws FetchError */ fetch(url: string): Promise<string>;",
+            "/** @throws ErrorCode */ fetch(url: string): Promise<string>;",
+        ]
+        .iter()
+        .map(|method| {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                enum ErrorCode {{
+                    Timeout = 1,
+                }}
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let Err(ParseError::General(err)) = try_parse_schema(&src) else {
+                panic!("expected a throws type error: {method}");
+            };
+            err.to_string()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [
+                "Type `FetchError` of the `@throws` annotation is not declared",
+                "Type `ErrorCode` of the `@throws` annotation must be an object type",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancellable_annotation() {
+        let src_1: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            /** @cancellable */
+            syncTask(n: number): number;
         }
 
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let src_2: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
         export interface Spec extends NativeModule {
-            myMethod(arg: Foo): void;
+            /** @cancellable */
+            longTask(cancelToken: number): Promise<number>;
         }
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
 
-        assert!(result.is_err());
+        assert!(try_parse_schema(src_1).is_err());
+        assert!(try_parse_schema(src_2).is_err());
     }
 
     #[test]
-    fn test_optional_3() {
-        let src: &'static str = "
-        import type { NativeModule, Signal } from 'craby-modules';
+    fn test_self_referential_type() {
+        let src_1: &'static str = "
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        type Foo = {
-            bar?: number
+        export interface Node {
+            value: number;
+            next: Node;
         }
 
         export interface Spec extends NativeModule {
-            myMethod(arg: Foo): void;
+            getNode(): Node;
         }
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
 
-        assert!(result.is_err());
+        let src_2: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Foo {
+            bar: Bar;
+        }
+
+        export interface Bar {
+            foo: Foo;
+        }
+
+        export interface Spec extends NativeModule {
+            getFoo(): Foo;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let Err(ParseError::General(err_1)) = try_parse_schema(src_1) else {
+            panic!("expected a recursive type error");
+        };
+        let Err(ParseError::General(err_2)) = try_parse_schema(src_2) else {
+            panic!("expected a recursive type error");
+        };
+
+        assert_eq!(
+            err_1.to_string(),
+            "Recursive type has infinite size (`Node` -> `Node`). Make the recursive field nullable (eg. `next: Node | null`)"
+        );
+        assert_eq!(
+            err_2.to_string(),
+            "Recursive type has infinite size (`Foo` -> `Bar` -> `Foo`). Make the recursive field nullable (eg. `foo: Foo | null`)"
+        );
+
+        let src_3: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Tree {
+            children: Tree[];
+        }
+
+        export interface Spec extends NativeModule {
+            getTree(): Tree;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let Err(ParseError::General(err_3)) = try_parse_schema(src_3) else {
+            panic!("expected a recursive type error");
+        };
+
+        assert_eq!(
+            err_3.to_string(),
+            "Recursive type is only supported through nullable references (`Tree` -> `Tree`). Reference the type directly as nullable (eg. `children: Tree | null`)"
+        );
     }
 
     #[test]
-    fn test_reserved_type() {
-        let src: &'static str = "
-        import type { NativeModule, Signal } from 'craby-modules';
+    fn test_unresolved_type() {
+        let src_1: &'static str = "
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        type Promise = number;
+        export interface Spec extends NativeModule {
+            getFoo(): Foo;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+
+        let src_2: &'static str = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { Foo } from './types';
 
         export interface Spec extends NativeModule {
-            myMethod(arg: Promise): void;
+            getFoo(): Foo | null;
         }
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
 
-        assert!(result.is_err());
+        let Err(ParseError::General(err_1)) = try_parse_schema(src_1) else {
+            panic!("expected an unresolved type error");
+        };
+        let Err(ParseError::General(err_2)) = try_parse_schema(src_2) else {
+            panic!("expected an unresolved type error");
+        };
+
+        assert_eq!(err_1.to_string(), "Type `Foo` is not defined");
+        assert_eq!(
+            err_2.to_string(),
+            "Type `Foo` cannot be resolved. Declare the type in the spec file or import it from a local file with `import type`"
+        );
+    }
+
+    #[test]
+    fn test_reexported_module() {
+        let head = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import * as Craby from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            foo(): void;
+        }
+        ";
+        let tails = [
+            "const MyModule = NativeModuleRegistry.getEnforcing<Spec>('MyModule'); export default MyModule;",
+            "const MyModule = NativeModuleRegistry.getEnforcing<Spec>('MyModule'); export { MyModule as default };",
+            "const Registry = NativeModuleRegistry; export default Registry.getEnforcing<Spec>('MyModule');",
+            "const Registry = Craby.NativeModuleRegistry; const Reg = Registry; export default Reg.get<Spec>('MyModule');",
+        ];
+
+        for tail in tails {
+            let result = try_parse_schema(&format!("{head}{tail}")).unwrap();
+
+            assert_eq!(result.len(), 1, "{tail}");
+            assert_eq!(result[0].module_name, "MyModule", "{tail}");
+        }
+
+        // Registration in another file is not supported
+        let Err(ParseError::General(err)) =
+            try_parse_schema(&format!("{head}export {{ default }} from './other';"))
+        else {
+            panic!("expected a module name error");
+        };
+
+        assert!(err
+            .to_string()
+            .starts_with("NativeModule name not found for `Spec`"));
     }
 
     #[test]
@@ -1480,4 +4782,258 @@ mod tests {
         assert_ne!(hash_1, hash_3);
         assert_snapshot!([hash_1, hash_2, hash_3].join("\n"));
     }
+
+    fn write_files(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join("craby-codegen-tests").join(name);
+
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        for (file, src) in files {
+            let path = dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, src).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn test_local_imports() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { Point as Position } from './types';
+        import { type Status } from './types/status';
+
+        export interface Spec extends NativeModule {
+            move(position: Position): Status;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let types = "
+        import type { Int32 } from 'craby-modules';
+        import type { Status } from './types/status';
+
+        export interface Point {
+            x: Int32;
+            y: Int32;
+            status: Status | null;
+        }
+        ";
+        let status = "
+        export enum Status {
+            Idle = 'idle',
+            Moving = 'moving',
+        }
+        ";
+        let dir = write_files(
+            "local_imports",
+            &[
+                ("NativeMyModule.ts", spec),
+                ("types.ts", types),
+                ("types/status/index.ts", status),
+            ],
+        );
+        let schemas = try_parse_schema_from_path(spec, &dir.join("NativeMyModule.ts")).unwrap();
+        let dir = dir.canonicalize().unwrap();
+
+        // Enums are imported from the declaring file by the generated code
+        assert_snapshot!(format!("{schemas:#?}").replace(dir.to_str().unwrap(), "[dir]"));
+    }
+
+    #[test]
+    fn test_local_imports_without_path() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { Point } from './types';
+
+        export interface Spec extends NativeModule {
+            move(position: Point): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(matches!(
+            result,
+            Err(ParseError::General(e)) if e.to_string().contains("Type `Point` cannot be resolved")
+        ));
+    }
+
+    #[test]
+    fn test_circular_imports() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { Foo } from './foo';
+
+        export interface Spec extends NativeModule {
+            foo(arg: Foo): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let foo = "
+        import type { Bar } from './bar';
+
+        export interface Foo {
+            bar: Bar;
+        }
+        ";
+        let bar = "
+        import type { Foo } from './foo';
+
+        export interface Bar {
+            foo: Foo | null;
+        }
+        ";
+        let dir = write_files(
+            "circular_imports",
+            &[
+                ("NativeMyModule.ts", spec),
+                ("foo.ts", foo),
+                ("bar.ts", bar),
+            ],
+        );
+        let result = try_parse_schema_from_path(spec, &dir.join("NativeMyModule.ts"));
+
+        assert!(matches!(
+            result,
+            Err(ParseError::General(e))
+                if e.to_string() == "Circular import detected (`foo.ts` -> `bar.ts` -> `foo.ts`)"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_local_imports() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { Missing } from './types';
+        import type { Unknown } from './unknown';
+
+        export interface Spec extends NativeModule {
+            foo(arg: Missing): void;
+            bar(arg: Unknown): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let types = "
+        export interface Point {
+            x: number;
+        }
+        ";
+        let dir = write_files(
+            "invalid_local_imports",
+            &[("NativeMyModule.ts", spec), ("types.ts", types)],
+        );
+        let result = try_parse_schema_from_path(spec, &dir.join("NativeMyModule.ts"));
+
+        assert!(matches!(
+            result,
+            Err(ParseError::General(e)) if e.to_string().starts_with("Type `Missing` cannot be resolved")
+        ));
+
+        let dir = write_files(
+            "invalid_local_imports_missing",
+            &[("NativeMyModule.ts", spec)],
+        );
+        let result = try_parse_schema_from_path(spec, &dir.join("NativeMyModule.ts"));
+
+        assert!(matches!(
+            result,
+            Err(ParseError::General(e)) if e.to_string().starts_with("Type `Missing` cannot be resolved")
+        ));
+    }
+
+    #[test]
+    fn test_local_value_imports() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import { MODULE_NAME } from './utils';
+        import { noop } from './helpers.js';
+
+        export interface Spec extends NativeModule {
+            foo(arg: number): void;
+        }
+
+        noop(MODULE_NAME);
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let utils = "
+        export const MODULE_NAME = 'MyModule';
+        ";
+        let helpers = "
+        export function noop() {}
+        ";
+        let dir = write_files(
+            "local_value_imports",
+            &[
+                ("NativeMyModule.ts", spec),
+                ("utils.ts", utils),
+                ("helpers.js", helpers),
+            ],
+        );
+        let result = try_parse_schema_from_path(spec, &dir.join("NativeMyModule.ts"));
+
+        // Value imports are not followed, so the existing specs keep working
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[test]
+    fn test_local_reexports() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { Foo, Bar as Baz } from './types';
+
+        export interface Spec extends NativeModule {
+            foo(arg: Foo): Baz;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let types = "
+        export type { Foo } from './foo';
+        export * from './bar';
+        ";
+        let foo = "
+        export interface Foo {
+            value: string;
+        }
+        ";
+        let bar = "
+        export interface Bar {
+            value: number;
+        }
+        ";
+        let dir = write_files(
+            "local_reexports",
+            &[
+                ("NativeMyModule.ts", spec),
+                ("types.ts", types),
+                ("foo.ts", foo),
+                ("bar.ts", bar),
+            ],
+        );
+        let schemas = try_parse_schema_from_path(spec, &dir.join("NativeMyModule.ts")).unwrap();
+        let method = &schemas[0].methods[0];
+
+        assert!(matches!(
+            &method.params[0].type_annotation,
+            TypeAnnotation::Object(obj) if obj.name == "Foo"
+        ));
+        assert!(matches!(
+            &method.ret_type,
+            TypeAnnotation::Object(obj) if obj.name == "Bar"
+        ));
+    }
 }
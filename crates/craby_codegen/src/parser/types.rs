@@ -1,4 +1,7 @@
-use std::hash::{DefaultHasher, Hash, Hasher};
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    path::PathBuf,
+};
 
 use oxc::{diagnostics::OxcDiagnostic, semantic::ReferenceId};
 use serde::Serialize;
@@ -27,12 +30,62 @@ pub struct Method {
     pub name: String,
     pub params: Vec<Param>,
     pub ret_type: TypeAnnotation,
+    /// Whether the Promise method receives a `CancellationToken` (`@cancellable`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cancellable: bool,
+    /// Whether the `Promise<void>` method returns immediately without a promise (`@detached`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub detached: bool,
+    /// The only platform that exposes the method (`@platform ios|android`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<Platform>,
+    /// Whether the method receives `&self` instead of `&mut self` (`@readonly`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub readonly: bool,
+    /// Object type of the structured error that rejects the Promise (`@throws MyError`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<TypeAnnotation>,
+    /// Description lines of the JSDoc comment
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    Ios,
+    Android,
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub struct Param {
     pub name: String,
     pub type_annotation: TypeAnnotation,
+    /// Expected byte length of `ArrayBuffer` parameter (`@bytes N`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_length: Option<usize>,
+    /// Whether the trailing parameter can be omitted (`arg?: T`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    /// Literal value used when the trailing parameter is omitted (`arg: T = 2`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<DefaultValue>,
+}
+
+impl Param {
+    /// Returns `true` if the argument must be passed (neither optional nor defaulted).
+    pub fn is_required(&self) -> bool {
+        !self.optional && self.default_value.is_none()
+    }
+}
+
+/// Literal default value of the parameter.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
+pub enum DefaultValue {
+    Boolean(bool),
+    /// Number in the shortest round-trip form (eg. `2.0`, `-0.5`, `1e21`)
+    Number(String),
+    String(String),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
@@ -49,6 +102,15 @@ pub enum TypeAnnotation {
     Nullable(Box<TypeAnnotation>),
     // Reference to `TypeAnnotation::Object` or `TypeAnnotation::Enum` or Alias types (eg. `Promise`)
     Ref(RefTypeAnnotation),
+    // String-keyed map (eg. `Record<string, T>`)
+    Map(Box<TypeAnnotation>),
+    // Integer types of `craby-modules` (eg. `Int32`)
+    Int32,
+    Int64,
+    // `bigint` (bridged as `i64` and `jsi::BigInt`)
+    BigInt,
+    // Fixed-length tuple (eg. `[number, string]`)
+    Tuple(Vec<TypeAnnotation>),
 }
 
 impl TypeAnnotation {
@@ -75,24 +137,174 @@ impl TypeAnnotation {
     pub fn is_nullable(&self) -> bool {
         matches!(self, TypeAnnotation::Nullable(..))
     }
+
+    /// Returns the map type of the type itself or the resolved type of the promise (eg. `Promise<Record<string, T>>`).
+    pub fn as_map(&self) -> Option<&TypeAnnotation> {
+        match self {
+            TypeAnnotation::Map(..) => Some(self),
+            TypeAnnotation::Promise(resolve_type) => resolve_type.as_map(),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the helper struct of the nullable type (eg. `number[] | null` -> `NullableNumberArray`).
+    ///
+    /// The name is derived from the structure of the inner type only, so every platform gets the same name.
+    /// Arrays and nullables nested in the inner type are written as `Array` and `OrNull` suffixes
+    /// to keep the names distinct (eg. `(number | null)[] | null` -> `NullableNumberOrNullArray`).
+    pub fn nullable_name(&self) -> Result<String, anyhow::Error> {
+        match self {
+            TypeAnnotation::Nullable(type_annotation) => {
+                Ok(format!("Nullable{}", type_annotation.type_name()?))
+            }
+            _ => anyhow::bail!("Not a nullable type: {:?}", self),
+        }
+    }
+
+    /// Returns the name of the key/value pair struct of the map type (eg. `Record<string, number>` -> `NumberMapEntry`).
+    pub fn map_entry_name(&self) -> Result<String, anyhow::Error> {
+        match self {
+            TypeAnnotation::Map(value_type) => Ok(format!("{}MapEntry", value_type.type_name()?)),
+            _ => anyhow::bail!("Not a map type: {:?}", self),
+        }
+    }
+
+    /// Returns the name of the wrapper struct of the array nested in another array (eg. `number[]` of `number[][]` -> `NestedNumberArray`).
+    pub fn nested_array_name(&self) -> Result<String, anyhow::Error> {
+        match self {
+            TypeAnnotation::Array(..) => Ok(format!("Nested{}", self.type_name()?)),
+            _ => anyhow::bail!("Not an array type: {:?}", self),
+        }
+    }
+
+    /// Returns the arrays nested in other arrays of the type, innermost first (eg. `number[]` of `number[][]`).
+    pub fn nested_arrays(&self) -> Vec<&TypeAnnotation> {
+        match self {
+            TypeAnnotation::Array(element_type) => {
+                let mut nested_arrays = element_type.nested_arrays();
+                if let TypeAnnotation::Array(..) = &**element_type {
+                    nested_arrays.push(element_type);
+                }
+                nested_arrays
+            }
+            TypeAnnotation::Nullable(type_annotation)
+            | TypeAnnotation::Promise(type_annotation)
+            | TypeAnnotation::Map(type_annotation) => type_annotation.nested_arrays(),
+            _ => vec![],
+        }
+    }
+
+    /// Returns the name of the struct of the tuple type (eg. `[number, string]` -> `NumberStringTuple2`).
+    ///
+    /// The element count keeps the names of the tuples with the concatenated element names distinct
+    /// (eg. `[NumberString]` -> `NumberStringTuple1`).
+    pub fn tuple_name(&self) -> Result<String, anyhow::Error> {
+        match self {
+            TypeAnnotation::Tuple(..) => self.type_name(),
+            _ => anyhow::bail!("Not a tuple type: {:?}", self),
+        }
+    }
+
+    /// Returns the tuple types of the type (eg. `[number, string]` of `[number, string][] | null`).
+    pub fn tuples(&self) -> Vec<&TypeAnnotation> {
+        match self {
+            TypeAnnotation::Tuple(..) => vec![self],
+            TypeAnnotation::Array(type_annotation)
+            | TypeAnnotation::Nullable(type_annotation)
+            | TypeAnnotation::Promise(type_annotation)
+            | TypeAnnotation::Map(type_annotation) => type_annotation.tuples(),
+            _ => vec![],
+        }
+    }
+
+    /// Returns the name of the type used to derive the names of the helper structs.
+    fn type_name(&self) -> Result<String, anyhow::Error> {
+        let name = match self {
+            TypeAnnotation::Void => "Void".to_string(),
+            TypeAnnotation::Boolean => "Boolean".to_string(),
+            TypeAnnotation::Number => "Number".to_string(),
+            TypeAnnotation::Int32 => "Int32".to_string(),
+            TypeAnnotation::Int64 => "Int64".to_string(),
+            TypeAnnotation::BigInt => "BigInt".to_string(),
+            TypeAnnotation::String => "String".to_string(),
+            TypeAnnotation::ArrayBuffer => "ArrayBuffer".to_string(),
+            TypeAnnotation::Object(ObjectTypeAnnotation { name, .. })
+            | TypeAnnotation::Enum(EnumTypeAnnotation { name, .. })
+            | TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => name.clone(),
+            TypeAnnotation::Array(element_type) => format!("{}Array", element_type.type_name()?),
+            TypeAnnotation::Map(value_type) => format!("{}Map", value_type.type_name()?),
+            TypeAnnotation::Nullable(type_annotation) => {
+                format!("{}OrNull", type_annotation.type_name()?)
+            }
+            TypeAnnotation::Tuple(element_types) => format!(
+                "{}Tuple{}",
+                element_types
+                    .iter()
+                    .map(TypeAnnotation::type_name)
+                    .collect::<Result<String, _>>()?,
+                element_types.len()
+            ),
+            TypeAnnotation::Promise(..) => {
+                anyhow::bail!("Unsupported type annotation for helper struct: {:?}", self)
+            }
+        };
+
+        Ok(name)
+    }
+}
+
+impl Method {
+    /// Returns the nullable type of the `@throws` error type.
+    ///
+    /// The structured error is passed through the nullable out parameter of the FFI function
+    /// since cxx only carries the error message of `Result`.
+    pub fn nullable_error_type(&self) -> Option<TypeAnnotation> {
+        self.error_type
+            .as_ref()
+            .map(|error_type| TypeAnnotation::Nullable(Box::new(error_type.clone())))
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub struct ObjectTypeAnnotation {
     pub name: String,
     pub props: Vec<Prop>,
+    /// Whether the struct derives `PartialEq`, `Eq` and `Hash` (`@hashable`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub hashable: bool,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub struct Prop {
     pub name: String,
     pub type_annotation: TypeAnnotation,
+    /// Description lines of the JSDoc comment
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub struct EnumTypeAnnotation {
     pub name: String,
     pub members: Vec<EnumMember>,
+    /// Member name that unknown values are mapped to (`@fallback`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// `true` if declared with `export enum`, so it can be imported from the declaring file
+    #[serde(skip)]
+    pub exported: bool,
+    /// File the enum is imported from (`None` if declared in the spec file)
+    #[serde(skip)]
+    pub decl_file: Option<PathBuf>,
+}
+
+impl Hash for EnumTypeAnnotation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Where the enum is declared does not change the generated types
+        self.name.hash(state);
+        self.members.hash(state);
+        self.fallback.hash(state);
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
@@ -104,16 +316,44 @@ pub struct EnumMember {
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub enum EnumMemberValue {
     String(String),
-    Number(usize),
+    Number(i64),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RefTypeAnnotation {
     #[serde(skip)]
     pub ref_id: ReferenceId,
     pub name: String,
 }
 
+// References to the same type may come from different sites (eg. recursive types),
+// so they are identified by the type name only.
+impl PartialEq for RefTypeAnnotation {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for RefTypeAnnotation {}
+
+impl PartialOrd for RefTypeAnnotation {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RefTypeAnnotation {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl Hash for RefTypeAnnotation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub struct Signal {
     pub name: String,
@@ -131,7 +371,9 @@ mod tests {
             props: vec![Prop {
                 name: "prop".to_string(),
                 type_annotation: TypeAnnotation::String,
+                docs: vec![],
             }],
+            hashable: false,
         });
 
         let t2 = TypeAnnotation::Object(ObjectTypeAnnotation {
@@ -139,7 +381,9 @@ mod tests {
             props: vec![Prop {
                 name: "prop".to_string(),
                 type_annotation: TypeAnnotation::String,
+                docs: vec![],
             }],
+            hashable: false,
         });
 
         let t3 = TypeAnnotation::Object(ObjectTypeAnnotation {
@@ -148,15 +392,122 @@ mod tests {
                 Prop {
                     name: "prop".to_string(),
                     type_annotation: TypeAnnotation::String,
+                    docs: vec![],
                 },
                 Prop {
                     name: "prop2".to_string(),
                     type_annotation: TypeAnnotation::String,
+                    docs: vec![],
                 },
             ],
+            hashable: false,
         });
 
         assert_eq!(t1.to_id(), t2.to_id());
         assert_ne!(t1.to_id(), t3.to_id());
     }
+
+    #[test]
+    fn test_nullable_name() {
+        let nullable = |t: TypeAnnotation| TypeAnnotation::Nullable(Box::new(t));
+        let array = |t: TypeAnnotation| TypeAnnotation::Array(Box::new(t));
+        let object = TypeAnnotation::Object(ObjectTypeAnnotation {
+            name: "Point".to_string(),
+            props: vec![],
+            hashable: false,
+        });
+        let names = [
+            nullable(TypeAnnotation::Number),
+            nullable(TypeAnnotation::ArrayBuffer),
+            nullable(object.clone()),
+            nullable(array(TypeAnnotation::String)),
+            nullable(array(object.clone())),
+            nullable(array(array(TypeAnnotation::Number))),
+            nullable(nullable(TypeAnnotation::Number)),
+            nullable(array(nullable(TypeAnnotation::Number))),
+            nullable(nullable(array(TypeAnnotation::Number))),
+        ]
+        .iter()
+        .map(|t| t.nullable_name().unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            [
+                "NullableNumber",
+                "NullableArrayBuffer",
+                "NullablePoint",
+                "NullableStringArray",
+                "NullablePointArray",
+                "NullableNumberArrayArray",
+                "NullableNumberOrNull",
+                "NullableNumberOrNullArray",
+                "NullableNumberArrayOrNull",
+            ]
+        );
+        assert!(TypeAnnotation::Number.nullable_name().is_err());
+        assert!(
+            nullable(TypeAnnotation::Promise(Box::new(TypeAnnotation::Number)))
+                .nullable_name()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_nested_arrays() {
+        let nullable = |t: TypeAnnotation| TypeAnnotation::Nullable(Box::new(t));
+        let array = |t: TypeAnnotation| TypeAnnotation::Array(Box::new(t));
+        let object = TypeAnnotation::Object(ObjectTypeAnnotation {
+            name: "Point".to_string(),
+            props: vec![],
+            hashable: false,
+        });
+
+        assert_eq!(
+            array(array(TypeAnnotation::Number))
+                .nested_arrays()
+                .iter()
+                .map(|t| t.nested_array_name().unwrap())
+                .collect::<Vec<_>>(),
+            ["NestedNumberArray"]
+        );
+        assert_eq!(
+            nullable(array(array(array(object))))
+                .nested_arrays()
+                .iter()
+                .map(|t| t.nested_array_name().unwrap())
+                .collect::<Vec<_>>(),
+            ["NestedPointArray", "NestedPointArrayArray"]
+        );
+        assert!(array(TypeAnnotation::Number).nested_arrays().is_empty());
+    }
+
+    #[test]
+    fn test_tuples() {
+        let nullable = |t: TypeAnnotation| TypeAnnotation::Nullable(Box::new(t));
+        let array = |t: TypeAnnotation| TypeAnnotation::Array(Box::new(t));
+        let tuple = TypeAnnotation::Tuple(vec![
+            TypeAnnotation::Number,
+            TypeAnnotation::String,
+            array(TypeAnnotation::Boolean),
+        ]);
+
+        assert_eq!(
+            tuple.tuple_name().unwrap(),
+            "NumberStringBooleanArrayTuple3"
+        );
+        assert_eq!(
+            nullable(array(tuple.clone()))
+                .tuples()
+                .iter()
+                .map(|t| t.tuple_name().unwrap())
+                .collect::<Vec<_>>(),
+            ["NumberStringBooleanArrayTuple3"]
+        );
+        assert_eq!(
+            nullable(tuple).nullable_name().unwrap(),
+            "NullableNumberStringBooleanArrayTuple3"
+        );
+        assert!(TypeAnnotation::Number.tuple_name().is_err());
+    }
 }
@@ -1,9 +1,10 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use oxc::{
     diagnostics::{DiagnosticService, GraphicalReportHandler, GraphicalTheme, OxcDiagnostic},
     span::Span,
 };
+use serde::Serialize;
 
 pub struct RenderReportOptions<'a> {
     pub project_root: &'a PathBuf,
@@ -29,6 +30,189 @@ pub fn render_report<'a>(diagnostics: Vec<OxcDiagnostic>, opts: RenderReportOpti
     }
 }
 
+/// Machine-readable diagnostic (eg. for editor problem matchers)
+#[derive(Debug, Serialize)]
+pub struct DiagnosticEntry {
+    /// Path relative to the project root
+    pub file: String,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number
+    pub column: usize,
+    /// `error`, `warning` or `advice`
+    pub severity: String,
+    pub message: String,
+}
+
+pub fn to_diagnostic_entries<'a>(
+    diagnostics: &[OxcDiagnostic],
+    opts: &RenderReportOptions<'a>,
+) -> Vec<DiagnosticEntry> {
+    let file = opts
+        .path
+        .strip_prefix(opts.project_root)
+        .unwrap_or(opts.path)
+        .to_string_lossy()
+        .to_string();
+
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let offset = diagnostic
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.first())
+                .map(|label| label.offset())
+                .unwrap_or(0);
+            let (line, column) = line_column(opts.src, offset);
+
+            DiagnosticEntry {
+                file: file.clone(),
+                line,
+                column,
+                severity: format!("{:?}", diagnostic.severity).to_lowercase(),
+                message: diagnostic.message.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Returns 1-based line and column of the byte offset.
+fn line_column(src: &str, offset: usize) -> (usize, usize) {
+    let before = &src[..offset.min(src.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = match before.rfind('\n') {
+        Some(idx) => before[idx + 1..].chars().count() + 1,
+        None => before.chars().count() + 1,
+    };
+
+    (line, column)
+}
+
+/// Returns the warning message with the location (eg. `src/NativeFoo.ts:2:7: Unused`).
+pub fn warning_str(diagnostic: &OxcDiagnostic, src: &str, path: Option<&Path>) -> String {
+    let offset = diagnostic
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.first())
+        .map(|label| label.offset())
+        .unwrap_or(0);
+    let (line, column) = line_column(src, offset);
+
+    match path {
+        Some(path) => format!("{}:{line}:{column}: {}", path.display(), diagnostic.message),
+        None => format!("{line}:{column}: {}", diagnostic.message),
+    }
+}
+
 pub fn error(message: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(message.to_string()).with_label(span)
 }
+
+/// Parses block tags of the JSDoc comment content.
+///
+/// ```ts
+/// /** @bytes 32 */
+/// ```
+///
+/// `[("bytes", "32")]`
+pub fn jsdoc_tags(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter_map(|line| line.strip_prefix('@'))
+        .map(|tag| match tag.split_once(char::is_whitespace) {
+            Some((name, value)) => (name.to_string(), value.trim().to_string()),
+            None => (tag.to_string(), String::new()),
+        })
+        .collect()
+}
+
+/// Returns the description lines of the JSDoc comment content (the lines before the block tags).
+///
+/// ```ts
+/// /**
+///  * Multiplies two numbers.
+///  * @readonly
+///  */
+/// ```
+///
+/// `["Multiplies two numbers."]`
+pub fn jsdoc_description(content: &str) -> Vec<String> {
+    let lines = content
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .take_while(|line| !line.starts_with('@'))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    match lines.iter().position(|line| !line.is_empty()) {
+        Some(start) => {
+            let end = lines.iter().rposition(|line| !line.is_empty()).unwrap();
+            lines[start..=end].to_vec()
+        }
+        None => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_entries() {
+        let project_root = PathBuf::from("/project");
+        let path = PathBuf::from("/project/src/NativeFoo.ts");
+        let src = "const a = 1;\nconst b = 2;\n";
+        let diagnostics = vec![
+            error("Invalid", Span::new(19, 20)),
+            OxcDiagnostic::warn("Unused"),
+        ];
+        let entries = to_diagnostic_entries(
+            &diagnostics,
+            &RenderReportOptions {
+                project_root: &project_root,
+                path: &path,
+                src,
+            },
+        );
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].file, "src/NativeFoo.ts");
+        assert_eq!((entries[0].line, entries[0].column), (2, 7));
+        assert_eq!(entries[0].severity, "error");
+        assert_eq!(entries[0].message, "Invalid");
+        assert_eq!((entries[1].line, entries[1].column), (1, 1));
+        assert_eq!(entries[1].severity, "warning");
+    }
+
+    #[test]
+    fn test_jsdoc_tags() {
+        assert_eq!(
+            jsdoc_tags("*\n * Description\n * @bytes 32\n * @readonly\n "),
+            vec![
+                ("bytes".to_string(), "32".to_string()),
+                ("readonly".to_string(), String::new()),
+            ]
+        );
+        assert_eq!(
+            jsdoc_tags("* @bytes  16 "),
+            vec![("bytes".to_string(), "16".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_jsdoc_description() {
+        assert_eq!(
+            jsdoc_description(
+                "*\n * Multiplies two numbers.\n *\n * Returns the product.\n * @readonly\n "
+            ),
+            vec![
+                "Multiplies two numbers.".to_string(),
+                String::new(),
+                "Returns the product.".to_string(),
+            ]
+        );
+        assert!(jsdoc_description("* @bytes 16 ").is_empty());
+    }
+}
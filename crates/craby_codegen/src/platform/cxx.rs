@@ -7,8 +7,10 @@ use template::{cxx_arg_ref, cxx_arg_var};
 
 use crate::{
     common::IntoCode,
-    constants::specs::RESERVED_ARG_NAME_MODULE,
-    parser::types::{EnumTypeAnnotation, Method, ObjectTypeAnnotation, TypeAnnotation},
+    constants::specs::{RESERVED_ARG_NAME_ERROR, RESERVED_ARG_NAME_MODULE},
+    parser::types::{
+        DefaultValue, EnumTypeAnnotation, Method, ObjectTypeAnnotation, Platform, TypeAnnotation,
+    },
     platform::cxx::template::CxxBridgingTemplate,
     types::{CxxModuleName, CxxNamespace, Schema},
     utils::{calc_deps_order, indent_str},
@@ -45,6 +47,24 @@ pub struct CxxMethod {
     /// }
     /// ```
     pub impl_func: String,
+    /// The only platform that registers the method (`@platform`)
+    pub platform: Option<Platform>,
+    /// Description lines of the JSDoc comment
+    pub docs: Vec<String>,
+}
+
+impl Platform {
+    /// Returns the preprocessor condition of the platform.
+    ///
+    /// ```cpp
+    /// #if defined(__APPLE__)
+    /// ```
+    pub fn as_cxx_condition(&self) -> &'static str {
+        match self {
+            Platform::Ios => "defined(__APPLE__)",
+            Platform::Android => "defined(__ANDROID__)",
+        }
+    }
 }
 
 impl TypeAnnotation {
@@ -55,69 +75,49 @@ impl TypeAnnotation {
     /// ```cpp
     /// bool                          // Boolean
     /// double                        // Number
+    /// int32_t                       // Int32
+    /// int64_t                       // Int64, BigInt
     /// rust::Str                     // String (arguments)
     /// rust::String                  // String
     /// rust::Vec<double>             // Array<Number>
+    /// rust::Vec<craby::mymodule::bridging::NestedNumberArray>  // Array<Array<Number>>
     /// craby::mymodule::bridging::MyEnum       // Enum
     /// craby::mymodule::bridging::MyStruct     // Object
     /// craby::mymodule::bridging::NullableNumber  // Nullable<Number>
+    /// rust::Vec<craby::mymodule::bridging::NumberMapEntry>  // Map<Number>
+    /// craby::mymodule::bridging::NumberStringTuple2  // Tuple<Number, String>
     /// ```
     pub fn as_cxx_type(&self, cxx_ns: &CxxNamespace) -> Result<String, anyhow::Error> {
         let cxx_type = match self {
             TypeAnnotation::Void => "void".to_string(),
             TypeAnnotation::Boolean => "bool".to_string(),
             TypeAnnotation::Number => "double".to_string(),
+            TypeAnnotation::Int32 => "int32_t".to_string(),
+            TypeAnnotation::Int64 | TypeAnnotation::BigInt => "int64_t".to_string(),
             TypeAnnotation::String => "rust::String".to_string(),
             TypeAnnotation::ArrayBuffer => "rust::Vec<uint8_t>".to_string(),
-            TypeAnnotation::Array(element_type) => {
-                format!("rust::Vec<{}>", element_type.as_cxx_type(cxx_ns)?)
-            }
+            TypeAnnotation::Array(element_type) => match &**element_type {
+                TypeAnnotation::Array(..) => format!(
+                    "rust::Vec<{cxx_ns}::bridging::{}>",
+                    element_type.nested_array_name()?
+                ),
+                _ => format!("rust::Vec<{}>", element_type.as_cxx_type(cxx_ns)?),
+            },
             TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
                 format!("{cxx_ns}::bridging::{name}")
             }
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
                 format!("{cxx_ns}::bridging::{name}")
             }
-            TypeAnnotation::Nullable(type_annotation) => {
-                let cxx_struct = match &**type_annotation {
-                    TypeAnnotation::Boolean => "NullableBoolean".to_string(),
-                    TypeAnnotation::Number => "NullableNumber".to_string(),
-                    TypeAnnotation::String => "NullableString".to_string(),
-                    TypeAnnotation::Void => "NullableVoid".to_string(), 
-                    TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => format!("Nullable{}", name),
-                    TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => format!("Nullable{}", name),
-                    TypeAnnotation::ArrayBuffer => "NullableArrayBuffer".to_string(),
-                    TypeAnnotation::Array(element_type) => match &**element_type {
-                        TypeAnnotation::Boolean => "NullableBooleanArray".to_string(),
-                        TypeAnnotation::Number=> {
-                            "NullableNumberArray".to_string()
-                        }
-                        TypeAnnotation::String => {
-                            "NullableStringArray".to_string()
-                        }
-                        TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
-                            format!("Nullable{name}Array")
-                        }
-                        TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
-                            format!("Nullable{name}Array")
-                        }
-                        _ => {
-                            return Err(anyhow::anyhow!(
-                                "[as_cxx_type] Unsupported type annotation for nullable array type: {:?}",
-                                element_type
-                            ))
-                        }
-                    },
-                    _ => {
-                        return Err(anyhow::anyhow!(
-                            "[as_cxx_type] Unsupported type annotation for nullable type: {:?}",
-                            type_annotation
-                        ))
-                    }
-                };
+            TypeAnnotation::Nullable(..) => {
+                let cxx_struct = self.nullable_name()?;
 
                 format!("{cxx_ns}::bridging::{cxx_struct}")
             }
+            TypeAnnotation::Map(..) => {
+                format!("rust::Vec<{cxx_ns}::bridging::{}>", self.map_entry_name()?)
+            }
+            TypeAnnotation::Tuple(..) => format!("{cxx_ns}::bridging::{}", self.tuple_name()?),
             _ => {
                 return Err(anyhow::anyhow!(
                     "[as_cxx_type] Unsupported type annotation: {:?}",
@@ -136,19 +136,23 @@ impl TypeAnnotation {
     /// ```cpp
     /// false                                 // Boolean
     /// 0.0                                   // Number
+    /// 0                                     // Int32, Int64, BigInt
     /// rust::String()                        // String
     /// rust::Vec<double>()                   // Array<Number>
     /// MyEnum::FirstMember                   // Enum
-    /// craby::mymodule::bridging::MyStruct{} // Object
+    /// craby::mymodule::bridging::MyStruct{} // Object, Tuple
     /// ```
     pub fn as_cxx_default_val(&self, cxx_ns: &CxxNamespace) -> Result<String, anyhow::Error> {
         let default_val = match self {
             TypeAnnotation::Boolean => "false".to_string(),
             TypeAnnotation::Number => "0.0".to_string(),
+            TypeAnnotation::Int32 | TypeAnnotation::Int64 | TypeAnnotation::BigInt => {
+                "0".to_string()
+            }
             TypeAnnotation::String => "rust::String()".to_string(),
             TypeAnnotation::ArrayBuffer => "rust::Vec<uint8_t>()".to_string(),
-            TypeAnnotation::Array(element_type) => {
-                format!("rust::Vec<{}>()", element_type.as_cxx_type(cxx_ns)?)
+            TypeAnnotation::Array(..) | TypeAnnotation::Map(..) => {
+                format!("{}()", self.as_cxx_type(cxx_ns)?)
             }
             TypeAnnotation::Enum(EnumTypeAnnotation { members, .. }) => {
                 let enum_type = self.as_cxx_type(cxx_ns)?;
@@ -158,7 +162,7 @@ impl TypeAnnotation {
 
                 format!("{enum_type}::{}", first_member.name)
             }
-            TypeAnnotation::Object(..) => {
+            TypeAnnotation::Object(..) | TypeAnnotation::Tuple(..) => {
                 let cxx_type = self.as_cxx_type(cxx_ns)?;
                 format!("{cxx_type}{{}}")
             }
@@ -189,6 +193,8 @@ impl TypeAnnotation {
     ///
     /// ```cpp
     /// facebook::react::bridging::fromJs<T>(rt, value, callInvoker)
+    /// craby::mymodule::utils::fromJsInt<int32_t>(rt, value) // Int32, Int64 (range checked)
+    /// craby::mymodule::utils::fromJsBigInt(rt, value)        // BigInt (range checked)
     /// ```
     pub fn as_cxx_from_js(
         &self,
@@ -203,10 +209,17 @@ impl TypeAnnotation {
             | TypeAnnotation::Array(..)
             | TypeAnnotation::Enum(..)
             | TypeAnnotation::Object(..)
-            | TypeAnnotation::Nullable(..) => format!(
+            | TypeAnnotation::Nullable(..)
+            | TypeAnnotation::Map(..)
+            | TypeAnnotation::Tuple(..) => format!(
                 "react::bridging::fromJs<{}>(rt, {ident}, callInvoker)",
                 self.as_cxx_type(cxx_ns)?,
             ),
+            TypeAnnotation::Int32 | TypeAnnotation::Int64 => format!(
+                "{cxx_ns}::utils::fromJsInt<{}>(rt, {ident})",
+                self.as_cxx_type(cxx_ns)?,
+            ),
+            TypeAnnotation::BigInt => format!("{cxx_ns}::utils::fromJsBigInt(rt, {ident})"),
             _ => {
                 return Err(anyhow::anyhow!(
                     "[as_cxx_from_js] Unsupported type annotation: {:?}",
@@ -222,6 +235,8 @@ impl TypeAnnotation {
     ///
     /// ```cpp
     /// react::bridging::toJs(rt, value)
+    /// jsi::Value(static_cast<double>(value)) // Int32, Int64
+    /// jsi::Value(jsi::BigInt::fromInt64(rt, value)) // BigInt
     /// ```
     pub fn as_cxx_to_js(&self, ident: &str) -> Result<CxxToJs, anyhow::Error> {
         let to_js_expr = match self {
@@ -232,7 +247,13 @@ impl TypeAnnotation {
             | TypeAnnotation::Array(..)
             | TypeAnnotation::Enum(..)
             | TypeAnnotation::Object(..)
-            | TypeAnnotation::Nullable(..) => format!("react::bridging::toJs(rt, {})", ident),
+            | TypeAnnotation::Nullable(..)
+            | TypeAnnotation::Map(..)
+            | TypeAnnotation::Tuple(..) => format!("react::bridging::toJs(rt, {})", ident),
+            TypeAnnotation::Int32 | TypeAnnotation::Int64 => {
+                format!("jsi::Value(static_cast<double>({ident}))")
+            }
+            TypeAnnotation::BigInt => format!("jsi::Value(jsi::BigInt::fromInt64(rt, {ident}))"),
             TypeAnnotation::Promise(..) => {
                 format!("react::bridging::toJs(rt, {})", ident)
             }
@@ -249,6 +270,39 @@ impl TypeAnnotation {
     }
 }
 
+impl DefaultValue {
+    /// Returns the cxx `jsi::Value` of the default value.
+    ///
+    /// ```cpp
+    /// jsi::Value(2.0)
+    /// jsi::Value(true)
+    /// jsi::Value(jsi::String::createFromUtf8(rt, "text"))
+    /// ```
+    pub fn as_cxx_js_value(&self) -> String {
+        match self {
+            DefaultValue::Boolean(value) => format!("jsi::Value({value})"),
+            DefaultValue::Number(value) => format!("jsi::Value({value})"),
+            DefaultValue::String(value) => {
+                // Control characters are escaped in octal since hex escapes are not length limited
+                let escaped = value
+                    .chars()
+                    .map(|c| match c {
+                        '"' => "\\\"".to_string(),
+                        '\\' => "\\\\".to_string(),
+                        '\n' => "\\n".to_string(),
+                        '\r' => "\\r".to_string(),
+                        '\t' => "\\t".to_string(),
+                        c if c.is_ascii_control() => format!("\\{:03o}", c as u32),
+                        c => c.to_string(),
+                    })
+                    .collect::<String>();
+
+                format!("jsi::Value(jsi::String::createFromUtf8(rt, \"{escaped}\"))")
+            }
+        }
+    }
+}
+
 impl Method {
     /// Converts schema Method to C++ TurboModule method implementation.
     ///
@@ -268,8 +322,8 @@ impl Method {
     ///       throw jsi::JSError(rt, "Expected 2 arguments");
     ///     }
     ///
-    ///     auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
-    ///     auto arg1 = react::bridging::fromJs<double>(rt, args[1], callInvoker);
+    ///     auto arg0 = craby::calculator::utils::fromJsArg("a", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
+    ///     auto arg1 = craby::calculator::utils::fromJsArg("b", 1, [&] { return react::bridging::fromJs<double>(rt, args[1], callInvoker); });
     ///     auto ret = craby::calculator::bridging::multiply(*it_, arg0, arg1);
     ///
     ///     return react::bridging::toJs(rt, ret);
@@ -280,45 +334,194 @@ impl Method {
     ///   }
     /// }
     /// ```
+    ///
+    /// With `unimplemented_error`, the error message names the method (eg. `errorMessage(err, "multiply")`).
     pub fn as_cxx_method(
         &self,
         cxx_ns: &CxxNamespace,
         cxx_mod: &CxxModuleName,
+        unimplemented_error: bool,
     ) -> Result<CxxMethod, anyhow::Error> {
         let fn_name = camel_case(&self.name);
+        // ```cpp
+        // craby::mymodule::utils::errorMessage(err, "myFunc")
+        // ```
+        let error_message = if unimplemented_error {
+            format!("{cxx_ns}::utils::errorMessage(err, \"{}\")", self.name)
+        } else {
+            format!("{cxx_ns}::utils::errorMessage(err)")
+        };
         // ["arg0", "arg1", "arg2"]
         let mut args = Vec::with_capacity(self.params.len() + 1);
         // ["auto arg0 = facebook::react::bridging::fromJs<T>(rt, value, callInvoker)", "..."]
         let mut args_decls = Vec::with_capacity(self.params.len());
 
         for (idx, param) in self.params.iter().enumerate() {
-            let arg_ref = cxx_arg_ref(idx);
             let arg_var = cxx_arg_var(idx);
+            // Omitted optional arguments are read as `undefined`,
+            // and omitted or `undefined` defaulted arguments are read as the default value (same as JavaScript)
+            let arg_ref = if param.optional {
+                format!("({idx} < count ? {} : undefinedArg)", cxx_arg_ref(idx))
+            } else if let Some(default_value) = &param.default_value {
+                let default_var = format!("{arg_var}$default");
+                args_decls.push(format!(
+                    "const jsi::Value {default_var} = {};",
+                    default_value.as_cxx_js_value()
+                ));
+                format!(
+                    "({idx} < count && !{arg}.isUndefined() ? {arg} : {default_var})",
+                    arg = cxx_arg_ref(idx)
+                )
+            } else {
+                cxx_arg_ref(idx)
+            };
 
             // `rust::Str` holds a reference to `std::string`.
             // To avoid dangling pointers, the converted `std::string` is retained within the scope for the lifetime of the reference.
+            //
+            // Conversion errors are prefixed with the parameter name (eg. `argument 'count' (arg0): ...`)
+            let with_arg_name = |expr: String| {
+                format!(
+                    "{cxx_ns}::utils::fromJsArg(\"{}\", {idx}, [&] {{ return {expr}; }})",
+                    param.name
+                )
+            };
             let from_js = if let TypeAnnotation::String = &param.type_annotation {
                 // Capture the converted `std::string` within the scope of the reference
                 let str_var = format!("{arg_var}$raw");
-                args_decls.push(format!("auto {str_var} = {arg_ref}.asString(rt).utf8(rt);",));
+                args_decls.push(format!(
+                    "auto {str_var} = {};",
+                    with_arg_name(format!("{arg_ref}.asString(rt).utf8(rt)"))
+                ));
 
                 // Convert the `std::string` to `rust::Str`
                 format!("rust::Str({str_var}.data(), {str_var}.size())")
             } else {
-                param.type_annotation.as_cxx_from_js(cxx_ns, &arg_ref)?.expr
+                with_arg_name(param.type_annotation.as_cxx_from_js(cxx_ns, &arg_ref)?.expr)
             };
             args.push(arg_var.clone());
             args_decls.push(format!("auto {arg_var} = {from_js};"));
         }
 
         let invoke_stmts = match &self.ret_type {
-            TypeAnnotation::Promise(resolve_type) => {
+            TypeAnnotation::Promise(..) if self.detached => {
+                // Invoke the FFI function in a separate thread without creating a promise object
+                //
+                // Errors are reported to the JS console since there is no promise to reject
                 let mut bind_args = Vec::with_capacity(args.len() + 2);
                 bind_args.push(RESERVED_ARG_NAME_MODULE.to_string());
+                bind_args.push("callInvoker".to_string());
+                bind_args.extend(args.clone());
+                let bind_args = bind_args.join(", ");
+
+                args.insert(0, format!("*{RESERVED_ARG_NAME_MODULE}"));
+                let fn_args = args.join(", ");
+
+                formatdoc! {
+                    r#"
+                    thisModule.threadPool_->enqueue([{bind_args}]() mutable {{
+                      std::string error;
+                      try {{
+                        {cxx_ns}::bridging::{fn_name}({fn_args});
+                        return;
+                      }} catch (const jsi::JSError &err) {{
+                        error = err.getMessage();
+                      }} catch (const std::exception &err) {{
+                        error = {error_message};
+                      }}
+
+                      {cxx_ns}::utils::invokeAsync(callInvoker, [error](jsi::Runtime &rt) {{
+                        auto message = jsi::String::createFromUtf8(rt, "[{name}] " + error);
+                        rt.global()
+                          .getPropertyAsObject(rt, "console")
+                          .getPropertyAsFunction(rt, "error")
+                          .call(rt, message);
+                      }});
+                    }});
+
+                    return jsi::Value::undefined();"#,
+                    name = self.name,
+                }
+            }
+            TypeAnnotation::Promise(resolve_type) if self.error_type.is_some() => {
+                // `react::AsyncPromise` only rejects with the error messages,
+                // so the structured error (`@throws`) is rejected through the promise of the utils
+                let error_type = self
+                    .nullable_error_type()
+                    .ok_or(anyhow::anyhow!("Error type is not declared"))?
+                    .as_cxx_type(cxx_ns)?;
+                let mut bind_args = Vec::with_capacity(args.len() + 3);
+                bind_args.push(RESERVED_ARG_NAME_MODULE.to_string());
+                bind_args.push("callInvoker".to_string());
+                bind_args.push("promise".to_string());
+                bind_args.extend(args.clone());
+                let bind_args = bind_args.join(", ");
+
+                args.insert(0, format!("*{RESERVED_ARG_NAME_MODULE}"));
+                args.push(RESERVED_ARG_NAME_ERROR.to_string());
+                let fn_args = args.join(", ");
+
+                let (ret_decl, ret_capture, to_js) = match &**resolve_type {
+                    TypeAnnotation::Void => {
+                        (String::new(), "", "jsi::Value::undefined()".to_string())
+                    }
+                    _ => (
+                        "auto ret = ".to_string(),
+                        "ret",
+                        resolve_type.as_cxx_to_js("ret")?.expr,
+                    ),
+                };
+
+                formatdoc! {
+                    r#"
+                    auto promise = std::make_shared<{cxx_ns}::utils::PromiseCallbacks>();
+                    auto promiseObj = {cxx_ns}::utils::createPromise(rt, promise);
+
+                    thisModule.threadPool_->enqueue([{bind_args}]() mutable {{
+                      {error_type} {RESERVED_ARG_NAME_ERROR};
+                      {RESERVED_ARG_NAME_ERROR}.null = true;
+                      std::string message;
+                      try {{
+                        {ret_decl}{cxx_ns}::bridging::{fn_name}({fn_args});
+                        {cxx_ns}::utils::settlePromise(callInvoker, promise, true, [{ret_capture}](jsi::Runtime &rt) {{
+                          return {to_js};
+                        }});
+                        return;
+                      }} catch (const jsi::JSError &err) {{
+                        message = err.getMessage();
+                      }} catch (const std::exception &err) {{
+                        message = {error_message};
+                      }}
+
+                      if (!{RESERVED_ARG_NAME_ERROR}.null) {{
+                        {cxx_ns}::utils::settlePromise(callInvoker, promise, false, [{RESERVED_ARG_NAME_ERROR}](jsi::Runtime &rt) {{
+                          return react::bridging::toJs(rt, {RESERVED_ARG_NAME_ERROR}.val);
+                        }});
+                        return;
+                      }}
+
+                      {cxx_ns}::utils::settlePromise(callInvoker, promise, false, [message](jsi::Runtime &rt) {{
+                        return jsi::Value(rt, jsi::JSError(rt, message).value());
+                      }});
+                    }});
+
+                    return promiseObj;"#,
+                }
+            }
+            TypeAnnotation::Promise(resolve_type) => {
+                let mut bind_args = Vec::with_capacity(args.len() + 3);
+                bind_args.push(RESERVED_ARG_NAME_MODULE.to_string());
                 bind_args.push("promise".to_string());
                 bind_args.extend(args.clone());
 
                 args.insert(0, format!("*{}", RESERVED_ARG_NAME_MODULE));
+
+                // The token is shared between the `cancel` function of the promise object (JS thread)
+                // and the Rust implementation (worker thread)
+                if self.cancellable {
+                    bind_args.push("cancelToken".to_string());
+                    args.push("**cancelToken".to_string());
+                }
                 let fn_args = args.join(", ");
 
                 let ret_stmts = if let TypeAnnotation::Void = &**resolve_type {
@@ -328,6 +531,22 @@ impl Method {
                         promise.resolve(std::monostate{{}});
                         "#,
                     }
+                } else if let TypeAnnotation::Int32 | TypeAnnotation::Int64 = &**resolve_type {
+                    // Integers are resolved as JS numbers
+                    formatdoc! {
+                        r#"
+                        auto ret = {cxx_ns}::bridging::{fn_name}({fn_args});
+                        promise.resolve(static_cast<double>(ret));
+                        "#,
+                    }
+                } else if let TypeAnnotation::BigInt = &**resolve_type {
+                    // `int64_t` is wrapped to be resolved as the JS BigInt
+                    formatdoc! {
+                        r#"
+                        auto ret = {cxx_ns}::bridging::{fn_name}({fn_args});
+                        promise.resolve({cxx_ns}::utils::BigIntValue{{ret}});
+                        "#,
+                    }
                 } else {
                     formatdoc! {
                         r#"
@@ -339,29 +558,58 @@ impl Method {
 
                 let bind_args = bind_args.join(", ");
                 let ret_stmts = indent_str(&ret_stmts, 4);
-                let ret_type = if let TypeAnnotation::Void = &**resolve_type {
-                    "std::monostate".to_string()
-                } else {
-                    resolve_type.as_cxx_type(cxx_ns)?
+                let ret_type = match &**resolve_type {
+                    TypeAnnotation::Void => "std::monostate".to_string(),
+                    TypeAnnotation::Int32 | TypeAnnotation::Int64 => "double".to_string(),
+                    TypeAnnotation::BigInt => format!("{cxx_ns}::utils::BigIntValue"),
+                    _ => resolve_type.as_cxx_type(cxx_ns)?,
                 };
                 let ret = self.ret_type.as_cxx_to_js("promise")?.expr;
 
+                let (token_decl, ret_stmt) = if self.cancellable {
+                    // Expose `cancel()` on the returned promise object
+                    (
+                        format!(
+                            "auto cancelToken = std::make_shared<rust::Box<{cxx_ns}::bridging::CancellationToken>>({cxx_ns}::bridging::createCancellationToken());\n"
+                        ),
+                        formatdoc! {
+                            r#"
+                            auto promiseObj = {ret};
+                            promiseObj.setProperty(
+                              rt,
+                              "cancel",
+                              jsi::Function::createFromHostFunction(
+                                rt,
+                                jsi::PropNameID::forAscii(rt, "cancel"),
+                                0,
+                                [cancelToken](jsi::Runtime &, const jsi::Value &, const jsi::Value *, size_t) -> jsi::Value {{
+                                  (*cancelToken)->cancel();
+                                  return jsi::Value::undefined();
+                                }}));
+
+                            return promiseObj;"#,
+                        },
+                    )
+                } else {
+                    (String::new(), format!("return {ret};"))
+                };
+
                 // Create a promise object and invoke the FFI function in a separate thread
                 formatdoc! {
                     r#"
                     react::AsyncPromise<{ret_type}> promise(rt, callInvoker);
-
+                    {token_decl}
                     thisModule.threadPool_->enqueue([{bind_args}]() mutable {{
                       try {{
                     {ret_stmts}
                       }} catch (const jsi::JSError &err) {{
                         promise.reject(err.getMessage());
                       }} catch (const std::exception &err) {{
-                        promise.reject({cxx_ns}::utils::errorMessage(err));
+                        promise.reject({error_message});
                       }}
                     }});
 
-                    return {ret};"#,
+                    {ret_stmt}"#,
                 }
             }
             _ => {
@@ -389,8 +637,42 @@ impl Method {
             }
         };
 
-        let args_decls = args_decls.join("\n");
         let args_count = self.params.len();
+        let required_count = self
+            .params
+            .iter()
+            .filter(|param| param.is_required())
+            .count();
+        if self.params.iter().any(|param| param.optional) {
+            args_decls.insert(
+                0,
+                "const jsi::Value undefinedArg = jsi::Value::undefined();".into(),
+            );
+        }
+        let args_decls = args_decls.join("\n");
+
+        // ```cpp
+        // if (2 != count) {
+        //   throw jsi::JSError(rt, "Expected 2 arguments");
+        // }
+        // ```
+        let count_check = if required_count == args_count {
+            formatdoc! {
+                r#"
+                if ({args_count} != count) {{
+                  throw jsi::JSError(rt, "Expected {args_count} argument{plural}");
+                }}"#,
+                plural = if args_count > 1 { "s" } else { "" },
+            }
+        } else {
+            formatdoc! {
+                r#"
+                if (count < {required_count} || count > {args_count}) {{
+                  throw jsi::JSError(rt, "Expected {required_count} to {args_count} arguments");
+                }}"#,
+            }
+        };
+        let count_check = indent_str(&count_check, 4);
 
         // ```cpp
         // MethodMetadata{{1, &CxxMyTestModule::myFunc}}
@@ -412,24 +694,23 @@ impl Method {
               auto it_ = thisModule.module_;
 
               try {{
-                if ({args_count} != count) {{
-                  throw jsi::JSError(rt, "Expected {args_count} argument{plural}");
-                }}
+            {count_check}
 
             {invoke_stmts}
               }} catch (const jsi::JSError &err) {{
                 throw err;
               }} catch (const std::exception &err) {{
-                throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
+                throw jsi::JSError(rt, {error_message});
               }}
             }}"#,
-            plural = if args_count > 1 { "s" } else { "" },
         };
 
         Ok(CxxMethod {
             name: self.name.clone(),
             metadata,
             impl_func,
+            platform: self.platform,
+            docs: self.docs.clone(),
         })
     }
 }
@@ -465,12 +746,15 @@ impl Schema {
     /// ```
     pub fn as_cxx_bridging_templates(
         &self,
-        project_name: &str,
+        cxx_namespace: &str,
     ) -> Result<Vec<String>, anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(project_name);
+        let cxx_ns = CxxNamespace::from(cxx_namespace);
         let mut bridging_templates = BTreeMap::new();
         let mut enum_bridging_templates = BTreeMap::new();
-        let mut nullable_bridging_templates = self.collect_nullable_types(project_name)?;
+        let mut nullable_bridging_templates = self.collect_nullable_types(cxx_namespace)?;
+        let mut map_bridging_templates = self.collect_map_types(cxx_namespace)?;
+        let mut nested_array_bridging_templates = self.collect_nested_array_types(cxx_namespace)?;
+        let mut tuple_bridging_templates = self.collect_tuple_types(cxx_namespace)?;
 
         for type_annotation in &self.aliases {
             let alias_spec = type_annotation.as_object().unwrap();
@@ -505,10 +789,25 @@ impl Schema {
             {
                 ordered_templates.push(template);
             }
+
+            if let Some(template) = map_bridging_templates.remove(name) {
+                ordered_templates.push(template);
+            }
+
+            if let Some(template) = nested_array_bridging_templates.remove(name) {
+                ordered_templates.push(template);
+            }
+
+            if let Some(template) = tuple_bridging_templates.remove(name) {
+                ordered_templates.push(template);
+            }
         });
 
         ordered_templates.extend(bridging_templates.into_values());
+        ordered_templates.extend(nested_array_bridging_templates.into_values());
+        ordered_templates.extend(tuple_bridging_templates.into_values());
         ordered_templates.extend(nullable_bridging_templates.into_values());
+        ordered_templates.extend(map_bridging_templates.into_values());
 
         Ok(ordered_templates)
     }
@@ -521,7 +820,7 @@ impl Schema {
     /// template <>
     /// struct Bridging<craby::mymodule::bridging::NullableNumber> {
     ///   static craby::mymodule::bridging::NullableNumber fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
-    ///     if (value.isNull()) {
+    ///     if (value.isNull() || value.isUndefined()) {
     ///       return craby::mymodule::bridging::NullableNumber{true, 0.0};
     ///     }
     ///
@@ -542,9 +841,9 @@ impl Schema {
     /// ```
     pub fn collect_nullable_types(
         &self,
-        project_name: &str,
+        cxx_namespace: &str,
     ) -> Result<BTreeMap<String, String>, anyhow::Error> {
-        let cxx_ns = CxxNamespace::from(project_name);
+        let cxx_ns = CxxNamespace::from(cxx_namespace);
         let mut templates = BTreeMap::new();
 
         for method in &self.methods {
@@ -602,6 +901,73 @@ impl Schema {
 
         Ok(templates)
     }
+
+    /// Collects all map types from schema to generate bridging templates (keyed by the name of the key/value pair struct).
+    ///
+    /// See [`CxxBridgingTemplate::try_into_map_template`] for the generated code.
+    pub fn collect_map_types(
+        &self,
+        cxx_namespace: &str,
+    ) -> Result<BTreeMap<String, String>, anyhow::Error> {
+        let cxx_ns = CxxNamespace::from(cxx_namespace);
+        let mut templates = BTreeMap::new();
+
+        for map_type in self.type_annotations().filter_map(TypeAnnotation::as_map) {
+            if let BTreeMapEntry::Vacant(e) = templates.entry(map_type.map_entry_name()?) {
+                let bridging_template =
+                    CxxBridgingTemplate::try_into_map_template(&cxx_ns, map_type)?.into_code();
+                e.insert(bridging_template);
+            }
+        }
+
+        Ok(templates)
+    }
+
+    /// Collects all nested array types from schema to generate bridging templates (keyed by the name of the wrapper struct).
+    ///
+    /// See [`CxxBridgingTemplate::try_into_nested_array_template`] for the generated code.
+    pub fn collect_nested_array_types(
+        &self,
+        cxx_namespace: &str,
+    ) -> Result<BTreeMap<String, String>, anyhow::Error> {
+        let cxx_ns = CxxNamespace::from(cxx_namespace);
+        let mut templates = BTreeMap::new();
+
+        for array_type in self
+            .type_annotations()
+            .flat_map(TypeAnnotation::nested_arrays)
+        {
+            if let BTreeMapEntry::Vacant(e) = templates.entry(array_type.nested_array_name()?) {
+                let bridging_template =
+                    CxxBridgingTemplate::try_into_nested_array_template(&cxx_ns, array_type)?
+                        .into_code();
+                e.insert(bridging_template);
+            }
+        }
+
+        Ok(templates)
+    }
+
+    /// Collects all tuple types from schema to generate bridging templates (keyed by the name of the tuple struct).
+    ///
+    /// See [`CxxBridgingTemplate::try_into_tuple_template`] for the generated code.
+    pub fn collect_tuple_types(
+        &self,
+        cxx_namespace: &str,
+    ) -> Result<BTreeMap<String, String>, anyhow::Error> {
+        let cxx_ns = CxxNamespace::from(cxx_namespace);
+        let mut templates = BTreeMap::new();
+
+        for tuple_type in self.type_annotations().flat_map(TypeAnnotation::tuples) {
+            if let BTreeMapEntry::Vacant(e) = templates.entry(tuple_type.tuple_name()?) {
+                let bridging_template =
+                    CxxBridgingTemplate::try_into_tuple_template(&cxx_ns, tuple_type)?.into_code();
+                e.insert(bridging_template);
+            }
+        }
+
+        Ok(templates)
+    }
 }
 
 pub mod template {
@@ -612,7 +978,7 @@ pub mod template {
         common::IntoCode,
         parser::types::{
             EnumMemberValue as ParserEnumMemberValue, EnumTypeAnnotation, ObjectTypeAnnotation,
-            TypeAnnotation,
+            RefTypeAnnotation, TypeAnnotation,
         },
         types::CxxNamespace,
         utils::indent_str,
@@ -891,18 +1257,32 @@ pub mod template {
                 },
             )?;
 
-            // ```cpp
-            // else {
-            //   throw jsi::JSError(rt, "Invalid enum value (MyEnum)");
-            // }
-            // ```
-            from_js_conds.push(formatdoc! {
-                r#"
-                else {{
-                  throw jsi::JSError(rt, "Invalid enum value ({enum_name})");
-                }}"#,
-                enum_name = enum_spec.name,
-            });
+            let from_js_fallback = match &enum_spec.fallback {
+                // ```cpp
+                // else {
+                //   return craby::mymodule::MyEnum::Unknown;
+                // }
+                // ```
+                Some(fallback) => formatdoc! {
+                    r#"
+                    else {{
+                      return {enum_namespace}::{fallback};
+                    }}"#,
+                },
+                // ```cpp
+                // else {
+                //   throw jsi::JSError(rt, "Invalid enum value (MyEnum)");
+                // }
+                // ```
+                None => formatdoc! {
+                    r#"
+                    else {{
+                      throw jsi::JSError(rt, "Invalid enum value ({enum_name})");
+                    }}"#,
+                    enum_name = enum_spec.name,
+                },
+            };
+            from_js_conds.push(from_js_fallback);
 
             // ```cpp
             // default:
@@ -966,7 +1346,7 @@ pub mod template {
         /// template <>
         /// struct Bridging<craby::mymodule::bridging::NullableNumber> {
         ///   static craby::mymodule::bridging::NullableNumber fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
-        ///     if (value.isNull()) {
+        ///     if (value.isNull() || value.isUndefined()) {
         ///       return craby::mymodule::bridging::NullableNumber{true, 0.0};
         ///     }
         ///
@@ -990,17 +1370,26 @@ pub mod template {
             nullable_type_annotation: &TypeAnnotation,
             type_annotation: &TypeAnnotation,
         ) -> Result<CxxBridgingTemplate, anyhow::Error> {
-            let origin_namespace = type_annotation.as_cxx_type(cxx_ns)?;
+            if let TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) = type_annotation {
+                return CxxBridgingTemplate::try_into_boxed_nullable_template(
+                    cxx_ns,
+                    nullable_type_annotation,
+                    name,
+                );
+            }
+
             let default_value = type_annotation.as_cxx_default_val(cxx_ns)?;
             let nullable_type_namespace = nullable_type_annotation.as_cxx_type(cxx_ns)?;
+            let from_js = type_annotation.as_cxx_from_js(cxx_ns, "value")?.expr;
+            let to_js = type_annotation.as_cxx_to_js("value.val")?.expr;
 
             let from_js_impl = formatdoc! {
                 r#"
-                if (value.isNull()) {{
+                if (value.isNull() || value.isUndefined()) {{
                   return {nullable_type_namespace}{{true, {default_value}}};
                 }}
 
-                auto val = react::bridging::fromJs<{origin_namespace}>(rt, value, callInvoker);
+                auto val = {from_js};
                 auto ret = {nullable_type_namespace}{{false, val}};
 
                 return ret;"#,
@@ -1012,7 +1401,7 @@ pub mod template {
                   return jsi::Value::null();
                 }}
 
-                return react::bridging::toJs(rt, value.val);"#,
+                return {to_js};"#,
             };
 
             Ok(CxxBridgingTemplate {
@@ -1021,6 +1410,307 @@ pub mod template {
                 to_js: to_js_impl,
             })
         }
+
+        /// Generates C++ bridging template for map types.
+        ///
+        /// The map is passed as a vector of the key/value pairs (`Map<T>` in Rust).
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<rust::Vec<craby::mymodule::bridging::NumberMapEntry>> {
+        ///   static rust::Vec<craby::mymodule::bridging::NumberMapEntry> fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     auto obj = value.asObject(rt);
+        ///     auto keys = obj.getPropertyNames(rt);
+        ///     auto size = keys.size(rt);
+        ///     rust::Vec<craby::mymodule::bridging::NumberMapEntry> ret;
+        ///     ret.reserve(size);
+        ///
+        ///     for (size_t i = 0; i < size; i++) {
+        ///       auto key = keys.getValueAtIndex(rt, i).asString(rt).utf8(rt);
+        ///       auto val = react::bridging::fromJs<double>(rt, obj.getProperty(rt, key.c_str()), callInvoker);
+        ///       ret.push_back(craby::mymodule::bridging::NumberMapEntry{rust::String(key), val});
+        ///     }
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, rust::Vec<craby::mymodule::bridging::NumberMapEntry> value) {
+        ///     jsi::Object obj = jsi::Object(rt);
+        ///
+        ///     for (auto &entry : value) {
+        ///       obj.setProperty(rt, std::string(entry.key).c_str(), react::bridging::toJs(rt, entry.value));
+        ///     }
+        ///
+        ///     return jsi::Value(rt, obj);
+        ///   }
+        /// };
+        /// ```
+        pub fn try_into_map_template(
+            cxx_ns: &CxxNamespace,
+            map_type_annotation: &TypeAnnotation,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let TypeAnnotation::Map(value_type) = map_type_annotation else {
+                anyhow::bail!("Not a map type: {:?}", map_type_annotation);
+            };
+
+            let map_namespace = map_type_annotation.as_cxx_type(cxx_ns)?;
+            let entry_namespace = format!(
+                "{cxx_ns}::bridging::{}",
+                map_type_annotation.map_entry_name()?
+            );
+            let from_js = value_type
+                .as_cxx_from_js(cxx_ns, "obj.getProperty(rt, key.c_str())")?
+                .expr;
+            let to_js = value_type.as_cxx_to_js("entry.value")?.expr;
+
+            let from_js_impl = formatdoc! {
+                r#"
+                auto obj = value.asObject(rt);
+                auto keys = obj.getPropertyNames(rt);
+                auto size = keys.size(rt);
+                {map_namespace} ret;
+                ret.reserve(size);
+
+                for (size_t i = 0; i < size; i++) {{
+                  auto key = keys.getValueAtIndex(rt, i).asString(rt).utf8(rt);
+                  auto val = {from_js};
+                  ret.push_back({entry_namespace}{{rust::String(key), val}});
+                }}
+
+                return ret;"#,
+            };
+
+            let to_js_impl = formatdoc! {
+                r#"
+                jsi::Object obj = jsi::Object(rt);
+
+                for (auto &entry : value) {{
+                  obj.setProperty(rt, std::string(entry.key).c_str(), {to_js});
+                }}
+
+                return jsi::Value(rt, obj);"#,
+            };
+
+            Ok(CxxBridgingTemplate {
+                namespace: map_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
+
+        /// Generates C++ bridging template for the arrays nested in other arrays.
+        ///
+        /// The nested array is wrapped with a struct (`Array<Array<T>>` in Rust).
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<craby::mymodule::bridging::NestedNumberArray> {
+        ///   static craby::mymodule::bridging::NestedNumberArray fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     auto items = react::bridging::fromJs<rust::Vec<double>>(rt, value, callInvoker);
+        ///     auto ret = craby::mymodule::bridging::NestedNumberArray{std::move(items)};
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, craby::mymodule::bridging::NestedNumberArray value) {
+        ///     return react::bridging::toJs(rt, value.items);
+        ///   }
+        /// };
+        /// ```
+        pub fn try_into_nested_array_template(
+            cxx_ns: &CxxNamespace,
+            array_type_annotation: &TypeAnnotation,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let nested_array_namespace = format!(
+                "{cxx_ns}::bridging::{}",
+                array_type_annotation.nested_array_name()?
+            );
+            let items_namespace = array_type_annotation.as_cxx_type(cxx_ns)?;
+
+            let from_js_impl = formatdoc! {
+                r#"
+                auto items = react::bridging::fromJs<{items_namespace}>(rt, value, callInvoker);
+                auto ret = {nested_array_namespace}{{std::move(items)}};
+
+                return ret;"#,
+            };
+
+            let to_js_impl = "return react::bridging::toJs(rt, value.items);".to_string();
+
+            Ok(CxxBridgingTemplate {
+                namespace: nested_array_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
+
+        /// Generates C++ bridging template for the tuple types.
+        ///
+        /// The tuple is wrapped with a struct (`(T1, T2, ...)` in Rust) and bridged as a JS array.
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<craby::mymodule::bridging::NumberStringTuple2> {
+        ///   static craby::mymodule::bridging::NumberStringTuple2 fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     auto arr = value.asObject(rt).asArray(rt);
+        ///     if (arr.size(rt) != 2) {
+        ///       throw jsi::JSError(rt, "Expected a tuple of 2 elements");
+        ///     }
+        ///
+        ///     auto arr$0 = react::bridging::fromJs<double>(rt, arr.getValueAtIndex(rt, 0), callInvoker);
+        ///     auto arr$1 = react::bridging::fromJs<rust::String>(rt, arr.getValueAtIndex(rt, 1), callInvoker);
+        ///
+        ///     craby::mymodule::bridging::NumberStringTuple2 ret = {
+        ///       arr$0,
+        ///       arr$1
+        ///     };
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, craby::mymodule::bridging::NumberStringTuple2 value) {
+        ///     auto arr = jsi::Array(rt, 2);
+        ///     arr.setValueAtIndex(rt, 0, react::bridging::toJs(rt, value.item_0));
+        ///     arr.setValueAtIndex(rt, 1, react::bridging::toJs(rt, value.item_1));
+        ///
+        ///     return jsi::Value(rt, arr);
+        ///   }
+        /// };
+        /// ```
+        pub fn try_into_tuple_template(
+            cxx_ns: &CxxNamespace,
+            tuple_type_annotation: &TypeAnnotation,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let TypeAnnotation::Tuple(element_types) = tuple_type_annotation else {
+                anyhow::bail!("Not a tuple type: {:?}", tuple_type_annotation);
+            };
+
+            let tuple_namespace = format!(
+                "{cxx_ns}::bridging::{}",
+                tuple_type_annotation.tuple_name()?
+            );
+            let len = element_types.len();
+            let mut from_js_stmts = vec![];
+            let mut from_js_ident = vec![];
+            let mut to_js_stmts = vec![];
+
+            for (i, element_type) in element_types.iter().enumerate() {
+                let ident = format!("arr${i}");
+                let from_js = element_type
+                    .as_cxx_from_js(cxx_ns, &format!("arr.getValueAtIndex(rt, {i})"))?;
+                let to_js = element_type.as_cxx_to_js(&format!("value.item_{i}"))?;
+
+                from_js_stmts.push(format!("auto {ident} = {};", from_js.expr));
+                from_js_ident.push(ident);
+                to_js_stmts.push(format!("arr.setValueAtIndex(rt, {i}, {});", to_js.expr));
+            }
+
+            let from_js_stmts = from_js_stmts.join("\n");
+            let from_js_ident = indent_str(&from_js_ident.join(",\n"), 2);
+            let from_js_impl = formatdoc! {
+                r#"
+                auto arr = value.asObject(rt).asArray(rt);
+                if (arr.size(rt) != {len}) {{
+                  throw jsi::JSError(rt, "Expected a tuple of {len} elements");
+                }}
+
+                {from_js_stmts}
+
+                {tuple_namespace} ret = {{
+                {from_js_ident}
+                }};
+
+                return ret;"#,
+            };
+
+            let to_js_stmts = to_js_stmts.join("\n");
+            let to_js_impl = formatdoc! {
+                r#"
+                auto arr = jsi::Array(rt, {len});
+                {to_js_stmts}
+
+                return jsi::Value(rt, arr);"#,
+            };
+
+            Ok(CxxBridgingTemplate {
+                namespace: tuple_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
+
+        /// Generates C++ bridging template for nullable recursive types.
+        ///
+        /// The value is held by a vector with zero or one element (`Nullable<Box<T>>` in Rust).
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<craby::mymodule::bridging::NullableNode> {
+        ///   static craby::mymodule::bridging::NullableNode fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     if (value.isNull() || value.isUndefined()) {
+        ///       return craby::mymodule::bridging::NullableNode{true, rust::Vec<craby::mymodule::bridging::Node>()};
+        ///     }
+        ///
+        ///     rust::Vec<craby::mymodule::bridging::Node> val;
+        ///     val.push_back(react::bridging::fromJs<craby::mymodule::bridging::Node>(rt, value, callInvoker));
+        ///     auto ret = craby::mymodule::bridging::NullableNode{false, std::move(val)};
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, craby::mymodule::bridging::NullableNode value) {
+        ///     if (value.null || value.val.empty()) {
+        ///       return jsi::Value::null();
+        ///     }
+        ///
+        ///     return react::bridging::toJs(rt, value.val[0]);
+        ///   }
+        /// };
+        /// ```
+        fn try_into_boxed_nullable_template(
+            cxx_ns: &CxxNamespace,
+            nullable_type_annotation: &TypeAnnotation,
+            name: &str,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let origin_namespace = format!("{cxx_ns}::bridging::{name}");
+            let nullable_type_namespace = nullable_type_annotation.as_cxx_type(cxx_ns)?;
+
+            let from_js_impl = formatdoc! {
+                r#"
+                if (value.isNull() || value.isUndefined()) {{
+                  return {nullable_type_namespace}{{true, rust::Vec<{origin_namespace}>()}};
+                }}
+
+                rust::Vec<{origin_namespace}> val;
+                val.push_back(react::bridging::fromJs<{origin_namespace}>(rt, value, callInvoker));
+                auto ret = {nullable_type_namespace}{{false, std::move(val)}};
+
+                return ret;"#,
+            };
+
+            let to_js_impl = formatdoc! {
+                r#"
+                if (value.null || value.val.empty()) {{
+                  return jsi::Value::null();
+                }}
+
+                return react::bridging::toJs(rt, value.val[0]);"#,
+            };
+
+            Ok(CxxBridgingTemplate {
+                namespace: nullable_type_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
     }
 
     /// Generates C++ argument reference expression.
@@ -4,18 +4,23 @@ use std::collections::{
 
 use craby_common::utils::string::{camel_case, pascal_case, snake_case};
 use indoc::formatdoc;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     common::IntoCode,
-    constants::specs::RESERVED_ARG_NAME_MODULE,
+    constants::specs::{
+        RESERVED_ARG_NAME_CANCEL_TOKEN, RESERVED_ARG_NAME_ERROR, RESERVED_ARG_NAME_MODULE,
+    },
     parser::types::{
-        EnumTypeAnnotation, Method, ObjectTypeAnnotation, Param, RefTypeAnnotation, TypeAnnotation,
+        EnumMemberValue, EnumTypeAnnotation, Method, ObjectTypeAnnotation, Param,
+        RefTypeAnnotation, TypeAnnotation,
     },
     platform::rust::template::{
-        collect_alias_default_impls, RsDefaultImpl, RsNullableStruct, RsStruct,
+        collect_alias_default_impls, enum_as_str_impl, enum_number_impls, enum_try_from_impl,
+        numeric_enum_repr, RsDefaultImpl, RsMapEntryStruct, RsNestedArrayStruct, RsNullableStruct,
+        RsStruct, RsTupleStruct,
     },
-    types::Schema,
+    types::{FfiSurface, Schema},
     utils::indent_str,
 };
 
@@ -65,6 +70,8 @@ pub struct RsCxxBridge {
     /// }
     /// ```
     pub struct_defs: Vec<String>,
+    /// The number of the nullable helper structs in `struct_defs`.
+    pub nullable_count: usize,
     /// The enum definition.
     ///
     /// ```rust,ignore
@@ -99,6 +106,18 @@ pub struct RsCxxBridge {
     pub func_impls: Vec<String>,
 }
 
+impl RsCxxBridge {
+    /// Returns the size of the FFI surface of the bridge.
+    pub fn surface(&self) -> FfiSurface {
+        FfiSurface {
+            extern_funcs: self.func_extern_sigs.len(),
+            structs: self.struct_defs.len() - self.nullable_count,
+            enums: self.enum_defs.len(),
+            nullables: self.nullable_count,
+        }
+    }
+}
+
 impl TypeAnnotation {
     /// Converts TypeAnnotation to Rust type representation.
     ///
@@ -107,11 +126,16 @@ impl TypeAnnotation {
     /// ```rust,ignore
     /// bool                          // Boolean
     /// f64                           // Number
+    /// i32                           // Int32
+    /// i64                           // Int64, BigInt
     /// String                        // String
     /// Vec<f64>                      // Array<Number>
+    /// Vec<NestedNumberArray>        // Array<Array<Number>>
     /// MyEnum                        // Enum
     /// MyStruct                      // Object
     /// NullableNumber                // Nullable<Number>
+    /// Vec<NumberMapEntry>           // Map<Number>
+    /// NumberStringTuple2             // Tuple<Number, String>
     /// Result<f64, anyhow::Error>    // Promise<Number>
     /// ```
     pub fn as_rs_type(&self) -> Result<RsType, anyhow::Error> {
@@ -119,17 +143,15 @@ impl TypeAnnotation {
             TypeAnnotation::Void => "()".to_string(),
             TypeAnnotation::Boolean => "bool".to_string(),
             TypeAnnotation::Number => "f64".to_string(),
+            TypeAnnotation::Int32 => "i32".to_string(),
+            TypeAnnotation::Int64 | TypeAnnotation::BigInt => "i64".to_string(),
             TypeAnnotation::String => "String".to_string(),
             TypeAnnotation::ArrayBuffer => "Vec<u8>".to_string(),
-            TypeAnnotation::Array(element_type) => {
-                if let TypeAnnotation::Array(..) = &**element_type {
-                    return Err(anyhow::anyhow!(
-                        "Nested array type is not supported: {:?}",
-                        element_type
-                    ));
-                }
-                format!("Vec<{}>", element_type.as_rs_type()?.into_code())
-            }
+            // cxx does not support the nested vectors, so the nested arrays are wrapped with the structs
+            TypeAnnotation::Array(element_type) => match &**element_type {
+                TypeAnnotation::Array(..) => format!("Vec<{}>", element_type.nested_array_name()?),
+                _ => format!("Vec<{}>", element_type.as_rs_type()?.into_code()),
+            },
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => name.clone(),
             TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => name.clone(),
             TypeAnnotation::Promise(resolve_type) => {
@@ -138,47 +160,10 @@ impl TypeAnnotation {
                     resolve_type.as_rs_type()?.into_code()
                 )
             }
-            TypeAnnotation::Nullable(type_annotation) => match &**type_annotation {
-                TypeAnnotation::Boolean => "NullableBoolean".to_string(),
-                TypeAnnotation::Number => "NullableNumber".to_string(),
-                TypeAnnotation::String => "NullableString".to_string(),
-                TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
-                    format!("Nullable{name}")
-                }
-                TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
-                    format!("Nullable{name}")
-                }
-                TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => {
-                    format!("Nullable{name}")
-                }
-                TypeAnnotation::ArrayBuffer => "NullableArrayBuffer".to_string(),
-                TypeAnnotation::Array(element_type) => match &**element_type {
-                    TypeAnnotation::Boolean => "NullableBooleanArray".to_string(),
-                    TypeAnnotation::Number => "NullableNumberArray".to_string(),
-                    TypeAnnotation::String => "NullableStringArray".to_string(),
-                    TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
-                        format!("Nullable{name}Array")
-                    }
-                    TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
-                        format!("Nullable{name}Array")
-                    }
-                    TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => {
-                        format!("Nullable{name}Array")
-                    }
-                    _ => {
-                        return Err(anyhow::anyhow!(
-                        "[as_rs_type] Unsupported type annotation for nullable array type: {:?}",
-                        element_type
-                    ))
-                    }
-                },
-                _ => {
-                    return Err(anyhow::anyhow!(
-                        "[as_rs_type] Unsupported type annotation for nullable type: {:?}",
-                        type_annotation
-                    ))
-                }
-            },
+            TypeAnnotation::Nullable(..) => self.nullable_name()?,
+            TypeAnnotation::Map(..) => format!("Vec<{}>", self.map_entry_name()?),
+            // cxx does not support the tuples, so the tuples are wrapped with the structs
+            TypeAnnotation::Tuple(..) => self.tuple_name()?,
             _ => {
                 return Err(anyhow::anyhow!(
                     "[as_rs_type] Unsupported type annotation: {:?}",
@@ -218,26 +203,29 @@ impl TypeAnnotation {
     /// ```rust,ignore
     /// Boolean          // Boolean (aliased bool)
     /// Number           // Number (aliased f64)
+    /// Int32            // Int32 (aliased i32)
+    /// BigInt           // BigInt (aliased i64)
     /// String           // String
     /// ArrayBuffer      // ArrayBuffer (aliased Vec<u8>)
     /// Array<Number>    // Array<Number>
+    /// Array<Array<Number>> // Array<Array<Number>>
     /// Promise<Number>  // Promise<Number>
     /// Nullable<Number> // Nullable<Number>
+    /// Map<Number>      // Map<Number> (aliased HashMap<String, f64>)
+    /// (Number, String) // Tuple<Number, String>
+    /// Box<Node>        // Ref (recursive type)
     /// ```
     pub fn as_rs_impl_type(&self) -> Result<RsImplType, anyhow::Error> {
         let rs_type = match self {
             TypeAnnotation::Void => "Void".to_string(),
             TypeAnnotation::Boolean => "Boolean".to_string(),
             TypeAnnotation::Number => "Number".to_string(),
+            TypeAnnotation::Int32 => "Int32".to_string(),
+            TypeAnnotation::Int64 => "Int64".to_string(),
+            TypeAnnotation::BigInt => "BigInt".to_string(),
             TypeAnnotation::String => "String".to_string(),
             TypeAnnotation::ArrayBuffer => "ArrayBuffer".to_string(),
             TypeAnnotation::Array(element_type) => {
-                if let TypeAnnotation::Array { .. } = &**element_type {
-                    return Err(anyhow::anyhow!(
-                        "Nested array type is not supported: {:?}",
-                        element_type
-                    ));
-                }
                 format!("Array<{}>", element_type.as_rs_impl_type()?.into_code())
             }
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => name.clone(),
@@ -249,7 +237,19 @@ impl TypeAnnotation {
                 let type_annotation = type_annotation.as_rs_impl_type()?.into_code();
                 format!("Nullable<{type_annotation}>")
             }
-            TypeAnnotation::Ref(..) => unreachable!(),
+            TypeAnnotation::Map(value_type) => {
+                format!("Map<{}>", value_type.as_rs_impl_type()?.into_code())
+            }
+            TypeAnnotation::Tuple(element_types) => format!(
+                "({})",
+                element_types
+                    .iter()
+                    .map(|t| t.as_rs_impl_type().map(IntoCode::into_code))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            // Recursive types are boxed (eg. `Nullable<Box<Node>>`)
+            TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => format!("Box<{name}>"),
         };
         Ok(RsImplType(rs_type))
     }
@@ -261,18 +261,25 @@ impl TypeAnnotation {
     /// ```rust,ignore
     /// false                         // Boolean
     /// 0.0                           // Number
+    /// 0                             // Int32, Int64, BigInt
     /// String::default()             // String
-    /// Vec::default()                // Array
+    /// Vec::default()                // Array, Map
     /// MyEnum::default()             // Enum
     /// MyStruct::default()           // Object
     /// NullableNumber::default()     // Nullable<Number>
+    /// NumberStringTuple2 { item_0: 0.0, item_1: String::default() } // Tuple<Number, String>
     /// ```
     pub fn as_rs_default_val(&self) -> Result<String, anyhow::Error> {
         let default_val = match self {
             TypeAnnotation::Boolean => "false".to_string(),
             TypeAnnotation::Number => "0.0".to_string(),
+            TypeAnnotation::Int32 | TypeAnnotation::Int64 | TypeAnnotation::BigInt => {
+                "0".to_string()
+            }
             TypeAnnotation::String => "String::default()".to_string(),
-            TypeAnnotation::ArrayBuffer | TypeAnnotation::Array(..) => "Vec::default()".to_string(),
+            TypeAnnotation::ArrayBuffer | TypeAnnotation::Array(..) | TypeAnnotation::Map(..) => {
+                "Vec::default()".to_string()
+            }
             TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
                 format!("{name}::default()")
             }
@@ -283,6 +290,16 @@ impl TypeAnnotation {
                 let nullable_type = self.as_rs_type()?.into_code();
                 format!("{nullable_type}::default()")
             }
+            TypeAnnotation::Tuple(element_types) => format!(
+                "{} {{ {} }}",
+                self.tuple_name()?,
+                element_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| Ok(format!("item_{i}: {}", t.as_rs_default_val()?)))
+                    .collect::<Result<Vec<_>, anyhow::Error>>()?
+                    .join(", ")
+            ),
             _ => {
                 return Err(anyhow::anyhow!(
                     "[as_rs_default_val] Unsupported type annotation: {:?}",
@@ -293,6 +310,166 @@ impl TypeAnnotation {
 
         Ok(default_val)
     }
+
+    /// Generates default value for user-facing Rust implementation types.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```rust,ignore
+    /// ()                            // Void
+    /// false                         // Boolean
+    /// 0.0                           // Number
+    /// String::default()             // String
+    /// Vec::default()                // Array
+    /// MyEnum::default()             // Enum
+    /// MyStruct::default()           // Object
+    /// Nullable::none()              // Nullable<Number>
+    /// Map::default()                // Map<Number>
+    /// (0.0, String::default())      // Tuple<Number, String>
+    /// promise::resolve(0.0)         // Promise<Number>
+    /// ```
+    pub fn as_rs_impl_default_val(&self) -> Result<String, anyhow::Error> {
+        let default_val = match self {
+            TypeAnnotation::Void => "()".to_string(),
+            TypeAnnotation::Nullable(..) => "Nullable::none()".to_string(),
+            TypeAnnotation::Map(..) => "Map::default()".to_string(),
+            TypeAnnotation::Tuple(element_types) => format!(
+                "({})",
+                element_types
+                    .iter()
+                    .map(TypeAnnotation::as_rs_impl_default_val)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            TypeAnnotation::Promise(resolved_type) => {
+                format!(
+                    "promise::resolve({})",
+                    resolved_type.as_rs_impl_default_val()?
+                )
+            }
+            _ => self.as_rs_default_val()?,
+        };
+
+        Ok(default_val)
+    }
+}
+
+impl TypeAnnotation {
+    /// Converts the FFI value to the user-facing implementation type.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```rust,ignore
+    /// value.into()                                                         // Nullable<Number>
+    /// value.into_iter().map(|entry| (entry.key, entry.value)).collect()    // Map<Number>
+    /// value.into_iter().map(|row| row.items).collect::<Vec<_>>()           // Array<Array<Number>>
+    /// (value.item_0, value.item_1)                                         // Tuple<Number, String>
+    /// value.into_iter().map(|item| (item.item_0, item.item_1)).collect::<Vec<_>>() // Array<Tuple<Number, String>>
+    /// value                                                                // Others
+    /// ```
+    pub fn as_rs_from_ffi(&self, expr: &str) -> Result<String, anyhow::Error> {
+        let from_ffi = match self {
+            TypeAnnotation::Nullable(..) => format!("{expr}.into()"),
+            TypeAnnotation::Map(value_type) => format!(
+                "{expr}.into_iter().map(|entry| (entry.key, {})).collect()",
+                value_type.as_rs_from_ffi("entry.value")?
+            ),
+            TypeAnnotation::Array(element_type)
+                if matches!(**element_type, TypeAnnotation::Array(..)) =>
+            {
+                format!(
+                    "{expr}.into_iter().map(|row| {}).collect::<Vec<_>>()",
+                    element_type.as_rs_from_ffi("row.items")?
+                )
+            }
+            TypeAnnotation::Array(element_type)
+                if matches!(**element_type, TypeAnnotation::Tuple(..)) =>
+            {
+                format!(
+                    "{expr}.into_iter().map(|item| {}).collect::<Vec<_>>()",
+                    element_type.as_rs_from_ffi("item")?
+                )
+            }
+            TypeAnnotation::Tuple(element_types) => format!(
+                "({})",
+                element_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| t.as_rs_from_ffi(&format!("{expr}.item_{i}")))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            _ => expr.to_string(),
+        };
+
+        Ok(from_ffi)
+    }
+
+    /// Converts the user-facing implementation value to the FFI type.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```rust,ignore
+    /// value.into()                                                                      // Nullable<Number>
+    /// value.into_iter().map(|(key, value)| NumberMapEntry { key, value }).collect()     // Map<Number>
+    /// value.into_iter().map(|row| NestedNumberArray { items: row }).collect::<Vec<_>>() // Array<Array<Number>>
+    /// NumberStringTuple2 { item_0: value.0, item_1: value.1 }                            // Tuple<Number, String>
+    /// value.into_iter().map(|item| NumberStringTuple2 { item_0: item.0, item_1: item.1 }).collect::<Vec<_>>() // Array<Tuple<Number, String>>
+    /// value.map(|ret| ret.into())                                                       // Promise<Nullable<Number>>
+    /// value                                                                             // Others
+    /// ```
+    pub fn as_rs_into_ffi(&self, expr: &str) -> Result<String, anyhow::Error> {
+        let into_ffi = match self {
+            TypeAnnotation::Nullable(..) => format!("{expr}.into()"),
+            TypeAnnotation::Map(value_type) => {
+                let value = match value_type.as_rs_into_ffi("value")? {
+                    value if value == "value" => value,
+                    value => format!("value: {value}"),
+                };
+                format!(
+                    "{expr}.into_iter().map(|(key, value)| {} {{ key, {value} }}).collect()",
+                    self.map_entry_name()?
+                )
+            }
+            TypeAnnotation::Array(element_type)
+                if matches!(**element_type, TypeAnnotation::Array(..)) =>
+            {
+                format!(
+                    "{expr}.into_iter().map(|row| {} {{ items: {} }}).collect::<Vec<_>>()",
+                    element_type.nested_array_name()?,
+                    element_type.as_rs_into_ffi("row")?
+                )
+            }
+            TypeAnnotation::Array(element_type)
+                if matches!(**element_type, TypeAnnotation::Tuple(..)) =>
+            {
+                format!(
+                    "{expr}.into_iter().map(|item| {}).collect::<Vec<_>>()",
+                    element_type.as_rs_into_ffi("item")?
+                )
+            }
+            TypeAnnotation::Tuple(element_types) => format!(
+                "{} {{ {} }}",
+                self.tuple_name()?,
+                element_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| Ok(format!(
+                        "item_{i}: {}",
+                        t.as_rs_into_ffi(&format!("{expr}.{i}"))?
+                    )))
+                    .collect::<Result<Vec<_>, anyhow::Error>>()?
+                    .join(", ")
+            ),
+            TypeAnnotation::Promise(resolve_type) => match resolve_type.as_rs_into_ffi("ret")? {
+                ret if ret == "ret" => expr.to_string(),
+                ret => format!("{expr}.map(|ret| {ret})"),
+            },
+            _ => expr.to_string(),
+        };
+
+        Ok(into_ffi)
+    }
 }
 
 impl Method {
@@ -303,16 +480,31 @@ impl Method {
     /// ```rust,ignore
     /// fn multiply(&mut self, a: Number, b: Number) -> Number
     /// fn add_async(&mut self, a: Number, b: Number) -> Promise<Number>
+    /// fn long_task(&mut self, n: Number, cancel_token: CancellationToken) -> Promise<Number>
+    /// fn get_version(&self) -> String
+    /// fn fetch(&mut self, url: &str) -> Promise<String, FetchError>
     /// ```
     pub fn try_into_impl_sig(&self) -> Result<String, anyhow::Error> {
-        let return_type = self.ret_type.as_rs_impl_type()?.into_code();
-        let params_sig = std::iter::once("&mut self".to_string())
+        let return_type = match (&self.ret_type, &self.error_type) {
+            (TypeAnnotation::Promise(resolve_type), Some(error_type)) => format!(
+                "Promise<{}, {}>",
+                resolve_type.as_rs_impl_type()?.into_code(),
+                error_type.as_rs_impl_type()?.into_code()
+            ),
+            _ => self.ret_type.as_rs_impl_type()?.into_code(),
+        };
+        let receiver = if self.readonly { "&self" } else { "&mut self" };
+        let params_sig = std::iter::once(receiver.to_string())
             .chain(
                 self.params
                     .iter()
                     .map(|param| param.try_into_impl_sig())
                     .collect::<Result<Vec<_>, _>>()?,
             )
+            .chain(
+                self.cancellable
+                    .then(|| format!("{RESERVED_ARG_NAME_CANCEL_TOKEN}: CancellationToken")),
+            )
             .collect::<Vec<_>>()
             .join(", ");
 
@@ -363,6 +555,27 @@ impl Param {
         };
         Ok(format!("{}: {}", snake_case(&self.name), param_type))
     }
+
+    /// Generates length check for `ArrayBuffer` parameter annotated with `@bytes N`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// if key.len() != 32 {
+    ///     anyhow::bail!("Invalid length of `key` (expected 32 bytes, got {})", key.len());
+    /// }
+    /// ```
+    pub fn as_rs_len_check(&self) -> Option<String> {
+        let byte_length = self.byte_length?;
+        let name = snake_case(&self.name);
+
+        Some(formatdoc! {
+            r#"
+            if {name}.len() != {byte_length} {{
+                anyhow::bail!("Invalid length of `{name}` (expected {byte_length} bytes, got {{}})", {name}.len());
+            }}"#,
+        })
+    }
 }
 
 impl Schema {
@@ -400,6 +613,8 @@ impl Schema {
         let mut func_impls = Vec::with_capacity(self.methods.len() + 1);
         let mut type_impls = vec![];
         let mut struct_defs = FxHashMap::default();
+        // IDs of the nullable types in `struct_defs`
+        let mut nullable_ids = FxHashSet::default();
 
         func_extern_sigs.push(formatdoc! {
             r#"
@@ -423,6 +638,7 @@ impl Schema {
                     let id = param.type_annotation.to_id();
                     if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
                         let nullable = RsNullableStruct::try_from(&param.type_annotation)?;
+                        nullable_ids.insert(id);
                         e.insert(nullable.definition);
                         type_impls.push(nullable.implementation);
                     }
@@ -434,11 +650,62 @@ impl Schema {
                 let id = method_spec.ret_type.to_id();
                 if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
                     let nullable = RsNullableStruct::try_from(&method_spec.ret_type)?;
+                    nullable_ids.insert(id);
+                    e.insert(nullable.definition);
+                    type_impls.push(nullable.implementation);
+                }
+            }
+
+            // Collect nullable error type
+            if let Some(nullable_error_type) = method_spec.nullable_error_type() {
+                let id = nullable_error_type.to_id();
+                if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
+                    let nullable = RsNullableStruct::try_from(&nullable_error_type)?;
+                    nullable_ids.insert(id);
                     e.insert(nullable.definition);
                     type_impls.push(nullable.implementation);
                 }
             }
 
+            // Collect wrapper structs of the nested arrays
+            for nested_array in method_spec
+                .params
+                .iter()
+                .map(|param| &param.type_annotation)
+                .chain(std::iter::once(&method_spec.ret_type))
+                .flat_map(TypeAnnotation::nested_arrays)
+            {
+                if let HashMapEntry::Vacant(e) = struct_defs.entry(nested_array.to_id()) {
+                    e.insert(RsNestedArrayStruct::try_from(nested_array)?.into_code());
+                }
+            }
+
+            // Collect structs of the tuples
+            for tuple in method_spec
+                .params
+                .iter()
+                .map(|param| &param.type_annotation)
+                .chain(std::iter::once(&method_spec.ret_type))
+                .flat_map(TypeAnnotation::tuples)
+            {
+                if let HashMapEntry::Vacant(e) = struct_defs.entry(tuple.to_id()) {
+                    e.insert(RsTupleStruct::try_from(tuple)?.into_code());
+                }
+            }
+
+            // Collect key/value pair structs of the map types
+            for map_type in method_spec
+                .params
+                .iter()
+                .map(|param| &param.type_annotation)
+                .chain(std::iter::once(&method_spec.ret_type))
+                .filter_map(TypeAnnotation::as_map)
+            {
+                if let HashMapEntry::Vacant(e) = struct_defs.entry(map_type.to_id()) {
+                    e.insert(RsMapEntryStruct::try_from(map_type)?.into_code());
+                }
+            }
+
             let ret_type = method_spec.ret_type.as_rs_type()?.into_code();
             let ret_type = match method_spec.ret_type {
                 TypeAnnotation::Promise(_) => ret_type,
@@ -459,27 +726,45 @@ impl Schema {
                     params.insert(
                         0,
                         format!(
-                            "{RESERVED_ARG_NAME_MODULE}: &mut {}",
+                            "{RESERVED_ARG_NAME_MODULE}: {}{}",
+                            if method_spec.readonly { "&" } else { "&mut " },
                             pascal_case(&self.module_name)
                         ),
                     );
-                    params.join(", ")
+                    if method_spec.cancellable {
+                        params.push(format!(
+                            "{RESERVED_ARG_NAME_CANCEL_TOKEN}: &CancellationToken"
+                        ));
+                    }
+                    params
                 })?;
+            let params_sig = match method_spec.nullable_error_type() {
+                Some(nullable_error_type) => [
+                    params_sig,
+                    vec![format!(
+                        "{RESERVED_ARG_NAME_ERROR}: &mut {}",
+                        nullable_error_type.as_rs_type()?.into_code()
+                    )],
+                ]
+                .concat(),
+                None => params_sig,
+            }
+            .join(", ");
 
             let mod_name = snake_case(&self.module_name);
             let fn_name = snake_case(&method_spec.name);
-            let fn_args = method_spec
+            let mut fn_args = method_spec
                 .params
                 .iter()
                 .map(|param| {
                     let name = snake_case(&param.name);
-                    if let TypeAnnotation::Nullable(..) = &param.type_annotation {
-                        format!("{name}.into()")
-                    } else {
-                        name
-                    }
+                    param.type_annotation.as_rs_from_ffi(&name)
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if method_spec.cancellable {
+                fn_args.push(format!("{RESERVED_ARG_NAME_CANCEL_TOKEN}.clone()"));
+            }
 
             let cxx_extern_fn_name = camel_case(&method_spec.name);
             let prefixed_fn_name = format!("{mod_name}_{fn_name}");
@@ -491,18 +776,35 @@ impl Schema {
                 fn {prefixed_fn_name}({params_sig}){ret_extern_annotation};"#,
             };
 
-            let ret = if let TypeAnnotation::Nullable(..) = &method_spec.ret_type {
-                "ret.into()"
-            } else {
-                "ret"
+            let ret = method_spec.ret_type.as_rs_into_ffi("ret")?;
+
+            // The structured error is passed to C++ through the out parameter
+            let ret = match &method_spec.error_type {
+                Some(error_type) => formatdoc! {
+                    r#"
+                    {ret}.map_err(|err| {{
+                                *{RESERVED_ARG_NAME_ERROR} = Nullable::some(err).into();
+                                anyhow::anyhow!("Rejected with `{name}`")
+                            }})"#,
+                    name = error_type.as_rs_type()?.into_code(),
+                },
+                None => ret,
             };
 
+            // Validate length of `ArrayBuffer` parameters annotated with `@bytes N`
+            let len_checks = method_spec
+                .params
+                .iter()
+                .filter_map(|param| param.as_rs_len_check())
+                .map(|check| format!("{}\n", indent_str(&check, 4)))
+                .collect::<String>();
+
             let fn_args = fn_args.join(", ");
             let impl_func = match method_spec.ret_type {
                 TypeAnnotation::Promise(_) => formatdoc! {
                     r#"
                     fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
-                        craby::catch_panic!({{
+                    {len_checks}    craby::catch_panic!({{
                             let ret = {it}.{fn_name}({fn_args});
                             {ret}
                         }}).and_then(|r| r)
@@ -512,7 +814,7 @@ impl Schema {
                 _ => formatdoc! {
                     r#"
                     fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
-                        craby::catch_panic!({{
+                    {len_checks}    craby::catch_panic!({{
                             let ret = {it}.{fn_name}({fn_args});
                             {ret}
                         }})
@@ -537,9 +839,28 @@ impl Schema {
                         let id = prop.type_annotation.to_id();
                         if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
                             let nullable = RsNullableStruct::try_from(&prop.type_annotation)?;
+                            nullable_ids.insert(id);
                             e.insert(nullable.definition);
                         }
                     }
+
+                    if let Some(map_type) = prop.type_annotation.as_map() {
+                        if let HashMapEntry::Vacant(e) = struct_defs.entry(map_type.to_id()) {
+                            e.insert(RsMapEntryStruct::try_from(map_type)?.into_code());
+                        }
+                    }
+
+                    for nested_array in prop.type_annotation.nested_arrays() {
+                        if let HashMapEntry::Vacant(e) = struct_defs.entry(nested_array.to_id()) {
+                            e.insert(RsNestedArrayStruct::try_from(nested_array)?.into_code());
+                        }
+                    }
+
+                    for tuple in prop.type_annotation.tuples() {
+                        if let HashMapEntry::Vacant(e) = struct_defs.entry(tuple.to_id()) {
+                            e.insert(RsTupleStruct::try_from(tuple)?.into_code());
+                        }
+                    }
                 }
 
                 // Collect default implementations for the alias type
@@ -561,15 +882,24 @@ impl Schema {
             .iter()
             .map(|type_annotation| {
                 let enum_schema = type_annotation.as_enum().unwrap();
+                // Numeric enums carry the same discriminants as the TypeScript enums
                 let members = enum_schema
                     .members
                     .iter()
-                    .map(|m| format!("{},", m.name))
+                    .map(|m| match &m.value {
+                        EnumMemberValue::Number(value) => format!("{} = {},", m.name, value),
+                        EnumMemberValue::String(_) => format!("{},", m.name),
+                    })
                     .collect::<Vec<_>>();
 
                 let members = indent_str(&members.join("\n"), 4);
+                let repr = match numeric_enum_repr(enum_schema) {
+                    Some(repr) => format!("#[repr({repr})]\n"),
+                    None => String::new(),
+                };
                 formatdoc! {
                     r#"
+                    {repr}#[derive(Debug)]
                     enum {name} {{
                     {members}
                     }}"#,
@@ -578,9 +908,14 @@ impl Schema {
             })
             .collect();
 
+        // Emit the structs in name order, `FxHashMap` iteration order is not stable across runs
+        let mut struct_defs = struct_defs.into_values().collect::<Vec<_>>();
+        struct_defs.sort_by_cached_key(|def| struct_def_name(def).to_string());
+
         Ok(RsCxxBridge {
             impl_type: format!("type {module_name};"),
-            struct_defs: struct_defs.into_values().collect(),
+            struct_defs,
+            nullable_count: nullable_ids.len(),
             enum_defs,
             func_extern_sigs,
             func_impls,
@@ -632,6 +967,14 @@ impl Schema {
                     e.insert(nullable.implementation);
                 }
             }
+
+            // Collect nullable error type
+            if let Some(nullable_error_type) = method_spec.nullable_error_type() {
+                if let BTreeMapEntry::Vacant(e) = type_impls.entry(nullable_error_type.to_id()) {
+                    let nullable = RsNullableStruct::try_from(&nullable_error_type)?;
+                    e.insert(nullable.implementation);
+                }
+            }
         }
 
         // impl Default trait for the alias type
@@ -647,7 +990,24 @@ impl Schema {
             let id = type_annotation.to_id();
             if let BTreeMapEntry::Vacant(e) = type_impls.entry(id) {
                 let enum_type_annotation = type_annotation.as_enum().unwrap();
-                e.insert(RsDefaultImpl::try_from(enum_type_annotation)?.into_code());
+                let default_impl = RsDefaultImpl::try_from(enum_type_annotation)?.into_code();
+
+                let try_from_impl = enum_try_from_impl(enum_type_annotation);
+
+                // String enums also provide the original string value of each member,
+                // and numeric enums the conversions from/into the JS number
+                match (
+                    enum_as_str_impl(enum_type_annotation),
+                    enum_number_impls(enum_type_annotation),
+                ) {
+                    (Some(as_str_impl), _) => e.insert(format!(
+                        "{default_impl}\n\n{as_str_impl}\n\n{try_from_impl}"
+                    )),
+                    (None, Some(number_impls)) => e.insert(format!(
+                        "{default_impl}\n\n{try_from_impl}\n\n{number_impls}"
+                    )),
+                    (None, None) => e.insert(format!("{default_impl}\n\n{try_from_impl}")),
+                };
             }
         }
 
@@ -655,6 +1015,14 @@ impl Schema {
     }
 }
 
+/// Returns the name of the struct definition (eg. `NullableString` of `struct NullableString { .. }`).
+fn struct_def_name(def: &str) -> &str {
+    def.lines()
+        .find_map(|line| line.strip_prefix("struct "))
+        .and_then(|rest| rest.split_whitespace().next())
+        .unwrap_or_default()
+}
+
 pub mod template {
     use std::collections::{btree_map::Entry as BTreeMapEntry, BTreeMap};
 
@@ -663,15 +1031,21 @@ pub mod template {
 
     use crate::{
         common::IntoCode,
-        parser::types::{EnumTypeAnnotation, ObjectTypeAnnotation, TypeAnnotation},
-        utils::indent_str,
+        parser::types::{
+            EnumMemberValue, EnumTypeAnnotation, ObjectTypeAnnotation, RefTypeAnnotation,
+            TypeAnnotation,
+        },
+        utils::{doc_comment, indent_str},
     };
 
     /// Rust struct definition for FFI.
     ///
+    /// `@hashable` object types also derive `PartialEq`, `Eq` and `Hash`.
+    ///
     /// # Generated Code
     ///
     /// ```rust,ignore
+    /// #[derive(Clone, Debug)]
     /// struct MyStruct {
     ///     foo: String,
     ///     bar: f64,
@@ -700,16 +1074,26 @@ pub mod template {
                 // baz: bool,
                 // ```
                 props.push(format!(
-                    "{}: {},",
+                    "{}{}: {},",
+                    doc_comment(&prop.docs),
                     snake_case(&prop.name),
                     prop.type_annotation.as_rs_bridge_type()?.into_code()
                 ));
             }
 
+            let derives = if obj.hashable {
+                for prop in &obj.props {
+                    assert_hashable(&obj.name, &prop.name, &prop.type_annotation)?;
+                }
+                "Clone, Debug, PartialEq, Eq, Hash"
+            } else {
+                "Clone, Debug"
+            };
+
             let props = indent_str(&props.join("\n"), 4);
             let struct_def = formatdoc! {
                 r#"
-                #[derive(Clone)]
+                #[derive({derives})]
                 struct {name} {{
                 {props}
                 }}"#,
@@ -720,6 +1104,194 @@ pub mod template {
         }
     }
 
+    /// Returns `true` if the type can derive `Eq` and `Hash`.
+    ///
+    /// Nested arrays are wrapped with the structs that derive them only if the element type does.
+    fn is_hashable(type_annotation: &TypeAnnotation) -> bool {
+        match type_annotation {
+            TypeAnnotation::Boolean
+            | TypeAnnotation::Int32
+            | TypeAnnotation::Int64
+            | TypeAnnotation::BigInt
+            | TypeAnnotation::String
+            | TypeAnnotation::ArrayBuffer => true,
+            TypeAnnotation::Array(element_type) => is_hashable(element_type),
+            TypeAnnotation::Object(obj) => obj.hashable,
+            _ => false,
+        }
+    }
+
+    /// Ensures that the field of the `@hashable` object type can derive `Eq` and `Hash`.
+    fn assert_hashable(
+        name: &str,
+        prop_name: &str,
+        type_annotation: &TypeAnnotation,
+    ) -> Result<(), anyhow::Error> {
+        match type_annotation {
+            TypeAnnotation::Boolean
+            | TypeAnnotation::Int32
+            | TypeAnnotation::Int64
+            | TypeAnnotation::BigInt
+            | TypeAnnotation::String
+            | TypeAnnotation::ArrayBuffer => Ok(()),
+            TypeAnnotation::Array(element_type) => assert_hashable(name, prop_name, element_type),
+            TypeAnnotation::Object(obj) if obj.hashable => Ok(()),
+            TypeAnnotation::Number => anyhow::bail!(
+                "`@hashable` type `{name}` cannot have the number field `{prop_name}` (`f64` is not `Eq` and `Hash`). Use `Int32` or `Int64` instead"
+            ),
+            TypeAnnotation::Object(obj) => anyhow::bail!(
+                "Object type `{}` of the field `{prop_name}` must be `@hashable` to be used in `@hashable` type `{name}`",
+                obj.name
+            ),
+            _ => anyhow::bail!(
+                "`@hashable` type `{name}` can only have boolean, string, integer, `ArrayBuffer`, array or `@hashable` object fields (found `{prop_name}`)"
+            ),
+        }
+    }
+
+    /// Rust struct definition for the key/value pairs of map types.
+    ///
+    /// cxx does not support the maps in the shared structs,
+    /// so the map is passed as a vector of the key/value pairs and exposed as `Map<T>`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[derive(Clone, Debug)]
+    /// struct NumberMapEntry {
+    ///     key: String,
+    ///     value: f64,
+    /// }
+    /// ```
+    pub struct RsMapEntryStruct(pub String);
+
+    impl IntoCode for RsMapEntryStruct {
+        fn into_code(self) -> String {
+            self.0
+        }
+    }
+
+    impl TryFrom<&TypeAnnotation> for RsMapEntryStruct {
+        type Error = anyhow::Error;
+
+        fn try_from(map_type: &TypeAnnotation) -> Result<Self, Self::Error> {
+            let TypeAnnotation::Map(value_type) = map_type else {
+                anyhow::bail!("Not a map type: {:?}", map_type);
+            };
+
+            let struct_def = formatdoc! {
+                r#"
+                #[derive(Clone, Debug)]
+                struct {name} {{
+                    key: String,
+                    value: {value_type},
+                }}"#,
+                name = map_type.map_entry_name()?,
+                value_type = value_type.as_rs_bridge_type()?.into_code(),
+            };
+
+            Ok(RsMapEntryStruct(struct_def))
+        }
+    }
+
+    /// Rust struct definition for the arrays nested in other arrays.
+    ///
+    /// cxx does not support the nested vectors,
+    /// so each nested array is wrapped with a struct and exposed as `Array<Array<T>>`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[derive(Clone, Debug)]
+    /// struct NestedNumberArray {
+    ///     items: Vec<f64>,
+    /// }
+    /// ```
+    ///
+    /// Arrays of the hashable elements also derive `PartialEq`, `Eq` and `Hash`,
+    /// so they can be used in `@hashable` object types.
+    pub struct RsNestedArrayStruct(pub String);
+
+    impl IntoCode for RsNestedArrayStruct {
+        fn into_code(self) -> String {
+            self.0
+        }
+    }
+
+    impl TryFrom<&TypeAnnotation> for RsNestedArrayStruct {
+        type Error = anyhow::Error;
+
+        fn try_from(array_type: &TypeAnnotation) -> Result<Self, Self::Error> {
+            let derives = if is_hashable(array_type) {
+                "Clone, Debug, PartialEq, Eq, Hash"
+            } else {
+                "Clone, Debug"
+            };
+
+            let struct_def = formatdoc! {
+                r#"
+                #[derive({derives})]
+                struct {name} {{
+                    items: {items_type},
+                }}"#,
+                name = array_type.nested_array_name()?,
+                items_type = array_type.as_rs_bridge_type()?.into_code(),
+            };
+
+            Ok(RsNestedArrayStruct(struct_def))
+        }
+    }
+
+    /// Rust struct definition for the tuple types.
+    ///
+    /// cxx does not support the tuples,
+    /// so each tuple is wrapped with a struct and exposed as `(T1, T2, ...)`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[derive(Clone, Debug)]
+    /// struct NumberStringTuple2 {
+    ///     item_0: f64,
+    ///     item_1: String,
+    /// }
+    /// ```
+    pub struct RsTupleStruct(pub String);
+
+    impl IntoCode for RsTupleStruct {
+        fn into_code(self) -> String {
+            self.0
+        }
+    }
+
+    impl TryFrom<&TypeAnnotation> for RsTupleStruct {
+        type Error = anyhow::Error;
+
+        fn try_from(tuple_type: &TypeAnnotation) -> Result<Self, Self::Error> {
+            let TypeAnnotation::Tuple(element_types) = tuple_type else {
+                anyhow::bail!("Not a tuple type: {:?}", tuple_type);
+            };
+
+            let items = element_types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| Ok(format!("item_{i}: {},", t.as_rs_type()?.into_code())))
+                .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+            let struct_def = formatdoc! {
+                r#"
+                #[derive(Clone, Debug)]
+                struct {name} {{
+                {items}
+                }}"#,
+                name = tuple_type.tuple_name()?,
+                items = indent_str(&items.join("\n"), 4),
+            };
+
+            Ok(RsTupleStruct(struct_def))
+        }
+    }
+
     /// Rust struct definition for nullable types.
     pub struct RsNullableStruct {
         pub definition: String,
@@ -731,14 +1303,26 @@ pub mod template {
 
         fn try_from(nullable_type: &TypeAnnotation) -> Result<Self, Self::Error> {
             if let TypeAnnotation::Nullable(type_annotation) = nullable_type {
+                if let TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) = &**type_annotation {
+                    return Ok(RsNullableStruct::boxed(
+                        &nullable_type.as_rs_bridge_type()?.into_code(),
+                        name,
+                    ));
+                }
+
                 let struct_type = nullable_type.as_rs_bridge_type()?.into_code();
                 let base_type = type_annotation.as_rs_type()?.into_code();
                 let rs_impl_type = type_annotation.as_rs_impl_type()?.into_code();
                 let default_val = type_annotation.as_rs_default_val()?;
+                let from_ffi = type_annotation.as_rs_from_ffi("val.val")?;
+                let into_ffi = match type_annotation.as_rs_into_ffi("val")? {
+                    into_ffi if into_ffi == "val" => format!("val.unwrap_or({default_val})"),
+                    into_ffi => format!("val.map(|val| {into_ffi}).unwrap_or({default_val})"),
+                };
 
                 let struct_def = formatdoc! {
                     r#"
-                    #[derive(Clone)]
+                    #[derive(Clone, Debug)]
                     struct {struct_type} {{
                         null: bool,
                         val: {base_type},
@@ -758,7 +1342,7 @@ pub mod template {
 
                     impl From<{struct_type}> for Nullable<{rs_impl_type}> {{
                         fn from(val: {struct_type}) -> Self {{
-                            Nullable::new(if val.null {{ None }} else {{ Some(val.val) }})
+                            Nullable::new(if val.null {{ None }} else {{ Some({from_ffi}) }})
                         }}
                     }}
     
@@ -767,7 +1351,7 @@ pub mod template {
                             let val = val.into_value();
                             let null = val.is_none();
                             {struct_type} {{
-                                val: val.unwrap_or({default_val}),
+                                val: {into_ffi},
                                 null,
                             }}
                         }}
@@ -784,6 +1368,67 @@ pub mod template {
         }
     }
 
+    impl RsNullableStruct {
+        /// Nullable struct for the recursive types.
+        ///
+        /// cxx does not support the nullable `Box` in the shared structs,
+        /// so the value is passed as a vector holding zero or one element and exposed as `Nullable<Box<T>>`.
+        ///
+        /// # Generated Code
+        ///
+        /// ```rust,ignore
+        /// #[derive(Clone, Debug)]
+        /// struct NullableNode {
+        ///     null: bool,
+        ///     val: Vec<Node>,
+        /// }
+        /// ```
+        fn boxed(struct_type: &str, name: &str) -> RsNullableStruct {
+            let struct_def = formatdoc! {
+                r#"
+                #[derive(Clone, Debug)]
+                struct {struct_type} {{
+                    null: bool,
+                    val: Vec<{name}>,
+                }}"#,
+            };
+
+            let struct_impl = formatdoc! {
+                r#"
+                impl Default for {struct_type} {{
+                    fn default() -> Self {{
+                        {struct_type} {{
+                            null: true,
+                            val: Vec::default(),
+                        }}
+                    }}
+                }}
+
+                impl From<{struct_type}> for Nullable<Box<{name}>> {{
+                    fn from(val: {struct_type}) -> Self {{
+                        Nullable::new(if val.null {{ None }} else {{ val.val.into_iter().next().map(Box::new) }})
+                    }}
+                }}
+
+                impl From<Nullable<Box<{name}>>> for {struct_type} {{
+                    fn from(val: Nullable<Box<{name}>>) -> Self {{
+                        let val = val.into_value();
+                        let null = val.is_none();
+                        {struct_type} {{
+                            val: val.map(|val| vec![*val]).unwrap_or_default(),
+                            null,
+                        }}
+                    }}
+                }}"#,
+            };
+
+            RsNullableStruct {
+                definition: struct_def,
+                implementation: struct_impl,
+            }
+        }
+    }
+
     /// Default implementation for struct types.
     ///
     /// # Generated Code
@@ -870,6 +1515,201 @@ pub mod template {
         }
     }
 
+    /// Returns the `as_str` implementation of the string enum.
+    /// Returns `None` for numeric enums.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// impl MyEnum {
+    ///     pub fn as_str(&self) -> &'static str {
+    ///         match *self {
+    ///             MyEnum::Foo => "foo",
+    ///             MyEnum::Bar => "bar",
+    ///             _ => unreachable!(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn enum_as_str_impl(enum_type_annotation: &EnumTypeAnnotation) -> Option<String> {
+        let arms = enum_type_annotation
+            .members
+            .iter()
+            .map(|member| match &member.value {
+                EnumMemberValue::String(value) => Some(format!(
+                    "{}::{} => {:?},",
+                    enum_type_annotation.name, member.name, value
+                )),
+                EnumMemberValue::Number(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if arms.is_empty() {
+            return None;
+        }
+
+        // cxx shared enums may hold any discriminant value, so the fallback arm is required
+        let arms = indent_str(
+            &[arms, vec!["_ => unreachable!(),".to_string()]]
+                .concat()
+                .join("\n"),
+            12,
+        );
+
+        Some(formatdoc! {
+            r#"
+            impl {name} {{
+                pub fn as_str(&self) -> &'static str {{
+                    match *self {{
+            {arms}
+                    }}
+                }}
+            }}"#,
+            name = enum_type_annotation.name,
+        })
+    }
+
+    /// Returns the `TryFrom` implementation that converts the raw value into the enum.
+    /// (`TryFrom<&str>` for string enums, `TryFrom<i64>` for numeric enums)
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// impl TryFrom<i64> for MyEnum {
+    ///     type Error = anyhow::Error;
+    ///
+    ///     fn try_from(value: i64) -> Result<Self, Self::Error> {
+    ///         match value {
+    ///             0 => Ok(MyEnum::Foo),
+    ///             1 => Ok(MyEnum::Bar),
+    ///             _ => Err(anyhow::anyhow!("Unknown `MyEnum` value: {}", value)),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn enum_try_from_impl(enum_type_annotation: &EnumTypeAnnotation) -> String {
+        let name = &enum_type_annotation.name;
+        let is_string_enum = enum_type_annotation
+            .members
+            .iter()
+            .any(|member| matches!(member.value, EnumMemberValue::String(_)));
+
+        let (value_type, value_fmt) = if is_string_enum {
+            ("&str", "{:?}")
+        } else {
+            ("i64", "{}")
+        };
+
+        let arms = enum_type_annotation
+            .members
+            .iter()
+            .map(|member| {
+                let pattern = match &member.value {
+                    EnumMemberValue::String(value) => format!("{value:?}"),
+                    EnumMemberValue::Number(value) => value.to_string(),
+                };
+                format!("{pattern} => Ok({name}::{}),", member.name)
+            })
+            .chain(std::iter::once(format!(
+                "_ => Err(anyhow::anyhow!(\"Unknown `{name}` value: {value_fmt}\", value)),"
+            )))
+            .collect::<Vec<_>>();
+        let arms = indent_str(&arms.join("\n"), 12);
+
+        formatdoc! {
+            r#"
+            impl TryFrom<{value_type}> for {name} {{
+                type Error = anyhow::Error;
+
+                fn try_from(value: {value_type}) -> Result<Self, Self::Error> {{
+                    match value {{
+            {arms}
+                    }}
+                }}
+            }}"#,
+        }
+    }
+
+    /// Returns the `#[repr(...)]` integer type of the numeric enum that fits all of the member values.
+    /// Returns `None` for string enums.
+    pub fn numeric_enum_repr(enum_type_annotation: &EnumTypeAnnotation) -> Option<&'static str> {
+        let values = enum_type_annotation
+            .members
+            .iter()
+            .map(|member| match member.value {
+                EnumMemberValue::Number(value) => Some(value),
+                EnumMemberValue::String(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if values.iter().all(|value| i32::try_from(*value).is_ok()) {
+            Some("i32")
+        } else {
+            Some("i64")
+        }
+    }
+
+    /// Returns the conversions between the numeric enum and the JS number (`f64`) or its backing integer.
+    /// Returns `None` for string enums.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// impl TryFrom<f64> for MyEnum {
+    ///     type Error = anyhow::Error;
+    ///
+    ///     fn try_from(value: f64) -> Result<Self, Self::Error> {
+    ///         if value.fract() != 0.0 {
+    ///             return Err(anyhow::anyhow!("Unknown `MyEnum` value: {}", value));
+    ///         }
+    ///         MyEnum::try_from(value as i64)
+    ///     }
+    /// }
+    ///
+    /// impl From<MyEnum> for i64 {
+    ///     fn from(value: MyEnum) -> Self {
+    ///         i64::from(value.repr)
+    ///     }
+    /// }
+    ///
+    /// impl From<MyEnum> for f64 {
+    ///     fn from(value: MyEnum) -> Self {
+    ///         value.repr as f64
+    ///     }
+    /// }
+    /// ```
+    pub fn enum_number_impls(enum_type_annotation: &EnumTypeAnnotation) -> Option<String> {
+        numeric_enum_repr(enum_type_annotation)?;
+
+        // The backing integer of the cxx shared enum is the `repr` field
+        Some(formatdoc! {
+            r#"
+            impl TryFrom<f64> for {name} {{
+                type Error = anyhow::Error;
+
+                fn try_from(value: f64) -> Result<Self, Self::Error> {{
+                    if value.fract() != 0.0 {{
+                        return Err(anyhow::anyhow!("Unknown `{name}` value: {{}}", value));
+                    }}
+                    {name}::try_from(value as i64)
+                }}
+            }}
+
+            impl From<{name}> for i64 {{
+                fn from(value: {name}) -> Self {{
+                    i64::from(value.repr)
+                }}
+            }}
+
+            impl From<{name}> for f64 {{
+                fn from(value: {name}) -> Self {{
+                    value.repr as f64
+                }}
+            }}"#,
+            name = enum_type_annotation.name,
+        })
+    }
+
     pub fn collect_alias_default_impls(
         id: u64,
         obj: &ObjectTypeAnnotation,
@@ -0,0 +1,203 @@
+use crate::{
+    common::IntoCode,
+    parser::types::{
+        EnumMemberValue, EnumTypeAnnotation, Method, ObjectTypeAnnotation, RefTypeAnnotation,
+        TypeAnnotation,
+    },
+};
+
+#[derive(Debug)]
+pub struct TsType(String);
+
+impl IntoCode for TsType {
+    fn into_code(self) -> String {
+        self.0
+    }
+}
+
+impl TypeAnnotation {
+    /// Converts TypeAnnotation to TypeScript type reconstructed from the schema.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```ts
+    /// boolean               // Boolean
+    /// number                // Number
+    /// bigint                // BigInt
+    /// number[]              // Array<Number>
+    /// MyStruct              // Object
+    /// MyStruct | null       // Nullable<Object>
+    /// (number | null)[]     // Array<Nullable<Number>>
+    /// Record<string, number> // Map<Number>
+    /// [number, string]      // Tuple<Number, String>
+    /// Promise<number>       // Promise<Number>
+    /// ```
+    pub fn as_ts_type(&self) -> TsType {
+        let ts_type = match self {
+            TypeAnnotation::Void => "void".to_string(),
+            TypeAnnotation::Boolean => "boolean".to_string(),
+            TypeAnnotation::Number => "number".to_string(),
+            TypeAnnotation::Int32 => "Int32".to_string(),
+            TypeAnnotation::Int64 => "Int64".to_string(),
+            TypeAnnotation::BigInt => "bigint".to_string(),
+            TypeAnnotation::String => "string".to_string(),
+            TypeAnnotation::ArrayBuffer => "ArrayBuffer".to_string(),
+            TypeAnnotation::Array(element_type) => match &**element_type {
+                TypeAnnotation::Nullable(..) => {
+                    format!("({})[]", element_type.as_ts_type().into_code())
+                }
+                _ => format!("{}[]", element_type.as_ts_type().into_code()),
+            },
+            TypeAnnotation::Object(ObjectTypeAnnotation { name, .. })
+            | TypeAnnotation::Enum(EnumTypeAnnotation { name, .. })
+            | TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => name.clone(),
+            TypeAnnotation::Promise(resolve_type) => {
+                format!("Promise<{}>", resolve_type.as_ts_type().into_code())
+            }
+            TypeAnnotation::Nullable(type_annotation) => {
+                format!("{} | null", type_annotation.as_ts_type().into_code())
+            }
+            TypeAnnotation::Map(value_type) => {
+                format!("Record<string, {}>", value_type.as_ts_type().into_code())
+            }
+            TypeAnnotation::Tuple(element_types) => format!(
+                "[{}]",
+                element_types
+                    .iter()
+                    .map(|t| t.as_ts_type().into_code())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        TsType(ts_type)
+    }
+
+    /// Returns the TypeScript default value of the type for the mocks.
+    ///
+    /// Enum values are asserted as `never` since the enums of the spec may not be exported.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```ts
+    /// false                  // Boolean
+    /// 0                      // Number, Int32, Int64
+    /// 0n                     // BigInt
+    /// ''                     // String
+    /// new ArrayBuffer(0)     // ArrayBuffer
+    /// []                     // Array
+    /// {}                     // Map
+    /// 'foo' as never         // Enum
+    /// { a: '', b: 0 }        // Object
+    /// null                   // Nullable
+    /// [0, '']                // Tuple<Number, String>
+    /// Promise.resolve(0)     // Promise<Number>
+    /// ```
+    pub fn as_ts_default_val(&self) -> Result<String, anyhow::Error> {
+        let default_val = match self {
+            TypeAnnotation::Void => "undefined".to_string(),
+            TypeAnnotation::Boolean => "false".to_string(),
+            TypeAnnotation::Number | TypeAnnotation::Int32 | TypeAnnotation::Int64 => {
+                "0".to_string()
+            }
+            TypeAnnotation::BigInt => "0n".to_string(),
+            TypeAnnotation::String => "''".to_string(),
+            TypeAnnotation::ArrayBuffer => "new ArrayBuffer(0)".to_string(),
+            TypeAnnotation::Array(..) => "[]".to_string(),
+            TypeAnnotation::Map(..) => "{}".to_string(),
+            TypeAnnotation::Enum(EnumTypeAnnotation { members, .. }) => {
+                let first_member = members
+                    .first()
+                    .ok_or(anyhow::anyhow!("Enum should have at least one member"))?;
+
+                format!("{} as never", first_member.value.as_ts_literal())
+            }
+            TypeAnnotation::Object(ObjectTypeAnnotation { props, .. }) => {
+                if props.is_empty() {
+                    "{}".to_string()
+                } else {
+                    let props = props
+                        .iter()
+                        .map(|prop| {
+                            Ok(format!(
+                                "{}: {}",
+                                prop.name,
+                                prop.type_annotation.as_ts_default_val()?
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+                    format!("{{ {} }}", props.join(", "))
+                }
+            }
+            TypeAnnotation::Nullable(..) => "null".to_string(),
+            TypeAnnotation::Tuple(element_types) => format!(
+                "[{}]",
+                element_types
+                    .iter()
+                    .map(|t| t.as_ts_default_val())
+                    .collect::<Result<Vec<_>, anyhow::Error>>()?
+                    .join(", ")
+            ),
+            TypeAnnotation::Promise(resolve_type) => match &**resolve_type {
+                TypeAnnotation::Void => "Promise.resolve()".to_string(),
+                _ => format!("Promise.resolve({})", resolve_type.as_ts_default_val()?),
+            },
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "[as_ts_default_val] Unsupported type annotation: {:?}",
+                    self
+                ))
+            }
+        };
+
+        Ok(default_val)
+    }
+}
+
+impl EnumMemberValue {
+    /// Converts the enum member value to the TypeScript literal (eg. `'foo'`, `1`).
+    pub fn as_ts_literal(&self) -> String {
+        match self {
+            EnumMemberValue::String(value) => ts_string(value),
+            EnumMemberValue::Number(value) => value.to_string(),
+        }
+    }
+}
+
+/// Returns the single-quoted TypeScript string literal of the value.
+fn ts_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+impl Method {
+    /// Converts Method to TypeScript method signature.
+    ///
+    /// # Generated Code
+    ///
+    /// ```ts
+    /// multiply(a: number, b: number): number;
+    /// getItem(id: string): Promise<Item | null>;
+    /// ```
+    pub fn as_ts_sig(&self) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|param| {
+                format!(
+                    "{}: {}",
+                    param.name,
+                    param.type_annotation.as_ts_type().into_code()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "{}({}): {};",
+            self.name,
+            params,
+            self.ret_type.as_ts_type().into_code()
+        )
+    }
+}
@@ -0,0 +1,292 @@
+//! Compile smoke tests of the generated C++ code.
+//!
+//! Requires a C++ toolchain (`c++`), so it is only enabled with the `cxx-compile-test` feature.
+//!
+//! ```sh
+//! cargo test -p craby_codegen --features cxx-compile-test
+//! ```
+use std::{fs, path::Path, process::Command};
+
+use crate::{
+    generators::{
+        cxx_generator::CxxGenerator,
+        types::{Generator, TemplateResult},
+    },
+    parser::native_spec_parser::try_parse_schema,
+    tests::get_codegen_context,
+    types::CodegenContext,
+};
+
+/// Minimal declarations of the JSI, React Native and cxx headers used by the generated code.
+const STUBS: &[(&str, &str)] = &[
+    ("cxx.h", include_str!("cxx_stubs/cxx.h")),
+    ("rust/cxx.h", "#pragma once\n#include \"../cxx.h\"\n"),
+    ("jsi/jsi.h", include_str!("cxx_stubs/jsi/jsi.h")),
+    (
+        "ReactCommon/CallInvoker.h",
+        include_str!("cxx_stubs/ReactCommon/CallInvoker.h"),
+    ),
+    (
+        "ReactCommon/TurboModule.h",
+        include_str!("cxx_stubs/ReactCommon/TurboModule.h"),
+    ),
+    (
+        "react/bridging/Bridging.h",
+        include_str!("cxx_stubs/react/bridging/Bridging.h"),
+    ),
+];
+
+/// Compiles the generated `bridging-generated.hpp`, `CrabyUtils.hpp` and module headers
+/// with the stub headers and the given `ffi.rs.h` (declarations of the Rust bridge).
+///
+/// With `sources`, the module sources (`Cxx*.cpp`) are compiled as well,
+/// so `ffi.rs.h` must declare every bridge function called by the modules.
+fn assert_cxx_compiles(ctx: &CodegenContext, ffi_h: &str, sources: bool) {
+    let dir = std::env::temp_dir().join(format!("craby-{}", uuid::Uuid::new_v4()));
+    let res = CxxGenerator::new().generate(ctx).unwrap();
+
+    for (path, content) in STUBS {
+        write_file(&dir.join(path), content);
+    }
+
+    write_file(&dir.join("ffi.rs.h"), ffi_h);
+
+    let mut includes = vec![];
+
+    for TemplateResult { path, content, .. } in &res {
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        let is_target = file_name == "bridging-generated.hpp"
+            || file_name == "CrabyUtils.hpp"
+            || file_name == "CrabySignals.h"
+            || (file_name.starts_with("Cxx") && file_name.ends_with(".hpp"))
+            || (sources && file_name.starts_with("Cxx") && file_name.ends_with(".cpp"));
+
+        if is_target {
+            write_file(&dir.join(&file_name), content);
+            includes.push(format!("#include \"{file_name}\""));
+        }
+    }
+
+    includes.sort();
+    write_file(&dir.join("main.cpp"), &format!("{}\n", includes.join("\n")));
+
+    let output = Command::new("c++")
+        .args(["-std=c++20", "-fsyntax-only", "-I"])
+        .arg(&dir)
+        .arg(dir.join("main.cpp"))
+        .output()
+        .expect("C++ compiler (`c++`) is required");
+
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn write_file(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn test_cxx_compile() {
+    let ctx = get_codegen_context();
+    let ffi_h = "
+#pragma once
+
+#include \"cxx.h\"
+#include <cstdint>
+
+namespace craby {
+namespace testmodule {
+namespace bridging {
+
+struct CrabyTest;
+struct CrabyTestSignal;
+
+enum class MyEnum : uint8_t { Foo, Bar, Baz };
+enum class SwitchState : uint8_t { Off, On };
+
+struct NullableString {
+  bool null;
+  rust::String val;
+};
+
+struct NullableNumber {
+  bool null;
+  double val;
+};
+
+struct SubObject {
+  NullableString a;
+  double b;
+  bool c;
+};
+
+struct NullableSubObject {
+  bool null;
+  SubObject val;
+};
+
+struct TestObject {
+  rust::String foo;
+  double bar;
+  bool baz;
+  NullableSubObject sub;
+  double camel_case;
+  double pascal_case;
+  double snake_case;
+};
+
+} // namespace bridging
+} // namespace testmodule
+} // namespace craby
+";
+
+    assert_cxx_compiles(&ctx, ffi_h, false);
+}
+
+#[test]
+fn test_cxx_compile_signals() {
+    let mut ctx = get_codegen_context();
+    ctx.schemas = try_parse_schema(
+        "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            onSignal: Signal;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+        ",
+    )
+    .unwrap();
+    let ffi_h = "
+#pragma once
+
+#include \"cxx.h\"
+#include \"CrabySignals.h\"
+#include <cstddef>
+
+namespace craby {
+namespace testmodule {
+namespace bridging {
+
+struct CrabyTest;
+struct CrabyTestSignal;
+
+rust::Box<CrabyTest> createCrabyTest(size_t id, rust::Str data_path) noexcept;
+void drop_craby_test_signal(CrabyTestSignal *signal) noexcept;
+
+} // namespace bridging
+} // namespace testmodule
+} // namespace craby
+";
+
+    assert_cxx_compiles(&ctx, ffi_h, true);
+}
+
+#[test]
+fn test_cxx_compile_throws() {
+    let mut ctx = get_codegen_context();
+    ctx.schemas = try_parse_schema(
+        "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type FetchError = {
+            code: number;
+            reason: string;
+        };
+
+        export interface Spec extends NativeModule {
+            /** @throws FetchError */
+            fetch(url: string): Promise<string>;
+            /** @throws FetchError */
+            ping(): Promise<void>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+        ",
+    )
+    .unwrap();
+    let ffi_h = "
+#pragma once
+
+#include \"cxx.h\"
+#include <cstddef>
+
+namespace craby {
+namespace testmodule {
+namespace bridging {
+
+struct CrabyTest;
+struct CrabyTestSignal;
+
+struct FetchError {
+  double code;
+  rust::String reason;
+};
+
+struct NullableFetchError {
+  bool null;
+  FetchError val;
+};
+
+rust::Box<CrabyTest> createCrabyTest(size_t id, rust::Str data_path) noexcept;
+void drop_craby_test_signal(CrabyTestSignal *signal) noexcept;
+rust::String fetch(CrabyTest &it_, rust::Str url, NullableFetchError &error_);
+void ping(CrabyTest &it_, NullableFetchError &error_);
+
+} // namespace bridging
+} // namespace testmodule
+} // namespace craby
+";
+
+    assert_cxx_compiles(&ctx, ffi_h, true);
+}
+
+#[test]
+fn test_cxx_compile_default_params() {
+    let mut ctx = get_codegen_context();
+    ctx.schemas = try_parse_schema(
+        "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            multiply(a: number, b: number = 2, label: string = 'x', round: boolean = false): number;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+        ",
+    )
+    .unwrap();
+    let ffi_h = "
+#pragma once
+
+#include \"cxx.h\"
+#include <cstddef>
+
+namespace craby {
+namespace testmodule {
+namespace bridging {
+
+struct CrabyTest;
+struct CrabyTestSignal;
+
+rust::Box<CrabyTest> createCrabyTest(size_t id, rust::Str data_path) noexcept;
+void drop_craby_test_signal(CrabyTestSignal *signal) noexcept;
+double multiply(CrabyTest &it_, double a, double b, rust::Str label, bool round);
+
+} // namespace bridging
+} // namespace testmodule
+} // namespace craby
+";
+
+    assert_cxx_compiles(&ctx, ffi_h, true);
+}
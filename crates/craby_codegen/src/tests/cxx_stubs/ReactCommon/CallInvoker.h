@@ -0,0 +1,20 @@
+// Stub of `ReactCommon/CallInvoker.h` for the compile smoke tests (declarations only)
+#pragma once
+
+#include <functional>
+#include <jsi/jsi.h>
+
+namespace facebook {
+namespace react {
+
+using CallFunc = std::function<void(jsi::Runtime &)>;
+
+class CallInvoker {
+public:
+  virtual ~CallInvoker();
+  virtual void invokeAsync(CallFunc &&func) noexcept = 0;
+  virtual void invokeSync(CallFunc &&func) = 0;
+};
+
+} // namespace react
+} // namespace facebook
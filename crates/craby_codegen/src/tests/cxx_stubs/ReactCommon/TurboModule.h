@@ -0,0 +1,35 @@
+// Stub of `ReactCommon/TurboModule.h` for the compile smoke tests (declarations only)
+#pragma once
+
+#include <ReactCommon/CallInvoker.h>
+#include <jsi/jsi.h>
+#include <memory>
+#include <string>
+#include <unordered_map>
+
+namespace facebook {
+namespace react {
+
+class JSI_EXPORT TurboModule {
+public:
+  TurboModule(std::string name, std::shared_ptr<CallInvoker> jsInvoker);
+  virtual ~TurboModule();
+
+  const std::string name_;
+  std::shared_ptr<CallInvoker> jsInvoker_;
+
+protected:
+  struct MethodMetadata {
+    size_t argCount;
+    jsi::Value (*invoker)(
+        jsi::Runtime &rt,
+        TurboModule &turboModule,
+        const jsi::Value *args,
+        size_t count);
+  };
+
+  std::unordered_map<std::string, MethodMetadata> methodMap_;
+};
+
+} // namespace react
+} // namespace facebook
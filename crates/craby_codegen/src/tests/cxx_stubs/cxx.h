@@ -0,0 +1,118 @@
+// Stub of `rust/cxx.h` for the compile smoke tests (declarations only)
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <string>
+
+namespace rust {
+
+class Str final {
+public:
+  Str() noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+};
+
+class String final {
+public:
+  String() noexcept;
+  String(const String &);
+  String(String &&) noexcept;
+  String(const std::string &);
+  String(const char *);
+  String(const char *, std::size_t);
+  ~String() noexcept;
+
+  String &operator=(const String &);
+  String &operator=(String &&) noexcept;
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  const char *c_str() noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+};
+
+template <typename T> class Slice final {
+public:
+  Slice() noexcept;
+  Slice(T *, std::size_t count) noexcept;
+
+  T *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  T &operator[](std::size_t n) const noexcept;
+};
+
+template <typename T> class Vec final {
+public:
+  Vec() noexcept;
+  Vec(const Vec &);
+  Vec(Vec &&) noexcept;
+  ~Vec() noexcept;
+
+  Vec &operator=(const Vec &);
+  Vec &operator=(Vec &&) noexcept;
+
+  std::size_t size() const noexcept;
+  bool empty() const noexcept;
+  const T *data() const noexcept;
+  T *data() noexcept;
+  std::size_t capacity() const noexcept;
+
+  const T &operator[](std::size_t n) const noexcept;
+  T &operator[](std::size_t n) noexcept;
+
+  void reserve(std::size_t new_cap);
+  void push_back(const T &value);
+  void push_back(T &&value);
+  template <typename... Args> void emplace_back(Args &&...args);
+  void truncate(std::size_t len);
+  void clear();
+
+  const T *begin() const noexcept;
+  const T *end() const noexcept;
+  T *begin() noexcept;
+  T *end() noexcept;
+};
+
+template <typename T> class Box final {
+public:
+  Box(Box &&) noexcept;
+  ~Box() noexcept;
+
+  Box &operator=(Box &&) & noexcept;
+
+  const T *operator->() const noexcept;
+  const T &operator*() const noexcept;
+  T *operator->() noexcept;
+  T &operator*() noexcept;
+
+  static Box from_raw(T *) noexcept;
+  T *into_raw() noexcept;
+};
+
+class Error final : public std::exception {
+public:
+  Error(const Error &);
+  Error(Error &&) noexcept;
+  ~Error() noexcept override;
+
+  const char *what() const noexcept override;
+};
+
+} // namespace rust
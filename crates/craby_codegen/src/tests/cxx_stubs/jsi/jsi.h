@@ -0,0 +1,182 @@
+// Stub of `jsi/jsi.h` for the compile smoke tests (declarations only)
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <functional>
+#include <memory>
+#include <string>
+#include <utility>
+
+#ifndef JSI_EXPORT
+#define JSI_EXPORT
+#endif
+
+namespace facebook {
+namespace jsi {
+
+class Runtime;
+class Value;
+class String;
+class Object;
+class Array;
+class ArrayBuffer;
+class Function;
+class PropNameID;
+
+using HostFunctionType =
+    std::function<Value(Runtime &rt, const Value &thisVal, const Value *args, size_t count)>;
+
+class Buffer {
+public:
+  virtual ~Buffer();
+  virtual size_t size() const = 0;
+  virtual const uint8_t *data() const = 0;
+};
+
+class MutableBuffer {
+public:
+  virtual ~MutableBuffer();
+  virtual size_t size() const = 0;
+  virtual uint8_t *data() = 0;
+};
+
+class Runtime {
+public:
+  virtual ~Runtime();
+
+  Object global();
+};
+
+class Pointer {
+public:
+  Pointer(Pointer &&) noexcept;
+  ~Pointer();
+  Pointer &operator=(Pointer &&) noexcept;
+};
+
+class PropNameID : public Pointer {
+public:
+  static PropNameID forAscii(Runtime &runtime, const char *str);
+  static PropNameID forUtf8(Runtime &runtime, const std::string &utf8);
+};
+
+class String : public Pointer {
+public:
+  static String createFromAscii(Runtime &runtime, const char *str, size_t length);
+  static String createFromAscii(Runtime &runtime, const std::string &str);
+  static String createFromUtf8(Runtime &runtime, const uint8_t *utf8, size_t length);
+  static String createFromUtf8(Runtime &runtime, const std::string &utf8);
+
+  std::string utf8(Runtime &runtime) const;
+};
+
+class Object : public Pointer {
+public:
+  Object(Runtime &runtime);
+
+  bool isArray(Runtime &runtime) const;
+  bool isArrayBuffer(Runtime &runtime) const;
+  bool isFunction(Runtime &runtime) const;
+
+  Array getArray(Runtime &runtime) const &;
+  Array asArray(Runtime &runtime) const &;
+  ArrayBuffer getArrayBuffer(Runtime &runtime) const &;
+  Function getFunction(Runtime &runtime) const &;
+  Function asFunction(Runtime &runtime) const &;
+
+  bool hasProperty(Runtime &runtime, const char *name) const;
+  Value getProperty(Runtime &runtime, const char *name) const;
+  Object getPropertyAsObject(Runtime &runtime, const char *name) const;
+  Function getPropertyAsFunction(Runtime &runtime, const char *name) const;
+  Array getPropertyNames(Runtime &runtime) const;
+
+  template <typename T> void setProperty(Runtime &runtime, const char *name, T &&value) const;
+  template <typename T> void setProperty(Runtime &runtime, const String &name, T &&value) const;
+};
+
+class Array : public Object {
+public:
+  Array(Runtime &runtime, size_t length);
+
+  size_t size(Runtime &runtime) const;
+  size_t length(Runtime &runtime) const;
+  Value getValueAtIndex(Runtime &runtime, size_t i) const;
+
+  template <typename T> void setValueAtIndex(Runtime &runtime, size_t i, T &&value) const;
+};
+
+class ArrayBuffer : public Object {
+public:
+  ArrayBuffer(Runtime &runtime, std::shared_ptr<MutableBuffer> buffer);
+
+  size_t size(Runtime &runtime) const;
+  size_t length(Runtime &runtime) const;
+  uint8_t *data(Runtime &runtime) const;
+};
+
+class Function : public Object {
+public:
+  static Function createFromHostFunction(
+      Runtime &runtime,
+      const PropNameID &name,
+      unsigned int paramCount,
+      HostFunctionType func);
+
+  template <typename... Args> Value call(Runtime &runtime, Args &&...args) const;
+  template <typename... Args> Value callAsConstructor(Runtime &runtime, Args &&...args) const;
+};
+
+class Value {
+public:
+  Value() noexcept;
+  Value(bool b);
+  Value(double d);
+  Value(int i);
+  Value(String &&str);
+  Value(Object &&obj);
+  Value(Runtime &runtime, const Value &value);
+  Value(Runtime &runtime, const String &str);
+  Value(Runtime &runtime, const Object &obj);
+  Value(Value &&other) noexcept;
+  ~Value();
+
+  Value &operator=(Value &&other) noexcept;
+
+  static Value undefined();
+  static Value null();
+
+  bool isUndefined() const;
+  bool isNull() const;
+  bool isBool() const;
+  bool isNumber() const;
+  bool isString() const;
+  bool isBigInt() const;
+  bool isObject() const;
+
+  bool getBool() const;
+  bool asBool() const;
+  double getNumber() const;
+  double asNumber() const;
+
+  String getString(Runtime &runtime) const &;
+  String asString(Runtime &runtime) const &;
+  Object getObject(Runtime &runtime) const &;
+  Object asObject(Runtime &runtime) const &;
+
+  String toString(Runtime &runtime) const;
+};
+
+class JSError : public std::exception {
+public:
+  JSError(Runtime &runtime, std::string message);
+  JSError(Runtime &runtime, const char *message);
+
+  const std::string &getMessage() const;
+  Value &value();
+  const char *what() const noexcept override;
+};
+
+} // namespace jsi
+} // namespace facebook
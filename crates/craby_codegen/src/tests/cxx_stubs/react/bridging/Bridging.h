@@ -0,0 +1,43 @@
+// Stub of `react/bridging/Bridging.h` for the compile smoke tests (declarations only)
+#pragma once
+
+#include <ReactCommon/CallInvoker.h>
+#include <jsi/jsi.h>
+#include <memory>
+#include <string>
+
+namespace facebook {
+namespace react {
+
+template <typename T, typename = void> struct Bridging;
+
+namespace bridging {
+
+template <typename T>
+T fromJs(jsi::Runtime &rt, const jsi::Value &value, const std::shared_ptr<CallInvoker> &jsInvoker);
+
+template <typename T> jsi::Value toJs(jsi::Runtime &rt, const T &value);
+
+template <typename T>
+jsi::Value toJs(jsi::Runtime &rt, const T &value, const std::shared_ptr<CallInvoker> &jsInvoker);
+
+} // namespace bridging
+
+template <typename T> class AsyncPromise {
+public:
+  AsyncPromise(jsi::Runtime &rt, const std::shared_ptr<CallInvoker> &jsInvoker);
+
+  void resolve(T value);
+  void reject(std::string message);
+};
+
+template <> class AsyncPromise<void> {
+public:
+  AsyncPromise(jsi::Runtime &rt, const std::shared_ptr<CallInvoker> &jsInvoker);
+
+  void resolve();
+  void reject(std::string message);
+};
+
+} // namespace react
+} // namespace facebook
@@ -1,6 +1,12 @@
+#[cfg(feature = "cxx-compile-test")]
+mod cxx_compile;
+
 use std::path::PathBuf;
 
-use crate::{parser::native_spec_parser::try_parse_schema, types::CodegenContext};
+use crate::{
+    constants::DEFAULT_THREAD_POOL_SIZE, parser::native_spec_parser::try_parse_schema,
+    types::CodegenContext,
+};
 
 pub fn get_codegen_context() -> CodegenContext {
     let schemas = try_parse_schema(
@@ -60,8 +66,26 @@ pub fn get_codegen_context() -> CodegenContext {
 
     CodegenContext {
         project_name: "test_module".to_string(),
+        cxx_namespace: "testmodule".to_string(),
         root: PathBuf::from("."),
+        source_dir: PathBuf::from("./src"),
         schemas,
         android_package_name: "rs.craby.testmodule".to_string(),
+        android_page_size_16kb: true,
+        stub_defaults: false,
+        c_shim: false,
+        listener_error_hook: false,
+        swift_header: false,
+        module_registry: false,
+        lazy_registration: false,
+        unimplemented_error: false,
+        docs: false,
+        kotlin_stubs: false,
+        dts: false,
+        mocks: false,
+        cxx_base_class: None,
+        instance_counter: false,
+        module_features: false,
+        thread_pool_size: DEFAULT_THREAD_POOL_SIZE,
     }
 }
@@ -1,16 +1,58 @@
-use std::{fmt::Display, hash::Hasher, path::PathBuf};
+use std::{
+    fmt::Display,
+    hash::Hasher,
+    path::{Path, PathBuf},
+};
 
 use crate::parser::types::{Method, Signal, TypeAnnotation};
-use craby_common::utils::string::{flat_case, pascal_case};
+use craby_common::{
+    config::CxxBaseClassConfig, constants::SPEC_FILE_PREFIX, utils::string::pascal_case,
+};
 use log::debug;
 use serde::Serialize;
 use xxhash_rust::xxh3::Xxh3;
 
 pub struct CodegenContext {
     pub project_name: String,
+    /// Base name of the C++ namespace (`craby::<cxx_namespace>`)
+    pub cxx_namespace: String,
     pub root: PathBuf,
+    /// Source directory of the spec files
+    pub source_dir: PathBuf,
     pub schemas: Vec<Schema>,
     pub android_package_name: String,
+    /// Link the Android shared library with 16KB page alignment
+    pub android_page_size_16kb: bool,
+    /// Generate default-returning method stubs instead of `unimplemented!()`
+    pub stub_defaults: bool,
+    /// Generate `extern "C"` shim of the module methods
+    pub c_shim: bool,
+    /// Report exceptions thrown by the signal listeners to the Rust module
+    pub listener_error_hook: bool,
+    /// Generate the Swift-friendly header of the iOS module provider
+    pub swift_header: bool,
+    /// Generate the shared module registry and register the modules through it
+    pub module_registry: bool,
+    /// Defer the expensive part of the module registration until the first access
+    pub lazy_registration: bool,
+    /// Throw a descriptive error naming the method and platform for the unimplemented methods
+    pub unimplemented_error: bool,
+    /// Generate the markdown usage snippet per module
+    pub docs: bool,
+    /// Generate the Kotlin object with the typed method stubs per module
+    pub kotlin_stubs: bool,
+    /// Generate the TypeScript declarations re-exporting the specs with the module names
+    pub dts: bool,
+    /// Generate the JS mocks of the modules for the unit tests
+    pub mocks: bool,
+    /// Base class of the C++ modules (Default: `facebook::react::TurboModule`)
+    pub cxx_base_class: Option<CxxBaseClassConfig>,
+    /// Count the live instances of the C++ modules (debug)
+    pub instance_counter: bool,
+    /// Gate the generated code of each module behind a cargo feature named after the module
+    pub module_features: bool,
+    /// Number of worker threads of the C++ modules (`0` is treated as `1`)
+    pub thread_pool_size: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -22,6 +64,9 @@ pub struct Schema {
     pub enums: Vec<TypeAnnotation>,
     pub methods: Vec<Method>,
     pub signals: Vec<Signal>,
+    /// Spec file the module is declared in (not included in the hash)
+    #[serde(skip)]
+    pub spec_file: Option<PathBuf>,
 }
 
 impl Schema {
@@ -32,6 +77,93 @@ impl Schema {
         hasher.write(serialized.as_bytes());
         format!("{:016x}", hasher.finish())
     }
+
+    /// Returns the path of the spec file the module is declared in.
+    ///
+    /// Falls back to `{source_dir}/Native{ModuleName}.ts` if the schema is not parsed from a file.
+    pub fn spec_path(&self, source_dir: &Path) -> PathBuf {
+        match &self.spec_file {
+            Some(spec_file) => spec_file.clone(),
+            None => source_dir.join(format!("{SPEC_FILE_PREFIX}{}.ts", self.module_name)),
+        }
+    }
+
+    /// Returns every type annotation of the schema that crosses the FFI boundary:
+    /// the method parameters and return types, the props of the alias types and the signal payloads.
+    ///
+    /// Nested types are not expanded (eg. `number[][]` is returned as is), so the callers pick
+    /// the types they need with the helpers of [`TypeAnnotation`] (eg. `as_map`, `nested_arrays`, `tuples`).
+    pub fn type_annotations(&self) -> impl Iterator<Item = &TypeAnnotation> {
+        self.methods
+            .iter()
+            .flat_map(|method| {
+                method
+                    .params
+                    .iter()
+                    .map(|param| &param.type_annotation)
+                    .chain(std::iter::once(&method.ret_type))
+            })
+            .chain(self.aliases.iter().flat_map(|type_annotation| {
+                type_annotation
+                    .as_object()
+                    .into_iter()
+                    .flat_map(|obj| obj.props.iter().map(|prop| &prop.type_annotation))
+            }))
+            .chain(
+                self.signals
+                    .iter()
+                    .filter_map(|signal| signal.payload_type.as_ref()),
+            )
+    }
+
+    /// Returns the helper structs generated for the types of the schema with their names
+    /// (eg. `NullableNumber`, `NumberMapEntry`, `NestedNumberArray`, `NumberStringTuple2`).
+    pub fn helper_types(&self) -> Result<Vec<(String, TypeAnnotation)>, anyhow::Error> {
+        let error_types = self
+            .methods
+            .iter()
+            .filter_map(Method::nullable_error_type)
+            .collect::<Vec<_>>();
+        let mut helper_types = vec![];
+
+        for type_annotation in self.type_annotations().chain(&error_types) {
+            if type_annotation.is_nullable() {
+                helper_types.push((type_annotation.nullable_name()?, type_annotation.clone()));
+            }
+
+            if let Some(map_type) = type_annotation.as_map() {
+                helper_types.push((map_type.map_entry_name()?, map_type.clone()));
+            }
+
+            for nested_array in type_annotation.nested_arrays() {
+                helper_types.push((nested_array.nested_array_name()?, nested_array.clone()));
+            }
+
+            for tuple in type_annotation.tuples() {
+                helper_types.push((tuple.tuple_name()?, tuple.clone()));
+            }
+        }
+
+        Ok(helper_types)
+    }
+
+    /// Returns `true` if the schema has `@cancellable` Promise methods.
+    pub fn has_cancellable_methods(&self) -> bool {
+        self.methods.iter().any(|method| method.cancellable)
+    }
+}
+
+/// Number of the FFI items generated for a module.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FfiSurface {
+    /// Extern functions (including the module constructor)
+    pub extern_funcs: usize,
+    /// Struct definitions of the alias types
+    pub structs: usize,
+    /// Enum definitions
+    pub enums: usize,
+    /// Struct definitions of the nullable types (eg. `NullableNumber`)
+    pub nullables: usize,
 }
 
 /// Represents the C++ base namespace for the Craby project.
@@ -43,7 +175,7 @@ where
     T: AsRef<str>,
 {
     fn from(value: T) -> Self {
-        CxxNamespace(format!("craby::{}", flat_case(value.as_ref())))
+        CxxNamespace(format!("craby::{}", value.as_ref()))
     }
 }
 
@@ -1,7 +1,17 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use craby_common::{
+    config::{IndentConfig, IndentStyle},
+    constants::{module_feature_macro, module_feature_name},
+};
 
 use crate::{
     common::IntoCode,
+    constants::GENERATED_COMMENT,
     parser::types::{EnumTypeAnnotation, ObjectTypeAnnotation, TypeAnnotation},
     types::Schema,
 };
@@ -20,6 +30,181 @@ pub fn indent_str(str: &str, indent_size: usize) -> String {
         .join("\n")
 }
 
+/// Re-indents the code from `base_size` spaces per level to the configured indentation.
+///
+/// Remaining spaces that do not fill a whole level (eg. alignment of continuation lines) are kept as is.
+pub fn reindent_str(str: &str, base_size: usize, indent: &IndentConfig) -> String {
+    let level_str = match indent.style {
+        IndentStyle::Space => " ".repeat(indent.width.unwrap_or(base_size)),
+        IndentStyle::Tab => "\t".repeat(indent.width.unwrap_or(1)),
+    };
+
+    str.split('\n')
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let spaces = line.len() - content.len();
+
+            if content.is_empty() {
+                line.to_string()
+            } else {
+                format!(
+                    "{}{}{content}",
+                    level_str.repeat(spaces / base_size),
+                    " ".repeat(spaces % base_size),
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the indentation size of the generated code for the given file.
+///
+/// Rust code is indented with 4 spaces and the others (C++, Objective-C++, Kotlin, CMake, Gradle) with 2 spaces.
+pub fn base_indent_size(path: &Path) -> Option<usize> {
+    match path.extension()?.to_str()? {
+        "rs" => Some(4),
+        "cpp" | "hpp" | "h" | "mm" | "kt" | "txt" | "gradle" => Some(2),
+        _ => None,
+    }
+}
+
+/// Renders a Makefile-style depfile that lists the generated outputs as targets of the spec inputs.
+///
+/// ```makefile
+/// crates/lib/src/ffi.rs \
+/// cpp/CxxMyModule.cpp: \
+///   src/NativeMyModule.ts
+/// ```
+pub fn depfile_str(outputs: &[PathBuf], inputs: &[PathBuf]) -> String {
+    let escape = |path: &PathBuf| path.to_string_lossy().replace(' ', "\\ ");
+    let targets = outputs.iter().map(escape).collect::<Vec<_>>().join(" \\\n");
+    let deps = inputs
+        .iter()
+        .map(|path| format!(" \\\n  {}", escape(path)))
+        .collect::<String>();
+
+    format!("{targets}:{deps}\n")
+}
+
+/// Returns the import specifier of the TypeScript file relative to the directory of the importing file.
+///
+/// ```ts
+/// import Calculator from '../src/NativeCalculator';
+/// ```
+pub fn ts_import_path(from_dir: &Path, file: &Path) -> String {
+    let normal_components = |path: &Path| {
+        path.components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+    };
+    let from = normal_components(from_dir);
+    let to = normal_components(&file.with_extension(""));
+    let common = from
+        .iter()
+        .zip(&to)
+        .take_while(|(from, to)| from == to)
+        .count();
+
+    let mut segments = vec!["..".to_string(); from.len() - common];
+    if segments.is_empty() {
+        segments.push(".".to_string());
+    }
+    segments.extend(to[common..].iter().cloned());
+    segments.join("/")
+}
+
+/// Returns `true` if the file does not exist yet or it was generated by Craby,
+/// so the hand-written files at the same path are not overwritten.
+pub fn is_generated_file(path: &Path) -> Result<bool, anyhow::Error> {
+    if !path.try_exists()? {
+        return Ok(true);
+    }
+
+    let content = fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .next()
+        .is_some_and(|line| line.contains(GENERATED_COMMENT)))
+}
+
+/// Returns the `cfg` attribute that gates the items behind the cargo features of the modules.
+///
+/// ```rust,ignore
+/// #[cfg(feature = "my_module")]
+/// #[cfg(any(feature = "my_module", feature = "other_module"))]
+/// ```
+pub fn rs_feature_cfg<S: AsRef<str>>(module_names: &[S]) -> String {
+    let features = module_names
+        .iter()
+        .map(|name| format!("feature = \"{}\"", module_feature_name(name.as_ref())))
+        .collect::<Vec<_>>();
+
+    match features.as_slice() {
+        [feature] => format!("#[cfg({feature})]"),
+        _ => format!("#[cfg(any({}))]", features.join(", ")),
+    }
+}
+
+/// Prepends the attribute to each top-level item of the Rust code.
+///
+/// Items are expected to be separated by blank lines. The attribute is placed after the doc comments
+/// of the item, and plain comments (`// ...`) are left as is.
+pub fn attr_items(code: &str, attr: &str) -> String {
+    let mut prev_blank = true;
+    let mut in_doc = false;
+    code.lines()
+        .map(|line| {
+            if line.starts_with("//") && !line.starts_with("///") {
+                return line.to_string();
+            }
+
+            let is_item_start = (prev_blank || in_doc)
+                && !line.is_empty()
+                && !line.starts_with(char::is_whitespace)
+                && !line.starts_with(['}', ')', ']']);
+            prev_blank = line.trim().is_empty();
+            in_doc = is_item_start && line.starts_with("///");
+
+            if is_item_start && !in_doc {
+                format!("{attr}\n{line}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps the C++ code with the guard of the module feature (see `CrabyFeatures.h`).
+///
+/// ```cpp
+/// #if defined(CRABY_FEATURE_MY_MODULE)
+/// // ...
+/// #endif
+/// ```
+pub fn cxx_feature_guard(code: &str, module_name: &str) -> String {
+    format!(
+        "#if defined({})\n{code}\n#endif",
+        module_feature_macro(module_name)
+    )
+}
+
+/// Renders the description lines as doc comments followed by a line break, or an empty string if there are none.
+///
+/// ```rust,ignore
+/// /// Multiplies two numbers.
+/// ```
+pub fn doc_comment(docs: &[String]) -> String {
+    docs.iter()
+        .map(|line| match line.is_empty() {
+            true => "///\n".to_string(),
+            false => format!("/// {line}\n"),
+        })
+        .collect()
+}
+
 pub fn calc_deps_order(schema: &Schema) -> Result<Vec<String>, anyhow::Error> {
     let mut dependencies = BTreeMap::new();
     let mut visited = BTreeSet::new();
@@ -70,14 +255,84 @@ pub fn calc_deps_order(schema: &Schema) -> Result<Vec<String>, anyhow::Error> {
                                 .unwrap()
                                 .push(enum_name.clone());
                         }
+                        tuple @ TypeAnnotation::Tuple(..) => {
+                            if let Some(dep) = element_dep(tuple, &mut dependencies)? {
+                                dependencies.get_mut(&rs_type).unwrap().push(dep);
+                            }
+                        }
                         _ => (),
                     }
                 }
+                tuple @ TypeAnnotation::Tuple(..) => {
+                    if let Some(dep) = element_dep(tuple, &mut dependencies)? {
+                        dependencies.get_mut(&alias_spec.name).unwrap().push(dep);
+                    }
+                }
+                TypeAnnotation::Array(element_type) => {
+                    if let Some(dep) = element_dep(element_type, &mut dependencies)? {
+                        dependencies.get_mut(&alias_spec.name).unwrap().push(dep);
+                    }
+                }
+                map @ TypeAnnotation::Map(value_type) => {
+                    // Key/value pair structs are ordered by their names (eg. `PointMapEntry`)
+                    let entry_name = map.map_entry_name()?;
+                    let value_type = match &**value_type {
+                        TypeAnnotation::Array(element_type) => element_type,
+                        value_type => value_type,
+                    };
+                    let deps = element_dep(value_type, &mut dependencies)?
+                        .into_iter()
+                        .collect();
+
+                    dependencies.entry(entry_name.clone()).or_insert(deps);
+                    dependencies
+                        .get_mut(&alias_spec.name)
+                        .unwrap()
+                        .push(entry_name);
+                }
                 _ => (),
             }
         }
     }
 
+    /// Returns the dependency of the array element (or map value).
+    ///
+    /// Nested arrays and tuples are wrapped with the structs that are ordered by their names (eg. `NestedPointArray`)
+    fn element_dep(
+        element_type: &TypeAnnotation,
+        dependencies: &mut BTreeMap<String, Vec<String>>,
+    ) -> Result<Option<String>, anyhow::Error> {
+        let dep = match element_type {
+            TypeAnnotation::Object(ObjectTypeAnnotation { name, .. })
+            | TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => Some(name.clone()),
+            TypeAnnotation::Array(inner_type) => {
+                let nested_name = element_type.nested_array_name()?;
+                let deps = element_dep(inner_type, dependencies)?.into_iter().collect();
+
+                dependencies.entry(nested_name.clone()).or_insert(deps);
+                Some(nested_name)
+            }
+            TypeAnnotation::Tuple(element_types) => {
+                let tuple_name = element_type.tuple_name()?;
+                let mut deps = vec![];
+                for element_type in element_types {
+                    // Arrays in the tuples are not nested arrays (eg. `[Point[], number]`)
+                    let element_type = match element_type {
+                        TypeAnnotation::Array(inner_type) => inner_type,
+                        element_type => element_type,
+                    };
+                    deps.extend(element_dep(element_type, dependencies)?);
+                }
+
+                dependencies.entry(tuple_name.clone()).or_insert(deps);
+                Some(tuple_name)
+            }
+            _ => None,
+        };
+
+        Ok(dep)
+    }
+
     fn visit(
         node: &str,
         dependencies: &BTreeMap<String, Vec<String>>,
@@ -135,4 +390,87 @@ mod tests {
         assert_eq!(indent_str("Hello\nWorld", 2), "  Hello\n  World");
         assert_eq!(indent_str("Hello\nWorld", 4), "    Hello\n    World");
     }
+
+    #[test]
+    fn test_reindent_str() {
+        let code = "fn foo() {\n    bar(\n      baz,\n    );\n\n    qux();\n}";
+        let tab = IndentConfig {
+            style: IndentStyle::Tab,
+            width: None,
+        };
+        let space = IndentConfig {
+            style: IndentStyle::Space,
+            width: Some(2),
+        };
+
+        assert_eq!(
+            reindent_str(code, 4, &tab),
+            "fn foo() {\n\tbar(\n\t  baz,\n\t);\n\n\tqux();\n}"
+        );
+        assert_eq!(
+            reindent_str(code, 4, &space),
+            "fn foo() {\n  bar(\n    baz,\n  );\n\n  qux();\n}"
+        );
+    }
+
+    #[test]
+    fn test_ts_import_path() {
+        let spec = Path::new("./src/NativeCalculator.ts");
+
+        assert_eq!(
+            ts_import_path(Path::new("./src"), spec),
+            "./NativeCalculator"
+        );
+        assert_eq!(
+            ts_import_path(Path::new("./src/__mocks__"), spec),
+            "../NativeCalculator"
+        );
+        assert_eq!(
+            ts_import_path(Path::new("./docs"), spec),
+            "../src/NativeCalculator"
+        );
+        assert_eq!(
+            ts_import_path(Path::new("."), Path::new("./src/specs/NativeCalculator.ts")),
+            "./src/specs/NativeCalculator"
+        );
+    }
+
+    #[test]
+    fn test_rs_feature_cfg() {
+        assert_eq!(
+            rs_feature_cfg(&["MyModule"]),
+            "#[cfg(feature = \"my_module\")]"
+        );
+        assert_eq!(
+            rs_feature_cfg(&["MyModule", "Calculator"]),
+            "#[cfg(any(feature = \"my_module\", feature = \"calculator\"))]"
+        );
+    }
+
+    #[test]
+    fn test_attr_items() {
+        let code = "#[derive(Clone)]\nstruct Foo {\n    a: f64,\n\n    b: f64,\n}\n\n// Comment\n\n/// Doc\nfn bar() {}";
+
+        assert_eq!(
+            attr_items(code, "#[cfg(test)]"),
+            "#[cfg(test)]\n#[derive(Clone)]\nstruct Foo {\n    a: f64,\n\n    b: f64,\n}\n\n// Comment\n\n/// Doc\n#[cfg(test)]\nfn bar() {}"
+        );
+    }
+
+    #[test]
+    fn test_depfile_str() {
+        let outputs = vec![
+            PathBuf::from("crates/lib/src/ffi.rs"),
+            PathBuf::from("cpp/My Module.cpp"),
+        ];
+        let inputs = vec![
+            PathBuf::from("src/NativeFoo.ts"),
+            PathBuf::from("src/NativeBar.ts"),
+        ];
+
+        assert_eq!(
+            depfile_str(&outputs, &inputs),
+            "crates/lib/src/ffi.rs \\\ncpp/My\\ Module.cpp: \\\n  src/NativeFoo.ts \\\n  src/NativeBar.ts\n"
+        );
+    }
 }
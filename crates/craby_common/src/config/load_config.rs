@@ -6,11 +6,19 @@ use std::{
 use log::debug;
 
 use crate::{
-    constants::crate_dir,
-    utils::{android::is_valid_android_package_name, cargo::cargo_version, string::flat_case},
+    constants::{crate_dir, crate_manifest_path, module_feature_name},
+    utils::{
+        android::is_valid_android_package_name,
+        cargo::cargo_version,
+        fs::compile_glob,
+        string::{flat_case, is_cxx_identifier, is_cxx_qualified_name},
+    },
 };
 
-use super::{types::Config, CargoManifest, CompleteConfig};
+use super::{
+    types::{effective_cxx_namespace, Config},
+    CargoManifest, CompleteConfig, IndentConfig,
+};
 
 pub fn load_config(project_root: &Path) -> Result<CompleteConfig, anyhow::Error> {
     debug!("Cargo version: {}", cargo_version()?);
@@ -82,5 +90,234 @@ fn validate_config(config: &Config) -> Result<(), anyhow::Error> {
         ));
     }
 
+    if let Some(IndentConfig { width: Some(0), .. }) = &config.project.indent {
+        anyhow::bail!("Indent width must be greater than 0");
+    }
+
+    if let Some(spec_glob) = &config.project.spec_glob {
+        compile_glob(spec_glob)?;
+    }
+
+    if let Some(base_class) = &config.project.cxx_base_class {
+        if !is_cxx_qualified_name(&base_class.name) {
+            anyhow::bail!(
+                "Invalid C++ base class: {} (Expected a qualified name, eg. `myapp::BaseTurboModule`)",
+                base_class.name
+            );
+        }
+    }
+
+    if let Some(cxx_namespace) = &config.project.cxx_namespace {
+        if !is_cxx_identifier(cxx_namespace) {
+            anyhow::bail!(
+                "Invalid C++ namespace: {} (Expected an identifier, eg. `mymodule`)",
+                cxx_namespace
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensures that no other Craby project in `node_modules` generates its code
+/// into the same C++ namespace (`craby::<cxx_namespace>`).
+///
+/// Both projects would define the same symbols (eg. `craby::<cxx_namespace>::utils`)
+/// and fail to link into the same app.
+///
+/// Only the packages installed in the project's own `node_modules` are checked.
+/// A conflict with a module that is only installed in the consuming app cannot be detected here.
+pub fn validate_cxx_namespace(config: &CompleteConfig) -> Result<(), anyhow::Error> {
+    let cxx_namespace = config.project.cxx_namespace();
+
+    if let Some(conflict) =
+        find_cxx_namespace_conflict(&config.project_root, &config.project.name, &cxx_namespace)?
+    {
+        anyhow::bail!(
+            "C++ namespace `craby::{}` is also used by {}. Set a unique `cxx_namespace` in craby.toml",
+            cxx_namespace,
+            conflict.display()
+        );
+    }
+
     Ok(())
 }
+
+/// Ensures that the cargo feature of each module is declared in the `Cargo.toml` of the crate.
+///
+/// With `module_features` enabled, the code of a module whose feature is not declared
+/// would be compiled out without any error.
+pub fn validate_module_features<S: AsRef<str>>(
+    project_root: &Path,
+    module_names: &[S],
+) -> Result<(), anyhow::Error> {
+    let manifest_path = crate_manifest_path(project_root);
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let manifest = toml::from_str::<CargoManifest>(&manifest)?;
+    let missing_features = find_missing_module_features(&manifest, module_names);
+
+    if !missing_features.is_empty() {
+        anyhow::bail!(
+            "`module_features` is enabled but the features of the modules are not declared in {}: {}\n\nAdd them to the `[features]` table (eg. `{} = []`) and to the `default` features to build the modules",
+            manifest_path.display(),
+            missing_features.join(", "),
+            missing_features[0]
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the cargo features of the modules that are not declared in the manifest.
+fn find_missing_module_features<S: AsRef<str>>(
+    manifest: &CargoManifest,
+    module_names: &[S],
+) -> Vec<String> {
+    module_names
+        .iter()
+        .map(|name| module_feature_name(name.as_ref()))
+        .filter(|feature| !manifest.features.contains_key(feature))
+        .collect()
+}
+
+/// Returns the `craby.toml` of the installed package (`node_modules/<name>` or `node_modules/@scope/<name>`)
+/// that uses the same C++ namespace, skipping the packages of the same project.
+fn find_cxx_namespace_conflict(
+    project_root: &Path,
+    project_name: &str,
+    cxx_namespace: &str,
+) -> Result<Option<PathBuf>, anyhow::Error> {
+    let node_modules = project_root.join("node_modules");
+    if !node_modules.is_dir() {
+        return Ok(None);
+    }
+
+    let mut package_dirs = vec![];
+    for entry in fs::read_dir(&node_modules)? {
+        let path = entry?.path();
+        let is_scope = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('@'));
+
+        if is_scope && path.is_dir() {
+            for entry in fs::read_dir(&path)? {
+                package_dirs.push(entry?.path());
+            }
+        } else {
+            package_dirs.push(path);
+        }
+    }
+    package_dirs.sort();
+
+    for package_dir in package_dirs {
+        let config_path = package_dir.join("craby.toml");
+        let Ok(content) = fs::read_to_string(&config_path) else {
+            continue;
+        };
+
+        // Parse leniently since the packages may be built with the other versions of Craby
+        let Some(project) = toml::from_str::<toml::Value>(&content)
+            .ok()
+            .and_then(|config| config.get("project").cloned())
+        else {
+            continue;
+        };
+        let Some(name) = project.get("name").and_then(|name| name.as_str()) else {
+            continue;
+        };
+
+        if name == project_name {
+            continue;
+        }
+
+        let namespace = project
+            .get("cxx_namespace")
+            .and_then(|namespace| namespace.as_str());
+        if effective_cxx_namespace(name, namespace) == cxx_namespace {
+            debug!("C++ namespace conflict: {}", config_path.display());
+            return Ok(Some(config_path));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_package(node_modules: &Path, package: &str, config: &str) {
+        let package_dir = node_modules.join(package);
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(package_dir.join("craby.toml"), config).unwrap();
+    }
+
+    #[test]
+    fn test_find_cxx_namespace_conflict() {
+        let root = std::env::temp_dir().join(format!("craby-cxx-namespace-{}", std::process::id()));
+        let node_modules = root.join("node_modules");
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(
+            find_cxx_namespace_conflict(&root, "my_module", "mymodule").unwrap(),
+            None
+        );
+
+        // Same project (eg. the published version installed by the example app)
+        write_package(
+            &node_modules,
+            "my-module",
+            "[project]\nname = \"my_module\"\n",
+        );
+        write_package(&node_modules, "other", "[project]\nname = \"other\"\n");
+        write_package(&node_modules, "invalid", "not toml");
+        assert_eq!(
+            find_cxx_namespace_conflict(&root, "my_module", "mymodule").unwrap(),
+            None
+        );
+
+        write_package(
+            &node_modules,
+            "@scope/renamed",
+            "[project]\nname = \"renamed\"\ncxx_namespace = \"mymodule\"\n",
+        );
+        assert_eq!(
+            find_cxx_namespace_conflict(&root, "my_module", "mymodule").unwrap(),
+            Some(node_modules.join("@scope/renamed/craby.toml"))
+        );
+        assert_eq!(
+            find_cxx_namespace_conflict(&root, "my_module", "custom").unwrap(),
+            None
+        );
+        assert_eq!(
+            find_cxx_namespace_conflict(&root, "my_module", "other").unwrap(),
+            Some(node_modules.join("other/craby.toml"))
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_find_missing_module_features() {
+        let manifest = toml::from_str::<CargoManifest>(
+            r#"
+            [package]
+            name = "my_module"
+
+            [lib]
+            name = "mymodule"
+
+            [features]
+            default = ["calculator"]
+            calculator = []
+            "#,
+        )
+        .unwrap();
+
+        assert!(find_missing_module_features(&manifest, &["Calculator"]).is_empty());
+        assert_eq!(
+            find_missing_module_features(&manifest, &["Calculator", "ImageProcessor"]),
+            vec!["image_processor"]
+        );
+    }
+}
@@ -1,11 +1,15 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::utils::{string::flat_case, version::VersionRange};
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CargoManifest {
     pub package: PackageConfig,
     pub lib: LibConfig,
+    #[serde(default)]
+    pub features: BTreeMap<String, Vec<String>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -29,17 +33,112 @@ pub struct Config {
 pub struct ProjectConfig {
     pub name: String,
     pub source_dir: String,
+    /// Glob of the spec files relative to the project root (Default: `Native*.ts` files in the source directory)
+    pub spec_glob: Option<String>,
+    /// Indentation of the generated code
+    pub indent: Option<IndentConfig>,
+    /// Method and signal names to expose per module (Default: all)
+    pub expose: Option<BTreeMap<String, Vec<String>>>,
+    /// Base class of the generated C++ modules (Default: `facebook::react::TurboModule`)
+    pub cxx_base_class: Option<CxxBaseClassConfig>,
+    /// Format the generated C++ files with `clang-format` (Default: `false`)
+    pub clang_format: Option<bool>,
+    /// Track the live instances of the generated C++ modules for leak detection (Default: `false`)
+    pub instance_counter: Option<bool>,
+    /// Gate the generated code of each module behind a cargo feature named after the module (Default: `false`)
+    pub module_features: Option<bool>,
+    /// Shell command to run after the code generation (Default: none)
+    pub post_codegen: Option<PostCodegenConfig>,
+    /// Write the output of each target build to `target/craby-logs/<target>.log` (Default: `false`)
+    pub build_log: Option<bool>,
+    /// Number of worker threads of the C++ modules running the async methods (Default: `10`)
+    pub thread_pool_size: Option<usize>,
+    /// Base of the C++ namespace (`craby::<cxx_namespace>`) (Default: flat case of the project name)
+    pub cxx_namespace: Option<String>,
+    /// Generate method stubs returning the default values instead of `unimplemented!()` (Default: `false`)
+    pub stub_defaults: Option<bool>,
+    /// Generate the `extern "C"` shim of the module methods (Default: `false`)
+    pub c_shim: Option<bool>,
+    /// Report the exceptions thrown by the signal listeners to the Rust module (Default: `false`)
+    pub listener_error_hook: Option<bool>,
+    /// Generate the Swift-friendly header of the iOS module provider (Default: `false`)
+    pub swift_header: Option<bool>,
+    /// Generate the shared registry of the module factories (Default: `false`)
+    pub module_registry: Option<bool>,
+    /// Defer the module registration until the first access (Default: `false`)
+    pub lazy_registration: Option<bool>,
+    /// Throw a descriptive error for the unimplemented methods (Default: `false`)
+    pub unimplemented_error: Option<bool>,
+    /// Generate the markdown usage snippet per module (Default: `false`)
+    pub docs: Option<bool>,
+    /// Generate the Kotlin object with the typed method stubs per module (Default: `false`)
+    pub kotlin_stubs: Option<bool>,
+    /// Generate the TypeScript module names and enum guards next to the specs (Default: `false`)
+    pub dts: Option<bool>,
+    /// Generate the JS mock per module for the unit tests (Default: `false`)
+    pub mocks: Option<bool>,
+}
+
+impl ProjectConfig {
+    /// Returns the base of the C++ namespace of the generated code.
+    pub fn cxx_namespace(&self) -> String {
+        effective_cxx_namespace(&self.name, self.cxx_namespace.as_deref())
+    }
+}
+
+/// Returns the configured C++ namespace base or the flat case of the project name.
+pub(crate) fn effective_cxx_namespace(project_name: &str, cxx_namespace: Option<&str>) -> String {
+    cxx_namespace.map_or_else(|| flat_case(project_name), str::to_string)
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CxxBaseClassConfig {
+    /// Qualified class name (eg. `myapp::BaseTurboModule`)
+    pub name: String,
+    /// Header that declares the base class (eg. `BaseTurboModule.h`)
+    pub header: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PostCodegenConfig {
+    /// Command to run with the system shell in the project root
+    pub command: String,
+    /// Fail the code generation if the command fails (Default: `true`)
+    pub fail_on_error: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IndentConfig {
+    pub style: IndentStyle,
+    /// Number of units per indentation level
+    /// (Default: language default width for spaces, `1` for tabs)
+    pub width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndentStyle {
+    Space,
+    Tab,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AndroidConfig {
     pub package_name: String,
     pub targets: Option<Vec<String>>,
+    /// Supported NDK version range (checked by `doctor`)
+    pub ndk_version: Option<VersionRange>,
+    /// Align the shared library to 16KB pages, required on Android 15+ (Default: `true`)
+    pub page_size_16kb: Option<bool>,
+    /// Strip debug symbols from the built libraries with `llvm-strip` (Default: `true`)
+    pub strip: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct IosConfig {
     pub targets: Option<Vec<String>>,
+    /// Supported Xcode version range (checked by `doctor`)
+    pub xcode_version: Option<VersionRange>,
 }
 
 #[derive(Debug)]
@@ -19,6 +19,9 @@ pub mod toolchain {
 }
 
 pub mod android {
+    /// Supported Android NDK versions (React Native 0.76+)
+    pub const NDK_VERSION_RANGE: (&str, &str) = ("26.1", "27");
+
     pub const ABI_TARGETS: &[&str] = &[
         // Target: aarch64-linux-android
         "arm64-v8a",
@@ -31,7 +34,10 @@ pub mod android {
     ];
 }
 
-pub mod ios {}
+pub mod ios {
+    /// Supported Xcode versions (React Native 0.76+)
+    pub const XCODE_VERSION_RANGE: (&str, &str) = ("15.1", "16");
+}
 
 pub const SPEC_FILE_PREFIX: &str = "Native";
 
@@ -51,6 +57,20 @@ pub fn impl_mod_name(name: &str) -> String {
     format!("{}_impl", snake_case(name))
 }
 
+/// Cargo feature that gates the module
+///
+/// Example: `some_module`
+pub fn module_feature_name(name: &str) -> String {
+    snake_case(name)
+}
+
+/// C++ macro defined in `CrabyFeatures.h` when the module feature is enabled
+///
+/// Example: `CRABY_FEATURE_SOME_MODULE`
+pub fn module_feature_macro(name: &str) -> String {
+    format!("CRABY_FEATURE_{}", module_feature_name(name).to_uppercase())
+}
+
 pub fn craby_tmp_dir(project_root: &Path) -> PathBuf {
     project_root.join(".craby")
 }
@@ -71,6 +91,16 @@ pub fn cxx_bridge_dir(project_root: &Path, target: &str) -> PathBuf {
     project_root.join("target").join(target).join("cxxbridge")
 }
 
+/// Output of the cargo build for the target (`build_log` option)
+///
+/// Example: `target/craby-logs/aarch64-linux-android.log`
+pub fn build_log_path(project_root: &Path, target: &str) -> PathBuf {
+    project_root
+        .join("target")
+        .join("craby-logs")
+        .join(format!("{target}.log"))
+}
+
 pub fn cxx_bridge_include_dir(project_root: &Path) -> PathBuf {
     crate_dir(project_root).join("include")
 }
@@ -9,6 +9,11 @@ pub fn is_rustup_installed() -> bool {
         .is_ok()
 }
 
+/// Returns `true` if the host can build the iOS targets (`lipo` and `xcodebuild` are macOS only)
+pub fn is_macos_host() -> bool {
+    std::env::consts::OS == "macos"
+}
+
 pub fn is_initialized(project_root: &Path) -> bool {
     let crates_dir = project_root.join("crates");
     project_root.join("craby.toml").exists()
@@ -3,6 +3,8 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use super::version::Version;
+
 pub fn is_gradle_configured(project_root: &Path) -> Result<bool, anyhow::Error> {
     let gradle_path = build_gradle_path(project_root);
 
@@ -19,6 +21,39 @@ pub fn is_valid_android_package_name(package_name: &str) -> Result<bool, anyhow:
     Ok(re.is_match(package_name))
 }
 
+/// Reads the Android NDK version from `$ANDROID_NDK_HOME/source.properties`
+pub fn get_ndk_version() -> Result<Version, anyhow::Error> {
+    let ndk_home = std::env::var("ANDROID_NDK_HOME")
+        .map_err(|_| anyhow::anyhow!("`ANDROID_NDK_HOME` environment variable is not set"))?;
+    let content = fs::read_to_string(PathBuf::from(ndk_home).join("source.properties"))?;
+
+    parse_ndk_version(&content)
+}
+
+fn parse_ndk_version(content: &str) -> Result<Version, anyhow::Error> {
+    content
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim() == "Pkg.Revision")
+        .and_then(|(_, value)| Version::parse(value.split('-').next().unwrap_or_default()))
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse NDK version"))
+}
+
 pub fn build_gradle_path(project_root: &Path) -> PathBuf {
     project_root.join("android").join("build.gradle")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ndk_version() {
+        let content = "Pkg.Desc = Android NDK\nPkg.Revision = 27.1.12297006\nPkg.BaseRevision = 27.1.12297006\n";
+
+        assert_eq!(
+            parse_ndk_version(content).unwrap(),
+            Version(vec![27, 1, 12297006])
+        );
+    }
+}
@@ -1,5 +1,9 @@
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
+use globset::{GlobBuilder, GlobMatcher};
 use log::debug;
 
 pub fn collect_files(
@@ -30,3 +34,51 @@ pub fn collect_files(
 
     Ok(files)
 }
+
+/// Compiles the glob pattern (`*` does not match the path separator, `**` matches any directories)
+pub fn compile_glob(pattern: &str) -> Result<GlobMatcher, anyhow::Error> {
+    let glob = GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Invalid glob pattern `{}`: {}", pattern, e.kind()))?;
+
+    Ok(glob.compile_matcher())
+}
+
+/// Collects the files matching the glob pattern relative to the root directory.
+///
+/// Only the base directory of the pattern is walked (eg. `src` of `src/**/*.ts`).
+pub fn collect_glob_files(root: &Path, pattern: &str) -> Result<Vec<PathBuf>, anyhow::Error> {
+    let matcher = compile_glob(pattern)?;
+    let mut components = pattern.split('/').collect::<Vec<_>>();
+    components.pop();
+
+    let base_dir = components
+        .into_iter()
+        .take_while(|component| !component.contains(['*', '?', '[', '{']))
+        .fold(root.to_path_buf(), |dir, component| dir.join(component));
+
+    collect_files(&base_dir, &|path: &PathBuf| {
+        path.strip_prefix(root)
+            .is_ok_and(|relative| matcher.is_match(relative))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_glob() {
+        let matcher = compile_glob("src/**/*.spec.ts").unwrap();
+
+        assert!(matcher.is_match("src/Calculator.spec.ts"));
+        assert!(matcher.is_match("src/modules/Calculator.spec.ts"));
+        assert!(!matcher.is_match("src/Calculator.ts"));
+        assert!(!matcher.is_match("lib/Calculator.spec.ts"));
+        assert!(!compile_glob("src/*.ts")
+            .unwrap()
+            .is_match("src/modules/Calculator.ts"));
+        assert!(compile_glob("src/[a-.ts").is_err());
+    }
+}
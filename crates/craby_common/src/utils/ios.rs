@@ -2,7 +2,7 @@ use std::{fs, path::PathBuf, process::Command};
 
 use regex::Regex;
 
-use super::string::SanitizedString;
+use super::{string::SanitizedString, version::Version};
 
 pub fn get_podspec_path(project_root: &PathBuf) -> Result<Option<String>, anyhow::Error> {
     let files = fs::read_dir(project_root)?;
@@ -24,6 +24,21 @@ pub fn is_xcode_cli_tools_installed() -> Result<bool, anyhow::Error> {
     Ok(res.status.success())
 }
 
+/// Returns the Xcode version from `xcodebuild -version` (eg. `Xcode 16.0`)
+pub fn get_xcode_version() -> Result<Version, anyhow::Error> {
+    let res = Command::new("xcodebuild").args(["-version"]).output()?;
+
+    if !res.status.success() {
+        anyhow::bail!("Failed to get Xcode version");
+    }
+
+    String::from_utf8(res.stdout)?
+        .lines()
+        .find_map(|line| line.strip_prefix("Xcode "))
+        .and_then(Version::parse)
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse Xcode version"))
+}
+
 pub fn is_podspec_configured(project_root: &PathBuf) -> Result<bool, anyhow::Error> {
     let mut passed = true;
     let podspec_path = get_podspec_path(project_root)?
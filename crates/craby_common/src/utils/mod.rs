@@ -3,3 +3,4 @@ pub mod cargo;
 pub mod fs;
 pub mod ios;
 pub mod string;
+pub mod version;
@@ -60,3 +60,17 @@ pub fn kebab_case(value: &str) -> String {
 pub fn flat_case(value: &str) -> String {
     value.to_case(Case::Flat)
 }
+
+/// Returns `true` if the value is a plain C++ identifier (eg. `mymodule`).
+pub fn is_cxx_identifier(value: &str) -> bool {
+    Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$")
+        .unwrap()
+        .is_match(value)
+}
+
+/// Returns `true` if the value is a namespace-qualified C++ name (eg. `myapp::BaseTurboModule`).
+pub fn is_cxx_qualified_name(value: &str) -> bool {
+    Regex::new(r"^(::)?[A-Za-z_][A-Za-z0-9_]*(::[A-Za-z_][A-Za-z0-9_]*)+$")
+        .unwrap()
+        .is_match(value)
+}
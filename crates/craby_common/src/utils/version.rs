@@ -0,0 +1,108 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+/// Numeric dot-separated version (eg. `27.1.12297006`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version(pub Vec<u64>);
+
+impl Version {
+    pub fn parse(value: &str) -> Option<Version> {
+        let parts = value
+            .trim()
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(Version(parts))
+        }
+    }
+
+    /// Compares versions up to the length of `other` (missing components are treated as `0`).
+    fn cmp_prefix(&self, other: &Version) -> Ordering {
+        for (idx, rhs) in other.0.iter().enumerate() {
+            let lhs = self.0.get(idx).copied().unwrap_or(0);
+            match lhs.cmp(rhs) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts = self.0.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", parts.join("."))
+    }
+}
+
+/// Inclusive version range
+///
+/// The upper bound matches by prefix, so `max = "27"` accepts every `27.x.y` version.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VersionRange {
+    pub min: Option<String>,
+    pub max: Option<String>,
+}
+
+impl VersionRange {
+    pub fn new(min: &str, max: &str) -> Self {
+        Self {
+            min: Some(min.to_string()),
+            max: Some(max.to_string()),
+        }
+    }
+
+    pub fn contains(&self, version: &Version) -> Result<bool, anyhow::Error> {
+        if let Some(min) = &self.min {
+            if version.cmp_prefix(&Self::parse_bound(min)?) == Ordering::Less {
+                return Ok(false);
+            }
+        }
+
+        if let Some(max) = &self.max {
+            if version.cmp_prefix(&Self::parse_bound(max)?) == Ordering::Greater {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn parse_bound(value: &str) -> Result<Version, anyhow::Error> {
+        Version::parse(value).ok_or_else(|| anyhow::anyhow!("Invalid version: {}", value))
+    }
+}
+
+impl Display for VersionRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => write!(f, "{} ~ {}", min, max),
+            (Some(min), None) => write!(f, ">= {}", min),
+            (None, Some(max)) => write!(f, "<= {}", max),
+            (None, None) => write!(f, "*"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_range() {
+        let range = VersionRange::new("26.1", "27");
+        let contains = |v: &str| range.contains(&Version::parse(v).unwrap()).unwrap();
+
+        assert!(contains("27.1.12297006"));
+        assert!(contains("26.1.10909125"));
+        assert!(!contains("25.2.9519653"));
+        assert!(!contains("28.0.13004108"));
+        assert!(Version::parse("16.0-beta").is_none());
+    }
+}
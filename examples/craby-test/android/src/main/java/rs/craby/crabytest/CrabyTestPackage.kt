@@ -1,3 +1,4 @@
+// Auto generated by Craby. DO NOT EDIT.
 package rs.craby.crabytest
 
 import com.facebook.react.BaseReactPackage
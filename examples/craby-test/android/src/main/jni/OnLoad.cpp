@@ -3,17 +3,21 @@
 #include <CxxCrabyTestModule.hpp>
 #include <ReactCommon/CxxTurboModuleUtils.h>
 #include <jni.h>
+#include <string>
+
+// Data path of the app, set by the package before any module is created
+static std::string dataPath;
 
 jint JNI_OnLoad(JavaVM *vm, void *reserved) {
   facebook::react::registerCxxModuleToGlobalModuleMap(
     craby::crabytest::modules::CxxCalculatorModule::kModuleName,
     [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
-      return std::make_shared<craby::crabytest::modules::CxxCalculatorModule>(jsInvoker);
+      return std::make_shared<craby::crabytest::modules::CxxCalculatorModule>(jsInvoker, dataPath);
     });
   facebook::react::registerCxxModuleToGlobalModuleMap(
     craby::crabytest::modules::CxxCrabyTestModule::kModuleName,
     [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
-      return std::make_shared<craby::crabytest::modules::CxxCrabyTestModule>(jsInvoker);
+      return std::make_shared<craby::crabytest::modules::CxxCrabyTestModule>(jsInvoker, dataPath);
     });
   return JNI_VERSION_1_6;
 }
@@ -22,8 +26,6 @@ extern "C"
 JNIEXPORT void JNICALL
 Java_rs_craby_crabytest_CrabyTestPackage_nativeSetDataPath(JNIEnv *env, jclass clazz, jstring jDataPath) {
   const char* cDataPath = env->GetStringUTFChars(jDataPath, nullptr);
-  auto dataPath = std::string(cDataPath);
+  dataPath = std::string(cDataPath);
   env->ReleaseStringUTFChars(jDataPath, cDataPath);
-  craby::crabytest::modules::CxxCalculatorModule::dataPath = dataPath;
-  craby::crabytest::modules::CxxCrabyTestModule::dataPath = dataPath;
 }
@@ -5,8 +5,11 @@
 #include "ffi.rs.h"
 #include <condition_variable>
 #include <functional>
+#include <memory>
 #include <mutex>
 #include <queue>
+#include <stdexcept>
+#include <string>
 #include <thread>
 #include <vector>
 
@@ -17,6 +20,7 @@ namespace utils {
 class ThreadPool {
 private:
   bool stop;
+  std::once_flag shutdownFlag;
   std::mutex mutex;
   std::condition_variable condition;
   std::queue<std::function<void()>> tasks;
@@ -59,21 +63,23 @@ public:
     condition.notify_one();
   }
 
+  /// Stops accepting new tasks and joins the workers once the queued tasks are finished.
+  /// Concurrent callers are blocked until the queue is drained.
   void shutdown() {
-    {
-      std::unique_lock<std::mutex> lock(mutex);
-      stop = true;
-      std::queue<std::function<void()>> empty;
-      std::swap(tasks, empty);
-    }
+    std::call_once(shutdownFlag, [this] {
+      {
+        std::unique_lock<std::mutex> lock(mutex);
+        stop = true;
+      }
 
-    condition.notify_all();
+      condition.notify_all();
 
-    for (std::thread &worker : workers) {
-      if (worker.joinable()) {
-        worker.join();
+      for (std::thread &worker : workers) {
+        if (worker.joinable()) {
+          worker.join();
+        }
       }
-    }
+    });
   }
 
   ~ThreadPool() {
@@ -86,6 +92,28 @@ inline std::string errorMessage(const std::exception &err) {
   return std::string(rs_err ? rs_err->what() : err.what());
 }
 
+/// Schedules the task on the JS thread through the `CallInvoker`.
+/// Failures of scheduling (eg. the runtime is being torn down) are ignored.
+template <class Invoker, class F>
+void invokeAsync(const std::shared_ptr<Invoker> &callInvoker, F &&task) {
+  try {
+    callInvoker->invokeAsync(std::forward<F>(task));
+  } catch (const std::exception &) {
+    // Noop
+  }
+}
+
+/// Converts the JS argument, prefixing the conversion errors with the argument name and index.
+template <class F>
+auto fromJsArg(const char *name, size_t index, F &&convert) -> decltype(convert()) {
+  try {
+    return convert();
+  } catch (const std::exception &err) {
+    throw std::runtime_error(
+      "argument '" + std::string(name) + "' (arg" + std::to_string(index) + "): " + errorMessage(err));
+  }
+}
+
 } // namespace utils
 } // namespace crabytest
 } // namespace craby
@@ -10,17 +10,17 @@ namespace craby {
 namespace crabytest {
 namespace modules {
 
-std::string CxxCalculatorModule::dataPath = std::string();
-
 CxxCalculatorModule::CxxCalculatorModule(
-    std::shared_ptr<react::CallInvoker> jsInvoker)
-    : TurboModule(CxxCalculatorModule::kModuleName, jsInvoker) {
+    std::shared_ptr<react::CallInvoker> jsInvoker,
+    std::string dataPath)
+    : TurboModule(CxxCalculatorModule::kModuleName, jsInvoker),
+      dataPath_(std::move(dataPath)) {
   // No signals
   callInvoker_ = std::move(jsInvoker);
   module_ = std::shared_ptr<craby::crabytest::bridging::Calculator>(
     craby::crabytest::bridging::createCalculator(
-      reinterpret_cast<uintptr_t>(this),
-      rust::Str(dataPath.data(), dataPath.size())).into_raw(),
+      reinterpret_cast<size_t>(this),
+      rust::Str(dataPath_.data(), dataPath_.size())).into_raw(),
     [](craby::crabytest::bridging::Calculator *ptr) { rust::Box<craby::crabytest::bridging::Calculator>::from_raw(ptr); }
   );
   threadPool_ = std::make_shared<craby::crabytest::utils::ThreadPool>(10);
@@ -39,13 +39,19 @@ void CxxCalculatorModule::invalidate() {
     return;
   }
 
-  invalidated_.store(true);
-  listenersMap_.clear();
+  // Teardown order matters since in-flight tasks may still reference `module_` and listeners
+  // 1. Stop routing signals to this module
+  // 2. Stop accepting new tasks and join the workers (waits for the queued and in-flight tasks)
+  // 3. Clear listeners once nothing can reference them anymore
 
   // No signals
 
-  // Shutdown thread pool
   threadPool_->shutdown();
+
+  {
+    std::lock_guard<std::mutex> lock(listenersMutex_);
+    listenersMap_.clear();
+  }
 }
 
 jsi::Value CxxCalculatorModule::add(jsi::Runtime &rt,
@@ -61,8 +67,8 @@ jsi::Value CxxCalculatorModule::add(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 2 arguments");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
-    auto arg1 = react::bridging::fromJs<double>(rt, args[1], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("a", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
+    auto arg1 = craby::crabytest::utils::fromJsArg("b", 1, [&] { return react::bridging::fromJs<double>(rt, args[1], callInvoker); });
     auto ret = craby::crabytest::bridging::add(*it_, arg0, arg1);
 
     return react::bridging::toJs(rt, ret);
@@ -86,8 +92,8 @@ jsi::Value CxxCalculatorModule::divide(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 2 arguments");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
-    auto arg1 = react::bridging::fromJs<double>(rt, args[1], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("a", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
+    auto arg1 = craby::crabytest::utils::fromJsArg("b", 1, [&] { return react::bridging::fromJs<double>(rt, args[1], callInvoker); });
     auto ret = craby::crabytest::bridging::divide(*it_, arg0, arg1);
 
     return react::bridging::toJs(rt, ret);
@@ -111,8 +117,8 @@ jsi::Value CxxCalculatorModule::multiply(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 2 arguments");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
-    auto arg1 = react::bridging::fromJs<double>(rt, args[1], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("a", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
+    auto arg1 = craby::crabytest::utils::fromJsArg("b", 1, [&] { return react::bridging::fromJs<double>(rt, args[1], callInvoker); });
     auto ret = craby::crabytest::bridging::multiply(*it_, arg0, arg1);
 
     return react::bridging::toJs(rt, ret);
@@ -136,8 +142,8 @@ jsi::Value CxxCalculatorModule::subtract(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 2 arguments");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
-    auto arg1 = react::bridging::fromJs<double>(rt, args[1], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("a", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
+    auto arg1 = craby::crabytest::utils::fromJsArg("b", 1, [&] { return react::bridging::fromJs<double>(rt, args[1], callInvoker); });
     auto ret = craby::crabytest::bridging::subtract(*it_, arg0, arg1);
 
     return react::bridging::toJs(rt, ret);
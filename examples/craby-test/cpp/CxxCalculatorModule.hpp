@@ -14,9 +14,8 @@ namespace modules {
 class JSI_EXPORT CxxCalculatorModule : public facebook::react::TurboModule {
 public:
   static constexpr const char *kModuleName = "Calculator";
-  static std::string dataPath;
 
-  CxxCalculatorModule(std::shared_ptr<facebook::react::CallInvoker> jsInvoker);
+  CxxCalculatorModule(std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath);
   ~CxxCalculatorModule();
 
   void invalidate();
@@ -42,6 +41,7 @@ public:
 
 protected:
   std::shared_ptr<facebook::react::CallInvoker> callInvoker_;
+  std::string dataPath_;
   std::shared_ptr<craby::crabytest::bridging::Calculator> module_;
   std::atomic<bool> invalidated_{false};
   std::atomic<size_t> nextListenerId_{0};
@@ -10,23 +10,23 @@ namespace craby {
 namespace crabytest {
 namespace modules {
 
-std::string CxxCrabyTestModule::dataPath = std::string();
-
 CxxCrabyTestModule::CxxCrabyTestModule(
-    std::shared_ptr<react::CallInvoker> jsInvoker)
-    : TurboModule(CxxCrabyTestModule::kModuleName, jsInvoker) {
-  uintptr_t id = reinterpret_cast<uintptr_t>(this);
-  auto& manager = craby::crabytest::signals::SignalManager::getInstance();
+    std::shared_ptr<react::CallInvoker> jsInvoker,
+    std::string dataPath)
+    : TurboModule(CxxCrabyTestModule::kModuleName, jsInvoker),
+      dataPath_(std::move(dataPath)) {
+  size_t id = reinterpret_cast<size_t>(this);
+  auto& manager = craby::crabytest::signals::CrabyTestSignalManager::getInstance();
   manager.registerDelegate(id,
-    [this](const std::string& name, void* signal) {
-      this->emit(name, reinterpret_cast<bridging::CrabyTestSignal*>(signal));
+    [this](const std::string& name, bridging::CrabyTestSignal* signal) {
+      this->emit(name, signal);
     }
   );
   callInvoker_ = std::move(jsInvoker);
   module_ = std::shared_ptr<craby::crabytest::bridging::CrabyTest>(
     craby::crabytest::bridging::createCrabyTest(
-      reinterpret_cast<uintptr_t>(this),
-      rust::Str(dataPath.data(), dataPath.size())).into_raw(),
+      reinterpret_cast<size_t>(this),
+      rust::Str(dataPath_.data(), dataPath_.size())).into_raw(),
     [](craby::crabytest::bridging::CrabyTest *ptr) { rust::Box<craby::crabytest::bridging::CrabyTest>::from_raw(ptr); }
   );
   threadPool_ = std::make_shared<craby::crabytest::utils::ThreadPool>(10);
@@ -62,16 +62,22 @@ void CxxCrabyTestModule::invalidate() {
     return;
   }
 
-  invalidated_.store(true);
-  listenersMap_.clear();
+  // Teardown order matters since in-flight tasks may still reference `module_` and listeners
+  // 1. Stop routing signals to this module
+  // 2. Stop accepting new tasks and join the workers (waits for the queued and in-flight tasks)
+  // 3. Clear listeners once nothing can reference them anymore
 
   // Unregister from signal manager
-  uintptr_t id = reinterpret_cast<uintptr_t>(this);
-  auto& manager = craby::crabytest::signals::SignalManager::getInstance();
+  size_t id = reinterpret_cast<size_t>(this);
+  auto& manager = craby::crabytest::signals::CrabyTestSignalManager::getInstance();
   manager.unregisterDelegate(id);
 
-  // Shutdown thread pool
   threadPool_->shutdown();
+
+  {
+    std::lock_guard<std::mutex> lock(listenersMutex_);
+    listenersMap_.clear();
+  }
 }
 
 void CxxCrabyTestModule::emit(std::string name, bridging::CrabyTestSignal* signal) {
@@ -98,7 +104,7 @@ void CxxCrabyTestModule::emit(std::string name, bridging::CrabyTestSignal* signa
       [](bridging::CrabyTestSignal* ptr) {
         // Use Rust FFI function to drop signal memory
         if (ptr != nullptr) {
-          craby::crabytest::bridging::drop_signal(ptr);
+          craby::crabytest::bridging::drop_craby_test_signal(ptr);
         }
       }
     );
@@ -106,39 +112,31 @@ void CxxCrabyTestModule::emit(std::string name, bridging::CrabyTestSignal* signa
     // Extract payload using FFI function and convert to jsi::Value
     // We'll need to capture signalPtr in the lambda
     for (auto& listener : listeners) {
-      try {
-        callInvoker_->invokeAsync([listener, signalPtr, name](jsi::Runtime &rt) {
-          jsi::Value data = jsi::Value::undefined();
-          if (name == "onError") {
-            auto payload = craby::crabytest::bridging::get_on_error_payload(*signalPtr);
-            data = react::bridging::toJs(rt, payload);
-          } else if (name == "onProgress") {
-            auto payload = craby::crabytest::bridging::get_on_progress_payload(*signalPtr);
-            data = react::bridging::toJs(rt, payload);
-          }
-          listener->call(rt, data);
-        });
-      } catch (const std::exception& err) {
-        // Noop
-      }
+      craby::crabytest::utils::invokeAsync(callInvoker_, [listener, signalPtr, name](jsi::Runtime &rt) {
+        jsi::Value data = jsi::Value::undefined();
+        if (name == "onError") {
+          auto payload = signalPtr->get_on_error_payload();
+          data = react::bridging::toJs(rt, payload);
+        } else if (name == "onProgress") {
+          auto payload = signalPtr->get_on_progress_payload();
+          data = react::bridging::toJs(rt, payload);
+        }
+        listener->call(rt, data);
+      });
     }
     return;
   }
 
   for (auto& listener : listeners) {
-    try {
-      callInvoker_->invokeAsync([listener, payloadPtr](jsi::Runtime &rt) {
-        try {
-          listener->call(rt, *payloadPtr);
-        } catch (const jsi::JSError &err) {
-          throw err;
-        } catch (const std::exception &err) {
-          throw jsi::JSError(rt, craby::crabytest::utils::errorMessage(err));
-        }
-      });
-    } catch (const std::exception& err) {
-      // Noop
-    }
+    craby::crabytest::utils::invokeAsync(callInvoker_, [listener, payloadPtr](jsi::Runtime &rt) {
+      try {
+        listener->call(rt, *payloadPtr);
+      } catch (const jsi::JSError &err) {
+        throw err;
+      } catch (const std::exception &err) {
+        throw jsi::JSError(rt, craby::crabytest::utils::errorMessage(err));
+      }
+    });
   }
 }
 
@@ -155,7 +153,7 @@ jsi::Value CxxCrabyTestModule::arrayBufferMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<rust::Vec<uint8_t>>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<rust::Vec<uint8_t>>(rt, args[0], callInvoker); });
     auto ret = craby::crabytest::bridging::arrayBufferMethod(*it_, arg0);
 
     return react::bridging::toJs(rt, ret);
@@ -179,7 +177,7 @@ jsi::Value CxxCrabyTestModule::arrayMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<rust::Vec<double>>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<rust::Vec<double>>(rt, args[0], callInvoker); });
     auto ret = craby::crabytest::bridging::arrayMethod(*it_, arg0);
 
     return react::bridging::toJs(rt, ret);
@@ -203,7 +201,7 @@ jsi::Value CxxCrabyTestModule::booleanMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<bool>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<bool>(rt, args[0], callInvoker); });
     auto ret = craby::crabytest::bridging::booleanMethod(*it_, arg0);
 
     return react::bridging::toJs(rt, ret);
@@ -250,8 +248,8 @@ jsi::Value CxxCrabyTestModule::enumMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 2 arguments");
     }
 
-    auto arg0 = react::bridging::fromJs<craby::crabytest::bridging::MyEnum>(rt, args[0], callInvoker);
-    auto arg1 = react::bridging::fromJs<craby::crabytest::bridging::SwitchState>(rt, args[1], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg0", 0, [&] { return react::bridging::fromJs<craby::crabytest::bridging::MyEnum>(rt, args[0], callInvoker); });
+    auto arg1 = craby::crabytest::utils::fromJsArg("arg1", 1, [&] { return react::bridging::fromJs<craby::crabytest::bridging::SwitchState>(rt, args[1], callInvoker); });
     auto ret = craby::crabytest::bridging::enumMethod(*it_, arg0, arg1);
 
     return react::bridging::toJs(rt, ret);
@@ -321,7 +319,7 @@ jsi::Value CxxCrabyTestModule::nullableMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<craby::crabytest::bridging::NullableNumber>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<craby::crabytest::bridging::NullableNumber>(rt, args[0], callInvoker); });
     auto ret = craby::crabytest::bridging::nullableMethod(*it_, arg0);
 
     return react::bridging::toJs(rt, ret);
@@ -345,7 +343,7 @@ jsi::Value CxxCrabyTestModule::numericMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
     auto ret = craby::crabytest::bridging::numericMethod(*it_, arg0);
 
     return react::bridging::toJs(rt, ret);
@@ -369,7 +367,7 @@ jsi::Value CxxCrabyTestModule::objectMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<craby::crabytest::bridging::TestObject>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<craby::crabytest::bridging::TestObject>(rt, args[0], callInvoker); });
     auto ret = craby::crabytest::bridging::objectMethod(*it_, arg0);
 
     return react::bridging::toJs(rt, ret);
@@ -416,7 +414,7 @@ jsi::Value CxxCrabyTestModule::promiseMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
     react::AsyncPromise<double> promise(rt, callInvoker);
 
     thisModule.threadPool_->enqueue([it_, promise, arg0]() mutable {
@@ -474,7 +472,7 @@ jsi::Value CxxCrabyTestModule::setState(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0 = react::bridging::fromJs<double>(rt, args[0], callInvoker);
+    auto arg0 = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return react::bridging::fromJs<double>(rt, args[0], callInvoker); });
     craby::crabytest::bridging::setState(*it_, arg0);
 
     return jsi::Value::undefined();
@@ -521,7 +519,7 @@ jsi::Value CxxCrabyTestModule::stringMethod(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0$raw = args[0].asString(rt).utf8(rt);
+    auto arg0$raw = craby::crabytest::utils::fromJsArg("arg", 0, [&] { return args[0].asString(rt).utf8(rt); });
     auto arg0 = rust::Str(arg0$raw.data(), arg0$raw.size());
     auto ret = craby::crabytest::bridging::stringMethod(*it_, arg0);
 
@@ -580,7 +578,7 @@ jsi::Value CxxCrabyTestModule::writeData(jsi::Runtime &rt,
       throw jsi::JSError(rt, "Expected 1 argument");
     }
 
-    auto arg0$raw = args[0].asString(rt).utf8(rt);
+    auto arg0$raw = craby::crabytest::utils::fromJsArg("value", 0, [&] { return args[0].asString(rt).utf8(rt); });
     auto arg0 = rust::Str(arg0$raw.data(), arg0$raw.size());
     auto ret = craby::crabytest::bridging::writeData(*it_, arg0);
 
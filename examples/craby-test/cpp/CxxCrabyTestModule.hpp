@@ -14,9 +14,8 @@ namespace modules {
 class JSI_EXPORT CxxCrabyTestModule : public facebook::react::TurboModule {
 public:
   static constexpr const char *kModuleName = "CrabyTest";
-  static std::string dataPath;
 
-  CxxCrabyTestModule(std::shared_ptr<facebook::react::CallInvoker> jsInvoker);
+  CxxCrabyTestModule(std::shared_ptr<facebook::react::CallInvoker> jsInvoker, std::string dataPath);
   ~CxxCrabyTestModule();
 
   void invalidate();
@@ -129,6 +128,7 @@ public:
 
 protected:
   std::shared_ptr<facebook::react::CallInvoker> callInvoker_;
+  std::string dataPath_;
   std::shared_ptr<craby::crabytest::bridging::CrabyTest> module_;
   std::atomic<bool> invalidated_{false};
   std::atomic<size_t> nextListenerId_{0};
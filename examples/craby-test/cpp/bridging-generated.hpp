@@ -4,10 +4,77 @@
 #include "cxx.h"
 #include "ffi.rs.h"
 #include <react/bridging/Bridging.h>
+#include <cmath>
+#include <cstring>
+#include <limits>
 #include <variant>
 
 using namespace facebook;
 
+namespace craby {
+namespace crabytest {
+namespace utils {
+
+/// Converts the JS number to the integer, throwing if it is not an integer within the range of `T`.
+template <typename T>
+T fromJsInt(jsi::Runtime& rt, const jsi::Value &value) {
+  double num = value.asNumber();
+  // `max() + 1` is exactly representable (`max()` of `int64_t` is rounded up to `2^63`)
+  if (std::trunc(num) != num
+      || num < static_cast<double>(std::numeric_limits<T>::min())
+      || num >= static_cast<double>(std::numeric_limits<T>::max()) + 1.0) {
+    throw jsi::JSError(rt, "Expected a " + std::to_string(sizeof(T) * 8) + "-bit integer, got " + value.toString(rt).utf8(rt));
+  }
+  return static_cast<T>(num);
+}
+
+template <typename T>
+struct IntVecBridging {
+  static rust::Vec<T> fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<react::CallInvoker> callInvoker) {
+    auto arr = value.asObject(rt).asArray(rt);
+    size_t len = arr.length(rt);
+    rust::Vec<T> vec;
+    vec.reserve(len);
+
+    for (size_t i = 0; i < len; i++) {
+      vec.push_back(fromJsInt<T>(rt, arr.getValueAtIndex(rt, i)));
+    }
+
+    return vec;
+  }
+
+  static jsi::Array toJs(jsi::Runtime& rt, const rust::Vec<T>& vec) {
+    auto arr = jsi::Array(rt, vec.size());
+
+    for (size_t i = 0; i < vec.size(); i++) {
+      arr.setValueAtIndex(rt, i, jsi::Value(static_cast<double>(vec[i])));
+    }
+
+    return arr;
+  }
+};
+
+/// Converts the JS BigInt to `int64_t`, throwing if it is not a BigInt within the range of `int64_t`.
+inline int64_t fromJsBigInt(jsi::Runtime& rt, const jsi::Value &value) {
+  if (!value.isBigInt()) {
+    throw jsi::JSError(rt, "Expected a BigInt, got " + value.toString(rt).utf8(rt));
+  }
+  auto bigint = value.getBigInt(rt);
+  if (!bigint.isInt64(rt)) {
+    throw jsi::JSError(rt, "Expected a 64-bit integer BigInt, got " + bigint.toString(rt).utf8(rt));
+  }
+  return bigint.asInt64(rt);
+}
+
+/// `int64_t` resolved as the JS BigInt by the promises (integers are resolved as the JS numbers).
+struct BigIntValue {
+  int64_t value;
+};
+
+} // namespace utils
+} // namespace crabytest
+} // namespace craby
+
 namespace crabytest {
 
 class RustVecBuffer : public jsi::MutableBuffer {
@@ -53,7 +120,7 @@ struct Bridging<rust::Str> {
   }
 
   static jsi::Value toJs(jsi::Runtime& rt, const rust::Str& value) {
-    return react::bridging::toJs(rt, std::string(value.data(), value.size()));
+    return jsi::String::createFromUtf8(rt, reinterpret_cast<const uint8_t*>(value.data()), value.size());
   }
 };
 
@@ -65,16 +132,22 @@ struct Bridging<rust::String> {
   }
 
   static jsi::Value toJs(jsi::Runtime& rt, const rust::String& value) {
-    return react::bridging::toJs(rt, std::string(value.data(), value.size()));
+    // `createFromUtf8` copies the bytes into the runtime before returning,
+    // so the borrowed buffer only has to outlive this call
+    return jsi::String::createFromUtf8(rt, reinterpret_cast<const uint8_t*>(value.data()), value.size());
   }
 };
 
 template <>
 struct Bridging<rust::Vec<uint8_t>> {
   static rust::Vec<uint8_t> fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<CallInvoker> callInvoker) {
-    auto arrayBuffer = value.asObject(rt).getArrayBuffer(rt);
-    uint8_t* data = arrayBuffer.data(rt);
-    size_t size = arrayBuffer.size(rt);
+    auto obj = value.asObject(rt);
+    // Typed arrays (eg. `Uint8Array`) are the views of the underlying `ArrayBuffer`
+    bool isView = !obj.isArrayBuffer(rt);
+    auto arrayBuffer = isView ? obj.getPropertyAsObject(rt, "buffer").getArrayBuffer(rt) : obj.getArrayBuffer(rt);
+    size_t offset = isView ? static_cast<size_t>(obj.getProperty(rt, "byteOffset").asNumber()) : 0;
+    size_t size = isView ? static_cast<size_t>(obj.getProperty(rt, "byteLength").asNumber()) : arrayBuffer.size(rt);
+    uint8_t* data = arrayBuffer.data(rt) + offset;
     rust::Vec<uint8_t> vec;
     vec.reserve(size);
 
@@ -117,6 +190,19 @@ struct Bridging<rust::Vec<T>> {
   }
 };
 
+template <>
+struct Bridging<rust::Vec<int32_t>> : craby::crabytest::utils::IntVecBridging<int32_t> {};
+
+template <>
+struct Bridging<rust::Vec<int64_t>> : craby::crabytest::utils::IntVecBridging<int64_t> {};
+
+template <>
+struct Bridging<craby::crabytest::utils::BigIntValue> {
+  static jsi::Value toJs(jsi::Runtime& rt, const craby::crabytest::utils::BigIntValue& value) {
+    return jsi::BigInt::fromInt64(rt, value.value);
+  }
+};
+
 template <>
 struct Bridging<craby::crabytest::bridging::MyEnum> {
   static craby::crabytest::bridging::MyEnum fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
@@ -199,7 +285,7 @@ struct Bridging<craby::crabytest::bridging::MyModuleError> {
 template <>
 struct Bridging<craby::crabytest::bridging::NullableString> {
   static craby::crabytest::bridging::NullableString fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
-    if (value.isNull()) {
+    if (value.isNull() || value.isUndefined()) {
       return craby::crabytest::bridging::NullableString{true, rust::String()};
     }
 
@@ -256,7 +342,7 @@ struct Bridging<craby::crabytest::bridging::SubObject> {
 template <>
 struct Bridging<craby::crabytest::bridging::NullableSubObject> {
   static craby::crabytest::bridging::NullableSubObject fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
-    if (value.isNull()) {
+    if (value.isNull() || value.isUndefined()) {
       return craby::crabytest::bridging::NullableSubObject{true, craby::crabytest::bridging::SubObject{}};
     }
 
@@ -358,7 +444,7 @@ struct Bridging<craby::crabytest::bridging::TestObject> {
 template <>
 struct Bridging<craby::crabytest::bridging::NullableNumber> {
   static craby::crabytest::bridging::NullableNumber fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
-    if (value.isNull()) {
+    if (value.isNull() || value.isUndefined()) {
       return craby::crabytest::bridging::NullableNumber{true, 0.0};
     }
 
@@ -1,6 +1,7 @@
 #pragma once
 
 #include "rust/cxx.h"
+#include <cstddef>
 #include <functional>
 #include <memory>
 #include <mutex>
@@ -9,53 +10,57 @@
 namespace craby {
 namespace crabytest {
 namespace bridging {
-  struct CrabyTestSignal;
-}
-namespace modules {
-  class CxxCrabyTest;
-}
-}
-}
+struct CrabyTestSignal;
+} // namespace bridging
+} // namespace crabytest
+} // namespace craby
 
 namespace craby {
 namespace crabytest {
 namespace signals {
 
-using Delegate = std::function<void(const std::string& signalName, void* signal)>;
+// Module ids are `usize` in Rust (bridged as `size_t`) and created from the module pointer
+static_assert(sizeof(size_t) == sizeof(void*), "Module id must be able to hold a pointer");
 
+template <typename Signal>
 class SignalManager {
 public:
+  // Typed with the signal enum to avoid casting the signal through `void*`
+  using Delegate = std::function<void(const std::string& signalName, Signal* signal)>;
+
   static SignalManager& getInstance() {
     static SignalManager instance;
     return instance;
   }
 
-  void emit(uintptr_t id, rust::Str name, craby::crabytest::bridging::CrabyTestSignal* signal) const {
+  void emit(size_t id, rust::Str name, Signal* signal) const {
     std::lock_guard<std::mutex> lock(mutex_);
     auto it = delegates_.find(id);
     if (it != delegates_.end()) {
-      it->second(std::string(name), reinterpret_cast<void*>(signal));
+      it->second(std::string(name), signal);
     }
   }
 
-  void registerDelegate(uintptr_t id, Delegate delegate) const {
+  void registerDelegate(size_t id, Delegate delegate) const {
     std::lock_guard<std::mutex> lock(mutex_);
     delegates_.insert_or_assign(id, delegate);
   }
 
-  void unregisterDelegate(uintptr_t id) const {
+  void unregisterDelegate(size_t id) const {
     std::lock_guard<std::mutex> lock(mutex_);
     delegates_.erase(id);
   }
 
 private:
   SignalManager() = default;
-  mutable std::unordered_map<uintptr_t, Delegate> delegates_;
+  mutable std::unordered_map<size_t, Delegate> delegates_;
   mutable std::mutex mutex_;
 };
 
-inline const SignalManager& getSignalManager() {
-  return SignalManager::getInstance();
+using CrabyTestSignalManager = SignalManager<craby::crabytest::bridging::CrabyTestSignal>;
+
+inline const CrabyTestSignalManager& getCrabyTestSignalManager() {
+  return CrabyTestSignalManager::getInstance();
 }
 
 } // namespace signals
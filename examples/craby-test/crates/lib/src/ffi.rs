@@ -1,4 +1,6 @@
 // Auto generated by Craby. DO NOT EDIT.
+#![allow(clippy::all)]
+
 #[rustfmt::skip]
 use craby::prelude::*;
 
@@ -10,61 +12,64 @@ use bridging::*;
 
 #[cxx::bridge(namespace = "craby::crabytest::bridging")]
 pub mod bridging {
-    #[derive(Clone)]
+    #[derive(Clone, Debug)]
+    struct MyModuleError {
+        reason: String,
+    }
+
+    #[derive(Clone, Debug)]
+    struct NullableNumber {
+        null: bool,
+        val: f64,
+    }
+
+    #[derive(Clone, Debug)]
     struct NullableString {
         null: bool,
         val: String,
     }
 
-    #[derive(Clone)]
-    struct TestObject {
-        foo: String,
-        bar: f64,
-        baz: bool,
-        sub: NullableSubObject,
-        camel_case: f64,
-        pascal_case: f64,
-        snake_case: f64,
+    #[derive(Clone, Debug)]
+    struct NullableSubObject {
+        null: bool,
+        val: SubObject,
     }
 
-    #[derive(Clone)]
+    #[derive(Clone, Debug)]
     struct ProgressEvent {
         progress: f64,
     }
 
-    #[derive(Clone)]
-    struct MyModuleError {
-        reason: String,
-    }
-
-    #[derive(Clone)]
+    #[derive(Clone, Debug)]
     struct SubObject {
         a: NullableString,
         b: f64,
         c: bool,
     }
 
-    #[derive(Clone)]
-    struct NullableSubObject {
-        null: bool,
-        val: SubObject,
-    }
-
-    #[derive(Clone)]
-    struct NullableNumber {
-        null: bool,
-        val: f64,
+    #[derive(Clone, Debug)]
+    struct TestObject {
+        foo: String,
+        bar: f64,
+        baz: bool,
+        sub: NullableSubObject,
+        camel_case: f64,
+        pascal_case: f64,
+        snake_case: f64,
     }
 
+    #[derive(Debug)]
     enum MyEnum {
         Foo,
         Bar,
         Baz,
     }
 
+    #[repr(i32)]
+    #[derive(Debug)]
     enum SwitchState {
-        Off,
-        On,
+        Off = 0,
+        On = 1,
     }
 
     extern "Rust" {
@@ -147,21 +152,21 @@ pub mod bridging {
 
     extern "Rust" {
         type CrabyTestSignal;
-        fn get_on_error_payload(s: &CrabyTestSignal) -> MyModuleError;
-        fn get_on_progress_payload(s: &CrabyTestSignal) -> ProgressEvent;
-        unsafe fn drop_signal(signal: *mut CrabyTestSignal);
+        fn get_on_error_payload(self: &CrabyTestSignal) -> MyModuleError;
+        fn get_on_progress_payload(self: &CrabyTestSignal) -> ProgressEvent;
+        unsafe fn drop_craby_test_signal(signal: *mut CrabyTestSignal);
     }
 
     #[namespace = "craby::crabytest::signals"]
     unsafe extern "C++" {
         include!("CrabySignals.h");
 
-        type SignalManager;
+        type CrabyTestSignalManager;
 
-        unsafe fn emit(self: &SignalManager, id: usize, name: &str, signal: *mut CrabyTestSignal);
-    
-        #[rust_name = "get_signal_manager"]
-        fn getSignalManager() -> &'static SignalManager;
+        unsafe fn emit(self: &CrabyTestSignalManager, id: usize, name: &str, signal: *mut CrabyTestSignal);
+
+        #[rust_name = "get_craby_test_signal_manager"]
+        fn getCrabyTestSignalManager() -> &'static CrabyTestSignalManager;
     }
 }
 
@@ -329,22 +334,28 @@ fn craby_test_write_data(it_: &mut CrabyTest, value: &str) -> Result<bool, anyho
     })
 }
 
-fn get_on_error_payload(s: &CrabyTestSignal) -> MyModuleError {
-    match s {
-        CrabyTestSignal::OnError(payload) => (*payload).clone(),
-        _ => panic!("Invalid signal type for get_on_error_payload"),
+impl CrabyTestSignal {
+    fn get_on_error_payload(&self) -> MyModuleError {
+        match self {
+            CrabyTestSignal::OnError(payload) => (*payload).clone(),
+            _ => panic!("Invalid signal type for get_on_error_payload"),
+        }
     }
-}
 
-fn get_on_progress_payload(s: &CrabyTestSignal) -> ProgressEvent {
-    match s {
-        CrabyTestSignal::OnProgress(payload) => (*payload).clone(),
-        _ => panic!("Invalid signal type for get_on_progress_payload"),
+    fn get_on_progress_payload(&self) -> ProgressEvent {
+        match self {
+            CrabyTestSignal::OnProgress(payload) => (*payload).clone(),
+            _ => panic!("Invalid signal type for get_on_progress_payload"),
+        }
     }
 }
 
-unsafe fn drop_signal(signal: *mut CrabyTestSignal) {
+/// # Safety
+///
+/// `signal` must be null or a pointer passed to C++ by `emit` that is not dropped yet.
+unsafe fn drop_craby_test_signal(signal: *mut CrabyTestSignal) {
     if !signal.is_null() {
-        drop(Box::from_raw(signal));
+        // SAFETY: Non-null signals are created by `Box::into_raw` and dropped only once by C++
+        drop(unsafe { Box::from_raw(signal) });
     }
 }
@@ -1,5 +1,7 @@
 // Auto generated by Craby. DO NOT EDIT.
 // Hash: 8742d8c38b968c19
+#![allow(clippy::all)]
+
 #[rustfmt::skip]
 use craby::prelude::*;
 
@@ -18,30 +20,35 @@ pub trait CrabyTestSpec {
     fn new(ctx: Context) -> Self;
     fn id(&self) -> usize;
     fn emit(&self, signal_name: CrabyTestSignal) {
-        let manager = crate::ffi::bridging::get_signal_manager();
+        let manager = crate::ffi::bridging::get_craby_test_signal_manager();
         match signal_name {
             CrabyTestSignal::OnError(data) => {
                 let signal = Box::new(CrabyTestSignal::OnError(data));
                 let signal_ptr = Box::into_raw(signal);
+                // SAFETY: `signal_ptr` is a valid pointer created by `Box::into_raw`.
+                // The ownership is transferred to C++, which releases it with `drop_craby_test_signal`
                 unsafe {
-                    manager.emit(self.id(), "onError", signal_ptr);
+                    manager.emit(self.id(), CrabyTestSignal::ON_ERROR, signal_ptr);
                 }
             }
             CrabyTestSignal::OnProgress(data) => {
                 let signal = Box::new(CrabyTestSignal::OnProgress(data));
                 let signal_ptr = Box::into_raw(signal);
+                // SAFETY: `signal_ptr` is a valid pointer created by `Box::into_raw`.
+                // The ownership is transferred to C++, which releases it with `drop_craby_test_signal`
                 unsafe {
-                    manager.emit(self.id(), "onProgress", signal_ptr);
+                    manager.emit(self.id(), CrabyTestSignal::ON_PROGRESS, signal_ptr);
                 }
             }
             CrabyTestSignal::OnSignal => {
+                // SAFETY: The signal manager accepts a null pointer for the signals without payload
                 unsafe {
-                    manager.emit(self.id(), "onSignal", std::ptr::null_mut());
+                    manager.emit(self.id(), CrabyTestSignal::ON_SIGNAL, std::ptr::null_mut());
                 }
             }
         }
     }
-    fn array_buffer_method(&mut self, arg: Vec<u8>) -> Vec<u8>; // TODO: update codegen
+    fn array_buffer_method(&mut self, arg: ArrayBuffer) -> ArrayBuffer;
     fn array_method(&mut self, arg: Array<Number>) -> Array<Number>;
     fn boolean_method(&mut self, arg: Boolean) -> Boolean;
     fn camel_method(&mut self) -> Void;
@@ -67,6 +74,12 @@ pub enum CrabyTestSignal {
     OnSignal,
 }
 
+impl CrabyTestSignal {
+    pub const ON_ERROR: &str = "onError";
+    pub const ON_PROGRESS: &str = "onProgress";
+    pub const ON_SIGNAL: &str = "onSignal";
+}
+
 impl Default for ProgressEvent {
     fn default() -> Self {
         ProgressEvent {
@@ -101,9 +114,11 @@ impl From<Nullable<SubObject>> for NullableSubObject {
     }
 }
 
-impl Default for SwitchState {
+impl Default for MyModuleError {
     fn default() -> Self {
-        SwitchState::Off
+        MyModuleError {
+            reason: String::default()
+        }
     }
 }
 
@@ -133,6 +148,47 @@ impl From<Nullable<String>> for NullableString {
     }
 }
 
+impl Default for SwitchState {
+    fn default() -> Self {
+        SwitchState::Off
+    }
+}
+
+impl TryFrom<i64> for SwitchState {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SwitchState::Off),
+            1 => Ok(SwitchState::On),
+            _ => Err(anyhow::anyhow!("Unknown `SwitchState` value: {}", value)),
+        }
+    }
+}
+
+impl TryFrom<f64> for SwitchState {
+    type Error = anyhow::Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.fract() != 0.0 {
+            return Err(anyhow::anyhow!("Unknown `SwitchState` value: {}", value));
+        }
+        SwitchState::try_from(value as i64)
+    }
+}
+
+impl From<SwitchState> for i64 {
+    fn from(value: SwitchState) -> Self {
+        i64::from(value.repr)
+    }
+}
+
+impl From<SwitchState> for f64 {
+    fn from(value: SwitchState) -> Self {
+        value.repr as f64
+    }
+}
+
 impl Default for NullableNumber {
     fn default() -> Self {
         NullableNumber {
@@ -173,17 +229,33 @@ impl Default for TestObject {
     }
 }
 
-impl Default for MyModuleError {
+impl Default for MyEnum {
     fn default() -> Self {
-        MyModuleError {
-            reason: String::default()
+        MyEnum::Foo
+    }
+}
+
+impl MyEnum {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            MyEnum::Foo => "foo",
+            MyEnum::Bar => "bar",
+            MyEnum::Baz => "baz",
+            _ => unreachable!(),
         }
     }
 }
 
-impl Default for MyEnum {
-    fn default() -> Self {
-        MyEnum::Foo
+impl TryFrom<&str> for MyEnum {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "foo" => Ok(MyEnum::Foo),
+            "bar" => Ok(MyEnum::Bar),
+            "baz" => Ok(MyEnum::Baz),
+            _ => Err(anyhow::anyhow!("Unknown `MyEnum` value: {:?}", value)),
+        }
     }
 }
 
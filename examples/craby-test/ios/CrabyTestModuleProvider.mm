@@ -13,18 +13,15 @@
   const char *cDataPath = [[self getDataPath] UTF8String];
   std::string dataPath(cDataPath);
 
-  craby::crabytest::modules::CxxCalculatorModule::dataPath = dataPath;
-  craby::crabytest::modules::CxxCrabyTestModule::dataPath = dataPath;
-
   facebook::react::registerCxxModuleToGlobalModuleMap(
       craby::crabytest::modules::CxxCalculatorModule::kModuleName,
-      [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
-        return std::make_shared<craby::crabytest::modules::CxxCalculatorModule>(jsInvoker);
+      [dataPath](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
+        return std::make_shared<craby::crabytest::modules::CxxCalculatorModule>(jsInvoker, dataPath);
       });
   facebook::react::registerCxxModuleToGlobalModuleMap(
       craby::crabytest::modules::CxxCrabyTestModule::kModuleName,
-      [](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
-        return std::make_shared<craby::crabytest::modules::CxxCrabyTestModule>(jsInvoker);
+      [dataPath](std::shared_ptr<facebook::react::CallInvoker> jsInvoker) {
+        return std::make_shared<craby::crabytest::modules::CxxCrabyTestModule>(jsInvoker, dataPath);
       });
 }
 
@@ -4,12 +4,14 @@ export declare function build(opts: BuildOptions): void
 
 export interface BuildOptions {
   projectRoot: string
+  targets?: Array<string>
 }
 
 export declare function clean(opts: CleanOptions): void
 
 export interface CleanOptions {
   projectRoot: string
+  scope?: string
 }
 
 export declare function codegen(opts: CodegenOptions): void
@@ -17,6 +19,28 @@ export declare function codegen(opts: CodegenOptions): void
 export interface CodegenOptions {
   projectRoot: string
   overwrite: boolean
+  stubDefaults?: boolean
+  cShim?: boolean
+  listenerErrorHook?: boolean
+  swiftHeader?: boolean
+  moduleRegistry?: boolean
+  lazyRegistration?: boolean
+  unimplementedError?: boolean
+  docs?: boolean
+  kotlinStubs?: boolean
+  dts?: boolean
+  mocks?: boolean
+  check?: boolean
+  dryRun?: boolean
+  diagnosticsOut?: string
+  depfile?: string
+}
+
+export declare function config(opts: ConfigOptions): void
+
+export interface ConfigOptions {
+  projectRoot: string
+  json?: boolean
 }
 
 export declare function debug(message: string): void
@@ -25,6 +49,7 @@ export declare function doctor(opts: DoctorOptions): void
 
 export interface DoctorOptions {
   projectRoot: string
+  json?: boolean
 }
 
 export declare function error(message: string): void
@@ -44,8 +69,28 @@ export declare function show(opts: ShowOptions): void
 
 export interface ShowOptions {
   projectRoot: string
+  ffiSurface?: boolean
+  json?: boolean
 }
 
 export declare function trace(message: string): void
 
 export declare function warn(message: string): void
+
+export declare function watch(opts: WatchOptions): void
+
+export interface WatchOptions {
+  projectRoot: string
+  overwrite: boolean
+  stubDefaults?: boolean
+  cShim?: boolean
+  listenerErrorHook?: boolean
+  swiftHeader?: boolean
+  moduleRegistry?: boolean
+  lazyRegistration?: boolean
+  unimplementedError?: boolean
+  docs?: boolean
+  kotlinStubs?: boolean
+  dts?: boolean
+  mocks?: boolean
+}
@@ -508,10 +508,11 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { build, clean, codegen, debug, doctor, error, info, init, setup, show, trace, warn } = nativeBinding
+const { build, clean, codegen, config, debug, doctor, error, info, init, setup, show, trace, warn } = nativeBinding
 export { build }
 export { clean }
 export { codegen }
+export { config }
 export { debug }
 export { doctor }
 export { error }
@@ -45,6 +45,21 @@ pub fn init(opts: InitOptions) -> napi::Result<()> {
 pub struct CodegenOptions {
     pub project_root: String,
     pub overwrite: bool,
+    pub stub_defaults: Option<bool>,
+    pub c_shim: Option<bool>,
+    pub listener_error_hook: Option<bool>,
+    pub swift_header: Option<bool>,
+    pub module_registry: Option<bool>,
+    pub lazy_registration: Option<bool>,
+    pub unimplemented_error: Option<bool>,
+    pub docs: Option<bool>,
+    pub kotlin_stubs: Option<bool>,
+    pub dts: Option<bool>,
+    pub mocks: Option<bool>,
+    pub check: Option<bool>,
+    pub dry_run: Option<bool>,
+    pub diagnostics_out: Option<String>,
+    pub depfile: Option<String>,
 }
 
 #[napi]
@@ -52,6 +67,21 @@ pub fn codegen(opts: CodegenOptions) -> napi::Result<()> {
     let opts = craby_cli::commands::codegen::CodegenOptions {
         project_root: opts.project_root.into(),
         overwrite: opts.overwrite,
+        stub_defaults: opts.stub_defaults.unwrap_or(false),
+        c_shim: opts.c_shim.unwrap_or(false),
+        listener_error_hook: opts.listener_error_hook.unwrap_or(false),
+        swift_header: opts.swift_header.unwrap_or(false),
+        module_registry: opts.module_registry.unwrap_or(false),
+        lazy_registration: opts.lazy_registration.unwrap_or(false),
+        unimplemented_error: opts.unimplemented_error.unwrap_or(false),
+        docs: opts.docs.unwrap_or(false),
+        kotlin_stubs: opts.kotlin_stubs.unwrap_or(false),
+        dts: opts.dts.unwrap_or(false),
+        mocks: opts.mocks.unwrap_or(false),
+        check: opts.check.unwrap_or(false),
+        dry_run: opts.dry_run.unwrap_or(false),
+        diagnostics_out: opts.diagnostics_out.map(Into::into),
+        depfile: opts.depfile.map(Into::into),
     };
 
     match craby_cli::commands::codegen::perform(opts) {
@@ -66,12 +96,20 @@ pub fn codegen(opts: CodegenOptions) -> napi::Result<()> {
 #[napi(object)]
 pub struct BuildOptions {
     pub project_root: String,
+    pub targets: Option<Vec<String>>,
 }
 
 #[napi]
 pub fn build(opts: BuildOptions) -> napi::Result<()> {
+    let targets = opts
+        .targets
+        .as_deref()
+        .map(craby_cli::commands::build::parse_build_targets)
+        .transpose()
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
     let opts = craby_cli::commands::build::BuildOptions {
         project_root: opts.project_root.into(),
+        targets,
     };
 
     match craby_cli::commands::build::perform(opts) {
@@ -86,12 +124,16 @@ pub fn build(opts: BuildOptions) -> napi::Result<()> {
 #[napi(object)]
 pub struct ShowOptions {
     pub project_root: String,
+    pub ffi_surface: Option<bool>,
+    pub json: Option<bool>,
 }
 
 #[napi]
 pub fn show(opts: ShowOptions) -> napi::Result<()> {
     let opts = craby_cli::commands::show::ShowOptions {
         project_root: opts.project_root.into(),
+        ffi_surface: opts.ffi_surface.unwrap_or(false),
+        json: opts.json.unwrap_or(false),
     };
 
     match craby_cli::commands::show::perform(opts) {
@@ -103,15 +145,39 @@ pub fn show(opts: ShowOptions) -> napi::Result<()> {
     }
 }
 
+#[napi(object)]
+pub struct ConfigOptions {
+    pub project_root: String,
+    pub json: Option<bool>,
+}
+
+#[napi]
+pub fn config(opts: ConfigOptions) -> napi::Result<()> {
+    let opts = craby_cli::commands::config::ConfigOptions {
+        project_root: opts.project_root.into(),
+        json: opts.json.unwrap_or(false),
+    };
+
+    match craby_cli::commands::config::perform(opts) {
+        Err(e) => Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            e.to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[napi(object)]
 pub struct DoctorOptions {
     pub project_root: String,
+    pub json: Option<bool>,
 }
 
 #[napi]
 pub fn doctor(opts: DoctorOptions) -> napi::Result<()> {
     let opts = craby_cli::commands::doctor::DoctorOptions {
         project_root: opts.project_root.into(),
+        json: opts.json.unwrap_or(false),
     };
 
     match craby_cli::commands::doctor::perform(opts) {
@@ -126,12 +192,20 @@ pub fn doctor(opts: DoctorOptions) -> napi::Result<()> {
 #[napi(object)]
 pub struct CleanOptions {
     pub project_root: String,
+    pub scope: Option<String>,
 }
 
 #[napi]
 pub fn clean(opts: CleanOptions) -> napi::Result<()> {
+    let scope = opts
+        .scope
+        .as_deref()
+        .map(craby_cli::commands::clean::CleanScope::try_from)
+        .transpose()
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
     let opts = craby_cli::commands::clean::CleanOptions {
         project_root: opts.project_root.into(),
+        scope: scope.unwrap_or(craby_cli::commands::clean::CleanScope::All),
     };
 
     match craby_cli::commands::clean::perform(opts) {
@@ -143,6 +217,50 @@ pub fn clean(opts: CleanOptions) -> napi::Result<()> {
     }
 }
 
+#[napi(object)]
+pub struct WatchOptions {
+    pub project_root: String,
+    pub overwrite: bool,
+    pub stub_defaults: Option<bool>,
+    pub c_shim: Option<bool>,
+    pub listener_error_hook: Option<bool>,
+    pub swift_header: Option<bool>,
+    pub module_registry: Option<bool>,
+    pub lazy_registration: Option<bool>,
+    pub unimplemented_error: Option<bool>,
+    pub docs: Option<bool>,
+    pub kotlin_stubs: Option<bool>,
+    pub dts: Option<bool>,
+    pub mocks: Option<bool>,
+}
+
+#[napi]
+pub fn watch(opts: WatchOptions) -> napi::Result<()> {
+    let opts = craby_cli::commands::watch::WatchOptions {
+        project_root: opts.project_root.into(),
+        overwrite: opts.overwrite,
+        stub_defaults: opts.stub_defaults.unwrap_or(false),
+        c_shim: opts.c_shim.unwrap_or(false),
+        listener_error_hook: opts.listener_error_hook.unwrap_or(false),
+        swift_header: opts.swift_header.unwrap_or(false),
+        module_registry: opts.module_registry.unwrap_or(false),
+        lazy_registration: opts.lazy_registration.unwrap_or(false),
+        unimplemented_error: opts.unimplemented_error.unwrap_or(false),
+        docs: opts.docs.unwrap_or(false),
+        kotlin_stubs: opts.kotlin_stubs.unwrap_or(false),
+        dts: opts.dts.unwrap_or(false),
+        mocks: opts.mocks.unwrap_or(false),
+    };
+
+    match craby_cli::commands::watch::perform(opts) {
+        Err(e) => Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            e.to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[napi]
 pub fn trace(message: String) {
     trace!("{}", message);
@@ -4,6 +4,28 @@ type NativeModule = {};
 
 type Signal<T = void> = (handler: (data: T) => void) => () => void;
 
+/**
+ * 32-bit signed integer (`i32` in Rust).
+ *
+ * Non-integer or out of range values are rejected by the native module.
+ */
+type Int32 = number;
+
+/**
+ * 64-bit signed integer (`i64` in Rust).
+ *
+ * Values beyond `Number.MAX_SAFE_INTEGER` lose precision on the JavaScript side.
+ */
+type Int64 = number;
+
+/**
+ * Promise returned by the `@cancellable` methods.
+ *
+ * Calling `cancel()` sets the cancellation flag of the Rust-side `CancellationToken`.
+ * The promise is settled by the Rust implementation (eg. rejected after it observes the flag).
+ */
+type CancellablePromise<T> = Promise<T> & { cancel(): void };
+
 /**
  * Android JNI initialization workaround
  *
@@ -43,4 +65,4 @@ export const NativeModuleRegistry: NativeModuleRegistry = {
   },
 };
 
-export type { NativeModule, Signal };
+export type { CancellablePromise, Int32, Int64, NativeModule, Signal };
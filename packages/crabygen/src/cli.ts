@@ -3,9 +3,11 @@ import { version } from '../package.json';
 import { command as buildCommand } from './commands/build';
 import { command as cleanCommand } from './commands/clean';
 import { command as codegenCommand } from './commands/codegen';
+import { command as configCommand } from './commands/config';
 import { command as doctorCommand } from './commands/doctor';
 import { command as initCommand } from './commands/init';
 import { command as showCommand } from './commands/show';
+import { command as watchCommand } from './commands/watch';
 
 export function run(baseCommand: string) {
   const cli = program.name(baseCommand).version(version);
@@ -14,8 +16,10 @@ export function run(baseCommand: string) {
   cli.addCommand(initCommand);
   cli.addCommand(buildCommand);
   cli.addCommand(showCommand);
+  cli.addCommand(configCommand);
   cli.addCommand(doctorCommand);
   cli.addCommand(cleanCommand);
+  cli.addCommand(watchCommand);
 
   cli.parse(
     isCodegenCommand(process.argv)
@@ -3,6 +3,17 @@ import { build } from '@craby/cli-bindings';
 import { withVerbose } from '../utils/command';
 import { withErrorHandler } from '../utils/errors';
 
+interface BuildCommandOptions {
+  target?: string[];
+}
+
 export const command = withVerbose(
-  new Command().name('build').action(withErrorHandler(build.bind(null, { projectRoot: process.cwd() }))),
+  new Command()
+    .name('build')
+    .option('--target <triple...>', 'Build only the given target triples (eg. `aarch64-linux-android`)')
+    .action(
+      withErrorHandler((options: BuildCommandOptions) =>
+        build({ projectRoot: process.cwd(), targets: options.target }),
+      ),
+    ),
 );
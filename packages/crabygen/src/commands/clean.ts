@@ -3,6 +3,15 @@ import { clean } from '@craby/cli-bindings';
 import { withVerbose } from '../utils/command';
 import { withErrorHandler } from '../utils/errors';
 
+interface CleanCommandOptions {
+  scope?: string;
+}
+
 export const command = withVerbose(
-  new Command().name('clean').action(withErrorHandler(clean.bind(null, { projectRoot: process.cwd() }))),
+  new Command()
+    .name('clean')
+    .option('--scope <scope>', 'Category of the files to clean (all, rust, cxx or artifacts)')
+    .action(
+      withErrorHandler((options: CleanCommandOptions) => clean({ projectRoot: process.cwd(), scope: options.scope })),
+    ),
 );
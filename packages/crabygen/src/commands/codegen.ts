@@ -1,13 +1,68 @@
+import path from 'node:path';
 import { Command } from '@commander-js/extra-typings';
 import { codegen } from '@craby/cli-bindings';
 import { withVerbose } from '../utils/command';
 import { withErrorHandler } from '../utils/errors';
 
-export const runCodegen = withErrorHandler((overwrite: boolean) => codegen({ projectRoot: process.cwd(), overwrite }));
+interface CodegenCommandOptions {
+  overwrite: boolean;
+  stubDefaults?: boolean;
+  cShim?: boolean;
+  listenerErrorHook?: boolean;
+  swiftHeader?: boolean;
+  moduleRegistry?: boolean;
+  lazyRegistration?: boolean;
+  unimplementedError?: boolean;
+  docs?: boolean;
+  kotlinStubs?: boolean;
+  dts?: boolean;
+  mocks?: boolean;
+  check?: boolean;
+  dryRun?: boolean;
+  diagnosticsOut?: string;
+  depfile?: string;
+}
+
+export const runCodegen = withErrorHandler((options: CodegenCommandOptions) =>
+  codegen({
+    projectRoot: process.cwd(),
+    overwrite: options.overwrite,
+    stubDefaults: options.stubDefaults,
+    cShim: options.cShim,
+    listenerErrorHook: options.listenerErrorHook,
+    swiftHeader: options.swiftHeader,
+    moduleRegistry: options.moduleRegistry,
+    lazyRegistration: options.lazyRegistration,
+    unimplementedError: options.unimplementedError,
+    docs: options.docs,
+    kotlinStubs: options.kotlinStubs,
+    dts: options.dts,
+    mocks: options.mocks,
+    check: options.check,
+    dryRun: options.dryRun,
+    diagnosticsOut: options.diagnosticsOut ? path.resolve(options.diagnosticsOut) : undefined,
+    depfile: options.depfile ? path.resolve(options.depfile) : undefined,
+  }),
+);
 
 export const command = withVerbose(
   new Command()
     .name('codegen')
     .option('--no-overwrite', 'Do not overwrite existing files')
-    .action((options) => runCodegen(options.overwrite)),
+    .option('--stub-defaults', 'Generate method stubs returning default values instead of `unimplemented!()`')
+    .option('--c-shim', 'Generate `extern "C"` shim of the module methods')
+    .option('--listener-error-hook', 'Report exceptions thrown by the signal listeners to the Rust module')
+    .option('--swift-header', 'Generate a Swift-friendly header of the iOS module provider')
+    .option('--module-registry', 'Generate a shared registry of the module factories')
+    .option('--lazy-registration', 'Defer loading the native library and resolving the data path until first access')
+    .option('--unimplemented-error', 'Throw a descriptive error for the unimplemented methods')
+    .option('--docs', 'Generate a markdown usage snippet per module')
+    .option('--kotlin-stubs', 'Generate a Kotlin object with typed method stubs per module')
+    .option('--dts', 'Generate TypeScript declarations that re-export the specs with the module names')
+    .option('--mocks', 'Generate a JS mock per module for the unit tests without the native module')
+    .option('--check', 'Fail if the generated files are out of date instead of writing them')
+    .option('--dry-run', 'Print the changes of the generated files instead of writing them')
+    .option('--diagnostics-out <path>', 'Write diagnostics to the given path as JSON')
+    .option('--depfile <path>', 'Write the spec inputs and generated outputs to the given path as a depfile')
+    .action((options) => runCodegen(options)),
 );
@@ -0,0 +1,17 @@
+import { Command } from '@commander-js/extra-typings';
+import { config } from '@craby/cli-bindings';
+import { withVerbose } from '../utils/command';
+import { withErrorHandler } from '../utils/errors';
+
+interface ConfigCommandOptions {
+  json?: boolean;
+}
+
+export const command = withVerbose(
+  new Command()
+    .name('config')
+    .option('--json', 'Print the resolved configuration as JSON')
+    .action(
+      withErrorHandler((options: ConfigCommandOptions) => config({ projectRoot: process.cwd(), json: options.json })),
+    ),
+);
@@ -3,6 +3,15 @@ import { doctor } from '@craby/cli-bindings';
 import { withVerbose } from '../utils/command';
 import { withErrorHandler } from '../utils/errors';
 
+interface DoctorCommandOptions {
+  json?: boolean;
+}
+
 export const command = withVerbose(
-  new Command().name('doctor').action(withErrorHandler(doctor.bind(null, { projectRoot: process.cwd() }))),
+  new Command()
+    .name('doctor')
+    .option('--json', 'Print the results of the checks as JSON')
+    .action(
+      withErrorHandler((options: DoctorCommandOptions) => doctor({ projectRoot: process.cwd(), json: options.json })),
+    ),
 );
@@ -3,6 +3,19 @@ import { show } from '@craby/cli-bindings';
 import { withVerbose } from '../utils/command';
 import { withErrorHandler } from '../utils/errors';
 
+interface ShowCommandOptions {
+  ffiSurface?: boolean;
+  json?: boolean;
+}
+
 export const command = withVerbose(
-  new Command().name('show').action(withErrorHandler(show.bind(null, { projectRoot: process.cwd() }))),
+  new Command()
+    .name('show')
+    .option('--ffi-surface', 'Print the number of generated FFI items per module')
+    .option('--json', 'Print the parsed module specifications as JSON')
+    .action(
+      withErrorHandler((options: ShowCommandOptions) =>
+        show({ projectRoot: process.cwd(), ffiSurface: options.ffiSurface, json: options.json }),
+      ),
+    ),
 );
@@ -0,0 +1,55 @@
+import { Command } from '@commander-js/extra-typings';
+import { watch } from '@craby/cli-bindings';
+import { withVerbose } from '../utils/command';
+import { withErrorHandler } from '../utils/errors';
+
+interface WatchCommandOptions {
+  overwrite: boolean;
+  stubDefaults?: boolean;
+  cShim?: boolean;
+  listenerErrorHook?: boolean;
+  swiftHeader?: boolean;
+  moduleRegistry?: boolean;
+  lazyRegistration?: boolean;
+  unimplementedError?: boolean;
+  docs?: boolean;
+  kotlinStubs?: boolean;
+  dts?: boolean;
+  mocks?: boolean;
+}
+
+export const runWatch = withErrorHandler((options: WatchCommandOptions) =>
+  watch({
+    projectRoot: process.cwd(),
+    overwrite: options.overwrite,
+    stubDefaults: options.stubDefaults,
+    cShim: options.cShim,
+    listenerErrorHook: options.listenerErrorHook,
+    swiftHeader: options.swiftHeader,
+    moduleRegistry: options.moduleRegistry,
+    lazyRegistration: options.lazyRegistration,
+    unimplementedError: options.unimplementedError,
+    docs: options.docs,
+    kotlinStubs: options.kotlinStubs,
+    dts: options.dts,
+    mocks: options.mocks,
+  }),
+);
+
+export const command = withVerbose(
+  new Command()
+    .name('watch')
+    .option('--no-overwrite', 'Do not overwrite existing files')
+    .option('--stub-defaults', 'Generate method stubs returning default values instead of `unimplemented!()`')
+    .option('--c-shim', 'Generate `extern "C"` shim of the module methods')
+    .option('--listener-error-hook', 'Report exceptions thrown by the signal listeners to the Rust module')
+    .option('--swift-header', 'Generate a Swift-friendly header of the iOS module provider')
+    .option('--module-registry', 'Generate a shared registry of the module factories')
+    .option('--lazy-registration', 'Defer loading the native library and resolving the data path until first access')
+    .option('--unimplemented-error', 'Throw a descriptive error for the unimplemented methods')
+    .option('--docs', 'Generate a markdown usage snippet per module')
+    .option('--kotlin-stubs', 'Generate a Kotlin object with typed method stubs per module')
+    .option('--dts', 'Generate TypeScript declarations that re-export the specs with the module names')
+    .option('--mocks', 'Generate a JS mock per module for the unit tests without the native module')
+    .action((options) => runWatch(options)),
+);
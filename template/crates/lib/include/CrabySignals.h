@@ -2,6 +2,7 @@
 
 #include "rust/cxx.h"
 
+#include <cstddef>
 #include <functional>
 #include <memory>
 #include <mutex>
@@ -11,6 +12,9 @@ namespace craby {
 namespace {{ flat_name }} {
 namespace signals {
 
+// Module ids are `usize` in Rust (bridged as `size_t`) and created from the module pointer
+static_assert(sizeof(size_t) == sizeof(void*), "Module id must be able to hold a pointer");
+
 using Delegate = std::function<void(const std::string& signalName)>;
 
 class SignalManager {
@@ -20,7 +24,7 @@ public:
     return instance;
   }
 
-  void emit(uintptr_t id, rust::Str name) const {
+  void emit(size_t id, rust::Str name) const {
     std::lock_guard<std::mutex> lock(mutex_);
     auto it = delegates_.find(id);
     if (it != delegates_.end()) {
@@ -28,19 +32,19 @@ public:
     }
   }
 
-  void registerDelegate(uintptr_t id, Delegate delegate) const {
+  void registerDelegate(size_t id, Delegate delegate) const {
     std::lock_guard<std::mutex> lock(mutex_);
     delegates_.insert_or_assign(id, delegate);
   }
 
-  void unregisterDelegate(uintptr_t id) const {
+  void unregisterDelegate(size_t id) const {
     std::lock_guard<std::mutex> lock(mutex_);
     delegates_.erase(id);
   }
 
 private:
   SignalManager() = default;
-  mutable std::unordered_map<uintptr_t, Delegate> delegates_;
+  mutable std::unordered_map<size_t, Delegate> delegates_;
   mutable std::mutex mutex_;
 };
 
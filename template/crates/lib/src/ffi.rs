@@ -1,4 +1,6 @@
 // Auto generated by Craby. DO NOT EDIT.
+#![allow(clippy::all)]
+
 #[rustfmt::skip]
 use craby::prelude::*;
 
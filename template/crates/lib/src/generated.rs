@@ -1,5 +1,7 @@
 // Auto generated by Craby. DO NOT EDIT.
 // Hash:
+#![allow(clippy::all)]
+
 #[rustfmt::skip]
 use craby::prelude::*;
 